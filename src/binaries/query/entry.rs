@@ -50,10 +50,7 @@ async fn run_cmd(conf: &InnerConfig) -> Result<bool> {
             println!("version: {}", *QUERY_SEMVER);
             println!("min-compatible-metasrv-version: {}", MIN_METASRV_SEMVER);
         }
-        "local" => {
-            println!("exec local query: {}", conf.local.sql);
-            local::query_local(conf).await?
-        }
+        "local" => local::query_local(conf).await?,
         _ => {
             eprintln!("Invalid cmd: {}", conf.cmd);
             eprintln!("Available cmds:");
@@ -0,0 +1,135 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Instant;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::block_debug::box_render;
+use common_expression::infer_table_schema;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_formats::ClickhouseFormatType;
+use common_formats::FileFormatOptionsExt;
+use common_settings::Settings;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Csv,
+    Json,
+    Parquet,
+}
+
+impl FromStr for OutputFormat {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(OutputFormat::Table),
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            "parquet" => Ok(OutputFormat::Parquet),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Unknown output format: {s}, expect one of table, csv, json, parquet"
+            ))),
+        }
+    }
+}
+
+impl OutputFormat {
+    // The clickhouse format of the output, `Table` is rendered by ourselves.
+    fn clickhouse_format(&self) -> Option<&'static str> {
+        match self {
+            OutputFormat::Table => None,
+            OutputFormat::Csv => Some("CSVWithNames"),
+            OutputFormat::Json => Some("NDJSON"),
+            OutputFormat::Parquet => Some("Parquet"),
+        }
+    }
+}
+
+pub struct Display<'a> {
+    format: OutputFormat,
+    settings: &'a Settings,
+    schema: DataSchemaRef,
+    blocks: &'a [DataBlock],
+    start: Instant,
+}
+
+impl<'a> Display<'a> {
+    pub fn new(
+        format: OutputFormat,
+        settings: &'a Settings,
+        schema: DataSchemaRef,
+        blocks: &'a [DataBlock],
+        start: Instant,
+    ) -> Self {
+        Self {
+            format,
+            settings,
+            schema,
+            blocks,
+            start,
+        }
+    }
+
+    pub fn display(&self) -> Result<()> {
+        match self.format.clickhouse_format() {
+            None => self.display_table(),
+            Some(name) => self.display_formatted(name),
+        }
+    }
+
+    fn display_table(&self) -> Result<()> {
+        let row_count: usize = self.blocks.iter().map(|block| block.num_rows()).sum();
+        if !self.schema.fields().is_empty() {
+            println!("{}", box_render(&self.schema, self.blocks)?);
+        }
+        println!(
+            "{} {} in set. Query took {:.3} seconds.",
+            row_count,
+            if row_count == 1 { "row" } else { "rows" },
+            self.start.elapsed().as_secs_f64()
+        );
+        println!();
+        Ok(())
+    }
+
+    // Write the result to stdout without the timing info, so that it can be piped to other tools.
+    fn display_formatted(&self, name: &str) -> Result<()> {
+        if self.schema.fields().is_empty() {
+            return Ok(());
+        }
+
+        let format = ClickhouseFormatType::parse_clickhouse_format(name)?;
+        let schema = infer_table_schema(&self.schema)?;
+        let mut output = FileFormatOptionsExt::get_output_format_from_clickhouse_format(
+            format,
+            schema,
+            self.settings,
+        )?;
+
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(&output.serialize_prefix()?)?;
+        for block in self.blocks {
+            stdout.write_all(&output.serialize_block(block)?)?;
+        }
+        stdout.write_all(&output.finalize()?)?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_meta_app::principal::UserInfo;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::Session;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use tokio_stream::StreamExt;
+
+use crate::local::display::Display;
+use crate::local::display::OutputFormat;
+use crate::local::helper::split_statements;
+
+const PROMPT: &str = ":) ";
+const PROMPT_CONTINUE: &str = ":-] ";
+
+pub struct SessionExecutor {
+    session: Arc<Session>,
+    output_format: OutputFormat,
+}
+
+impl SessionExecutor {
+    pub async fn try_create(output_format: OutputFormat) -> Result<Self> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Local)
+            .await?;
+        let user = UserInfo::new_no_auth("root", "127.0.0.1");
+        session.set_authed_user(user, None).await?;
+
+        let executor = Self {
+            session,
+            output_format,
+        };
+        executor
+            .execute("CREATE DATABASE IF NOT EXISTS default", false)
+            .await?;
+        Ok(executor)
+    }
+
+    /// Run the statements of the script one by one, stop at the first error.
+    pub async fn run_script(&self, script: &str) -> Result<()> {
+        let (mut statements, rest) = split_statements(script)?;
+        if !rest.is_empty() {
+            statements.push(rest);
+        }
+        for sql in statements {
+            self.execute(&sql, true).await?;
+        }
+        Ok(())
+    }
+
+    /// Read statements from the prompt until `exit`, `quit` or EOF,
+    /// a statement may span multiple lines and ends with `;`.
+    pub async fn run_repl(&self, history_path: PathBuf) -> Result<()> {
+        let mut editor = DefaultEditor::new().map_err(readline_error)?;
+        // The history file does not exist on the first run.
+        let _ = editor.load_history(&history_path);
+
+        println!("Welcome to databend local mode, statements end with `;`.");
+        println!();
+
+        let mut buffer = String::new();
+        loop {
+            let prompt = if buffer.is_empty() {
+                PROMPT
+            } else {
+                PROMPT_CONTINUE
+            };
+            match editor.readline(prompt) {
+                Ok(line) => {
+                    let line = line.trim();
+                    if buffer.is_empty() && matches!(line.to_lowercase().as_str(), "exit" | "quit")
+                    {
+                        break;
+                    }
+                    if line.is_empty() {
+                        continue;
+                    }
+                    if !buffer.is_empty() {
+                        buffer.push('\n');
+                    }
+                    buffer.push_str(line);
+
+                    let (statements, rest) = match split_statements(&buffer) {
+                        Ok(res) => res,
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            buffer.clear();
+                            continue;
+                        }
+                    };
+                    if statements.is_empty() {
+                        continue;
+                    }
+                    let _ = editor.add_history_entry(buffer.as_str());
+                    buffer = rest;
+                    for sql in statements {
+                        if let Err(e) = self.execute(&sql, true).await {
+                            eprintln!("Error: {}", e);
+                        }
+                    }
+                }
+                Err(ReadlineError::Interrupted) => {
+                    // Ctrl-C discards the statement being typed.
+                    buffer.clear();
+                }
+                Err(ReadlineError::Eof) => break,
+                Err(e) => return Err(readline_error(e)),
+            }
+        }
+
+        if let Err(e) = editor.save_history(&history_path) {
+            eprintln!(
+                "Failed to save history to {}: {}",
+                history_path.display(),
+                e
+            );
+        }
+        println!("Bye~");
+        Ok(())
+    }
+
+    pub async fn execute(&self, sql: &str, display: bool) -> Result<()> {
+        let start = Instant::now();
+        let res: Result<()> = try {
+            let ctx = self.session.create_query_context().await?;
+            let mut planner = Planner::new(ctx.clone());
+            let (plan, _) = planner.plan_sql(sql).await?;
+            let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+            let stream = interpreter.execute(ctx.clone()).await?;
+            let blocks = stream.collect::<Result<Vec<DataBlock>>>().await?;
+
+            if display {
+                let settings = ctx.get_settings();
+                Display::new(
+                    self.output_format,
+                    &settings,
+                    interpreter.schema(),
+                    &blocks,
+                    start,
+                )
+                .display()?;
+            }
+        };
+        res.map_err(|err| err.display_with_sql(sql))
+    }
+}
+
+fn readline_error(e: ReadlineError) -> ErrorCode {
+    ErrorCode::Internal(format!("Readline error: {e}"))
+}
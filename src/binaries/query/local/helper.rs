@@ -0,0 +1,300 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::path::Path;
+
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Split the sql text into statements by `;`, the `;` in strings and comments are ignored.
+///
+/// Returns the complete statements and the rest of the text without a `;` at the end.
+pub fn split_statements(sql: &str) -> Result<(Vec<String>, String)> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    for token in Tokenizer::new(sql) {
+        let token = token?;
+        if token.kind == TokenKind::SemiColon {
+            let statement = sql[start..token.span.start].trim();
+            if !statement.is_empty() {
+                statements.push(statement.to_string());
+            }
+            start = token.span.end;
+        }
+    }
+    Ok((statements, sql[start..].trim().to_string()))
+}
+
+// Parse `name1=filepath1,name2=filepath2`.
+pub fn parse_tables(tables: &str) -> Result<Vec<(String, String)>> {
+    tables
+        .split(',')
+        .map(|table| table.trim())
+        .filter(|table| !table.is_empty())
+        .map(|table| match table.split_once('=') {
+            Some((name, path)) if !name.trim().is_empty() && !path.trim().is_empty() => {
+                Ok((name.trim().to_string(), path.trim().to_string()))
+            }
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Invalid table {table}, expect name=path"
+            ))),
+        })
+        .collect()
+}
+
+/// The statements to register the file `path` as table `name`, each with the statement to undo it,
+/// the format of the file is detected by its extension.
+///
+/// The table is an external table over a stage of the directory of the file, so the file is read
+/// in place instead of being copied, and the table and the stage are dropped by the undo statements.
+pub fn register_table_statements(name: &str, path: &str) -> Result<Vec<(String, String)>> {
+    let file = Path::new(path);
+    let location = std::fs::canonicalize(file)
+        .map_err(|e| ErrorCode::BadArguments(format!("Cannot open file {path}: {e}")))?;
+    let (Some(dir), Some(file_name)) = (
+        location.parent(),
+        location.file_name().and_then(|name| name.to_str()),
+    ) else {
+        return Err(ErrorCode::BadArguments(format!("Invalid file {path}")));
+    };
+    let file_name = escape_at_string(file_name).ok_or_else(|| {
+        ErrorCode::BadArguments(format!("Unsupported characters in file name {path}"))
+    })?;
+    let extension = file
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+
+    let (columns, file_format) = match extension.as_str() {
+        "parquet" => (String::new(), "TYPE = PARQUET"),
+        "csv" => (
+            format!(" ({})", column_defs(&csv_header(file)?, "VARCHAR")),
+            "TYPE = CSV SKIP_HEADER = 1",
+        ),
+        "ndjson" | "jsonl" => (
+            format!(" ({})", column_defs(&ndjson_keys(file)?, "VARIANT")),
+            "TYPE = NDJSON",
+        ),
+        _ => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Cannot detect the format of file {path}, expect .parquet, .csv, .ndjson or .jsonl"
+            )));
+        }
+    };
+
+    let table = format!("`{}`", name.replace('`', "``"));
+    let stage = stage_name(name);
+    let url = format!("fs://{}/", dir.display()).replace('\'', "\\'");
+    Ok(vec![
+        (
+            format!("CREATE STAGE {stage} URL = '{url}'"),
+            format!("DROP STAGE IF EXISTS {stage}"),
+        ),
+        (
+            format!(
+                "CREATE TABLE {table}{columns} ENGINE = EXTERNAL LOCATION = @{stage}/{file_name} FILE_FORMAT = ({file_format})"
+            ),
+            format!("DROP TABLE IF EXISTS {table}"),
+        ),
+    ])
+}
+
+// The stage of the directory of the file registered as table `name`.
+fn stage_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    format!("local_table_{name}")
+}
+
+// Escape the file name in `@stage/<file name>`, `None` if it can not be escaped.
+fn escape_at_string(s: &str) -> Option<String> {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            ';' | '`' => return None,
+            c if c.is_whitespace() || matches!(c, '\'' | '"' | '\\') => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    Some(escaped)
+}
+
+fn column_defs(columns: &[String], data_type: &str) -> String {
+    columns
+        .iter()
+        .map(|column| format!("`{}` {data_type} NULL", column.replace('`', "``")))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// The column names in the header line of the CSV file.
+fn csv_header(file: &Path) -> Result<Vec<String>> {
+    let line = first_line(file)?;
+    match split_csv_line(&line) {
+        Some(columns) if columns.iter().all(|column| !column.is_empty()) => Ok(columns),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "Invalid header of CSV file {}: {line}",
+            file.display()
+        ))),
+    }
+}
+
+// Split a CSV line by `,`, the `,` in the fields quoted by `"` are kept and `""` in them is a `"`.
+// Returns `None` if a quoted field is not closed.
+fn split_csv_line(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        while matches!(chars.peek(), Some(c) if *c != ',' && c.is_whitespace()) {
+            chars.next();
+        }
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next()? {
+                    '"' if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    '"' => break,
+                    c => field.push(c),
+                }
+            }
+            // Only spaces are allowed between the closing quote and the next `,`.
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                if !c.is_whitespace() {
+                    return None;
+                }
+            }
+        } else {
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                field.push(c);
+            }
+            field = field.trim().to_string();
+        }
+        fields.push(field);
+        if chars.next().is_none() {
+            return Some(fields);
+        }
+    }
+}
+
+// The keys of the first object in the NDJSON file.
+fn ndjson_keys(file: &Path) -> Result<Vec<String>> {
+    let line = first_line(file)?;
+    match serde_json::from_str::<serde_json::Value>(&line) {
+        Ok(serde_json::Value::Object(object)) if !object.is_empty() => {
+            Ok(object.keys().cloned().collect())
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "The first line of NDJSON file {} must be an object",
+            file.display()
+        ))),
+    }
+}
+
+fn first_line(file: &Path) -> Result<String> {
+    let reader = BufReader::new(File::open(file)?);
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            return Ok(line.trim().to_string());
+        }
+    }
+    Err(ErrorCode::BadArguments(format!(
+        "File {} is empty",
+        file.display()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_statements() -> Result<()> {
+        let (statements, rest) = split_statements("select 1; select 2;")?;
+        assert_eq!(statements, vec!["select 1", "select 2"]);
+        assert_eq!(rest, "");
+
+        // The `;` in strings and comments do not end the statements.
+        let (statements, rest) =
+            split_statements("select 'a;b'; -- c;d\nselect \"e;f\" /* g; */;\nselect 3")?;
+        assert_eq!(statements, vec![
+            "select 'a;b'",
+            "-- c;d\nselect \"e;f\" /* g; */"
+        ]);
+        assert_eq!(rest, "select 3");
+
+        // The empty statements are skipped.
+        let (statements, rest) = split_statements(" ;; select 1 ;\n")?;
+        assert_eq!(statements, vec!["select 1"]);
+        assert_eq!(rest, "");
+
+        assert!(split_statements("select 'a;").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_tables() -> Result<()> {
+        assert_eq!(parse_tables("")?, vec![]);
+        assert_eq!(parse_tables("t1=a.csv")?, vec![(
+            "t1".to_string(),
+            "a.csv".to_string()
+        )]);
+        assert_eq!(parse_tables(" t1 = /tmp/a.csv , t2=b.parquet,")?, vec![
+            ("t1".to_string(), "/tmp/a.csv".to_string()),
+            ("t2".to_string(), "b.parquet".to_string())
+        ]);
+
+        assert!(parse_tables("t1").is_err());
+        assert!(parse_tables("=a.csv").is_err());
+        assert!(parse_tables("t1=").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_csv_line() {
+        assert_eq!(
+            split_csv_line("a, b ,c"),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+        assert_eq!(
+            split_csv_line(r#""a,b", "c ""d""" ,e"#),
+            Some(vec![
+                "a,b".to_string(),
+                "c \"d\"".to_string(),
+                "e".to_string()
+            ])
+        );
+        assert_eq!(
+            split_csv_line("a,,b"),
+            Some(vec!["a".to_string(), "".to_string(), "b".to_string()])
+        );
+        assert_eq!(split_csv_line(r#""a,b"#), None);
+        assert_eq!(split_csv_line(r#""a"b,c"#), None);
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod display;
+mod executor;
+mod helper;
+
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use common_config::InnerConfig;
+use common_config::LocalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use common_meta_embedded::MetaEmbedded;
+use databend_query::GlobalServices;
+use is_terminal::IsTerminal;
+
+use crate::local::display::OutputFormat;
+use crate::local::executor::SessionExecutor;
+use crate::local::helper::parse_tables;
+use crate::local::helper::register_table_statements;
+
+/// Run the statements of `--sql`, `--file` or stdin, or start an interactive prompt if none is given.
+///
+/// Without meta endpoints, the meta and data are stored in `--data-path`,
+/// so the tables created in local mode are still there in the next run.
+/// The files of `--table` are read in place, and their tables are dropped on exit.
+pub async fn query_local(conf: &InnerConfig) -> Result<()> {
+    let mut conf = conf.clone();
+    let local_conf = conf.local.clone();
+    let output_format = OutputFormat::from_str(&local_conf.output_format)?;
+
+    let data_path = PathBuf::from(&local_conf.data_path);
+    std::fs::create_dir_all(&data_path)?;
+    conf.storage.allow_insecure = true;
+    if conf.meta.endpoints.is_empty() {
+        conf.storage.params = StorageParams::Fs(StorageFsConfig {
+            root: data_path.join("_data").display().to_string(),
+        });
+        MetaEmbedded::init_global_meta_store(data_path.join("_meta").display().to_string())
+            .await
+            .map_err(|e| ErrorCode::MetaStorageError(e.to_string()))?;
+    }
+    GlobalServices::init(conf).await?;
+
+    let executor = SessionExecutor::try_create(output_format).await?;
+    // The statements to drop the registered tables on exit, in reverse order of creation.
+    let mut undo_statements = vec![];
+    let res = run(&executor, &local_conf, &data_path, &mut undo_statements).await;
+    for sql in undo_statements.iter().rev() {
+        if let Err(e) = executor.execute(sql, false).await {
+            eprintln!("Error: {}", e);
+        }
+    }
+    res
+}
+
+async fn run(
+    executor: &SessionExecutor,
+    local_conf: &LocalConfig,
+    data_path: &Path,
+    undo_statements: &mut Vec<String>,
+) -> Result<()> {
+    for (name, path) in parse_tables(&local_conf.table)? {
+        for (sql, undo) in register_table_statements(&name, &path)? {
+            executor.execute(&sql, false).await?;
+            undo_statements.push(undo);
+        }
+    }
+
+    if !local_conf.sql.is_empty() {
+        executor.run_script(&local_conf.sql).await
+    } else if !local_conf.file.is_empty() && local_conf.file != "-" {
+        let script = std::fs::read_to_string(&local_conf.file).map_err(|e| {
+            ErrorCode::BadArguments(format!("Cannot read file {}: {}", local_conf.file, e))
+        })?;
+        executor.run_script(&script).await
+    } else if local_conf.file == "-" || !std::io::stdin().is_terminal() {
+        let mut script = String::new();
+        std::io::stdin().read_to_string(&mut script)?;
+        executor.run_script(&script).await
+    } else {
+        executor.run_repl(data_path.join("history")).await
+    }
+}
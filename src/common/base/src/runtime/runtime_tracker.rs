@@ -381,6 +381,17 @@ impl MemStat {
         self.limit.store(size, Ordering::Relaxed);
     }
 
+    /// Set the limit as it is, even if it is lower than the lowest limit of `set_limit`, e.g.
+    /// the memory quota of a user.
+    pub fn set_exact_limit(&self, size: i64) {
+        self.limit.store(size, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn get_limit(&self) -> i64 {
+        self.limit.load(Ordering::Relaxed)
+    }
+
     /// Feed memory usage stat to MemStat and return if it exceeds the limit.
     ///
     /// It feeds `state` to the this tracker and all of its ancestors, including GLOBAL_TRACKER.
@@ -144,6 +144,8 @@ build_exceptions! {
     UnmatchColumnDataType(1114),
     VirtualColumnNotFound(1115),
     VirtualColumnAlreadyExists(1116),
+    CurrentTransactionIsAborted(1117),
+    UnsupportedInTransaction(1118),
 
     // Data Related Errors
 
@@ -294,6 +296,15 @@ build_exceptions! {
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
 
+    // User quota error codes.
+    UserQuotaExceeded(2911),
+
+    // Workload group error codes.
+    UnknownWorkloadGroup(2921),
+    WorkloadGroupAlreadyExists(2922),
+    IllegalWorkloadGroup(2923),
+    WorkloadGroupQueueTimeout(2924),
+
 }
 
 // Storage errors [3001, 4000].
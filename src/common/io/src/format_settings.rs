@@ -17,6 +17,7 @@ use chrono_tz::Tz;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FormatSettings {
     pub timezone: Tz,
+    pub binary_format: BinaryFormat,
 }
 
 // only used for tests
@@ -24,6 +25,29 @@ impl Default for FormatSettings {
     fn default() -> Self {
         Self {
             timezone: "UTC".parse::<Tz>().unwrap(),
+            binary_format: BinaryFormat::default(),
+        }
+    }
+}
+
+/// How BINARY values are rendered to and parsed from text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryFormat {
+    #[default]
+    Hex,
+    Base64,
+}
+
+impl std::str::FromStr for BinaryFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "hex" => Ok(BinaryFormat::Hex),
+            "base64" => Ok(BinaryFormat::Base64),
+            _ => Err(format!(
+                "Invalid binary format: {s}, must be one of {{hex, base64}}"
+            )),
         }
     }
 }
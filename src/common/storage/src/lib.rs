@@ -54,8 +54,20 @@ mod parquet;
 pub use parquet::read_parquet_metas_in_parallel;
 pub use parquet::read_parquet_schema_async;
 
+mod orc;
+pub use orc::infer_orc_schema;
+pub use orc::orc_column_id;
+pub use orc::orc_stripe_range;
+pub use orc::read_orc_metadata;
+pub use orc::read_orc_metadata_async;
+pub use orc::read_orc_schema_async;
+pub use orc::read_orc_stripe;
+pub use orc::read_orc_stripe_async;
+pub use orc::OrcRangeReader;
+
 mod stage;
 pub use stage::init_stage_operator;
+pub use stage::parse_partition_values;
 pub use stage::StageFileInfo;
 pub use stage::StageFileStatus;
 pub use stage::StageFilesInfo;
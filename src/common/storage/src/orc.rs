@@ -0,0 +1,224 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::ops::Range;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::compute::cast::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::orc::format::read::read_metadata;
+use common_arrow::arrow::io::orc::format::read::read_stripe_column;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_arrow::arrow::io::orc::read as oread;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+// The size of the tail read first to get the metadata of an ORC file, the tail is read
+// again with a larger size if the metadata doesn't fit in.
+const ORC_TAIL_SIZE: u64 = 64 * 1024;
+
+/// A range of the bytes of an ORC file. The offsets of the reads and seeks are the offsets
+/// in the whole file, so the ORC reader can read the tail or a stripe without the whole file.
+pub struct OrcRangeReader {
+    data: Vec<u8>,
+    offset: u64,
+    file_size: u64,
+    pos: u64,
+}
+
+impl OrcRangeReader {
+    pub fn new(data: Vec<u8>, offset: u64, file_size: u64) -> Self {
+        OrcRangeReader {
+            data,
+            offset,
+            file_size,
+            pos: offset,
+        }
+    }
+}
+
+impl Read for OrcRangeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos >= self.file_size {
+            return Ok(0);
+        }
+        let end = self.offset + self.data.len() as u64;
+        if self.pos < self.offset || self.pos >= end {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!(
+                    "read orc file at {} out of the range {}..{}",
+                    self.pos, self.offset, end
+                ),
+            ));
+        }
+        let start = (self.pos - self.offset) as usize;
+        let len = buf.len().min(self.data.len() - start);
+        buf[..len].copy_from_slice(&self.data[start..start + len]);
+        self.pos += len as u64;
+        Ok(len)
+    }
+}
+
+impl Seek for OrcRangeReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => Some(pos),
+            SeekFrom::End(delta) => self.file_size.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.pos.checked_add_signed(delta),
+        };
+        match pos {
+            Some(pos) => {
+                self.pos = pos;
+                Ok(pos)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position of orc file",
+            )),
+        }
+    }
+}
+
+/// Read the metadata of an ORC file, the whole file is in memory.
+pub fn read_orc_metadata(data: &[u8]) -> Result<FileMetadata> {
+    read_metadata(&mut Cursor::new(data))
+        .map_err(|e| ErrorCode::BadBytes(format!("Read orc file meta error: {:?}", e)))
+}
+
+/// Read the metadata of an ORC file from the tail of the file.
+#[async_backtrace::framed]
+pub async fn read_orc_metadata_async(
+    operator: &Operator,
+    path: &str,
+    file_size: Option<u64>,
+) -> Result<FileMetadata> {
+    let file_size = match file_size {
+        Some(size) => size,
+        None => operator.stat(path).await?.content_length(),
+    };
+    let mut tail_size = ORC_TAIL_SIZE.min(file_size);
+    loop {
+        let offset = file_size - tail_size;
+        let data = operator.range_read(path, offset..file_size).await?;
+        let mut reader = OrcRangeReader::new(data, offset, file_size);
+        match read_metadata(&mut reader) {
+            Ok(metadata) => return Ok(metadata),
+            Err(_) if tail_size < file_size => tail_size = (tail_size * 4).min(file_size),
+            Err(e) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Read orc file meta error: {:?} ({path})",
+                    e
+                )));
+            }
+        }
+    }
+}
+
+pub fn infer_orc_schema(metadata: &FileMetadata) -> Result<ArrowSchema> {
+    Ok(oread::infer_schema(&metadata.footer)?)
+}
+
+#[async_backtrace::framed]
+pub async fn read_orc_schema_async(operator: &Operator, path: &str) -> Result<ArrowSchema> {
+    let metadata = read_orc_metadata_async(operator, path, None).await?;
+    infer_orc_schema(&metadata)
+}
+
+/// Returns the column id of the top-level field of the ORC file, the name is case-insensitive.
+///
+/// The root struct of the file is the column 0, the ids of the other columns are
+/// assigned in pre-order, so a nested field takes more than one id.
+pub fn orc_column_id(metadata: &FileMetadata, name: &str) -> Option<u32> {
+    let root = metadata.footer.types.first()?;
+    root.field_names
+        .iter()
+        .position(|n| n.eq_ignore_ascii_case(name))
+        .and_then(|i| root.subtypes.get(i).copied())
+}
+
+/// The byte range of a stripe in the ORC file, including the indexes and the footer.
+pub fn orc_stripe_range(metadata: &FileMetadata, stripe: usize) -> Range<u64> {
+    let stripe = &metadata.footer.stripes[stripe];
+    let length = stripe.index_length() + stripe.data_length() + stripe.footer_length();
+    stripe.offset()..stripe.offset() + length
+}
+
+/// Read the bytes of a stripe of the ORC file, the returned reader can be passed to
+/// [`read_orc_stripe`] to deserialize the stripe.
+#[async_backtrace::framed]
+pub async fn read_orc_stripe_async(
+    operator: &Operator,
+    path: &str,
+    file_size: u64,
+    metadata: &FileMetadata,
+    stripe: usize,
+) -> Result<OrcRangeReader> {
+    let range = orc_stripe_range(metadata, stripe);
+    let offset = range.start;
+    let data = operator.range_read(path, range).await?;
+    Ok(OrcRangeReader::new(data, offset, file_size))
+}
+
+/// Read the fields from a stripe of the ORC file, the arrays are cast to the types of
+/// the fields if the types in the file are different.
+///
+/// `file_schema` is the schema inferred from the metadata by [`infer_orc_schema`].
+/// The fields not in the file are returned as `None`, the caller fills the default values.
+pub fn read_orc_stripe<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    file_schema: &ArrowSchema,
+    stripe: usize,
+    fields: &[ArrowField],
+) -> Result<Vec<Option<Box<dyn Array>>>> {
+    let mut arrays = Vec::with_capacity(fields.len());
+    for field in fields {
+        let Some((index, file_field)) = file_schema
+            .fields
+            .iter()
+            .enumerate()
+            .find(|(_, f)| f.name.eq_ignore_ascii_case(&field.name))
+        else {
+            arrays.push(None);
+            continue;
+        };
+        let column_id = metadata.footer.types[0].subtypes[index];
+        let stripe_error = |e: &dyn std::fmt::Debug| {
+            ErrorCode::BadBytes(format!(
+                "Read column {} of orc stripe {} error: {:?}",
+                field.name, stripe, e
+            ))
+        };
+        let column = read_stripe_column(&mut *reader, metadata, stripe, column_id, vec![])
+            .map_err(|e| stripe_error(&e))?;
+        let array = oread::deserialize(file_field.data_type.clone(), &column)
+            .map_err(|e| stripe_error(&e))?;
+        let array = if array.data_type() != field.data_type() {
+            cast(array.as_ref(), field.data_type(), CastOptions::default())?
+        } else {
+            array
+        };
+        arrays.push(Some(array));
+    }
+    Ok(arrays)
+}
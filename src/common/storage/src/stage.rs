@@ -80,6 +80,22 @@ pub fn init_stage_operator(stage_info: &StageInfo) -> Result<Operator> {
         Ok(init_operator(&param)?)
     }
 }
+/// Parse the values of the partition columns from a hive style path,
+/// e.g. `sales/year=2023/month=01/data.parquet`.
+///
+/// The value is `None` if the path has no segment of the column.
+pub fn parse_partition_values(path: &str, columns: &[String]) -> Vec<Option<String>> {
+    let mut values = vec![None; columns.len()];
+    for segment in path.split('/') {
+        if let Some((name, value)) = segment.split_once('=') {
+            if let Some(i) = columns.iter().position(|c| c.eq_ignore_ascii_case(name)) {
+                values[i] = Some(value.to_string());
+            }
+        }
+    }
+    values
+}
+
 /// select * from @s1/<path> (FILES => <files> PATTERN => <pattern>)
 /// copy from @s1/<path> FILES = <files> PATTERN => <pattern>
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug)]
@@ -0,0 +1,33 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_storage::parse_partition_values;
+
+#[test]
+fn test_parse_partition_values() {
+    let columns = vec!["year".to_string(), "month".to_string()];
+
+    let values = parse_partition_values("sales/year=2023/month=01/data.parquet", &columns);
+    assert_eq!(values, vec![
+        Some("2023".to_string()),
+        Some("01".to_string())
+    ]);
+
+    // The name of the column is case insensitive.
+    let values = parse_partition_values("sales/MONTH=02/data.parquet", &columns);
+    assert_eq!(values, vec![None, Some("02".to_string())]);
+
+    let values = parse_partition_values("sales/data.parquet", &columns);
+    assert_eq!(values, vec![None, None]);
+}
@@ -12,11 +12,45 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
 use common_exception::ErrorCode;
 use common_exception::Result;
 use ndarray::ArrayView;
 
 pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+
+    let a = ArrayView::from(from);
+    let b = ArrayView::from(to);
+    let aa_sum = (&a * &a).sum();
+    let bb_sum = (&b * &b).sum();
+
+    Ok(1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt()))
+}
+
+pub fn l2_distance(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+
+    let a = ArrayView::from(from);
+    let b = ArrayView::from(to);
+    let diff = &a - &b;
+
+    Ok((&diff * &diff).sum().sqrt())
+}
+
+pub fn inner_product(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+
+    let a = ArrayView::from(from);
+    let b = ArrayView::from(to);
+
+    Ok((&a * &b).sum())
+}
+
+fn check_length(from: &[f32], to: &[f32]) -> Result<()> {
     if from.len() != to.len() {
         return Err(ErrorCode::InvalidArgument(format!(
             "Vector length not equal: {:} != {:}",
@@ -24,11 +58,88 @@ pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
             to.len(),
         )));
     }
+    Ok(())
+}
 
-    let a = ArrayView::from(from);
-    let b = ArrayView::from(to);
-    let aa_sum = (&a * &a).sum();
-    let bb_sum = (&b * &b).sum();
+/// The distance functions that the vector index can be built for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+    InnerProduct,
+}
 
-    Ok(1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt()))
+impl VectorDistance {
+    /// Get the distance by the name of the SQL function.
+    pub fn from_func_name(name: &str) -> Option<VectorDistance> {
+        match name {
+            "cosine_distance" => Some(VectorDistance::Cosine),
+            "l2_distance" => Some(VectorDistance::L2),
+            "inner_product" => Some(VectorDistance::InnerProduct),
+            _ => None,
+        }
+    }
+
+    pub fn func_name(&self) -> &'static str {
+        match self {
+            VectorDistance::Cosine => "cosine_distance",
+            VectorDistance::L2 => "l2_distance",
+            VectorDistance::InnerProduct => "inner_product",
+        }
+    }
+
+    /// The nearest vectors have the largest inner product, but the smallest distance otherwise.
+    pub fn is_similarity(&self) -> bool {
+        matches!(self, VectorDistance::InnerProduct)
+    }
+
+    /// Compute the distance of two vectors with the same length, the smaller is the nearer.
+    ///
+    /// The inner product is negated, and NaN is treated as the farthest.
+    pub fn eval(&self, from: &[f32], to: &[f32]) -> f32 {
+        let a = ArrayView::from(from);
+        let b = ArrayView::from(to);
+        let distance = match self {
+            VectorDistance::Cosine => {
+                let aa_sum = (&a * &a).sum();
+                let bb_sum = (&b * &b).sum();
+                1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt())
+            }
+            VectorDistance::L2 => {
+                let diff = &a - &b;
+                (&diff * &diff).sum().sqrt()
+            }
+            VectorDistance::InnerProduct => -(&a * &b).sum(),
+        };
+        if distance.is_nan() {
+            f32::INFINITY
+        } else {
+            distance
+        }
+    }
+}
+
+impl FromStr for VectorDistance {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(VectorDistance::Cosine),
+            "l2" => Ok(VectorDistance::L2),
+            "inner_product" => Ok(VectorDistance::InnerProduct),
+            other => Err(ErrorCode::InvalidArgument(format!(
+                "Unknown vector distance: {other}, expect one of cosine, l2, inner_product",
+            ))),
+        }
+    }
+}
+
+impl Display for VectorDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorDistance::Cosine => write!(f, "cosine"),
+            VectorDistance::L2 => write!(f, "l2"),
+            VectorDistance::InnerProduct => write!(f, "inner_product"),
+        }
+    }
 }
@@ -0,0 +1,424 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::VectorDistance;
+
+const MAGIC: &[u8; 4] = b"HNSW";
+const FORMAT_VERSION: u8 = 1;
+
+/// Max number of links of a node on the upper levels, the level 0 keeps twice of it.
+const MAX_LINKS: usize = 16;
+/// Size of the dynamic candidate list while inserting.
+const EF_CONSTRUCTION: usize = 64;
+/// Size of the dynamic candidate list while searching, if the k is smaller.
+const EF_SEARCH: usize = 64;
+const MAX_LEVEL: usize = 16;
+const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+/// Hierarchical Navigable Small World graph over the vectors of one column in a block,
+/// used to find the approximate nearest rows of a query vector.
+///
+/// See: <https://arxiv.org/abs/1603.09320>
+#[derive(Clone, Debug)]
+pub struct HnswIndex {
+    distance: VectorDistance,
+    dimension: usize,
+    // Vectors of the nodes, `dimension` values per node.
+    vectors: Vec<f32>,
+    // Row offset in the block of each node.
+    rows: Vec<u32>,
+    // Links of each node, from level 0 to the level of the node.
+    links: Vec<Vec<Vec<u32>>>,
+    entry_point: u32,
+    max_level: usize,
+    seed: u64,
+}
+
+impl HnswIndex {
+    pub fn new(distance: VectorDistance, dimension: usize) -> Self {
+        HnswIndex {
+            distance,
+            dimension,
+            vectors: vec![],
+            rows: vec![],
+            links: vec![],
+            entry_point: 0,
+            max_level: 0,
+            seed: SEED,
+        }
+    }
+
+    pub fn distance(&self) -> VectorDistance {
+        self.distance
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Add the vector of a row to the graph.
+    pub fn insert(&mut self, row: u32, vector: &[f32]) -> Result<()> {
+        self.check_dimension(vector)?;
+
+        let node = self.rows.len() as u32;
+        let level = self.random_level();
+        self.rows.push(row);
+        self.vectors.extend_from_slice(vector);
+        self.links.push(vec![vec![]; level + 1]);
+        if node == 0 {
+            self.entry_point = node;
+            self.max_level = level;
+            return Ok(());
+        }
+
+        let mut entry = Candidate {
+            distance: self.distance_to(vector, self.entry_point),
+            node: self.entry_point,
+        };
+        for l in (level + 1..=self.max_level).rev() {
+            entry = self.greedy_search(vector, entry, l);
+        }
+
+        for l in (0..=level.min(self.max_level)).rev() {
+            let candidates = self.search_layer(vector, entry, EF_CONSTRUCTION, l);
+            let max_links = if l == 0 { MAX_LINKS * 2 } else { MAX_LINKS };
+            let neighbors = candidates
+                .iter()
+                .filter(|c| c.node != node)
+                .take(MAX_LINKS)
+                .map(|c| c.node)
+                .collect::<Vec<_>>();
+            for neighbor in neighbors.iter() {
+                let links = &mut self.links[*neighbor as usize][l];
+                links.push(node);
+                if links.len() > max_links {
+                    self.shrink_links(*neighbor, l, max_links);
+                }
+            }
+            self.links[node as usize][l] = neighbors;
+            if let Some(nearest) = candidates.first() {
+                entry = *nearest;
+            }
+        }
+
+        if level > self.max_level {
+            self.max_level = level;
+            self.entry_point = node;
+        }
+        Ok(())
+    }
+
+    /// Find the approximate k nearest rows, return the row offsets with the distances,
+    /// from the nearest to the farthest.
+    ///
+    /// The distances are computed by [`VectorDistance::eval`], so the inner product is negated.
+    pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(u32, f32)>> {
+        self.check_dimension(query)?;
+        if self.is_empty() || k == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut entry = Candidate {
+            distance: self.distance_to(query, self.entry_point),
+            node: self.entry_point,
+        };
+        for l in (1..=self.max_level).rev() {
+            entry = self.greedy_search(query, entry, l);
+        }
+
+        let candidates = self.search_layer(query, entry, EF_SEARCH.max(k), 0);
+        Ok(candidates
+            .into_iter()
+            .take(k)
+            .map(|c| (self.rows[c.node as usize], c.distance))
+            .collect())
+    }
+
+    pub fn serialize(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(MAGIC);
+        buf.push(FORMAT_VERSION);
+        buf.push(match self.distance {
+            VectorDistance::Cosine => 0,
+            VectorDistance::L2 => 1,
+            VectorDistance::InnerProduct => 2,
+        });
+        buf.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.rows.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&self.entry_point.to_le_bytes());
+        buf.push(self.max_level as u8);
+
+        for v in self.vectors.iter() {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for (row, links) in self.rows.iter().zip(self.links.iter()) {
+            buf.extend_from_slice(&row.to_le_bytes());
+            buf.push((links.len() - 1) as u8);
+            for level_links in links.iter() {
+                buf.extend_from_slice(&(level_links.len() as u32).to_le_bytes());
+                for link in level_links.iter() {
+                    buf.extend_from_slice(&link.to_le_bytes());
+                }
+            }
+        }
+    }
+
+    pub fn deserialize(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader { data, pos: 0 };
+        if reader.read_bytes(MAGIC.len())? != MAGIC {
+            return Err(ErrorCode::StorageOther("Invalid vector index file"));
+        }
+        let version = reader.read_u8()?;
+        if version != FORMAT_VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "Unsupported vector index version: {version}"
+            )));
+        }
+        let distance = match reader.read_u8()? {
+            0 => VectorDistance::Cosine,
+            1 => VectorDistance::L2,
+            2 => VectorDistance::InnerProduct,
+            other => {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Unknown vector distance in vector index: {other}"
+                )));
+            }
+        };
+        let dimension = reader.read_u32()? as usize;
+        let len = reader.read_u32()? as usize;
+        let entry_point = reader.read_u32()?;
+        let max_level = reader.read_u8()? as usize;
+        if len > 0 && entry_point as usize >= len {
+            return Err(ErrorCode::StorageOther(
+                "Invalid entry point in vector index",
+            ));
+        }
+
+        let mut vectors = Vec::with_capacity(len * dimension);
+        for _ in 0..len * dimension {
+            vectors.push(f32::from_le_bytes(reader.read_array()?));
+        }
+        let mut rows = Vec::with_capacity(len);
+        let mut links = Vec::with_capacity(len);
+        for _ in 0..len {
+            rows.push(reader.read_u32()?);
+            let level = reader.read_u8()? as usize;
+            let mut node_links = Vec::with_capacity(level + 1);
+            for _ in 0..=level {
+                let count = reader.read_u32()? as usize;
+                let mut level_links = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let link = reader.read_u32()?;
+                    if link as usize >= len {
+                        return Err(ErrorCode::StorageOther("Invalid link in vector index"));
+                    }
+                    level_links.push(link);
+                }
+                node_links.push(level_links);
+            }
+            links.push(node_links);
+        }
+
+        Ok(HnswIndex {
+            distance,
+            dimension,
+            vectors,
+            rows,
+            links,
+            entry_point,
+            max_level,
+            seed: SEED,
+        })
+    }
+
+    fn check_dimension(&self, vector: &[f32]) -> Result<()> {
+        if vector.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                vector.len(),
+                self.dimension,
+            )));
+        }
+        Ok(())
+    }
+
+    fn vector(&self, node: u32) -> &[f32] {
+        let start = node as usize * self.dimension;
+        &self.vectors[start..start + self.dimension]
+    }
+
+    fn distance_to(&self, query: &[f32], node: u32) -> f32 {
+        self.distance.eval(query, self.vector(node))
+    }
+
+    // Level of a new node, with the probability decaying exponentially by 1/ln(MAX_LINKS).
+    fn random_level(&mut self) -> usize {
+        // xorshift64*, the graph only needs a reproducible spread of the levels.
+        self.seed ^= self.seed >> 12;
+        self.seed ^= self.seed << 25;
+        self.seed ^= self.seed >> 27;
+        let r = self.seed.wrapping_mul(SEED) >> 11;
+        let uniform = (r as f64 + 1.0) / ((1u64 << 53) as f64 + 1.0);
+        let level = -uniform.ln() / (MAX_LINKS as f64).ln();
+        (level as usize).min(MAX_LEVEL)
+    }
+
+    fn greedy_search(&self, query: &[f32], mut entry: Candidate, level: usize) -> Candidate {
+        loop {
+            let mut changed = false;
+            for &neighbor in self.links[entry.node as usize][level].iter() {
+                let distance = self.distance_to(query, neighbor);
+                if distance < entry.distance {
+                    entry = Candidate {
+                        distance,
+                        node: neighbor,
+                    };
+                    changed = true;
+                }
+            }
+            if !changed {
+                return entry;
+            }
+        }
+    }
+
+    // Return the ef nearest nodes found on the level, from the nearest to the farthest.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry: Candidate,
+        ef: usize,
+        level: usize,
+    ) -> Vec<Candidate> {
+        let mut visited = HashSet::new();
+        visited.insert(entry.node);
+        let mut candidates = BinaryHeap::new();
+        candidates.push(Reverse(entry));
+        let mut results = BinaryHeap::new();
+        results.push(entry);
+
+        while let Some(Reverse(current)) = candidates.pop() {
+            let farthest = results.peek().map(|c: &Candidate| c.distance);
+            if results.len() >= ef && Some(current.distance) > farthest {
+                break;
+            }
+            for &neighbor in self.links[current.node as usize][level].iter() {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let candidate = Candidate {
+                    distance: self.distance_to(query, neighbor),
+                    node: neighbor,
+                };
+                if results.len() < ef || candidate < *results.peek().unwrap() {
+                    candidates.push(Reverse(candidate));
+                    results.push(candidate);
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+        results.into_sorted_vec()
+    }
+
+    // Keep the nearest links of the node on the level.
+    fn shrink_links(&mut self, node: u32, level: usize, max_links: usize) {
+        let mut links = self.links[node as usize][level]
+            .iter()
+            .map(|&link| Candidate {
+                distance: self.distance.eval(self.vector(node), self.vector(link)),
+                node: link,
+            })
+            .collect::<Vec<_>>();
+        links.sort();
+        links.truncate(max_links);
+        self.links[node as usize][level] = links.into_iter().map(|c| c.node).collect();
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.pos + len > self.data.len() {
+            return Err(ErrorCode::StorageOther(
+                "Unexpected end of vector index file",
+            ));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+}
@@ -13,5 +13,10 @@
 // limitations under the License.
 
 mod distance;
+mod hnsw;
 
 pub use distance::cosine_distance;
+pub use distance::inner_product;
+pub use distance::l2_distance;
+pub use distance::VectorDistance;
+pub use hnsw::HnswIndex;
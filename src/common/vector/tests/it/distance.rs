@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use common_vector::cosine_distance;
+use common_vector::inner_product;
+use common_vector::l2_distance;
+use common_vector::VectorDistance;
 
 #[test]
 fn test_cosine() {
@@ -39,3 +42,57 @@ fn test_cosine() {
         assert!(d.is_err());
     }
 }
+
+#[test]
+fn test_l2() {
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0];
+        let y = vec![2.0, 54.0, 13.0, 15.0];
+        let d = l2_distance(&x, &y).unwrap();
+        // from scipy.spatial.distance.euclidean
+        approx::assert_relative_eq!(d, 16.941_074);
+    }
+
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0];
+        let y = vec![2.0, 54.0];
+        assert!(l2_distance(&x, &y).is_err());
+    }
+}
+
+#[test]
+fn test_inner_product() {
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0];
+        let y = vec![2.0, 54.0, 13.0, 15.0];
+        let d = inner_product(&x, &y).unwrap();
+        approx::assert_relative_eq!(d, 2557.0);
+    }
+
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0];
+        let y = vec![2.0, 54.0];
+        assert!(inner_product(&x, &y).is_err());
+    }
+}
+
+#[test]
+fn test_vector_distance_eval() {
+    let x = vec![3.0, 45.0, 7.0, 2.0];
+    let y = vec![2.0, 54.0, 13.0, 15.0];
+
+    let d = VectorDistance::Cosine.eval(&x, &y);
+    approx::assert_relative_eq!(d, cosine_distance(&x, &y).unwrap());
+    let d = VectorDistance::L2.eval(&x, &y);
+    approx::assert_relative_eq!(d, l2_distance(&x, &y).unwrap());
+    // the larger inner product is the nearer
+    let d = VectorDistance::InnerProduct.eval(&x, &y);
+    approx::assert_relative_eq!(d, -inner_product(&x, &y).unwrap());
+
+    // NaN is the farthest
+    let zero = vec![0.0; 4];
+    assert_eq!(VectorDistance::Cosine.eval(&x, &zero), f32::INFINITY);
+
+    assert_eq!("L2".parse::<VectorDistance>().unwrap(), VectorDistance::L2);
+    assert!("hamming".parse::<VectorDistance>().is_err());
+}
@@ -0,0 +1,107 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_vector::HnswIndex;
+use common_vector::VectorDistance;
+
+// Deterministic pseudo random vectors.
+fn gen_vectors(num: usize, dimension: usize) -> Vec<Vec<f32>> {
+    let mut state = 42u64;
+    (0..num)
+        .map(|_| {
+            (0..dimension)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    ((state >> 33) % 1000) as f32 / 1000.0
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn brute_force(
+    distance: VectorDistance,
+    vectors: &[Vec<f32>],
+    query: &[f32],
+    k: usize,
+) -> Vec<u32> {
+    let mut distances = vectors
+        .iter()
+        .enumerate()
+        .map(|(row, v)| (row as u32, distance.eval(query, v)))
+        .collect::<Vec<_>>();
+    distances.sort_by(|a, b| a.1.total_cmp(&b.1));
+    distances.into_iter().take(k).map(|(row, _)| row).collect()
+}
+
+#[test]
+fn test_hnsw_search() {
+    let vectors = gen_vectors(2000, 16);
+    let queries = gen_vectors(10, 16);
+
+    for distance in [
+        VectorDistance::Cosine,
+        VectorDistance::L2,
+        VectorDistance::InnerProduct,
+    ] {
+        let mut index = HnswIndex::new(distance, 16);
+        for (row, v) in vectors.iter().enumerate() {
+            index.insert(row as u32, v).unwrap();
+        }
+        assert_eq!(index.len(), 2000);
+
+        let mut found = 0;
+        for query in queries.iter() {
+            let expected = brute_force(distance, &vectors, query, 10);
+            let result = index.search(query, 10).unwrap();
+            assert_eq!(result.len(), 10);
+            // distances are ascending
+            assert!(result.windows(2).all(|w| w[0].1 <= w[1].1));
+            found += result
+                .iter()
+                .filter(|(row, _)| expected.contains(row))
+                .count();
+        }
+        // recall of the approximate search
+        assert!(found >= 90, "{distance}: {found} of 100 found");
+    }
+}
+
+#[test]
+fn test_hnsw_serialize() {
+    let vectors = gen_vectors(300, 8);
+    let mut index = HnswIndex::new(VectorDistance::L2, 8);
+    // rows which are not indexed, e.g. NULL, are skipped.
+    for (row, v) in vectors.iter().enumerate().filter(|(row, _)| row % 3 != 0) {
+        index.insert(row as u32, v).unwrap();
+    }
+
+    let mut buf = vec![];
+    index.serialize(&mut buf);
+    let deserialized = HnswIndex::deserialize(&buf).unwrap();
+    assert_eq!(deserialized.len(), index.len());
+    assert_eq!(deserialized.dimension(), 8);
+    assert_eq!(deserialized.distance(), VectorDistance::L2);
+
+    let query = &vectors[0];
+    let result = deserialized.search(query, 5).unwrap();
+    assert_eq!(result, index.search(query, 5).unwrap());
+    assert!(result.iter().all(|(row, _)| row % 3 != 0));
+
+    assert!(index.search(&[0.0; 4], 5).is_err());
+    assert!(HnswIndex::deserialize(&buf[..buf.len() - 1]).is_err());
+    assert!(HnswIndex::deserialize(b"PAR1").is_err());
+}
@@ -112,6 +112,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2648,6 +2650,131 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         }
     }
 
+    #[tracing::instrument(level = "debug", ret, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+
+            for update in req.update_table_metas.iter() {
+                let tbid = TableId {
+                    table_id: update.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(update.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if update.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update.table_id,
+                            update.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&update.new_table_meta)?));
+
+                if let Some(req) = &update.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            req,
+                            tb_meta_seq,
+                            req.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                if let Some(deduplicated_label) = update.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label))
+                }
+
+                table_metas.push(table_meta.unwrap());
+            }
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(succ = display(succ), "update_multi_table_meta");
+
+            if succ {
+                let mut share_table_info = vec![];
+                for table_meta in table_metas.iter() {
+                    if let Some(infos) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.extend(infos);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply {
+                    share_table_info: if share_table_info.is_empty() {
+                        None
+                    } else {
+                        Some(share_table_info)
+                    },
+                });
+            }
+
+            // The txn failed, either one of the tables is changed, or the copied files conflict.
+            // Without `fail_if_duplicated`, the versions are checked again in the next round.
+            let fail_if_duplicated = req.update_table_metas.iter().any(|update| {
+                update
+                    .copied_files
+                    .as_ref()
+                    .map(|v| v.fail_if_duplicated)
+                    .unwrap_or(false)
+            });
+            if fail_if_duplicated {
+                for update in req.update_table_metas.iter() {
+                    let tbid = TableId {
+                        table_id: update.table_id,
+                    };
+                    let (tb_meta_seq, _): (_, Option<TableMeta>) =
+                        get_pb_value(self, &tbid).await?;
+                    if update.seq.match_seq(tb_meta_seq).is_err() {
+                        return Err(KVAppError::AppError(AppError::from(
+                            TableVersionMismatched::new(
+                                update.table_id,
+                                update.seq,
+                                tb_meta_seq,
+                                "update_multi_table_meta",
+                            ),
+                        )));
+                    }
+                }
+                let table_id = req
+                    .update_table_metas
+                    .iter()
+                    .find(|update| update.copied_files.is_some())
+                    .map(|update| update.table_id)
+                    .unwrap_or_default();
+                return Err(KVAppError::AppError(AppError::from(
+                    DuplicatedUpsertFiles::new(table_id, "update_multi_table_meta"),
+                )));
+            }
+        }
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn gc_dropped_data(
         &self,
@@ -34,6 +34,8 @@ const OPT_NAN_DISPLAY: &str = "nan_display";
 const OPT_ESCAPE: &str = "escape";
 const OPT_QUOTE: &str = "quote";
 const OPT_ROW_TAG: &str = "row_tag";
+const OPT_STRIP_OUTER_ARRAY: &str = "strip_outer_array";
+const OPT_JSON_PATH: &str = "json_path";
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FileFormatOptionsAst {
@@ -84,6 +86,15 @@ impl FileFormatOptionsAst {
             None => Ok(default),
         }
     }
+
+    fn take_bool(&mut self, key: &str, default: bool) -> Result<bool> {
+        match self.options.remove(key) {
+            Some(v) => bool::from_str(&v.to_lowercase()).map_err(|_| {
+                ErrorCode::IllegalFileFormat(format!("Invalid value '{}' of option {}", v, key))
+            }),
+            None => Ok(default),
+        }
+    }
 }
 
 /// File format parameters after checking and parsing.
@@ -96,6 +107,9 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
+    Arrow(ArrowFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -107,6 +121,9 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Arrow(_) => StageFileFormatType::Arrow,
         }
     }
 
@@ -124,6 +141,13 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Arrow => {
+                Ok(FileFormatParams::Arrow(ArrowFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -139,6 +163,9 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Avro(v) => v.compression,
+            FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Arrow(_) => StageFileCompression::None,
         }
     }
 
@@ -156,14 +183,28 @@ impl FileFormatParams {
                 })
             }
             StageFileFormatType::Json => {
+                let default = JsonFileFormatParams::default();
                 let compression = ast.take_compression()?;
-                FileFormatParams::Json(JsonFileFormatParams { compression })
+                let strip_outer_array =
+                    ast.take_bool(OPT_STRIP_OUTER_ARRAY, default.strip_outer_array)?;
+                let json_path = ast.take_string(OPT_JSON_PATH, default.json_path);
+                FileFormatParams::Json(JsonFileFormatParams {
+                    compression,
+                    strip_outer_array,
+                    json_path,
+                })
             }
             StageFileFormatType::NdJson => {
                 let compression = ast.take_compression()?;
                 FileFormatParams::NdJson(NdJsonFileFormatParams { compression })
             }
             StageFileFormatType::Parquet => FileFormatParams::Parquet(ParquetFileFormatParams {}),
+            StageFileFormatType::Avro => {
+                let compression = ast.take_compression()?;
+                FileFormatParams::Avro(AvroFileFormatParams { compression })
+            }
+            StageFileFormatType::Orc => FileFormatParams::Orc(OrcFileFormatParams {}),
+            StageFileFormatType::Arrow => FileFormatParams::Arrow(ArrowFileFormatParams {}),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -359,6 +400,11 @@ impl Default for XmlFileFormatParams {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JsonFileFormatParams {
     pub compression: StageFileCompression,
+    /// If true, each element of the outer array is a row, otherwise each top-level value is a row.
+    pub strip_outer_array: bool,
+    /// The path of the array to load in the top-level object, such as `$.data.items`,
+    /// empty for the top-level array.
+    pub json_path: String,
 }
 
 impl JsonFileFormatParams {
@@ -374,6 +420,8 @@ impl Default for JsonFileFormatParams {
     fn default() -> Self {
         JsonFileFormatParams {
             compression: StageFileCompression::None,
+            strip_outer_array: true,
+            json_path: "".to_string(),
         }
     }
 }
@@ -403,6 +451,38 @@ impl NdJsonFileFormatParams {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {}
 
+/// Avro object container files, the schema is read from the header of each file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    pub compression: StageFileCompression,
+}
+
+impl Default for AvroFileFormatParams {
+    fn default() -> Self {
+        AvroFileFormatParams {
+            compression: StageFileCompression::None,
+        }
+    }
+}
+
+impl AvroFileFormatParams {
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &AvroFileFormatParams {
+        match params {
+            FileFormatParams::Avro(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// ORC files, the stripes are compressed by the codec recorded in the file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {}
+
+/// Arrow IPC files or streams, the record batches may be compressed by the codec
+/// recorded in the messages.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArrowFileFormatParams {}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -440,7 +520,13 @@ impl Display for FileFormatParams {
                 )
             }
             FileFormatParams::Json(params) => {
-                write!(f, "TYPE = JSON, COMPRESSION = {:?}", params.compression)
+                write!(
+                    f,
+                    "TYPE = JSON, COMPRESSION = {:?}, STRIP_OUTER_ARRAY = {}, JSON_PATH = '{}'",
+                    params.compression,
+                    params.strip_outer_array,
+                    escape_string(&params.json_path)
+                )
             }
             FileFormatParams::NdJson(params) => {
                 write!(f, "TYPE = NDJSON, COMPRESSION = {:?}", params.compression)
@@ -448,6 +534,15 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(_) => {
                 write!(f, "TYPE = PARQUET")
             }
+            FileFormatParams::Avro(params) => {
+                write!(f, "TYPE = AVRO, COMPRESSION = {:?}", params.compression)
+            }
+            FileFormatParams::Orc(_) => {
+                write!(f, "TYPE = ORC")
+            }
+            FileFormatParams::Arrow(_) => {
+                write!(f, "TYPE = ARROW")
+            }
         }
     }
 }
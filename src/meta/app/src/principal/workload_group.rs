@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A named group of queries sharing the limits of a query node.
+///
+/// Users and roles are assigned to a workload group, the queries of them are
+/// queued when the group reaches its max concurrency.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+#[serde(default)]
+pub struct WorkloadGroup {
+    pub name: String,
+
+    // The max number of the running queries on a query node (0 is no limited).
+    pub max_concurrency: u64,
+
+    // The percentage of the server memory the running queries can use (0 is no limited).
+    pub memory_share: u64,
+
+    // The max seconds a query waits in the queue (0 is waiting forever).
+    pub queue_timeout: u64,
+}
+
+impl WorkloadGroup {
+    pub fn new(name: &str) -> Self {
+        WorkloadGroup {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+}
@@ -246,6 +246,9 @@ impl FromToProto for ex::TableDataType {
                         Box::into_inner(x),
                     )?)),
                     Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
+                    Dt24::TimestampTzT(_) => ex::TableDataType::TimestampTz,
                     Dt24::TupleT(t) => {
                         reader_check_msg(t.ver, t.min_reader_ver)?;
 
@@ -282,6 +285,7 @@ impl FromToProto for ex::TableDataType {
             TableDataType::EmptyMap => new_pb_dt24(Dt24::EmptyMapT(pb::Empty {})),
             TableDataType::Boolean => new_pb_dt24(Dt24::BoolT(pb::Empty {})),
             TableDataType::String => new_pb_dt24(Dt24::StringT(pb::Empty {})),
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
             TableDataType::Number(n) => {
                 let x = n.to_pb()?;
                 new_pb_dt24(Dt24::NumberT(x))
@@ -292,6 +296,8 @@ impl FromToProto for ex::TableDataType {
             }
             TableDataType::Timestamp => new_pb_dt24(Dt24::TimestampT(pb::Empty {})),
             TableDataType::Date => new_pb_dt24(Dt24::DateT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
+            TableDataType::TimestampTz => new_pb_dt24(Dt24::TimestampTzT(pb::Empty {})),
             TableDataType::Nullable(v) => {
                 let x = v.to_pb()?;
                 new_pb_dt24(Dt24::NullableT(Box::new(x)))
@@ -74,6 +74,13 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (42, "2023-06-03: Add allow_anonymous in S3 Config", ),
     (43, "2023-06-05: Add fields `number_of_segments` and `number_of_blocks` to TableStatistics", ),
     (44, "2023-06-07: Add: metadata.proto/ComputedExpr", ),
+    (45, "2023-06-09: Add: file_format.proto/AvroFileFormatParams", ),
+    (46, "2023-06-12: Add: file_format.proto/OrcFileFormatParams", ),
+    (47, "2023-06-14: Add: file_format.proto/ArrowFileFormatParams", ),
+    (48, "2023-06-15: Add: file_format.proto/JsonFileFormatParams add strip_outer_array and json_path", ),
+    (49, "2026-10-18: Add: metadata.proto/DataType Interval type", ),
+    (50, "2026-10-18: Add: metadata.proto/DataType Binary type", ),
+    (51, "2026-10-18: Add: metadata.proto/DataType TimestampTz type", ),
 
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
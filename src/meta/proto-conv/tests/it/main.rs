@@ -49,3 +49,10 @@ mod v041_virtual_column;
 mod v042_s3_stage_new_field;
 mod v043_table_statistics;
 mod v044_table_meta;
+mod v045_avro_file_format_params;
+mod v046_orc_file_format_params;
+mod v047_arrow_file_format_params;
+mod v048_json_file_format_params;
+mod v049_interval_schema;
+mod v050_binary_schema;
+mod v051_timestamp_tz_schema;
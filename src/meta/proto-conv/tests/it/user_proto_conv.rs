@@ -80,6 +80,8 @@ pub(crate) fn test_fs_stage_info() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -120,6 +122,8 @@ pub(crate) fn test_s3_stage_info() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -157,6 +161,8 @@ pub(crate) fn test_s3_stage_info_v16() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -194,6 +200,8 @@ pub(crate) fn test_s3_stage_info_v14() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -227,6 +235,8 @@ pub(crate) fn test_gcs_stage_info() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -262,6 +272,8 @@ pub(crate) fn test_oss_stage_info() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -294,6 +306,8 @@ pub(crate) fn test_webhdfs_stage_info() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -714,6 +728,8 @@ pub(crate) fn test_internal_stage_info_v17() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -743,6 +759,8 @@ pub(crate) fn test_stage_info_v18() -> mt::principal::StageInfo {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -84,6 +84,8 @@ fn test_user_stage_webhdfs_v30() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -128,6 +130,8 @@ fn test_user_stage_fs_v22() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -171,6 +175,8 @@ fn test_user_stage_fs_v21() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -213,6 +219,8 @@ fn test_user_stage_fs_v20() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -256,6 +264,8 @@ fn test_user_stage_fs_v16() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -311,6 +321,8 @@ fn test_user_stage_s3_v16() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -360,6 +372,8 @@ fn test_user_stage_gcs_v16() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -412,6 +426,8 @@ fn test_user_stage_oss_v16() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -466,6 +482,8 @@ fn test_user_stage_oss_v13() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -520,6 +538,8 @@ fn test_user_stage_s3_v11() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -572,6 +592,8 @@ fn test_user_stage_s3_v8() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -615,6 +637,8 @@ fn test_user_stage_fs_v6() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -668,6 +692,8 @@ fn test_user_stage_s3_v6() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -717,6 +743,8 @@ fn test_user_stage_gcs_v6() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -759,6 +787,8 @@ fn test_user_stage_fs_v4() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -812,6 +842,8 @@ fn test_user_stage_s3_v4() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -860,6 +892,8 @@ fn test_user_stage_gcs_v4() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -910,6 +944,8 @@ fn test_user_stage_s3_v1() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -955,6 +991,8 @@ fn test_internal_stage_v17() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -999,6 +1037,8 @@ fn test_user_stage_v19() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -55,6 +55,8 @@ fn test_decode_v31_copy_max_file() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Json(
             mt::principal::JsonFileFormatParams {
                 compression: mt::principal::StageFileCompression::Bz2,
+                strip_outer_array: true,
+                json_path: "".to_string(),
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -97,6 +97,8 @@ fn test_decode_v32_json_file_format_params() -> anyhow::Result<()> {
     let want = || {
         mt::principal::FileFormatParams::Json(JsonFileFormatParams {
             compression: StageFileCompression::Gzip,
+            strip_outer_array: true,
+            json_path: "".to_string(),
         })
     };
     common::test_load_old(func_name!(), file_format_params_v32.as_slice(), 0, want())?;
@@ -0,0 +1,44 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_meta_app as mt;
+use common_meta_app::principal::JsonFileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v48_json_file_format_params() -> anyhow::Result<()> {
+    let file_format_params_v48 = vec![
+        34, 18, 8, 9, 16, 0, 26, 6, 36, 46, 100, 97, 116, 97, 160, 6, 48, 168, 6, 24,
+    ];
+    let want = || {
+        mt::principal::FileFormatParams::Json(JsonFileFormatParams {
+            compression: StageFileCompression::None,
+            strip_outer_array: false,
+            json_path: "$.data".to_string(),
+        })
+    };
+    common::test_load_old(func_name!(), file_format_params_v48.as_slice(), 0, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
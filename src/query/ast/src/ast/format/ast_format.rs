@@ -431,12 +431,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         _over: &'ast Option<Window>,
+        lambda: &'ast Option<Lambda>,
     ) {
-        let mut children = Vec::with_capacity(args.len());
+        let mut children = Vec::with_capacity(args.len() + 1);
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
         }
+        if let Some(lambda) = lambda {
+            self.visit_expr(&lambda.expr);
+            let lambda_child = self.children.pop().unwrap();
+            let params = lambda
+                .params
+                .iter()
+                .map(|param| param.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let lambda_name = format!("Lambda ({params})");
+            let lambda_format_ctx = AstFormatContext::with_children(lambda_name, 1);
+            let lambda_node = FormatTreeNode::with_children(lambda_format_ctx, vec![lambda_child]);
+            children.push(lambda_node);
+        }
         let node_name = if distinct {
             format!("Function {name}Distinct")
         } else {
@@ -1230,6 +1245,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let engine_node = FormatTreeNode::new(engine_format_ctx);
             children.push(engine_node);
         }
+        if let Some(external_location) = &stmt.external_location {
+            let location_name = format!("ExternalLocation {}", external_location);
+            let location_format_ctx = AstFormatContext::new(location_name);
+            let location_node = FormatTreeNode::new(location_format_ctx);
+            children.push(location_node);
+        }
         if !stmt.cluster_by.is_empty() {
             let mut cluster_by_children = Vec::with_capacity(stmt.cluster_by.len());
             for cluster_by in stmt.cluster_by.iter() {
@@ -1339,6 +1360,14 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                let action_name = format!("Action RenameColumn {} to {}", old_column, new_column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
             AlterTableAction::AddColumn { column } => {
                 let action_name = format!("Action Add column {}", column);
                 let action_format_ctx = AstFormatContext::new(action_name);
@@ -1388,6 +1417,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::with_children(action_name, 1);
                 FormatTreeNode::with_children(action_format_ctx, vec![point_node])
             }
+            AlterTableAction::RefreshTable => {
+                let action_name = "Action Refresh".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1523,6 +1557,43 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node = FormatTreeNode::with_children(format_ctx, vec![
+            index_child,
+            table_child,
+            columns_child,
+        ]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_columns(&mut self, stmt: &'ast CreateVirtualColumnsStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -238,6 +238,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             args,
             params,
             window,
+            lambda,
             ..
         } => RcDoc::text(name.to_string())
             .append(if !params.is_empty() {
@@ -257,7 +258,11 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             })
-            .append(inline_comma(args.into_iter().map(pretty_expr)))
+            .append(inline_comma(
+                args.into_iter()
+                    .map(pretty_expr)
+                    .chain(lambda.map(|lambda| RcDoc::text(lambda.to_string()))),
+            ))
             .append(RcDoc::text(")"))
             .append(if let Some(window) = window {
                 RcDoc::text(" OVER (")
@@ -384,5 +389,6 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::space())
             .append(pretty_expr(*date))
             .append(RcDoc::text(")")),
+        Expr::Placeholder { .. } => RcDoc::text("?"),
     }
 }
@@ -23,6 +23,7 @@ mod hint;
 mod index;
 mod insert;
 mod kill;
+mod merge_into;
 mod presign;
 mod replace;
 mod share;
@@ -35,6 +36,7 @@ mod update;
 mod user;
 mod view;
 mod virtual_columns;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -47,6 +49,7 @@ pub use hint::*;
 pub use index::*;
 pub use insert::*;
 pub use kill::*;
+pub use merge_into::*;
 pub use presign::*;
 pub use replace::*;
 pub use share::*;
@@ -59,3 +62,4 @@ pub use update::*;
 pub use user::*;
 pub use view::*;
 pub use virtual_columns::*;
+pub use workload_group::*;
@@ -61,6 +61,11 @@ pub enum Statement {
         object_id: String,
     },
 
+    // Transactions
+    Begin,
+    Commit,
+    Rollback,
+
     SetVariable {
         is_global: bool,
         variable: Identifier,
@@ -84,6 +89,7 @@ pub enum Statement {
     },
 
     Update(UpdateStmt),
+    MergeInto(MergeIntoStmt),
 
     // Catalogs
     ShowCatalogs(ShowCatalogsStmt),
@@ -128,6 +134,8 @@ pub enum Statement {
     // Indexes
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumns(CreateVirtualColumnsStmt),
@@ -158,6 +166,12 @@ pub enum Statement {
     },
     Revoke(RevokeStmt),
 
+    // Workload groups
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+    ShowWorkloadGroups,
+
     // UDF
     CreateUDF {
         if_not_exists: bool,
@@ -301,6 +315,7 @@ impl Display for Statement {
                 }
             }
             Statement::Update(update) => write!(f, "{update}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
@@ -310,6 +325,9 @@ impl Display for Statement {
             }
             Statement::ShowProcessList => write!(f, "SHOW PROCESSLIST")?,
             Statement::ShowMetrics => write!(f, "SHOW METRICS")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Rollback => write!(f, "ROLLBACK")?,
             Statement::ShowEngines => write!(f, "SHOW ENGINES")?,
             Statement::ShowIndexes => write!(f, "SHOW INDEXES")?,
             Statement::ShowFunctions { limit } => {
@@ -389,6 +407,8 @@ impl Display for Statement {
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumns(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumns(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumns(stmt) => write!(f, "{stmt}")?,
@@ -433,6 +453,10 @@ impl Display for Statement {
                 }
             }
             Statement::Revoke(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWorkloadGroups => write!(f, "SHOW WORKLOAD GROUPS")?,
             Statement::CreateUDF {
                 if_not_exists,
                 udf_name,
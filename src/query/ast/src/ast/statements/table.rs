@@ -23,6 +23,7 @@ use crate::ast::write_space_separated_map;
 use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::StageLocation;
 use crate::ast::TableReference;
 use crate::ast::TimeTravelPoint;
 use crate::ast::TypeName;
@@ -111,6 +112,7 @@ pub struct CreateTableStmt {
     pub source: Option<CreateTableSource>,
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
+    pub external_location: Option<ExternalTableLocation>,
     pub cluster_by: Vec<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
@@ -143,6 +145,10 @@ impl Display for CreateTableStmt {
             write!(f, " ENGINE = {engine}")?;
         }
 
+        if let Some(external_location) = &self.external_location {
+            write!(f, " {external_location}")?;
+        }
+
         if !self.cluster_by.is_empty() {
             write!(f, " CLUSTER BY (")?;
             write_comma_separated_list(f, &self.cluster_by)?;
@@ -190,6 +196,38 @@ impl Display for CreateTableSource {
     }
 }
 
+/// The files of an `EXTERNAL` table:
+/// `LOCATION = @stage/path [FILE_FORMAT = (...)] [PATTERN = '...'] [PARTITION BY (col, ...)]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalTableLocation {
+    pub location: StageLocation,
+    pub file_format: BTreeMap<String, String>,
+    pub pattern: Option<String>,
+    pub partition_by: Vec<Identifier>,
+}
+
+impl Display for ExternalTableLocation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "LOCATION = {}", self.location)?;
+        if !self.file_format.is_empty() {
+            write!(f, " FILE_FORMAT = (")?;
+            for (k, v) in self.file_format.iter() {
+                write!(f, " {} = '{}'", k, v)?;
+            }
+            write!(f, " )")?;
+        }
+        if let Some(pattern) = &self.pattern {
+            write!(f, " PATTERN = '{}'", pattern)?;
+        }
+        if !self.partition_by.is_empty() {
+            write!(f, " PARTITION BY (")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DescribeTableStmt {
     pub catalog: Option<Identifier>,
@@ -282,6 +320,10 @@ pub enum AlterTableAction {
     RenameTable {
         new_table: Identifier,
     },
+    RenameColumn {
+        old_column: Identifier,
+        new_column: Identifier,
+    },
     AddColumn {
         column: ColumnDefinition,
     },
@@ -303,6 +345,7 @@ pub enum AlterTableAction {
     RevertTo {
         point: TimeTravelPoint,
     },
+    RefreshTable,
 }
 
 impl Display for AlterTableAction {
@@ -311,6 +354,12 @@ impl Display for AlterTableAction {
             AlterTableAction::RenameTable { new_table } => {
                 write!(f, "RENAME TO {new_table}")
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                write!(f, "RENAME COLUMN {old_column} TO {new_column}")
+            }
             AlterTableAction::AddColumn { column } => {
                 write!(f, "ADD COLUMN {column}")
             }
@@ -344,6 +393,9 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::RefreshTable => {
+                write!(f, "REFRESH")
+            }
         }
     }
 }
@@ -506,6 +558,7 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    External,
 }
 
 impl Display for Engine {
@@ -516,6 +569,7 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::External => write!(f, "EXTERNAL"),
         }
     }
 }
@@ -638,12 +692,25 @@ impl Display for ColumnDefinition {
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
     SetMaskingPolicy(String),
+    SetDataType(TypeName),
+    SetDefault(Box<Expr>),
+    DropDefault,
+    Comment(String),
 }
 
 impl Display for ModifyColumnAction {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match &self {
             ModifyColumnAction::SetMaskingPolicy(name) => write!(f, "SET MASKING POLICY {}", name)?,
+            ModifyColumnAction::SetDataType(data_type) => {
+                write!(f, "{data_type}")?;
+                if !matches!(data_type, TypeName::Nullable(_)) {
+                    write!(f, " NOT NULL")?;
+                }
+            }
+            ModifyColumnAction::SetDefault(expr) => write!(f, "SET DEFAULT {expr}")?,
+            ModifyColumnAction::DropDefault => write!(f, "DROP DEFAULT")?,
+            ModifyColumnAction::Comment(comment) => write!(f, "COMMENT '{comment}'")?,
         }
 
         Ok(())
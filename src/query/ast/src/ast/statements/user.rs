@@ -21,6 +21,7 @@ use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserOptionFlag;
 use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::principal::UserQuota;
 
 use crate::ast::write_comma_separated_list;
 
@@ -158,6 +159,9 @@ pub enum AccountMgrLevel {
 pub enum UserOptionItem {
     TenantSetting(bool),
     DefaultRole(String),
+    MaxCpu(u64),
+    MaxMemoryInBytes(u64),
+    MaxStorageInBytes(u64),
 }
 
 impl UserOptionItem {
@@ -167,6 +171,16 @@ impl UserOptionItem {
                 option.switch_option_flag(UserOptionFlag::TenantSetting, *enabled);
             }
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
+            Self::MaxCpu(_) | Self::MaxMemoryInBytes(_) | Self::MaxStorageInBytes(_) => {}
+        }
+    }
+
+    pub fn apply_quota(&self, quota: &mut UserQuota) {
+        match self {
+            Self::MaxCpu(v) => quota.max_cpu = *v,
+            Self::MaxMemoryInBytes(v) => quota.max_memory_in_bytes = *v,
+            Self::MaxStorageInBytes(v) => quota.max_storage_in_bytes = *v,
+            Self::TenantSetting(_) | Self::DefaultRole(_) => {}
         }
     }
 }
@@ -229,6 +243,9 @@ impl Display for UserOptionItem {
             UserOptionItem::TenantSetting(true) => write!(f, "TENANTSETTING"),
             UserOptionItem::TenantSetting(false) => write!(f, "NOTENANTSETTING"),
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
+            UserOptionItem::MaxCpu(v) => write!(f, "MAX_CPU = {}", v),
+            UserOptionItem::MaxMemoryInBytes(v) => write!(f, "MAX_MEMORY_IN_BYTES = {}", v),
+            UserOptionItem::MaxStorageInBytes(v) => write!(f, "MAX_STORAGE_IN_BYTES = {}", v),
         }
     }
 }
@@ -0,0 +1,115 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::WorkloadGroup;
+
+use crate::ast::write_comma_separated_list;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateWorkloadGroupStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub options: Vec<WorkloadGroupOption>,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE WORKLOAD GROUP")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {}", self.name)?;
+        if !self.options.is_empty() {
+            write!(f, " WITH ")?;
+            write_comma_separated_list(f, &self.options)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterWorkloadGroupStmt {
+    pub name: String,
+    pub action: AlterWorkloadGroupAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterWorkloadGroupAction {
+    Set(Vec<WorkloadGroupOption>),
+    AssignTo(PrincipalIdentity),
+    UnassignFrom(PrincipalIdentity),
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP {}", self.name)?;
+        match &self.action {
+            AlterWorkloadGroupAction::Set(options) => {
+                write!(f, " SET ")?;
+                write_comma_separated_list(f, options)
+            }
+            AlterWorkloadGroupAction::AssignTo(principal) => write!(f, " ASSIGN TO{principal}"),
+            AlterWorkloadGroupAction::UnassignFrom(principal) => {
+                write!(f, " UNASSIGN FROM{principal}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkloadGroupOption {
+    MaxConcurrency(u64),
+    MemoryShare(u64),
+    QueueTimeout(u64),
+}
+
+impl WorkloadGroupOption {
+    pub fn apply(&self, group: &mut WorkloadGroup) {
+        match self {
+            Self::MaxConcurrency(v) => group.max_concurrency = *v,
+            Self::MemoryShare(v) => group.memory_share = *v,
+            Self::QueueTimeout(v) => group.queue_timeout = *v,
+        }
+    }
+}
+
+impl Display for WorkloadGroupOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkloadGroupOption::MaxConcurrency(v) => write!(f, "MAX_CONCURRENCY = {v}"),
+            WorkloadGroupOption::MemoryShare(v) => write!(f, "MEMORY_SHARE = {v}"),
+            WorkloadGroupOption::QueueTimeout(v) => write!(f, "QUEUE_TIMEOUT = {v}"),
+        }
+    }
+}
@@ -175,6 +175,36 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge_into = map(
+        rule! {
+            MERGE ~ #hint? ~ INTO ~ #period_separated_idents_1_to_3 ~ #table_alias?
+            ~ USING ~ ^#table_reference ~ ON ~ ^#expr ~ #merge_option+
+        },
+        |(
+            _,
+            opt_hints,
+            _,
+            (catalog, database, table),
+            target_alias,
+            _,
+            source,
+            _,
+            join_expr,
+            merge_options,
+        )| {
+            Statement::MergeInto(MergeIntoStmt {
+                hints: opt_hints,
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                merge_options,
+            })
+        },
+    );
+
     let show_settings = map(
         rule! {
             SHOW ~ SETTINGS ~ (LIKE ~ #literal_string)?
@@ -212,6 +242,11 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let start_transaction = value(Statement::Begin, rule! { START ~ TRANSACTION });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let rollback = value(Statement::Rollback, rule! { ROLLBACK });
+
     let set_variable = map(
         rule! {
             SET ~ (GLOBAL)? ~ #ident ~ "=" ~ #subexpr(0)
@@ -480,6 +515,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ #create_table_source?
             ~ ( #engine )?
             ~ ( #uri_location )?
+            ~ ( #external_table_location )?
             ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
@@ -493,6 +529,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             source,
             engine,
             uri_location,
+            external_location,
             opt_cluster_by,
             opt_table_options,
             opt_as_query,
@@ -505,6 +542,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 source,
                 engine,
                 uri_location,
+                external_location,
                 cluster_by: opt_cluster_by
                     .map(|(_, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
@@ -719,6 +757,39 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, opt_if_not_exists, index_name, _, (catalog, database, table), _, columns, _)| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ EXISTS )? ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_columns = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMNS ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #period_separated_idents_1_to_3
@@ -881,6 +952,45 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_workload_group = map(
+        rule! {
+            CREATE ~ WORKLOAD ~ GROUP ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ( WITH ~ ^#comma_separated_list1(workload_group_option) )?
+        },
+        |(_, _, _, opt_if_not_exists, name, opt_options)| {
+            Statement::CreateWorkloadGroup(CreateWorkloadGroupStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                options: opt_options.map(|(_, options)| options).unwrap_or_default(),
+            })
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ GROUP ~ #ident ~ #alter_workload_group_action
+        },
+        |(_, _, _, name, action)| {
+            Statement::AlterWorkloadGroup(AlterWorkloadGroupStmt {
+                name: name.to_string(),
+                action,
+            })
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ GROUP ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropWorkloadGroup(DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_workload_groups = value(
+        Statement::ShowWorkloadGroups,
+        rule! { SHOW ~ WORKLOAD ~ GROUPS },
+    );
     let create_udf = map(
         rule! {
             CREATE ~ FUNCTION ~ ( IF ~ NOT ~ EXISTS )?
@@ -1293,6 +1403,13 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #merge_into : "`MERGE INTO <target_table> USING <source> ON <join_expr> { WHEN [NOT] MATCHED [AND <condition>] THEN <operation> }...`"
+        ),
+        rule!(
+            #begin : "`BEGIN [TRANSACTION]`"
+            | #start_transaction : "`START TRANSACTION`"
+            | #commit : "`COMMIT`"
+            | #rollback : "`ROLLBACK`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
@@ -1325,6 +1442,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...)`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_columns: "`CREATE VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
@@ -1377,6 +1496,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #show_grants : "`SHOW GRANTS {FOR  { ROLE <role_name> | USER <user> }] | ON {DATABASE <db_name> | TABLE <db_name>.<table_name>} }`"
             | #revoke : "`REVOKE { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } FROM { [ROLE <role_name>] | [USER] <user> }`"
         ),
+        rule!(
+            #create_workload_group : "`CREATE WORKLOAD GROUP [IF NOT EXISTS] <group_name> [WITH <workload_group_option>, ...]`"
+            | #alter_workload_group : "`ALTER WORKLOAD GROUP <group_name> { SET <workload_group_option>, ... | ASSIGN TO { ROLE <role_name> | [USER] <user> } | UNASSIGN FROM { ROLE <role_name> | [USER] <user> } }`"
+            | #drop_workload_group : "`DROP WORKLOAD GROUP [IF EXISTS] <group_name>`"
+            | #show_workload_groups : "`SHOW WORKLOAD GROUPS`"
+        ),
         rule!(
             #presign: "`PRESIGN [{DOWNLOAD | UPLOAD}] <location> [EXPIRE = 3600]`"
         ),
@@ -1816,14 +1941,54 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, column)| AlterTableAction::AddColumn { column },
     );
-    let modify_column = map(
+    let rename_column = map(
         rule! {
-            MODIFY ~ COLUMN ~ #ident ~ SET ~ MASKING ~ POLICY ~ #ident
+            RENAME ~ COLUMN ~ #ident ~ TO ~ #ident
         },
-        |(_, _, column, _, _, _, mask_name)| AlterTableAction::ModifyColumn {
-            column,
-            action: ModifyColumnAction::SetMaskingPolicy(mask_name.to_string()),
+        |(_, _, old_column, _, new_column)| AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        },
+    );
+    let nullable = alt((
+        value(true, rule! { NULL }),
+        value(false, rule! { NOT ~ ^NULL }),
+    ));
+    let modify_column_action = alt((
+        map(
+            rule! {
+                SET ~ MASKING ~ POLICY ~ #ident
+            },
+            |(_, _, _, mask_name)| ModifyColumnAction::SetMaskingPolicy(mask_name.to_string()),
+        ),
+        map(
+            rule! {
+                SET ~ DEFAULT ~ ^#subexpr(NOT_PREC)
+            },
+            |(_, _, default_expr)| ModifyColumnAction::SetDefault(Box::new(default_expr)),
+        ),
+        value(ModifyColumnAction::DropDefault, rule! { DROP ~ DEFAULT }),
+        map(
+            rule! {
+                COMMENT ~ ^#literal_string
+            },
+            |(_, comment)| ModifyColumnAction::Comment(comment),
+        ),
+        map(
+            rule! {
+                #type_name ~ #nullable?
+            },
+            |(data_type, nullable)| match nullable {
+                Some(true) => ModifyColumnAction::SetDataType(data_type.wrap_nullable()),
+                _ => ModifyColumnAction::SetDataType(data_type),
+            },
+        ),
+    ));
+    let modify_column = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ #modify_column_action
         },
+        |(_, _, column, action)| AlterTableAction::ModifyColumn { column, action },
     );
     let drop_column = map(
         rule! {
@@ -1862,8 +2027,11 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::RevertTo { point },
     );
 
+    let refresh_table = value(AlterTableAction::RefreshTable, rule! { REFRESH });
+
     rule!(
-        #rename_table
+        #rename_column
+        | #rename_table
         | #add_column
         | #drop_column
         | #modify_column
@@ -1871,6 +2039,7 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
+        | #refresh_table
     )(i)
 }
 
@@ -2015,6 +2184,25 @@ pub fn table_option(i: Input) -> IResult<BTreeMap<String, String>> {
     )(i)
 }
 
+pub fn external_table_location(i: Input) -> IResult<ExternalTableLocation> {
+    map(
+        rule! {
+            LOCATION ~ ^"=" ~ ^#stage_location
+            ~ ( #file_format_clause )?
+            ~ ( PATTERN ~ ^"=" ~ ^#literal_string )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+        },
+        |(_, _, location, opt_file_format, opt_pattern, opt_partition_by)| ExternalTableLocation {
+            location,
+            file_format: opt_file_format.unwrap_or_default(),
+            pattern: opt_pattern.map(|(_, _, pattern)| pattern),
+            partition_by: opt_partition_by
+                .map(|(_, _, _, columns, _)| columns)
+                .unwrap_or_default(),
+        },
+    )(i)
+}
+
 pub fn engine(i: Input) -> IResult<Engine> {
     let engine = alt((
         value(Engine::Null, rule! { NULL }),
@@ -2022,6 +2210,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::External, rule! { EXTERNAL }),
     ));
 
     map(
@@ -2081,6 +2270,58 @@ pub fn catalog_type(i: Input) -> IResult<CatalogType> {
     map(rule! { ^#catalog_type }, |catalog_type| catalog_type)(i)
 }
 
+pub fn workload_group_option(i: Input) -> IResult<WorkloadGroupOption> {
+    let max_concurrency = map(
+        rule! {
+            "MAX_CONCURRENCY" ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| WorkloadGroupOption::MaxConcurrency(v),
+    );
+    let memory_share = map(
+        rule! {
+            "MEMORY_SHARE" ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| WorkloadGroupOption::MemoryShare(v),
+    );
+    let queue_timeout = map(
+        rule! {
+            "QUEUE_TIMEOUT" ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| WorkloadGroupOption::QueueTimeout(v),
+    );
+    rule!(
+        #max_concurrency
+        | #memory_share
+        | #queue_timeout
+    )(i)
+}
+
+pub fn alter_workload_group_action(i: Input) -> IResult<AlterWorkloadGroupAction> {
+    let set = map(
+        rule! {
+            SET ~ #comma_separated_list1(workload_group_option)
+        },
+        |(_, options)| AlterWorkloadGroupAction::Set(options),
+    );
+    let assign = map(
+        rule! {
+            ASSIGN ~ TO ~ #grant_option
+        },
+        |(_, _, principal)| AlterWorkloadGroupAction::AssignTo(principal),
+    );
+    let unassign = map(
+        rule! {
+            UNASSIGN ~ FROM ~ #grant_option
+        },
+        |(_, _, principal)| AlterWorkloadGroupAction::UnassignFrom(principal),
+    );
+    rule!(
+        #set
+        | #assign
+        | #unassign
+    )(i)
+}
+
 pub fn user_option(i: Input) -> IResult<UserOptionItem> {
     let default_role_option = map(
         rule! {
@@ -2088,6 +2329,24 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, role)| UserOptionItem::DefaultRole(role),
     );
+    let max_cpu_option = map(
+        rule! {
+            "MAX_CPU" ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| UserOptionItem::MaxCpu(v),
+    );
+    let max_memory_option = map(
+        rule! {
+            "MAX_MEMORY_IN_BYTES" ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| UserOptionItem::MaxMemoryInBytes(v),
+    );
+    let max_storage_option = map(
+        rule! {
+            "MAX_STORAGE_IN_BYTES" ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| UserOptionItem::MaxStorageInBytes(v),
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -2095,6 +2354,9 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
             rule! { NOTENANTSETTING },
         ),
         default_role_option,
+        max_cpu_option,
+        max_memory_option,
+        max_storage_option,
     ))(i)
 }
 
@@ -2219,6 +2481,51 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
     )(i)
 }
 
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    let matched = map(
+        rule! {
+            WHEN ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ ^#match_operation
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched = map(
+        rule! {
+            WHEN ~ NOT ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ INSERT
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                values,
+            })
+        },
+    );
+
+    rule!(
+        #matched
+        | #unmatched
+    )(i)
+}
+
+pub fn match_operation(i: Input) -> IResult<MatchOperation> {
+    alt((
+        map(
+            rule! { UPDATE ~ SET ~ ^#comma_separated_list1(update_expr) },
+            |(_, _, update_list)| MatchOperation::Update { update_list },
+        ),
+        value(MatchOperation::Delete, rule! { DELETE }),
+    ))(i)
+}
+
 pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
     map(rule! { ( #ident ~ "=" ~ ^#expr ) }, |(name, _, expr)| {
         UpdateExpr { name, expr }
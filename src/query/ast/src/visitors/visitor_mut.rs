@@ -244,6 +244,7 @@ pub trait VisitorMut: Sized {
         args: &mut [Expr],
         _params: &mut [Literal],
         over: &mut Option<Window>,
+        lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
             walk_expr_mut(self, arg);
@@ -269,6 +270,10 @@ pub trait VisitorMut: Sized {
                 }
             }
         }
+
+        if let Some(lambda) = lambda {
+            walk_expr_mut(self, &mut lambda.expr);
+        }
     }
 
     fn visit_frame_bound(&mut self, bound: &mut WindowFrameBound) {
@@ -364,6 +369,8 @@ pub trait VisitorMut: Sized {
         walk_expr_mut(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &mut Statement) {
         walk_statement_mut(self, statement);
     }
@@ -388,6 +395,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_metrics(&mut self) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_rollback(&mut self) {}
+
     fn visit_show_engines(&mut self) {}
 
     fn visit_show_functions(&mut self, _limit: &mut Option<ShowLimit>) {}
@@ -426,6 +439,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_update(&mut self, _update: &mut UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &mut ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &mut ShowCreateCatalogStmt) {}
@@ -492,6 +507,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+
     fn visit_create_virtual_columns(&mut self, _stmt: &mut CreateVirtualColumnsStmt) {}
 
     fn visit_alter_virtual_columns(&mut self, _stmt: &mut AlterVirtualColumnsStmt) {}
@@ -520,6 +539,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_revoke(&mut self, _revoke: &mut RevokeStmt) {}
 
+    fn visit_create_workload_group(&mut self, _stmt: &mut CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &mut AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &mut DropWorkloadGroupStmt) {}
+
+    fn visit_show_workload_groups(&mut self) {}
+
     fn visit_create_udf(
         &mut self,
         _if_not_exists: bool,
@@ -78,6 +78,11 @@ fn test_statement() {
         r#"show columns in t from ctl.db"#,
         r#"show full columns from t from db like 'id%'"#,
         r#"show processlist;"#,
+        r#"begin;"#,
+        r#"begin transaction;"#,
+        r#"start transaction;"#,
+        r#"commit;"#,
+        r#"rollback;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
@@ -127,6 +132,7 @@ fn test_statement() {
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1', TENANTSETTING;"#,
+        r#"ALTER USER u1 WITH MAX_CPU = 4, MAX_MEMORY_IN_BYTES = 1073741824, MAX_STORAGE_IN_BYTES = 0;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
@@ -180,6 +186,8 @@ fn test_statement() {
         r#"ALTER TABLE t ADD COLUMN a float default 101 COMMENT 'hello';"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask;"#,
+        r#"ALTER TABLE t MODIFY COLUMN b int NULL;"#,
+        r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
@@ -214,6 +222,12 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant'@'localhost';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"CREATE WORKLOAD GROUP IF NOT EXISTS etl WITH MAX_CONCURRENCY = 4, MEMORY_SHARE = 30, QUEUE_TIMEOUT = 60;"#,
+        r#"ALTER WORKLOAD GROUP etl SET MAX_CONCURRENCY = 8;"#,
+        r#"ALTER WORKLOAD GROUP etl ASSIGN TO ROLE 'loader';"#,
+        r#"ALTER WORKLOAD GROUP etl UNASSIGN FROM 'u1';"#,
+        r#"DROP WORKLOAD GROUP IF EXISTS etl;"#,
+        r#"SHOW WORKLOAD GROUPS;"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
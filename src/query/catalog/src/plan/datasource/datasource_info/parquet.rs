@@ -32,6 +32,8 @@ pub struct ParquetTableInfo {
     pub table_info: TableInfo,
     pub arrow_schema: ArrowSchema,
     pub files_to_read: Option<Vec<StageFileInfo>>,
+    /// The last columns of the table schema, whose values are parsed from the file paths.
+    pub partition_columns: Vec<String>,
 }
 
 impl ParquetTableInfo {
@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 
 use common_expression::types::DataType;
+use common_expression::types::F32;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -74,6 +75,20 @@ pub struct AggIndexInfo {
     pub filter: Option<RemoteExpr>,
 }
 
+/// Information about the top-k query by the distance of vectors,
+/// e.g. `ORDER BY cosine_distance(embedding, [...]) LIMIT 10`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    /// Name of the vector column
+    pub column_name: String,
+    /// Name of the distance function
+    pub func_name: String,
+    /// The constant vector compared with the column
+    pub query: Vec<F32>,
+    /// Number of the nearest rows
+    pub limit: usize,
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -101,6 +116,8 @@ pub struct PushDownInfo {
     pub lazy_materialization: bool,
     /// Aggregating index information.
     pub agg_index: Option<AggIndexInfo>,
+    /// Vector index information.
+    pub vector_index: Option<VectorIndexInfo>,
 }
 
 /// TopK is a wrapper for topk push down items.
@@ -427,6 +427,16 @@ pub struct ColumnStatistics {
     pub max: Scalar,
     pub null_count: u64,
     pub number_of_distinct_values: u64,
+    /// equi-height histogram collected by `ANALYZE TABLE`, if any.
+    pub histogram: Option<Vec<HistogramBucket>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HistogramBucket {
+    /// upper bound (inclusive) of the bucket
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
 }
 
 pub enum CompactTarget {
@@ -29,6 +29,7 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::TableInfo;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -43,6 +44,7 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -138,11 +140,24 @@ pub trait TableContext: Send + Sync {
     // Get the storage data accessor operator from the session manager.
     fn get_data_operator(&self) -> Result<DataOperator>;
 
+    // Get the explicit transaction of the session.
+    fn txn_mgr(&self) -> TxnManagerRef;
+
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams>;
 
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 
+    /// Check the storage quota of the owner of the table before committing `added_bytes` to it.
+    async fn check_storage_quota(&self, table_info: &TableInfo, added_bytes: i64) -> Result<()>;
+
+    /// Release the bytes reserved by `check_storage_quota` for a commit which is retried.
+    async fn release_storage_quota(
+        &self,
+        table_info: &TableInfo,
+        reserved_bytes: i64,
+    ) -> Result<()>;
+
     async fn filter_out_copied_files(
         &self,
         catalog_name: &str,
@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxnState {
+    /// Each statement is committed on its own.
+    AutoCommit,
+    /// In an explicit transaction, the new table metas are buffered until COMMIT.
+    Active,
+    /// A statement of the explicit transaction failed, it can only be rolled back.
+    Fail,
+}
+
+#[derive(Clone, Debug)]
+struct TxnTable {
+    catalog: String,
+    req: UpdateTableMetaReq,
+    // The table info with the uncommitted meta, it's seen by the following statements.
+    table_info: TableInfo,
+}
+
+/// The state of the explicit transaction of a session.
+///
+/// The tables mutated by the statements of a transaction are not committed to the meta
+/// service, instead their new metas are buffered here, and published together by a single
+/// multi-table meta transaction when the transaction is committed.
+#[derive(Debug)]
+pub struct TxnManager {
+    state: TxnState,
+    // table id -> uncommitted table.
+    tables: BTreeMap<u64, TxnTable>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            tables: BTreeMap::new(),
+        }))
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    /// Discard the buffered table metas and go back to auto commit.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.tables.clear();
+    }
+
+    /// Buffer the new meta of a table in the active transaction.
+    pub fn update_table_meta(
+        &mut self,
+        catalog: &str,
+        table_info: &TableInfo,
+        mut req: UpdateTableMetaReq,
+    ) -> Result<()> {
+        if let Some(table) = self.tables.values().next() {
+            if table.catalog != catalog {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Transaction across catalogs is not supported, table {} is in catalog {}, but the transaction is in catalog {}",
+                    table_info.desc, catalog, table.catalog
+                )));
+            }
+        }
+
+        if let Some(prev) = self.tables.remove(&req.table_id) {
+            // The version of the table is the one before the transaction.
+            req.seq = prev.req.seq;
+            req.copied_files = merge_copied_files(prev.req.copied_files, req.copied_files);
+            req.deduplicated_label = req.deduplicated_label.or(prev.req.deduplicated_label);
+        }
+
+        let mut table_info = table_info.clone();
+        table_info.meta = req.new_table_meta.clone();
+        self.tables.insert(req.table_id, TxnTable {
+            catalog: catalog.to_string(),
+            req,
+            table_info,
+        });
+        Ok(())
+    }
+
+    /// Returns the table info with the uncommitted meta, if the table is mutated in the transaction.
+    pub fn get_table_info(&self, table_id: u64) -> Option<TableInfo> {
+        self.tables
+            .get(&table_id)
+            .map(|table| table.table_info.clone())
+    }
+
+    /// Returns the table infos with the uncommitted metas of the tables mutated in the transaction.
+    pub fn table_infos(&self) -> Vec<TableInfo> {
+        self.tables
+            .values()
+            .map(|table| table.table_info.clone())
+            .collect()
+    }
+
+    /// Returns the catalog and the request to commit the buffered table metas,
+    /// none if no table is mutated in the transaction.
+    pub fn req(&self) -> Option<(String, UpdateMultiTableMetaReq)> {
+        let catalog = self.tables.values().next()?.catalog.clone();
+        let update_table_metas = self
+            .tables
+            .values()
+            .map(|table| table.req.clone())
+            .collect();
+        Some((catalog, UpdateMultiTableMetaReq { update_table_metas }))
+    }
+}
+
+fn merge_copied_files(
+    prev: Option<UpsertTableCopiedFileReq>,
+    cur: Option<UpsertTableCopiedFileReq>,
+) -> Option<UpsertTableCopiedFileReq> {
+    match (prev, cur) {
+        (Some(mut prev), Some(cur)) => {
+            prev.file_info.extend(cur.file_info);
+            prev.expire_at = prev.expire_at.max(cur.expire_at);
+            prev.fail_if_duplicated |= cur.fail_if_duplicated;
+            Some(prev)
+        }
+        (prev, cur) => prev.or(cur),
+    }
+}
@@ -1956,13 +1956,25 @@ impl From<AuthInfo> for UserAuthConfig {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct LocalConfig {
-    // sql to run
-    #[clap(long, default_value = "SELECT 1")]
+    // sql to run, statements are separated by `;`
+    #[clap(long, default_value = "")]
     pub sql: String,
 
     // name1=filepath1,name2=filepath2
     #[clap(long, default_value = "")]
     pub table: String,
+
+    // sql file to run, `-` to read from stdin
+    #[clap(long, default_value = "")]
+    pub file: String,
+
+    // table, csv, json or parquet
+    #[clap(long, default_value = "table")]
+    pub output_format: String,
+
+    // dir to store the meta and data of the created tables
+    #[clap(long, default_value = ".databend")]
+    pub data_path: String,
 }
 
 impl Default for LocalConfig {
@@ -1976,6 +1988,9 @@ impl From<InnerLocalConfig> for LocalConfig {
         Self {
             sql: inner.sql,
             table: inner.table,
+            file: inner.file,
+            output_format: inner.output_format,
+            data_path: inner.data_path,
         }
     }
 }
@@ -1987,6 +2002,9 @@ impl TryInto<InnerLocalConfig> for LocalConfig {
         Ok(InnerLocalConfig {
             sql: self.sql,
             table: self.table,
+            file: self.file,
+            output_format: self.output_format,
+            data_path: self.data_path,
         })
     }
 }
@@ -430,13 +430,20 @@ pub struct LocalConfig {
     pub sql: String,
     // name1=filepath1,name2=filepath2
     pub table: String,
+    // sql file to run, `-` to read from stdin
+    pub file: String,
+    pub output_format: String,
+    pub data_path: String,
 }
 
 impl Default for LocalConfig {
     fn default() -> Self {
         Self {
-            sql: "SELECT 1".to_string(),
+            sql: "".to_string(),
             table: "".to_string(),
+            file: "".to_string(),
+            output_format: "table".to_string(),
+            data_path: ".databend".to_string(),
         }
     }
 }
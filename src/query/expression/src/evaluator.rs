@@ -161,6 +161,17 @@ impl<'a> Evaluator<'a> {
                 ctx.render_error(*span, id.params(), &args, &function.signature.name)?;
                 Ok(result)
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_expr,
+                ..
+            } => {
+                let data_type = args[0].data_type();
+                let value = self.partial_run(&args[0], validity.clone())?;
+                let lambda_expr = lambda_expr.as_expr(self.fn_registry);
+                self.run_lambda(name, value, data_type, &lambda_expr, validity)
+            }
         };
 
         #[cfg(debug_assertions)]
@@ -876,6 +887,320 @@ impl<'a> Evaluator<'a> {
 
         unreachable!("expr is not a set returning function: {expr}")
     }
+
+    /// Evaluate a higher-order function. The lambda body is evaluated vectorized over
+    /// the flattened values of the array argument instead of row by row.
+    fn run_lambda(
+        &self,
+        func_name: &str,
+        value: Value<AnyType>,
+        data_type: &DataType,
+        lambda_expr: &Expr,
+        validity: Option<Bitmap>,
+    ) -> Result<Value<AnyType>> {
+        match value {
+            Value::Scalar(Scalar::Null) => Ok(Value::Scalar(Scalar::Null)),
+            Value::Scalar(scalar) => {
+                let column = ColumnBuilder::repeat(&scalar.as_ref(), 1, data_type).build();
+                let result = self.run_lambda_column(func_name, column, lambda_expr, None)?;
+                Ok(Value::Scalar(result.index(0).unwrap().to_owned()))
+            }
+            Value::Column(column) => Ok(Value::Column(self.run_lambda_column(
+                func_name,
+                column,
+                lambda_expr,
+                validity,
+            )?)),
+        }
+    }
+
+    fn run_lambda_column(
+        &self,
+        func_name: &str,
+        column: Column,
+        lambda_expr: &Expr,
+        validity: Option<Bitmap>,
+    ) -> Result<Column> {
+        match column {
+            Column::Nullable(box NullableColumn {
+                column,
+                validity: arg_validity,
+            }) => {
+                let validity = validity
+                    .map(|validity| (&validity) & (&arg_validity))
+                    .unwrap_or_else(|| arg_validity.clone());
+                let result =
+                    self.run_lambda_column(func_name, column, lambda_expr, Some(validity))?;
+                Ok(result.wrap_nullable(Some(arg_validity)))
+            }
+            Column::Array(box array) => {
+                self.run_lambda_array(func_name, array, lambda_expr, validity)
+            }
+            other => unreachable!("lambda function argument must be an array: {other:?}"),
+        }
+    }
+
+    fn run_lambda_array(
+        &self,
+        func_name: &str,
+        array: ArrayColumn<AnyType>,
+        lambda_expr: &Expr,
+        validity: Option<Bitmap>,
+    ) -> Result<Column> {
+        let ArrayColumn { values, offsets } = array;
+        let num_rows = offsets.len() - 1;
+        let row_len = |row: usize| (offsets[row + 1] - offsets[row]) as usize;
+
+        // Expand the row validity to every element of the row.
+        let values_validity = validity.as_ref().map(|validity| {
+            let mut builder = MutableBitmap::with_capacity(values.len());
+            for row in 0..num_rows {
+                builder.extend_constant(row_len(row), validity.get_bit(row));
+            }
+            Bitmap::from(builder)
+        });
+
+        match func_name {
+            "array_transform" => {
+                let num_values = values.len();
+                let values =
+                    self.run_lambda_body(lambda_expr, vec![values], num_values, values_validity)?;
+                Ok(Column::Array(Box::new(ArrayColumn { values, offsets })))
+            }
+            "array_filter" => {
+                let num_values = values.len();
+                let predicate = self.run_lambda_body(
+                    lambda_expr,
+                    vec![values.clone()],
+                    num_values,
+                    values_validity,
+                )?;
+                let predicate = predicate_to_bitmap(predicate);
+
+                let mut new_offsets = Vec::with_capacity(num_rows + 1);
+                new_offsets.push(0);
+                let mut offset = 0;
+                for row in 0..num_rows {
+                    let start = offsets[row] as usize;
+                    offset += (start..start + row_len(row))
+                        .filter(|i| predicate.get_bit(*i))
+                        .count() as u64;
+                    new_offsets.push(offset);
+                }
+                Ok(Column::Array(Box::new(ArrayColumn {
+                    values: values.filter(&predicate),
+                    offsets: new_offsets.into(),
+                })))
+            }
+            "array_any_match" => {
+                let num_values = values.len();
+                let predicate =
+                    self.run_lambda_body(lambda_expr, vec![values], num_values, values_validity)?;
+                let predicate = predicate_to_bitmap(predicate);
+                let result = (0..num_rows)
+                    .map(|row| {
+                        let start = offsets[row] as usize;
+                        (start..start + row_len(row)).any(|i| predicate.get_bit(i))
+                    })
+                    .collect();
+                Ok(Column::Boolean(result))
+            }
+            "array_reduce" => {
+                // Order the rows by length descending, so the rows that still have elements
+                // to fold at each step always form a prefix of the accumulator.
+                let mut rows = (0..num_rows).collect::<Vec<_>>();
+                rows.sort_by_key(|row| std::cmp::Reverse(row_len(*row)));
+                let max_len = rows.first().map(|row| row_len(*row)).unwrap_or(0);
+
+                let active_rows =
+                    |step: usize| rows.iter().take_while(move |row| row_len(**row) > step);
+                let take_step = |step: usize| {
+                    let indices = active_rows(step)
+                        .map(|row| offsets[*row] + step as u64)
+                        .collect::<Vec<_>>();
+                    values.take(&indices)
+                };
+
+                let mut acc = take_step(0);
+                for step in 1..max_len {
+                    let num_active = active_rows(step).count();
+                    let step_validity = validity.as_ref().map(|validity| {
+                        active_rows(step)
+                            .map(|row| validity.get_bit(*row))
+                            .collect::<Bitmap>()
+                    });
+                    let reduced = self.run_lambda_body(
+                        lambda_expr,
+                        vec![acc.slice(0..num_active), take_step(step)],
+                        num_active,
+                        step_validity,
+                    )?;
+                    acc = if num_active < acc.len() {
+                        Column::concat(&[reduced, acc.slice(num_active..acc.len())])
+                    } else {
+                        reduced
+                    };
+                }
+
+                // Reducing an empty array results in NULL, pad a default value for it.
+                let mut default = ColumnBuilder::with_capacity(&values.data_type(), 1);
+                default.push_default();
+                let num_non_empty = acc.len();
+                let acc = Column::concat(&[acc, default.build()]);
+
+                let mut indices = vec![num_non_empty as u64; num_rows];
+                for (pos, row) in rows.iter().take(num_non_empty).enumerate() {
+                    indices[*row] = pos as u64;
+                }
+                let result_validity = (0..num_rows).map(|row| row_len(row) > 0).collect();
+                Ok(acc.take(&indices).wrap_nullable(Some(result_validity)))
+            }
+            "array_sort" => {
+                // Bottom-up merge sort of all the rows at once. In each round the runs of
+                // `width` elements are merged pairwise, and each step of the round evaluates
+                // the comparator once on the heads of all the unfinished merges, so a row of
+                // `n` elements takes `O(n log n)` comparisons in `O(n)` evaluations.
+                let mut order = (0..values.len() as u64).collect::<Vec<_>>();
+                let max_len = (0..num_rows).map(row_len).max().unwrap_or(0);
+                let mut width = 1;
+                while width < max_len {
+                    let mut merged = order.clone();
+                    let mut merges = Vec::new();
+                    for row in 0..num_rows {
+                        let start = offsets[row] as usize;
+                        let end = start + row_len(row);
+                        for left in (start..end).step_by(2 * width) {
+                            let mid = (left + width).min(end);
+                            let right_end = (mid + width).min(end);
+                            if mid < right_end {
+                                merges.push(Merge {
+                                    row,
+                                    left,
+                                    left_end: mid,
+                                    right: mid,
+                                    right_end,
+                                    out: left,
+                                });
+                            }
+                        }
+                    }
+
+                    while !merges.is_empty() {
+                        let lhs = merges.iter().map(|m| order[m.left]).collect::<Vec<_>>();
+                        let rhs = merges.iter().map(|m| order[m.right]).collect::<Vec<_>>();
+                        let step_validity = validity.as_ref().map(|validity| {
+                            merges
+                                .iter()
+                                .map(|m| validity.get_bit(m.row))
+                                .collect::<Bitmap>()
+                        });
+                        let num_merges = merges.len();
+                        let result = self.run_lambda_body(
+                            lambda_expr,
+                            vec![values.take(&lhs), values.take(&rhs)],
+                            num_merges,
+                            step_validity,
+                        )?;
+                        let cmp_values = lambda_cmp_values(result);
+
+                        // Take the left head unless it is greater, to keep the sort stable.
+                        for (merge, cmp) in merges.iter_mut().zip(cmp_values) {
+                            if cmp > 0 {
+                                merged[merge.out] = order[merge.right];
+                                merge.right += 1;
+                            } else {
+                                merged[merge.out] = order[merge.left];
+                                merge.left += 1;
+                            }
+                            merge.out += 1;
+                        }
+                        merges.retain(|merge| {
+                            if merge.left < merge.left_end && merge.right < merge.right_end {
+                                return true;
+                            }
+                            // One of the runs is exhausted, the rest of the other is in order.
+                            let rest = if merge.left < merge.left_end {
+                                merge.left..merge.left_end
+                            } else {
+                                merge.right..merge.right_end
+                            };
+                            let out = merge.out..merge.out + rest.len();
+                            merged[out].copy_from_slice(&order[rest]);
+                            false
+                        });
+                    }
+                    order = merged;
+                    width *= 2;
+                }
+
+                Ok(Column::Array(Box::new(ArrayColumn {
+                    values: values.take(&order),
+                    offsets,
+                })))
+            }
+            _ => unreachable!("unknown lambda function: {func_name}"),
+        }
+    }
+
+    /// Evaluate the lambda body over a block whose columns are the lambda parameters.
+    fn run_lambda_body(
+        &self,
+        lambda_expr: &Expr,
+        params: Vec<Column>,
+        num_rows: usize,
+        validity: Option<Bitmap>,
+    ) -> Result<Column> {
+        let entries = params
+            .into_iter()
+            .map(|column| BlockEntry::new(column.data_type(), Value::Column(column)))
+            .collect();
+        let block = DataBlock::new(entries, num_rows);
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.partial_run(lambda_expr, validity)?;
+        Ok(result.convert_to_full_column(lambda_expr.data_type(), num_rows))
+    }
+}
+
+/// Two sorted runs of a row being merged by `array_sort`, by the positions in the row order.
+struct Merge {
+    row: usize,
+    left: usize,
+    left_end: usize,
+    right: usize,
+    right_end: usize,
+    out: usize,
+}
+
+/// Convert the result of an `array_sort` comparator to numbers, treating NULL as equal.
+fn lambda_cmp_values(result: Column) -> Vec<i64> {
+    match result {
+        Column::Nullable(box NullableColumn { column, validity }) => column
+            .as_number()
+            .and_then(|col| col.as_int64())
+            .unwrap()
+            .iter()
+            .zip(validity.iter())
+            .map(|(v, valid)| if valid { *v } else { 0 })
+            .collect(),
+        column => column
+            .as_number()
+            .and_then(|col| col.as_int64())
+            .unwrap()
+            .to_vec(),
+    }
+}
+
+/// Convert the result of a lambda predicate to a bitmap, treating NULL as false.
+fn predicate_to_bitmap(predicate: Column) -> Bitmap {
+    match predicate {
+        Column::Boolean(bitmap) => bitmap,
+        Column::Nullable(box NullableColumn {
+            column: Column::Boolean(bitmap),
+            validity,
+        }) => (&bitmap) & (&validity),
+        Column::Null { len } => constant_bitmap(false, len).into(),
+        other => unreachable!("lambda predicate must be boolean: {other:?}"),
+    }
 }
 
 pub struct ConstantFolder<'a, Index: ColumnIndex> {
@@ -1214,6 +1539,48 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args_expr = args
+                    .iter()
+                    .map(|arg| self.fold_once(arg).0)
+                    .collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let func_expr = Expr::LambdaFunctionCall {
+                    span: *span,
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let block = DataBlock::empty();
+                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                    // Since we know the expression is constant, it'll be safe to change its column index type.
+                    let func_expr = func_expr.project_column_ref(|_| unreachable!());
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&func_expr) {
+                        return (
+                            Expr::Constant {
+                                span: *span,
+                                scalar,
+                                data_type: return_type.clone(),
+                            },
+                            None,
+                        );
+                    }
+                }
+
+                (func_expr, None)
+            }
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
@@ -123,6 +123,10 @@ impl Column {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::String(column)
             }
+            Column::Binary(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Binary(column)
+            }
             Column::Timestamp(column) => {
                 let ts = Self::filter_primitive_types(column, filter);
                 Column::Timestamp(ts)
@@ -131,6 +135,14 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::Interval(i)
+            }
+            Column::TimestampTz(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::TimestampTz(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
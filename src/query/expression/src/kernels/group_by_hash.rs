@@ -33,12 +33,15 @@ use micromarshal::Marshal;
 use crate::types::boolean::BooleanType;
 use crate::types::decimal::Decimal;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::interval_normalize;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::Number;
 use crate::types::number::NumberColumn;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::string::StringIterator;
+use crate::types::timestamp_tz::make_timestamp_tz;
+use crate::types::timestamp_tz::timestamp_tz_parts;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::NumberDataType;
@@ -642,6 +645,13 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
         }
         Column::Timestamp(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Date(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
+        Column::Interval(v) => {
+            vec.extend_from_slice(interval_normalize(v[row]).to_le_bytes().as_ref())
+        }
+        Column::TimestampTz(v) => {
+            let micros = timestamp_tz_parts(v[row]).0;
+            vec.extend_from_slice(make_timestamp_tz(micros, 0).to_le_bytes().as_ref())
+        }
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             BinaryWrite::write_uvarint(vec, data.len() as u64).unwrap();
@@ -649,7 +659,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
                 serialize_column_binary(&data, i, vec);
             }
         }
-        Column::Bitmap(v) => {
+        Column::Binary(v) | Column::Bitmap(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
         Column::Nullable(c) => {
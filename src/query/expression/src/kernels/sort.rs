@@ -28,10 +28,13 @@ use common_arrow::arrow::error::Result as ArrowResult;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::types::interval::interval_cmp_key;
 use crate::types::DataType;
 use crate::utils::arrow::column_to_arrow_array;
 use crate::Column;
 use crate::DataBlock;
+use crate::ARROW_EXT_TYPE_INTERVAL;
+use crate::ARROW_EXT_TYPE_VARIANT;
 
 pub type Aborting = Arc<Box<dyn Fn() -> bool + Send + Sync + 'static>>;
 
@@ -237,11 +240,31 @@ fn compare_decimal256(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCom
     Ok(Box::new(move |i, j| left.value(i).cmp(&right.value(j))))
 }
 
+// The intervals are ordered by the normalized value instead of the packed fields.
+fn compare_interval(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i128>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i128>>()
+        .unwrap()
+        .clone();
+
+    Ok(Box::new(move |i, j| {
+        interval_cmp_key(left.value(i)).cmp(&interval_cmp_key(right.value(j)))
+    }))
+}
+
 fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     match left.data_type() {
         ArrowType::Extension(name, _, _) => {
-            if name == "Variant" {
+            if name == ARROW_EXT_TYPE_VARIANT {
                 compare_variant(left, right)
+            } else if name == ARROW_EXT_TYPE_INTERVAL {
+                compare_interval(left, right)
             } else {
                 Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for data type {:?}",
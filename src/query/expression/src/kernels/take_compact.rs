@@ -19,6 +19,7 @@ use common_exception::Result;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
 use crate::types::map::KvColumnBuilder;
@@ -100,6 +101,9 @@ impl Column {
             Column::String(column) => {
                 StringType::upcast_column(Self::take_string_types(column, indices, row_num))
             }
+            Column::Binary(column) => {
+                BinaryType::upcast_column(Self::take_string_types(column, indices, row_num))
+            }
             Column::Timestamp(column) => {
                 let builder = Self::take_primitive_types(column, indices, row_num);
                 let ts = <NumberType<i64>>::upcast_column(<NumberType<i64>>::column_from_vec(
@@ -124,6 +128,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices, row_num);
+                Column::Interval(builder.into())
+            }
+            Column::TimestampTz(column) => {
+                let builder = Self::take_primitive_types(column, indices, row_num);
+                Column::TimestampTz(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -22,6 +22,7 @@ use super::fixed;
 use super::fixed::FixedLengthEncoding;
 use super::variable;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::interval_cmp_key;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::DataType;
@@ -123,6 +124,12 @@ impl RowConverter {
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
+                DataType::Interval => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += i128::ENCODED_LEN as u64),
+                DataType::TimestampTz => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += i128::ENCODED_LEN as u64),
                 DataType::String => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -146,6 +153,29 @@ impl RowConverter {
                             })
                     }
                 }
+                DataType::Binary => {
+                    let col = col.remove_nullable();
+                    if all_null {
+                        lengths.iter_mut().for_each(|x| *x += 1)
+                    } else if let Some(validity) = validity {
+                        col.as_binary()
+                            .unwrap()
+                            .iter()
+                            .zip(validity.iter())
+                            .zip(lengths.iter_mut())
+                            .for_each(|((bytes, v), length)| {
+                                *length += variable::encoded_len(bytes, !v) as u64
+                            })
+                    } else {
+                        col.as_binary()
+                            .unwrap()
+                            .iter()
+                            .zip(lengths.iter_mut())
+                            .for_each(|(bytes, length)| {
+                                *length += variable::encoded_len(bytes, false) as u64
+                            })
+                    }
+                }
                 DataType::Variant => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -231,7 +261,17 @@ fn encode_column(out: &mut StringColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        // The intervals are ordered by the normalized value instead of the packed fields.
+        Column::Interval(col) => fixed::encode(
+            out,
+            col.iter().map(|i| interval_cmp_key(*i)),
+            validity,
+            asc,
+            nulls_first,
+        ),
+        Column::TimestampTz(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::String(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
+        Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::Variant(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         _ => unimplemented!(),
     }
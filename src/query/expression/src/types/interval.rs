@@ -0,0 +1,399 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+/// The days of a month used to spill the fractional months, e.g. `1.5 months * 2`,
+/// and to compare the intervals.
+pub const DAYS_IN_A_MONTH: i64 = 30;
+
+/// An interval of months, days and microseconds.
+///
+/// The three fields are kept apart because a month has a variable number of days,
+/// and a day has a variable number of hours around the daylight saving time.
+/// They are packed into an `i128` as `months * 2^96 + days * 2^64 + micros`.
+///
+/// The packed values are not ordered, the intervals are compared by [`interval_cmp_key`],
+/// so that `24 hours` equals `1 day` and `30 days` equals `1 month`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = i128;
+    type ScalarRef<'a> = i128;
+    type Column = Buffer<i128>;
+    type Domain = SimpleDomain<i128>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, i128>>;
+    type ColumnBuilder = Vec<i128>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: i128) -> i128 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<i128>> {
+        domain.as_interval().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<i128>) -> Domain {
+        Domain::Interval(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: make_interval(-i32::MAX, i32::MIN, i64::MIN),
+            max: make_interval(i32::MAX, i32::MAX, i64::MAX),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Pack the fields into an interval, `months` must not be `i32::MIN`.
+#[inline]
+pub fn make_interval(months: i32, days: i32, micros: i64) -> i128 {
+    ((months as i128) << 96) + ((days as i128) << 64) + micros as i128
+}
+
+/// Split the interval into months, days and micros.
+#[inline]
+pub fn interval_parts(interval: i128) -> (i32, i32, i64) {
+    let micros = interval as i64;
+    let rest = (interval - micros as i128) >> 64;
+    let days = rest as i32;
+    let months = ((rest - days as i128) >> 32) as i32;
+    (months, days, micros)
+}
+
+/// The value to compare the intervals by, in micros with 30-day months and 24-hour days.
+#[inline]
+pub fn interval_cmp_key(interval: i128) -> i128 {
+    let (months, days, micros) = interval_parts(interval);
+    let days = months as i128 * DAYS_IN_A_MONTH as i128 + days as i128;
+    days * MICROS_IN_A_DAY as i128 + micros as i128
+}
+
+/// Build the interval from the wider fields, fails if any of them overflows.
+pub fn checked_make_interval(months: i64, days: i64, micros: i128) -> Result<i128, String> {
+    match (
+        i32::try_from(months),
+        i32::try_from(days),
+        i64::try_from(micros),
+    ) {
+        (Ok(months), Ok(days), Ok(micros)) if months != i32::MIN => {
+            Ok(make_interval(months, days, micros))
+        }
+        _ => Err("interval is out of range".to_string()),
+    }
+}
+
+/// The canonical interval of the same length, all the intervals comparing equal are
+/// normalized to the same value, e.g. `24 hours` and `1 day`.
+pub fn interval_normalize(interval: i128) -> i128 {
+    let key = interval_cmp_key(interval);
+    let micros_in_a_month = DAYS_IN_A_MONTH as i128 * MICROS_IN_A_DAY as i128;
+    let months = key / micros_in_a_month;
+    let days = key % micros_in_a_month / MICROS_IN_A_DAY as i128;
+    let micros = key % MICROS_IN_A_DAY as i128;
+    checked_make_interval(months as i64, days as i64, micros).unwrap_or(interval)
+}
+
+pub fn interval_add(lhs: i128, rhs: i128) -> Result<i128, String> {
+    let (m1, d1, u1) = interval_parts(lhs);
+    let (m2, d2, u2) = interval_parts(rhs);
+    checked_make_interval(
+        m1 as i64 + m2 as i64,
+        d1 as i64 + d2 as i64,
+        u1 as i128 + u2 as i128,
+    )
+}
+
+pub fn interval_neg(interval: i128) -> Result<i128, String> {
+    let (months, days, micros) = interval_parts(interval);
+    checked_make_interval(-(months as i64), -(days as i64), -(micros as i128))
+}
+
+/// Multiply the interval by a factor, the fractional months are spilled into days,
+/// and the fractional days are spilled into micros.
+pub fn interval_mul(interval: i128, factor: f64) -> Result<i128, String> {
+    let (months, days, micros) = interval_parts(interval);
+    let months = months as f64 * factor;
+    let days = days as f64 * factor + months.fract() * DAYS_IN_A_MONTH as f64;
+    let micros = micros as f64 * factor + days.fract() * MICROS_IN_A_DAY as f64;
+    if !(months.is_finite() && days.is_finite() && micros.is_finite()) {
+        return Err("interval is out of range".to_string());
+    }
+    checked_make_interval(
+        months.trunc() as i64,
+        days.trunc() as i64,
+        micros.round() as i128,
+    )
+}
+
+/// The interval between two timestamps, the whole days are kept in the days field.
+pub fn interval_between(end: i64, start: i64) -> i128 {
+    // The difference of two `i64` can not overflow the days field.
+    let micros = end as i128 - start as i128;
+    let days = micros / MICROS_IN_A_DAY as i128;
+    let micros = micros % MICROS_IN_A_DAY as i128;
+    make_interval(0, days as i32, micros as i64)
+}
+
+/// Parse the interval from strings like `1 year 2 months`, `-3 days 04:05:06.5` or `1.5 hours`.
+pub fn string_to_interval(s: impl AsRef<[u8]>) -> Result<i128, String> {
+    let s = std::str::from_utf8(s.as_ref()).map_err(|e| e.to_string())?;
+    let invalid = || format!("invalid interval: '{s}'");
+
+    let (mut months, mut days, mut micros) = (0_i64, 0_i64, 0_i128);
+    let mut tokens = s.split_whitespace().peekable();
+    if tokens.peek().is_none() {
+        return Err(invalid());
+    }
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            micros += parse_time(token).ok_or_else(invalid)? as i128;
+            continue;
+        }
+
+        // The unit may follow the number without spaces, e.g. `10s`.
+        let split = token
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(token.len());
+        let (number, unit) = match &token[..split] {
+            "" => return Err(invalid()),
+            number if split < token.len() => (number, token[split..].to_string()),
+            number => (number, tokens.next().ok_or_else(invalid)?.to_string()),
+        };
+        let number = number.parse::<f64>().map_err(|_| invalid())?;
+        if !number.is_finite() {
+            return Err(invalid());
+        }
+
+        let unit = unit.to_lowercase();
+        let (unit_months, unit_micros) = match unit.as_str() {
+            "y" | "yr" | "yrs" | "year" | "years" => (12, 0),
+            "quarter" | "quarters" => (3, 0),
+            "mon" | "mons" | "month" | "months" => (1, 0),
+            "w" | "week" | "weeks" => (0, 7 * MICROS_IN_A_DAY),
+            "d" | "day" | "days" => (0, MICROS_IN_A_DAY),
+            "h" | "hr" | "hrs" | "hour" | "hours" => (0, MICROS_IN_AN_HOUR),
+            "m" | "min" | "mins" | "minute" | "minutes" => (0, MICROS_IN_A_MINUTE),
+            "s" | "sec" | "secs" | "second" | "seconds" => (0, MICROS_IN_A_SEC),
+            "ms" | "millisecond" | "milliseconds" => (0, 1_000),
+            "us" | "microsecond" | "microseconds" => (0, 1),
+            _ => return Err(invalid()),
+        };
+        if unit_months > 0 {
+            if number.fract() != 0.0 {
+                return Err(format!(
+                    "invalid interval: '{s}', the number of {unit} must be an integer"
+                ));
+            }
+            months = months.saturating_add((number * unit_months as f64) as i64);
+        } else if unit_micros >= MICROS_IN_A_DAY {
+            // Whole days are kept in the days field.
+            let unit_days = unit_micros / MICROS_IN_A_DAY;
+            days = days.saturating_add((number.trunc() * unit_days as f64) as i64);
+            micros = micros.saturating_add((number.fract() * unit_micros as f64).round() as i128);
+        } else {
+            micros = micros.saturating_add((number * unit_micros as f64).round() as i128);
+        }
+    }
+
+    checked_make_interval(months, days, micros)
+}
+
+// Parse `[-]HH:MM[:SS[.ffffff]]` into micros.
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let mut parts = s.split(':');
+    let hours = parts.next()?.parse::<u32>().ok()? as i64;
+    let minutes = parts.next()?.parse::<u32>().ok()? as i64;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || minutes >= 60 || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let micros = hours * MICROS_IN_AN_HOUR
+        + minutes * MICROS_IN_A_MINUTE
+        + (seconds * MICROS_IN_A_SEC as f64).round() as i64;
+    Some(if negative { -micros } else { micros })
+}
+
+/// Format the interval like `1 year 2 months 3 days 04:05:06.5`, which can be parsed back.
+pub fn interval_to_string(interval: i128) -> String {
+    let (months, days, micros) = interval_parts(interval);
+    let mut parts = vec![];
+    let (years, months) = (months / 12, months % 12);
+    for (value, unit) in [(years, "year"), (months, "month"), (days, "day")] {
+        if value != 0 {
+            let plural = if value.abs() == 1 { "" } else { "s" };
+            parts.push(format!("{value} {unit}{plural}"));
+        }
+    }
+
+    if micros != 0 || parts.is_empty() {
+        let sign = if micros < 0 { "-" } else { "" };
+        let abs = micros.unsigned_abs();
+        let hours = abs / MICROS_IN_AN_HOUR as u64;
+        let minutes = abs % MICROS_IN_AN_HOUR as u64 / MICROS_IN_A_MINUTE as u64;
+        let seconds = abs % MICROS_IN_A_MINUTE as u64 / MICROS_IN_A_SEC as u64;
+        let fraction = abs % MICROS_IN_A_SEC as u64;
+        let mut time = format!("{sign}{hours:02}:{minutes:02}:{seconds:02}");
+        if fraction != 0 {
+            let fraction = format!("{fraction:06}");
+            write!(time, ".{}", fraction.trim_end_matches('0')).unwrap();
+        }
+        parts.push(time);
+    }
+    parts.join(" ")
+}
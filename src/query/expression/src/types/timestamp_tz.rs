@@ -0,0 +1,323 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use chrono::FixedOffset;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::check_timestamp;
+use crate::types::timestamp::string_to_timestamp;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::timestamp::TIMESTAMP_FORMAT;
+use crate::types::timestamp::TIMESTAMP_MAX;
+use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::utils::date_helper::DateConverter;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// The maximum absolute offset from UTC in seconds, `+18:00` or `-18:00`.
+pub const TIMESTAMP_TZ_MAX_OFFSET: i32 = 18 * 3600;
+
+/// A timestamp with the offset from UTC it was written in.
+///
+/// The UTC microseconds and the offset in seconds are packed into an `i128`
+/// as `micros * 2^32 + offset`, so that the values are ordered by the instant,
+/// and the original offset is kept for rendering and the datetime functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = i128;
+    type ScalarRef<'a> = i128;
+    type Column = Buffer<i128>;
+    type Domain = SimpleDomain<i128>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, i128>>;
+    type ColumnBuilder = Vec<i128>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: i128) -> i128 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<i128>> {
+        domain.as_timestamp_tz().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<i128>) -> Domain {
+        Domain::TimestampTz(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: make_timestamp_tz(TIMESTAMP_MIN, -TIMESTAMP_TZ_MAX_OFFSET),
+            max: make_timestamp_tz(TIMESTAMP_MAX, TIMESTAMP_TZ_MAX_OFFSET),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Pack the UTC micros and the offset in seconds into a timestamp with time zone.
+#[inline]
+pub fn make_timestamp_tz(micros: i64, offset: i32) -> i128 {
+    ((micros as i128) << 32) + offset as i128
+}
+
+/// Split the timestamp with time zone into the UTC micros and the offset in seconds.
+#[inline]
+pub fn timestamp_tz_parts(ts: i128) -> (i64, i32) {
+    let offset = ts as i32;
+    let micros = ((ts - offset as i128) >> 32) as i64;
+    (micros, offset)
+}
+
+/// The micros of the wall clock time in the offset of the timestamp with time zone.
+#[inline]
+pub fn timestamp_tz_local_micros(ts: i128) -> i64 {
+    let (micros, offset) = timestamp_tz_parts(ts);
+    micros + offset as i64 * MICROS_IN_A_SEC
+}
+
+/// Build the timestamp with time zone from the UTC micros, using the offset of the time zone at that instant.
+pub fn timestamp_to_timestamp_tz(micros: i64, tz: Tz) -> i128 {
+    let offset = micros.to_timestamp(tz).offset().fix();
+    make_timestamp_tz(micros, offset.local_minus_utc())
+}
+
+/// Parse the offset like `+08:00`, `+0800`, `+08` or `-05:30`.
+pub fn parse_utc_offset(s: &str) -> Option<i32> {
+    let (sign, s) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match s.len() {
+        2 => (s, "0"),
+        4 => (&s[..2], &s[2..]),
+        5 if s.as_bytes()[2] == b':' => (&s[..2], &s[3..]),
+        _ => return None,
+    };
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let hours = hours.parse::<i32>().ok()?;
+    let minutes = minutes.parse::<i32>().ok()?;
+    let offset = hours * 3600 + minutes * 60;
+    if minutes >= 60 || offset > TIMESTAMP_TZ_MAX_OFFSET {
+        return None;
+    }
+    Some(sign * offset)
+}
+
+/// Parse the timestamp with time zone from strings like `2023-01-01 10:00:00 +08:00`,
+/// `2023-01-01T02:00:00Z` or `2023-01-01 10:00:00 Asia/Shanghai`.
+///
+/// The timestamp without an offset or a time zone name is in the time zone `tz`.
+pub fn string_to_timestamp_tz(s: impl AsRef<[u8]>, tz: Tz) -> Result<i128, String> {
+    let s = std::str::from_utf8(s.as_ref()).map_err(|e| e.to_string())?;
+    let invalid = || format!("cannot parse to type `TIMESTAMP_TZ`: '{s}'");
+    let s = s.trim();
+
+    // The date part `YYYY-MM-DD` contains `-`, so the offset is searched after it.
+    let date_len = s.len().min(10);
+    if !s.is_char_boundary(date_len) {
+        return Err(invalid());
+    }
+    let (datetime, zone) =
+        if s.ends_with(['Z', 'z']) && s[..s.len() - 1].ends_with(char::is_numeric) {
+            (&s[..s.len() - 1], Some(Ok(0)))
+        } else if let Some(pos) = s[date_len..].rfind(['+', '-']) {
+            let (datetime, offset) = s.split_at(date_len + pos);
+            let offset = parse_utc_offset(offset).ok_or_else(invalid)?;
+            (datetime, Some(Ok(offset)))
+        } else {
+            match s.rsplit_once(' ') {
+                Some((datetime, name)) if name.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                    let tz = name.parse::<Tz>().map_err(|_| invalid())?;
+                    (datetime, Some(Err(tz)))
+                }
+                _ => (s, None),
+            }
+        };
+
+    let local = string_to_timestamp(datetime.trim_end(), Tz::UTC)
+        .ok_or_else(invalid)?
+        .naive_utc();
+    let offset = match zone {
+        Some(Ok(offset)) => offset,
+        Some(Err(tz)) => local_offset(&local, tz).ok_or_else(invalid)?,
+        None => local_offset(&local, tz).ok_or_else(invalid)?,
+    };
+    let micros = local.timestamp_micros() - offset as i64 * MICROS_IN_A_SEC;
+    check_timestamp(micros).map(|micros| make_timestamp_tz(micros, offset))
+}
+
+// The offset of the time zone at the wall clock time, takes the earlier one in the DST overlap.
+fn local_offset(local: &chrono::NaiveDateTime, tz: Tz) -> Option<i32> {
+    tz.offset_from_local_datetime(local)
+        .earliest()
+        .map(|offset| offset.fix().local_minus_utc())
+}
+
+/// Format the timestamp with time zone like `2023-01-01 10:00:00.000000 +08:00`, which can be parsed back.
+pub fn timestamp_tz_to_string(ts: i128) -> String {
+    let (micros, offset) = timestamp_tz_parts(ts);
+    let offset = FixedOffset::east_opt(offset).unwrap();
+    let secs = micros.div_euclid(MICROS_IN_A_SEC);
+    let nanos = (micros.rem_euclid(MICROS_IN_A_SEC) * 1_000) as u32;
+    offset
+        .timestamp_opt(secs, nanos)
+        .unwrap()
+        .format(&format!("{TIMESTAMP_FORMAT} %:z"))
+        .to_string()
+}
+
+/// Parse the time zone name like `Asia/Shanghai` or `UTC`.
+pub fn parse_timezone(name: impl AsRef<[u8]>) -> Result<Tz, String> {
+    let name = String::from_utf8_lossy(name.as_ref());
+    name.trim()
+        .parse::<Tz>()
+        .map_err(|_| format!("invalid time zone: '{name}'"))
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use chrono_tz::Tz;
+use common_io::prelude::BinaryFormat;
 
 #[derive(Clone)]
 pub struct CommonSettings {
@@ -22,5 +23,6 @@ pub struct CommonSettings {
     pub nan_bytes: Vec<u8>,
     pub inf_bytes: Vec<u8>,
     pub timezone: Tz,
+    pub binary_format: BinaryFormat,
     pub disable_variant_check: bool,
 }
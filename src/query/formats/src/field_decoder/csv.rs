@@ -51,6 +51,7 @@ impl FieldDecoderCSV {
                 nan_bytes: params.nan_display.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options_ext.timezone,
+                binary_format: options_ext.binary_format,
                 disable_variant_check: options_ext.disable_variant_check,
             },
         }
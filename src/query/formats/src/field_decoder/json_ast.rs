@@ -26,10 +26,12 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::interval::string_to_interval;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
 use common_expression::with_decimal_type;
@@ -37,15 +39,18 @@ use common_expression::with_number_mapped_type;
 use common_expression::ColumnBuilder;
 use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::ReadNumberExt;
+use common_io::prelude::BinaryFormat;
 use lexical_core::FromLexical;
 use num::cast::AsPrimitive;
 use serde_json::Value;
 
+use crate::field_decoder::read_binary_text;
 use crate::FieldDecoder;
 use crate::FileFormatOptionsExt;
 
 pub struct FieldJsonAstDecoder {
     pub timezone: Tz,
+    pub binary_format: BinaryFormat,
     pub ident_case_sensitive: bool,
     pub is_select: bool,
 }
@@ -60,6 +65,7 @@ impl FieldJsonAstDecoder {
     pub fn create(options: &FileFormatOptionsExt) -> Self {
         FieldJsonAstDecoder {
             timezone: options.timezone,
+            binary_format: options.binary_format,
             ident_case_sensitive: options.ident_case_sensitive,
             is_select: options.is_select,
         }
@@ -84,7 +90,10 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, value),
             ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
@@ -188,6 +197,13 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) => read_binary_text(s.as_bytes(), self.binary_format, column),
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -236,6 +252,28 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<i128>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let interval = string_to_interval(v).map_err(ErrorCode::BadBytes)?;
+                column.push(interval);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect interval value")),
+        }
+    }
+
+    fn read_timestamp_tz(&self, column: &mut Vec<i128>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let ts = string_to_timestamp_tz(v, self.timezone).map_err(ErrorCode::BadBytes)?;
+                column.push(ts);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect timestamp_tz value")),
+        }
+    }
+
     fn read_variant(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
         let v = jsonb::Value::from(value);
         v.write_to_vec(&mut column.data);
@@ -22,6 +22,12 @@ mod xml;
 
 use std::any::Any;
 
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_io::prelude::BinaryFormat;
 pub use csv::FieldDecoderCSV;
 pub use fast_values::FastFieldDecoderValues;
 pub use json_ast::FieldJsonAstDecoder;
@@ -33,3 +39,21 @@ pub use xml::FieldDecoderXML;
 pub trait FieldDecoder: Send + Sync {
     fn as_any(&self) -> &dyn Any;
 }
+
+/// Decode a textual BINARY value and append it to `column` as a new row.
+pub(crate) fn read_binary_text(
+    data: &[u8],
+    format: BinaryFormat,
+    column: &mut StringColumnBuilder,
+) -> Result<()> {
+    let decoded = match format {
+        BinaryFormat::Hex => hex::decode(data).map_err(|e| e.to_string()),
+        BinaryFormat::Base64 => general_purpose::STANDARD
+            .decode(data)
+            .map_err(|e| e.to_string()),
+    }
+    .map_err(|e| ErrorCode::BadBytes(format!("Invalid binary value: {e}")))?;
+    column.put_slice(&decoded);
+    column.commit_row();
+    Ok(())
+}
@@ -41,6 +41,7 @@ impl FieldEncoderTSV {
                 nan_bytes: params.nan_display.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options_ext.timezone,
+                binary_format: options_ext.binary_format,
                 disable_variant_check: options_ext.disable_variant_check,
             },
             quote_char: params.quote.as_bytes().to_vec()[0],
@@ -16,10 +16,12 @@ use chrono_tz::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
+use common_io::prelude::BinaryFormat;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_settings::Settings;
 
+use crate::output_format::ArrowOutputFormat;
 use crate::output_format::CSVOutputFormat;
 use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
@@ -44,6 +46,7 @@ pub struct FileFormatOptionsExt {
     pub json_strings: bool,
     pub disable_variant_check: bool,
     pub timezone: Tz,
+    pub binary_format: BinaryFormat,
     pub is_select: bool,
 }
 
@@ -53,6 +56,7 @@ impl FileFormatOptionsExt {
         is_select: bool,
     ) -> Result<FileFormatOptionsExt> {
         let timezone = parse_timezone(settings)?;
+        let binary_format = parse_binary_format(settings)?;
         let options = FileFormatOptionsExt {
             ident_case_sensitive: false,
             headers: 0,
@@ -60,6 +64,7 @@ impl FileFormatOptionsExt {
             json_strings: false,
             disable_variant_check: false,
             timezone,
+            binary_format,
             is_select,
         };
         Ok(options)
@@ -70,6 +75,7 @@ impl FileFormatOptionsExt {
         settings: &Settings,
     ) -> Result<FileFormatOptionsExt> {
         let timezone = parse_timezone(settings)?;
+        let binary_format = parse_binary_format(settings)?;
         let mut options = FileFormatOptionsExt {
             ident_case_sensitive: settings.get_unquoted_ident_case_sensitive()?,
             headers: 0,
@@ -77,6 +83,7 @@ impl FileFormatOptionsExt {
             json_strings: false,
             disable_variant_check: false,
             timezone,
+            binary_format,
             is_select: false,
         };
         let suf = &clickhouse_type.suffixes;
@@ -156,6 +163,7 @@ impl FileFormatOptionsExt {
                 }
             }
             FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
+            FileFormatParams::Arrow(_) => Box::new(ArrowOutputFormat::create(schema, self)),
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
@@ -174,6 +182,7 @@ impl FileFormatTypeExt for StageFileFormatType {
             StageFileFormatType::Tsv => "text/tab-separated-values; charset=UTF-8",
             StageFileFormatType::Csv => "text/csv; charset=UTF-8",
             StageFileFormatType::Parquet => "application/octet-stream",
+            StageFileFormatType::Arrow => "application/vnd.apache.arrow.stream",
             StageFileFormatType::NdJson => "application/x-ndjson; charset=UTF-8",
             StageFileFormatType::Json => "application/json; charset=UTF-8",
             _ => "text/plain; charset=UTF-8",
@@ -187,3 +196,10 @@ pub fn parse_timezone(settings: &Settings) -> Result<Tz> {
     tz.parse::<Tz>()
         .map_err(|_| ErrorCode::InvalidTimezone("Timezone has been checked and should be valid"))
 }
+
+pub fn parse_binary_format(settings: &Settings) -> Result<BinaryFormat> {
+    let binary_format = settings.get_binary_format()?;
+    binary_format
+        .parse::<BinaryFormat>()
+        .map_err(ErrorCode::InvalidArgument)
+}
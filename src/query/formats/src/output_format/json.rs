@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use base64::engine::general_purpose;
+use base64::prelude::*;
 use common_expression::date_helper::DateConverter;
+use common_expression::types::interval::interval_to_string;
 use common_expression::types::number::NumberScalar;
+use common_expression::types::timestamp_tz::timestamp_tz_to_string;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
+use common_io::prelude::BinaryFormat;
 use common_io::prelude::FormatSettings;
 use roaring::RoaringTreemap;
 use serde_json::Map as JsonMap;
@@ -42,6 +47,7 @@ impl JSONOutputFormat {
             rows: 0,
             format_settings: FormatSettings {
                 timezone: options.timezone,
+                binary_format: options.binary_format,
             },
         }
     }
@@ -96,9 +102,15 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => JsonValue::String(interval_to_string(v)),
+        ScalarRef::TimestampTz(v) => JsonValue::String(timestamp_tz_to_string(v)),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
+        ScalarRef::Binary(x) => match format.binary_format {
+            BinaryFormat::Hex => JsonValue::String(hex::encode(x)),
+            BinaryFormat::Base64 => JsonValue::String(general_purpose::STANDARD.encode(x)),
+        },
         ScalarRef::Array(x) => {
             let vals = x
                 .iter()
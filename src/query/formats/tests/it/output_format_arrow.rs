@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+
+use crate::get_output_format_clickhouse;
+use crate::output_format_utils::get_simple_block;
+
+fn read_stream(buffer: &[u8], schema: &DataSchema) -> Result<Vec<DataBlock>> {
+    let mut reader = Cursor::new(buffer);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut blocks = vec![];
+    for state in StreamReader::new(reader, metadata, None) {
+        match state? {
+            StreamState::Some(chunk) => blocks.push(DataBlock::from_arrow_chunk(&chunk, schema)?),
+            StreamState::Waiting => break,
+        }
+    }
+    Ok(blocks)
+}
+
+#[test]
+fn test_arrow_stream() -> Result<()> {
+    for is_nullable in [false, true] {
+        let (schema, block) = get_simple_block(is_nullable);
+        let data_schema = DataSchema::from(&schema);
+
+        let mut formatter = get_output_format_clickhouse("arrow", schema.clone())?;
+        assert!(formatter.serialize_block(&block)?.is_empty());
+        assert!(formatter.serialize_block(&block.slice(1..3))?.is_empty());
+        let buffer = formatter.finalize()?;
+
+        let blocks = read_stream(&buffer, &data_schema)?;
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].num_rows(), 3);
+        assert_eq!(blocks[1].num_rows(), 2);
+        for (restored, expected) in blocks.iter().zip([block.clone(), block.slice(1..3)]) {
+            for i in 0..expected.num_columns() {
+                assert_eq!(
+                    restored.get_by_offset(i).value.as_column(),
+                    expected.get_by_offset(i).value.as_column()
+                );
+            }
+        }
+    }
+
+    // The schema is written even if there are no blocks.
+    let (schema, _) = get_simple_block(false);
+    let data_schema = DataSchema::from(&schema);
+    let mut formatter = get_output_format_clickhouse("ArrowStream", schema)?;
+    let buffer = formatter.finalize()?;
+    assert!(read_stream(&buffer, &data_schema)?.is_empty());
+
+    Ok(())
+}
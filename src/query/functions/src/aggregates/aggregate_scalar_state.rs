@@ -29,18 +29,21 @@ use serde::Serialize;
 // These types can downcast their builders successfully.
 // TODO(@b41sh):  Variant => VariantType can't be used because it will use Scalar::String to compare
 // Maybe we could use ValueType::compare() to compare them.
+// Interval => IntervalType can't be used either, the intervals are compared by the normalized value of ScalarRef.
 #[macro_export]
 macro_rules! with_simple_no_number_mapped_type {
     (| $t:tt | $($tail:tt)*) => {
         match_template::match_template! {
             $t = [
                 String => StringType,
+                Binary => BinaryType,
                 Boolean => BooleanType,
                 Timestamp => TimestampType,
                 Null => NullType,
                 EmptyArray => EmptyArrayType,
                 EmptyMap => EmptyMapType,
                 Date => DateType,
+                TimestampTz => TimestampTzType,
             ],
             $($tail)*
         }
@@ -250,7 +253,7 @@ where
 
 pub fn need_manual_drop_state(data_type: &DataType) -> bool {
     match data_type {
-        DataType::String | DataType::Variant => true,
+        DataType::String | DataType::Binary | DataType::Variant => true,
         DataType::Nullable(t) | DataType::Array(t) | DataType::Map(t) => need_manual_drop_state(t),
         DataType::Tuple(ts) => ts.iter().any(need_manual_drop_state),
         _ => false,
@@ -21,11 +21,16 @@ use common_arrow::arrow::bitmap::Bitmap;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::decimal::*;
+use common_expression::types::interval::checked_make_interval;
+use common_expression::types::interval::interval_parts;
+use common_expression::types::interval::DAYS_IN_A_MONTH;
+use common_expression::types::interval::MICROS_IN_A_DAY;
 use common_expression::types::number::Int8Type;
 use common_expression::types::number::Number;
 use common_expression::types::ArgType;
 use common_expression::types::DataType;
 use common_expression::types::DecimalDataType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::ValueType;
@@ -254,6 +259,103 @@ where T: Decimal
     }
 }
 
+/// The intervals are summed by each field, so that `sum(1 month, 1 day)` is `1 month 1 day`.
+#[derive(Default)]
+pub struct IntervalSumState {
+    pub months: i64,
+    pub days: i64,
+    pub micros: i128,
+}
+
+impl IntervalSumState {
+    #[inline]
+    fn add(&mut self, interval: i128) {
+        let (months, days, micros) = interval_parts(interval);
+        self.months += months as i64;
+        self.days += days as i64;
+        self.micros += micros as i128;
+    }
+}
+
+impl SumState for IntervalSumState {
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        serialize_into_buf(writer, &(self.months, self.days, self.micros))
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        (self.months, self.days, self.micros) = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn accumulate_row(&mut self, column: &Column, row: usize) -> Result<()> {
+        let buffer = IntervalType::try_downcast_column(column).unwrap();
+        self.add(buffer[row]);
+        Ok(())
+    }
+
+    fn accumulate(&mut self, column: &Column, validity: Option<&Bitmap>) -> Result<()> {
+        let buffer = IntervalType::try_downcast_column(column).unwrap();
+        match validity {
+            Some(validity) => {
+                for (v, valid) in buffer.iter().zip(validity.iter()) {
+                    if valid {
+                        self.add(*v);
+                    }
+                }
+            }
+            None => buffer.iter().for_each(|v| self.add(*v)),
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(places: &[StateAddr], offset: usize, columns: &Column) -> Result<()> {
+        let buffer = IntervalType::try_downcast_column(columns).unwrap();
+        for (v, place) in buffer.iter().zip(places.iter()) {
+            let state = place.next(offset).get::<IntervalSumState>();
+            state.add(*v);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &mut Self) -> Result<()> {
+        self.months += other.months;
+        self.days += other.days;
+        self.micros += other.micros;
+        Ok(())
+    }
+
+    fn merge_result(&mut self, builder: &mut ColumnBuilder) -> Result<()> {
+        let builder = IntervalType::try_downcast_builder(builder).unwrap();
+        let value = checked_make_interval(self.months, self.days, self.micros)
+            .map_err(ErrorCode::Overflow)?;
+        builder.push(value);
+        Ok(())
+    }
+
+    fn merge_avg_result(
+        &mut self,
+        builder: &mut ColumnBuilder,
+        count: u64,
+        _scale_add: u8,
+    ) -> Result<()> {
+        let builder = IntervalType::try_downcast_builder(builder).unwrap();
+        // The fractional months are spilled into days, and the fractional days into micros.
+        let count = count as f64;
+        let months = self.months as f64 / count;
+        let days = self.days as f64 / count + months.fract() * DAYS_IN_A_MONTH as f64;
+        let micros = self.micros as f64 / count + days.fract() * MICROS_IN_A_DAY as f64;
+        let value = checked_make_interval(
+            months.trunc() as i64,
+            days.trunc() as i64,
+            micros.round() as i128,
+        )
+        .map_err(ErrorCode::Overflow)?;
+        builder.push(value);
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 pub struct AggregateSumFunction<State> {
     display_name: String,
@@ -403,6 +505,11 @@ pub fn try_create_aggregate_sum_function(
                 DataType::Decimal(DecimalDataType::from_size(decimal_size)?),
             )
         }
+        DataType::Interval => AggregateSumFunction::<IntervalSumState>::try_create(
+            display_name,
+            arguments,
+            DataType::Interval,
+        ),
         _ => Err(ErrorCode::BadDataValueType(format!(
             "AggregateSumFunction does not support type '{:?}'",
             arguments[0]
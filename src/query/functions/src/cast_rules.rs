@@ -70,8 +70,12 @@ pub fn register(registry: &mut FunctionRegistry) {
 pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Timestamp),
     (DataType::String, DataType::Date),
+    (DataType::String, DataType::Interval),
+    (DataType::String, DataType::TimestampTz),
+    (DataType::String, DataType::Binary),
     (DataType::String, DataType::Boolean),
     (DataType::Date, DataType::Timestamp),
+    (DataType::Timestamp, DataType::TimestampTz),
     (
         DataType::Number(NumberDataType::UInt8),
         DataType::Number(NumberDataType::UInt16),
@@ -0,0 +1,186 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_expression::error_to_null;
+use common_expression::types::binary::BinaryType;
+use common_expression::types::string::StringColumn;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS binary)
+    // to_binary(xx)
+    register_string_to_binary(registry);
+
+    // cast(binary AS string)
+    // to_string(binary)
+    register_binary_to_string(registry);
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, NumberType<u64>, _, _>(
+        "length",
+        |_| FunctionDomain::Full,
+        |val, _| match val {
+            ValueRef::Scalar(s) => Value::Scalar(s.len() as u64),
+            ValueRef::Column(c) => {
+                let diffs = c
+                    .offsets
+                    .iter()
+                    .zip(c.offsets.iter().skip(1))
+                    .map(|(a, b)| b - a)
+                    .collect::<Vec<_>>();
+
+                Value::Column(diffs.into())
+            }
+        },
+    );
+
+    // hex, to_base64, unhex, from_base64
+    register_binary_encoding(registry);
+}
+
+fn register_string_to_binary(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_| FunctionDomain::Full,
+        |val, _| match val {
+            ValueRef::Scalar(s) => Value::Scalar(s.to_vec()),
+            ValueRef::Column(c) => Value::Column(c),
+        },
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_to_binary",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, NullableType<BinaryType>>(|val, output, _| {
+            output.push(val);
+        }),
+    );
+}
+
+fn register_binary_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::MayThrow,
+        eval_binary_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_binary_to_string),
+    );
+
+    fn eval_binary_to_string(
+        val: ValueRef<BinaryType>,
+        ctx: &mut EvalContext,
+    ) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, ctx| {
+            if let Err(e) = std::str::from_utf8(val) {
+                ctx.set_error(output.len(), format!("invalid utf8 sequence: {e}"));
+            } else {
+                output.put_slice(val);
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_binary_encoding(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "hex",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            let old_len = output.data.len();
+            let extra_len = val.len() * 2;
+            output.data.resize(old_len + extra_len, 0);
+            hex::encode_to_slice(val, &mut output.data[old_len..]).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            base64::write::EncoderWriter::new(&mut output.data, &general_purpose::STANDARD)
+                .write_all(val)
+                .unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "unhex",
+        |_| FunctionDomain::MayThrow,
+        vectorize_string_to_binary(
+            |col| col.data.len() / 2,
+            |val, output, ctx| {
+                let old_len = output.data.len();
+                let extra_len = val.len() / 2;
+                output.data.resize(old_len + extra_len, 0);
+                if let Err(err) = hex::decode_to_slice(val, &mut output.data[old_len..]) {
+                    ctx.set_error(output.len(), err.to_string());
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_base64",
+        |_| FunctionDomain::MayThrow,
+        vectorize_string_to_binary(
+            |col| col.data.len() * 4 / 3 + col.len() * 4,
+            |val, output, ctx| {
+                if let Err(err) = general_purpose::STANDARD.decode_vec(val, &mut output.data) {
+                    ctx.set_error(output.len(), err.to_string());
+                }
+                output.commit_row();
+            },
+        ),
+    );
+}
+
+/// String to Binary scalar function with estimated output column capacity.
+fn vectorize_string_to_binary(
+    estimate_bytes: impl Fn(&StringColumn) -> usize + Copy,
+    func: impl Fn(&[u8], &mut StringColumnBuilder, &mut EvalContext) + Copy,
+) -> impl Fn(ValueRef<StringType>, &mut EvalContext) -> Value<BinaryType> + Copy {
+    move |arg1, ctx| match arg1 {
+        ValueRef::Scalar(val) => {
+            let mut builder = StringColumnBuilder::with_capacity(1, 0);
+            func(val, &mut builder, ctx);
+            Value::Scalar(builder.build_scalar())
+        }
+        ValueRef::Column(col) => {
+            let data_capacity = estimate_bytes(&col);
+            let mut builder = StringColumnBuilder::with_capacity(col.len(), data_capacity);
+            for val in col.iter() {
+                func(val, &mut builder, ctx);
+            }
+
+            Value::Column(builder.build())
+        }
+    }
+}
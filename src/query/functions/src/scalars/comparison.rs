@@ -18,19 +18,24 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::interval::interval_cmp_key;
 use common_expression::types::string::StringDomain;
+use common_expression::types::timestamp_tz::timestamp_tz_parts;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::GenericType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
 use common_expression::types::ValueType;
 use common_expression::types::VariantType;
 use common_expression::types::ALL_NUMBER_CLASSES;
@@ -55,8 +60,11 @@ use crate::scalars::string_multi_args::regexp;
 pub fn register(registry: &mut FunctionRegistry) {
     register_variant_cmp(registry);
     register_string_cmp(registry);
+    register_binary_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_interval_cmp(registry);
+    register_timestamp_tz_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -160,6 +168,39 @@ fn register_string_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, StringType);
 }
 
+fn register_binary_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_date_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, DateType);
 }
@@ -168,6 +209,74 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+// The intervals are compared by the normalized value, with 30-day months and 24-hour days.
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| interval_cmp_key(lhs) == interval_cmp_key(rhs),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| interval_cmp_key(lhs) != interval_cmp_key(rhs),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| interval_cmp_key(lhs) > interval_cmp_key(rhs),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| interval_cmp_key(lhs) >= interval_cmp_key(rhs),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| interval_cmp_key(lhs) < interval_cmp_key(rhs),
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| interval_cmp_key(lhs) <= interval_cmp_key(rhs),
+    );
+}
+
+// The timestamps with time zone are compared by the instant, regardless of the offsets.
+fn register_timestamp_tz_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| timestamp_tz_parts(lhs).0 == timestamp_tz_parts(rhs).0,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| timestamp_tz_parts(lhs).0 != timestamp_tz_parts(rhs).0,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| timestamp_tz_parts(lhs).0 > timestamp_tz_parts(rhs).0,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| timestamp_tz_parts(lhs).0 >= timestamp_tz_parts(rhs).0,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| timestamp_tz_parts(lhs).0 < timestamp_tz_parts(rhs).0,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| timestamp_tz_parts(lhs).0 <= timestamp_tz_parts(rhs).0,
+    );
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
@@ -24,6 +24,7 @@ use common_expression::types::date::date_to_string;
 use common_expression::types::date::string_to_date;
 use common_expression::types::date::DATE_MAX;
 use common_expression::types::date::DATE_MIN;
+use common_expression::types::interval::interval_between;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::Int64Type;
@@ -39,8 +40,10 @@ use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::timestamp::timestamp_to_string;
 use common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::timestamp_tz::parse_timezone;
 use common_expression::types::DateType;
 use common_expression::types::Int32Type;
+use common_expression::types::IntervalType;
 use common_expression::types::NullableType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -95,7 +98,11 @@ pub fn register(registry: &mut FunctionRegistry) {
     // to_*([date | timestamp]) -> [date | timestamp]
     register_rounder_functions(registry);
 
+    // to_*(timestamp, timezone) -> [number | date | timestamp]
+    register_functions_with_timezone(registry);
+
     // [date | timestamp] +/- number
+    // timestamp - timestamp
     register_timestamp_add_sub(registry);
 }
 
@@ -1120,26 +1127,118 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         |a, b, _| a - b,
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
         |lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
+            FunctionDomain::Domain(SimpleDomain::<i128> {
+                min: interval_between(lhs.min, rhs.max),
+                max: interval_between(lhs.max, rhs.min),
+            })
         },
-        |a, b, _| a - b,
+        |a, b, _| interval_between(a, b),
     );
 }
 
+// The timestamp is evaluated in the given time zone instead of the `timezone` setting.
+fn register_functions_with_timezone(registry: &mut FunctionRegistry) {
+    macro_rules! register_with_timezone {
+        ($name:expr, $output:ty, $eval:expr) => {
+            registry
+                .register_passthrough_nullable_2_arg::<TimestampType, StringType, $output, _, _>(
+                    $name,
+                    |_, _| FunctionDomain::MayThrow,
+                    vectorize_with_builder_2_arg::<TimestampType, StringType, $output>(
+                        |val, tz, output, ctx| match parse_timezone(tz) {
+                            Ok(tz) => output.push($eval(val, TzFactory::instance().get(tz))),
+                            Err(e) => {
+                                ctx.set_error(output.len(), e);
+                                output.push(Default::default());
+                            }
+                        },
+                    ),
+                );
+        };
+    }
+
+    // timestamp -> number
+    register_with_timezone!("to_yyyymm", UInt32Type, |val, tz| {
+        ToNumberImpl::eval_timestamp::<ToYYYYMM, _>(val, tz)
+    });
+    register_with_timezone!("to_yyyymmdd", UInt32Type, |val, tz| {
+        ToNumberImpl::eval_timestamp::<ToYYYYMMDD, _>(val, tz)
+    });
+    register_with_timezone!("to_yyyymmddhhmmss", UInt64Type, |val, tz| {
+        ToNumberImpl::eval_timestamp::<ToYYYYMMDDHHMMSS, _>(val, tz)
+    });
+    register_with_timezone!("to_year", UInt16Type, |val, tz| {
+        ToNumberImpl::eval_timestamp::<ToYear, _>(val, tz)
+    });
+    register_with_timezone!("to_month", UInt8Type, |val, tz| {
+        ToNumberImpl::eval_timestamp::<ToMonth, _>(val, tz)
+    });
+    register_with_timezone!("to_day_of_year", UInt16Type, |val, tz| {
+        ToNumberImpl::eval_timestamp::<ToDayOfYear, _>(val, tz)
+    });
+    register_with_timezone!("to_day_of_month", UInt8Type, |val, tz| {
+        ToNumberImpl::eval_timestamp::<ToDayOfMonth, _>(val, tz)
+    });
+    register_with_timezone!("to_day_of_week", UInt8Type, |val, tz| {
+        ToNumberImpl::eval_timestamp::<ToDayOfWeek, _>(val, tz)
+    });
+    register_with_timezone!("to_hour", UInt8Type, |val, tz: TzLUT| tz.to_hour(val));
+    register_with_timezone!("to_minute", UInt8Type, |val, tz: TzLUT| tz.to_minute(val));
+    register_with_timezone!("to_second", UInt8Type, |val, tz: TzLUT| tz.to_second(val));
+
+    // timestamp -> timestamp
+    register_with_timezone!("to_start_of_second", TimestampType, |val, tz: TzLUT| {
+        tz.round_us(val, Round::Second)
+    });
+    register_with_timezone!("to_start_of_minute", TimestampType, |val, tz: TzLUT| {
+        tz.round_us(val, Round::Minute)
+    });
+    register_with_timezone!(
+        "to_start_of_five_minutes",
+        TimestampType,
+        |val, tz: TzLUT| { tz.round_us(val, Round::FiveMinutes) }
+    );
+    register_with_timezone!(
+        "to_start_of_ten_minutes",
+        TimestampType,
+        |val, tz: TzLUT| { tz.round_us(val, Round::TenMinutes) }
+    );
+    register_with_timezone!(
+        "to_start_of_fifteen_minutes",
+        TimestampType,
+        |val, tz: TzLUT| { tz.round_us(val, Round::FifteenMinutes) }
+    );
+    register_with_timezone!("to_start_of_hour", TimestampType, |val, tz: TzLUT| {
+        tz.round_us(val, Round::Hour)
+    });
+    register_with_timezone!("to_start_of_day", TimestampType, |val, tz: TzLUT| {
+        tz.round_us(val, Round::Day)
+    });
+    register_with_timezone!("time_slot", TimestampType, |val, tz: TzLUT| {
+        tz.round_us(val, Round::TimeSlot)
+    });
+
+    // timestamp -> date
+    register_with_timezone!("to_monday", DateType, |val, tz| {
+        DateRounder::eval_timestamp::<ToLastMonday>(val, tz)
+    });
+    register_with_timezone!("to_start_of_month", DateType, |val, tz| {
+        DateRounder::eval_timestamp::<ToStartOfMonth>(val, tz)
+    });
+    register_with_timezone!("to_start_of_quarter", DateType, |val, tz| {
+        DateRounder::eval_timestamp::<ToStartOfQuarter>(val, tz)
+    });
+    register_with_timezone!("to_start_of_year", DateType, |val, tz| {
+        DateRounder::eval_timestamp::<ToStartOfYear>(val, tz)
+    });
+    register_with_timezone!("to_start_of_iso_year", DateType, |val, tz| {
+        DateRounder::eval_timestamp::<ToStartOfISOYear>(val, tz)
+    });
+}
+
 fn register_rounder_functions(registry: &mut FunctionRegistry) {
     // timestamp -> timestamp
     registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampType, _, _>(
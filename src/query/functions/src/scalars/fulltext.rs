@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::BooleanType;
+use common_expression::types::Float64Type;
+use common_expression::types::StringType;
+use common_expression::types::F64;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+/// Term frequency saturation of the relevance score, the `k1` of BM25.
+const SCORE_K1: f64 = 1.2;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // match
+    // This function takes a text and a query, returns true if the text contains all the terms of the query.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |text, query, output, _| {
+                let query = query_terms(query);
+                let text = tokenize(text);
+                output.push(!query.is_empty() && query.iter().all(|term| text.contains(term)));
+            },
+        ),
+    );
+
+    // score
+    // This function takes a text and a query, returns the relevance of the text to the query terms.
+    // It is the term frequency part of BM25 only, a scalar function sees one row at a time,
+    // so there is no inverse document frequency nor length normalization over the table.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "score",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, Float64Type>(
+            |text, query, output, _| {
+                let text = tokenize(text);
+                let score = query_terms(query)
+                    .iter()
+                    .map(|term| {
+                        let tf = text.iter().filter(|t| *t == term).count() as f64;
+                        tf * (SCORE_K1 + 1.0) / (tf + SCORE_K1)
+                    })
+                    .sum::<f64>();
+                output.push(F64::from(score));
+            },
+        ),
+    );
+}
+
+/// Split the text into lowercase terms of alphanumeric characters.
+///
+/// The inverted index of fuse tables is built with the same terms,
+/// so that it can be used to prune the blocks filtered by `match`.
+pub fn tokenize(text: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+/// The distinct terms of a `match` query, all of them must be in the matched text.
+pub fn query_terms(query: &[u8]) -> Vec<String> {
+    let mut terms = tokenize(query);
+    terms.sort();
+    terms.dedup();
+    terms
+}
@@ -0,0 +1,312 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_expression::error_to_null;
+use common_expression::types::interval::checked_make_interval;
+use common_expression::types::interval::interval_add;
+use common_expression::types::interval::interval_mul;
+use common_expression::types::interval::interval_neg;
+use common_expression::types::interval::interval_parts;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MICROS_IN_AN_HOUR;
+use common_expression::types::interval::MICROS_IN_A_DAY;
+use common_expression::types::interval::MICROS_IN_A_MINUTE;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::Int64Type;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::DateType;
+use common_expression::types::IntervalType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::date_helper::AddMonthsImpl;
+use common_expression::utils::date_helper::TzLUT;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast(interval AS string)
+    // to_string(interval)
+    register_interval_to_string(registry);
+
+    // to_[years | quarters | months | weeks | days | hours | minutes | seconds](number)
+    // interval number [year | quarter | month | week | day | hour | minute | second]
+    register_number_to_interval(registry);
+
+    // interval [+ | -] interval, - interval, interval [* | /] number
+    register_interval_arithmetic(registry);
+
+    // [date | timestamp] [+ | -] interval
+    register_timestamp_add_sub_interval(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", interval_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", interval_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_number_to_interval(registry: &mut FunctionRegistry) {
+    macro_rules! register_to_interval {
+        ($name:expr, $months:expr, $micros:expr) => {
+            registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+                $name,
+                |_| FunctionDomain::MayThrow,
+                vectorize_with_builder_1_arg::<Int64Type, IntervalType>(|val, output, ctx| {
+                    match number_to_interval(val, $months, $micros) {
+                        Ok(interval) => output.push(interval),
+                        Err(e) => {
+                            ctx.set_error(output.len(), e);
+                            output.push(0);
+                        }
+                    }
+                }),
+            );
+        };
+    }
+
+    register_to_interval!("to_years", 12, 0);
+    register_to_interval!("to_quarters", 3, 0);
+    register_to_interval!("to_months", 1, 0);
+    register_to_interval!("to_weeks", 0, 7 * MICROS_IN_A_DAY);
+    register_to_interval!("to_days", 0, MICROS_IN_A_DAY);
+    register_to_interval!("to_hours", 0, MICROS_IN_AN_HOUR);
+    register_to_interval!("to_minutes", 0, MICROS_IN_A_MINUTE);
+    register_to_interval!("to_seconds", 0, MICROS_IN_A_SEC);
+}
+
+fn number_to_interval(n: i64, unit_months: i64, unit_micros: i64) -> Result<i128, String> {
+    if unit_months > 0 {
+        checked_make_interval(n.saturating_mul(unit_months), 0, 0)
+    } else if unit_micros >= MICROS_IN_A_DAY {
+        // Whole days are kept in the days field.
+        checked_make_interval(0, n.saturating_mul(unit_micros / MICROS_IN_A_DAY), 0)
+    } else {
+        checked_make_interval(0, 0, n as i128 * unit_micros as i128)
+    }
+}
+
+fn register_interval_arithmetic(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| push_interval(interval_add(a, b), output, ctx),
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| {
+                push_interval(
+                    interval_neg(b).and_then(|b| interval_add(a, b)),
+                    output,
+                    ctx,
+                )
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|a, output, ctx| {
+            push_interval(interval_neg(a), output, ctx)
+        }),
+    );
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Float64Type, IntervalType, _, _>(
+        "multiply",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Float64Type, IntervalType>(
+            |a, b, output, ctx| push_interval(interval_mul(a, b.0), output, ctx),
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<Float64Type, IntervalType, IntervalType, _, _>(
+        "multiply",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Float64Type, IntervalType, IntervalType>(
+            |a, b, output, ctx| push_interval(interval_mul(b, a.0), output, ctx),
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Float64Type, IntervalType, _, _>(
+        "divide",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Float64Type, IntervalType>(
+            |a, b, output, ctx| {
+                if b.0 == 0.0 {
+                    ctx.set_error(output.len(), "divided by zero");
+                    output.push(0);
+                } else {
+                    push_interval(interval_mul(a, 1.0 / b.0), output, ctx)
+                }
+            },
+        ),
+    );
+}
+
+fn push_interval(result: Result<i128, String>, output: &mut Vec<i128>, ctx: &mut EvalContext) {
+    match result {
+        Ok(interval) => output.push(interval),
+        Err(e) => {
+            ctx.set_error(output.len(), e);
+            output.push(0);
+        }
+    }
+}
+
+fn register_timestamp_add_sub_interval(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    let result = timestamp_add_interval(ts, interval, ctx.func_ctx.tz);
+                    push_timestamp(result, output, ctx)
+                },
+            ),
+        );
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| {
+                    let result = timestamp_add_interval(ts, interval, ctx.func_ctx.tz);
+                    push_timestamp(result, output, ctx)
+                },
+            ),
+        );
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    let result = interval_neg(interval)
+                        .and_then(|interval| timestamp_add_interval(ts, interval, ctx.func_ctx.tz));
+                    push_timestamp(result, output, ctx)
+                },
+            ),
+        );
+
+    // The date is converted to the timestamp at the midnight, like `DATE '2023-01-01' + INTERVAL 1 HOUR`.
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                let result = timestamp_add_interval(ts, interval, ctx.func_ctx.tz);
+                push_timestamp(result, output, ctx)
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                let result = timestamp_add_interval(ts, interval, ctx.func_ctx.tz);
+                push_timestamp(result, output, ctx)
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                let result = interval_neg(interval)
+                    .and_then(|interval| timestamp_add_interval(ts, interval, ctx.func_ctx.tz));
+                push_timestamp(result, output, ctx)
+            },
+        ),
+    );
+}
+
+/// Add the months of the interval to the timestamp, then the days and the micros.
+fn timestamp_add_interval(ts: i64, interval: i128, tz: TzLUT) -> Result<i64, String> {
+    let (months, days, micros) = interval_parts(interval);
+    let ts = AddMonthsImpl::eval_timestamp(ts, tz, months)?;
+    let ts = ts as i128 + days as i128 * MICROS_IN_A_DAY as i128 + micros as i128;
+    check_timestamp(i64::try_from(ts).unwrap_or(i64::MAX))
+}
+
+fn push_timestamp(result: Result<i64, String>, output: &mut Vec<i64>, ctx: &mut EvalContext) {
+    match result {
+        Ok(ts) => output.push(ts),
+        Err(e) => {
+            ctx.set_error(output.len(), e);
+            output.push(0);
+        }
+    }
+}
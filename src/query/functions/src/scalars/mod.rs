@@ -17,19 +17,23 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
 mod bitmap;
 mod boolean;
 mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod fulltext;
 mod geo;
 mod hash;
+mod interval;
 mod map;
 mod math;
 mod other;
 mod string;
 mod string_multi_args;
+mod timestamp_tz;
 mod tuple;
 mod variant;
 mod vector;
@@ -38,6 +42,8 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use fulltext::query_terms;
+pub use fulltext::tokenize;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -47,6 +53,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     control::register(registry);
     comparison::register(registry);
     datetime::register(registry);
+    interval::register(registry);
+    timestamp_tz::register(registry);
     math::register(registry);
     map::register(registry);
     string::register(registry);
@@ -57,5 +65,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     other::register(registry);
     decimal::register(registry);
     vector::register(registry);
+    fulltext::register(registry);
     bitmap::register(registry);
+    binary::register(registry);
 }
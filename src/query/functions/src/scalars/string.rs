@@ -312,20 +312,6 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
-    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
-        "from_base64",
-        |_| FunctionDomain::MayThrow,
-        vectorize_string_to_string(
-            |col| col.data.len() * 4 / 3 + col.len() * 4,
-            |val, output, ctx| {
-                if let Err(err) = general_purpose::STANDARD.decode_vec(val, &mut output.data) {
-                    ctx.set_error(output.len(), err.to_string());
-                }
-                output.commit_row();
-            },
-        ),
-    );
-
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "quote",
         |_| FunctionDomain::Full,
@@ -550,23 +536,6 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
-    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
-        "unhex",
-        |_| FunctionDomain::MayThrow,
-        vectorize_string_to_string(
-            |col| col.data.len() / 2,
-            |val, output, ctx| {
-                let old_len = output.data.len();
-                let extra_len = val.len() / 2;
-                output.data.resize(old_len + extra_len, 0);
-                if let Err(err) = hex::decode_to_slice(val, &mut output.data[old_len..]) {
-                    ctx.set_error(output.len(), err.to_string());
-                }
-                output.commit_row();
-            },
-        ),
-    );
-
     registry.register_1_arg::<StringType, UInt64Type, _, _>(
         "ord",
         |_| FunctionDomain::Full,
@@ -0,0 +1,349 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_expression::error_to_null;
+use common_expression::types::interval::MICROS_IN_A_DAY;
+use common_expression::types::number::Int64Type;
+use common_expression::types::number::UInt16Type;
+use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::number::UInt8Type;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::timestamp_tz::make_timestamp_tz;
+use common_expression::types::timestamp_tz::parse_timezone;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_to_timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_tz_local_micros;
+use common_expression::types::timestamp_tz::timestamp_tz_parts;
+use common_expression::types::timestamp_tz::timestamp_tz_to_string;
+use common_expression::types::DateType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
+use common_expression::utils::date_helper::*;
+use common_expression::vectorize_1_arg;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS timestamp_tz)
+    // to_timestamp_tz(xx)
+    register_string_to_timestamp_tz(registry);
+    register_timestamp_to_timestamp_tz(registry);
+
+    // cast(timestamp_tz AS [timestamp | date | string])
+    // to_[timestamp | date | string](timestamp_tz)
+    register_timestamp_tz_to_timestamp(registry);
+    register_timestamp_tz_to_date(registry);
+    register_timestamp_tz_to_string(registry);
+
+    // convert_timezone([from,] to, [timestamp | timestamp_tz])
+    // [timestamp | timestamp_tz] AT TIME ZONE to
+    register_convert_timezone(registry);
+
+    // to_*(timestamp_tz) -> number, in the offset of the value
+    register_to_number_functions(registry);
+
+    // to_*(timestamp_tz) -> [date | timestamp_tz], in the offset of the value
+    register_rounder_functions(registry);
+}
+
+fn register_string_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_tz),
+    );
+
+    fn eval_string_to_timestamp_tz(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            match string_to_timestamp_tz(val, ctx.func_ctx.tz.tz) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_timestamp_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_| FunctionDomain::Full,
+        eval_timestamp_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_timestamp_to_timestamp_tz),
+    );
+
+    fn eval_timestamp_to_timestamp_tz(
+        val: ValueRef<TimestampType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<TimestampType, TimestampTzType>(|val, output, ctx| {
+            output.push(timestamp_to_timestamp_tz(val, ctx.func_ctx.tz.tz));
+        })(val, ctx)
+    }
+}
+
+fn register_timestamp_tz_to_timestamp(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, TimestampType>(|val, _| timestamp_tz_parts(val).0),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "try_to_timestamp",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, NullableType<TimestampType>>(|val, _| {
+            Some(timestamp_tz_parts(val).0)
+        }),
+    );
+}
+
+fn register_timestamp_tz_to_date(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+        "to_date",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, DateType>(|val, _| local_date(val)),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+        "try_to_date",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, NullableType<DateType>>(|val, _| Some(local_date(val))),
+    );
+
+    // The date of the wall clock time in the offset of the value.
+    fn local_date(val: i128) -> i32 {
+        timestamp_tz_local_micros(val).div_euclid(MICROS_IN_A_DAY) as i32
+    }
+}
+
+fn register_timestamp_tz_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, StringType>(|val, output, _| {
+            write!(output.data, "{}", timestamp_tz_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, NullableType<StringType>>(
+            |val, output, _| {
+                write!(output.builder.data, "{}", timestamp_tz_to_string(val)).unwrap();
+                output.builder.commit_row();
+                output.validity.push(true);
+            },
+        ),
+    );
+}
+
+fn register_convert_timezone(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampType, TimestampTzType>(
+                |to, ts, output, ctx| {
+                    let result = parse_timezone(to).map(|to| timestamp_to_timestamp_tz(ts, to));
+                    push_timestamp_tz(result, output, ctx)
+                },
+            ),
+        );
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampTzType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampTzType, TimestampTzType>(
+                |to, ts, output, ctx| {
+                    let result = parse_timezone(to)
+                        .map(|to| timestamp_to_timestamp_tz(timestamp_tz_parts(ts).0, to));
+                    push_timestamp_tz(result, output, ctx)
+                },
+            ),
+        );
+
+    // The wall clock time of the timestamp is taken as the time in the time zone `from`.
+    registry.register_passthrough_nullable_3_arg::<
+        StringType,
+        StringType,
+        TimestampType,
+        TimestampTzType,
+        _,
+        _,
+    >(
+        "convert_timezone",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, TimestampType, TimestampTzType>(
+            |from, to, ts, output, ctx| {
+                let result = parse_timezone(from).and_then(|from| {
+                    let to = parse_timezone(to)?;
+                    let micros = local_to_utc(ts, ctx.func_ctx.tz.tz, from)?;
+                    Ok(timestamp_to_timestamp_tz(micros, to))
+                });
+                push_timestamp_tz(result, output, ctx)
+            },
+        ),
+    );
+}
+
+/// Take the wall clock time of the timestamp in the time zone `tz` as the time in the time zone `from`,
+/// and return the UTC micros of it.
+fn local_to_utc(ts: i64, tz: Tz, from: Tz) -> Result<i64, String> {
+    let local = ts.to_timestamp(tz).naive_local();
+    let micros = from
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|dt| dt.naive_utc().timestamp_micros())
+        .ok_or_else(|| format!("the time {local} does not exist in the time zone {from}"))?;
+    check_timestamp(micros)
+}
+
+fn push_timestamp_tz(result: Result<i128, String>, output: &mut Vec<i128>, ctx: &mut EvalContext) {
+    match result {
+        Ok(ts) => output.push(ts),
+        Err(e) => {
+            ctx.set_error(output.len(), e);
+            output.push(0);
+        }
+    }
+}
+
+fn register_to_number_functions(registry: &mut FunctionRegistry) {
+    // The wall clock time of the value is evaluated as a time in UTC.
+    macro_rules! register_to_number {
+        ($name:expr, $output:ty, $eval:expr) => {
+            registry.register_passthrough_nullable_1_arg::<TimestampTzType, $output, _, _>(
+                $name,
+                |_| FunctionDomain::Full,
+                vectorize_1_arg::<TimestampTzType, $output>(|val, _| {
+                    $eval(timestamp_tz_local_micros(val), TzLUT::default())
+                }),
+            );
+        };
+    }
+
+    register_to_number!("to_yyyymm", UInt32Type, |us, tz| {
+        ToNumberImpl::eval_timestamp::<ToYYYYMM, _>(us, tz)
+    });
+    register_to_number!("to_yyyymmdd", UInt32Type, |us, tz| {
+        ToNumberImpl::eval_timestamp::<ToYYYYMMDD, _>(us, tz)
+    });
+    register_to_number!("to_yyyymmddhhmmss", UInt64Type, |us, tz| {
+        ToNumberImpl::eval_timestamp::<ToYYYYMMDDHHMMSS, _>(us, tz)
+    });
+    register_to_number!("to_year", UInt16Type, |us, tz| {
+        ToNumberImpl::eval_timestamp::<ToYear, _>(us, tz)
+    });
+    register_to_number!("to_month", UInt8Type, |us, tz| {
+        ToNumberImpl::eval_timestamp::<ToMonth, _>(us, tz)
+    });
+    register_to_number!("to_day_of_year", UInt16Type, |us, tz| {
+        ToNumberImpl::eval_timestamp::<ToDayOfYear, _>(us, tz)
+    });
+    register_to_number!("to_day_of_month", UInt8Type, |us, tz| {
+        ToNumberImpl::eval_timestamp::<ToDayOfMonth, _>(us, tz)
+    });
+    register_to_number!("to_day_of_week", UInt8Type, |us, tz| {
+        ToNumberImpl::eval_timestamp::<ToDayOfWeek, _>(us, tz)
+    });
+    register_to_number!("to_hour", UInt8Type, |us, tz: TzLUT| tz.to_hour(us));
+    register_to_number!("to_minute", UInt8Type, |us, tz: TzLUT| tz.to_minute(us));
+    register_to_number!("to_second", UInt8Type, |us, tz: TzLUT| tz.to_second(us));
+
+    // The unix timestamp is the same instant in any offset.
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, Int64Type, _, _>(
+        "to_unix_timestamp",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, Int64Type>(|val, _| {
+            timestamp_tz_parts(val).0.div_euclid(MICROS_IN_A_SEC)
+        }),
+    );
+}
+
+fn register_rounder_functions(registry: &mut FunctionRegistry) {
+    // timestamp_tz -> timestamp_tz, keeps the offset of the value
+    macro_rules! register_rounder {
+        ($name:expr, $round:expr) => {
+            registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampTzType, _, _>(
+                $name,
+                |_| FunctionDomain::Full,
+                vectorize_1_arg::<TimestampTzType, TimestampTzType>(|val, _| {
+                    let (_, offset) = timestamp_tz_parts(val);
+                    let local = TzLUT::default().round_us(timestamp_tz_local_micros(val), $round);
+                    make_timestamp_tz(local - offset as i64 * MICROS_IN_A_SEC, offset)
+                }),
+            );
+        };
+    }
+
+    register_rounder!("to_start_of_second", Round::Second);
+    register_rounder!("to_start_of_minute", Round::Minute);
+    register_rounder!("to_start_of_five_minutes", Round::FiveMinutes);
+    register_rounder!("to_start_of_ten_minutes", Round::TenMinutes);
+    register_rounder!("to_start_of_fifteen_minutes", Round::FifteenMinutes);
+    register_rounder!("to_start_of_hour", Round::Hour);
+    register_rounder!("to_start_of_day", Round::Day);
+    register_rounder!("time_slot", Round::TimeSlot);
+
+    // timestamp_tz -> date
+    macro_rules! register_date_rounder {
+        ($name:expr, $T:ty) => {
+            registry.register_passthrough_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+                $name,
+                |_| FunctionDomain::Full,
+                vectorize_1_arg::<TimestampTzType, DateType>(|val, _| {
+                    DateRounder::eval_timestamp::<$T>(
+                        timestamp_tz_local_micros(val),
+                        TzLUT::default(),
+                    )
+                }),
+            );
+        };
+    }
+
+    register_date_rounder!("to_monday", ToLastMonday);
+    register_date_rounder!("to_start_of_week", ToLastSunday);
+    register_date_rounder!("to_start_of_month", ToStartOfMonth);
+    register_date_rounder!("to_start_of_quarter", ToStartOfQuarter);
+    register_date_rounder!("to_start_of_year", ToStartOfYear);
+    register_date_rounder!("to_start_of_iso_year", ToStartOfISOYear);
+}
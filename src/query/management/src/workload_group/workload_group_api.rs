@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::WorkloadGroup;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait WorkloadGroupApi: Sync + Send {
+    // Add a workload group to /tenant/group-name.
+    async fn add_workload_group(&self, group: WorkloadGroup) -> Result<u64>;
+
+    // Update a workload group to /tenant/group-name.
+    async fn update_workload_group(&self, group: WorkloadGroup, seq: MatchSeq) -> Result<u64>;
+
+    // Get workload group by name.
+    async fn get_workload_group(&self, name: &str, seq: MatchSeq) -> Result<SeqV<WorkloadGroup>>;
+
+    // Get all the workload groups for a tenant.
+    async fn get_workload_groups(&self) -> Result<Vec<WorkloadGroup>>;
+
+    // Drop the tenant's workload group by name, the assignments to it are removed too.
+    async fn drop_workload_group(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    // Assign a user or a role to a workload group, or remove the assignment if `group` is None.
+    async fn set_assignment(
+        &self,
+        principal: &PrincipalIdentity,
+        group: Option<&str>,
+    ) -> Result<()>;
+
+    // Get the workload group which a user or a role is assigned to.
+    async fn get_assignment(&self, principal: &PrincipalIdentity) -> Result<Option<String>>;
+}
@@ -0,0 +1,203 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::WorkloadGroup;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::workload_group::WorkloadGroupApi;
+
+static WORKLOAD_GROUP_API_KEY_PREFIX: &str = "__fd_workload_groups";
+static WORKLOAD_GROUP_ASSIGNMENT_API_KEY_PREFIX: &str = "__fd_workload_group_assignments";
+
+pub struct WorkloadGroupMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    group_prefix: String,
+    assignment_prefix: String,
+}
+
+impl WorkloadGroupMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while workload group mgr create)",
+            ));
+        }
+
+        Ok(WorkloadGroupMgr {
+            kv_api,
+            group_prefix: format!(
+                "{}/{}",
+                WORKLOAD_GROUP_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+            assignment_prefix: format!(
+                "{}/{}",
+                WORKLOAD_GROUP_ASSIGNMENT_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    fn assignment_key(&self, principal: &PrincipalIdentity) -> Result<String> {
+        let (typ, name) = match principal {
+            PrincipalIdentity::User(user) => ("user", user.to_string()),
+            PrincipalIdentity::Role(role) => ("role", role.clone()),
+        };
+        Ok(format!(
+            "{}/{}/{}",
+            self.assignment_prefix,
+            typ,
+            escape_for_key(&name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl WorkloadGroupApi for WorkloadGroupMgr {
+    #[async_backtrace::framed]
+    async fn add_workload_group(&self, group: WorkloadGroup) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&group)?);
+        let key = format!("{}/{}", self.group_prefix, escape_for_key(&group.name)?);
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::WorkloadGroupAlreadyExists(format!(
+                "Workload group already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn update_workload_group(&self, group: WorkloadGroup, seq: MatchSeq) -> Result<u64> {
+        let val = Operation::Update(serde_json::to_vec(&group)?);
+        let key = format!("{}/{}", self.group_prefix, escape_for_key(&group.name)?);
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownWorkloadGroup(format!(
+                "Unknown workload group, or seq not match {}",
+                group.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_workload_group(&self, name: &str, seq: MatchSeq) -> Result<SeqV<WorkloadGroup>> {
+        let key = format!("{}/{}", self.group_prefix, escape_for_key(name)?);
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownWorkloadGroup(format!("Unknown workload group {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownWorkloadGroup(format!(
+                "Unknown workload group {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_workload_groups(&self) -> Result<Vec<WorkloadGroup>> {
+        let values = self.kv_api.prefix_list_kv(&self.group_prefix).await?;
+
+        let mut groups = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let group = serde_json::from_slice::<WorkloadGroup>(&value.data)?;
+            groups.push(group);
+        }
+        Ok(groups)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_workload_group(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = format!("{}/{}", self.group_prefix, escape_for_key(name)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_none() || res.result.is_some() {
+            return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                "Unknown workload group {}",
+                name
+            )));
+        }
+
+        // Remove the users and roles from the dropped group.
+        let assignments = self.kv_api.prefix_list_kv(&self.assignment_prefix).await?;
+        for (key, value) in assignments {
+            if serde_json::from_slice::<String>(&value.data)? == name {
+                self.kv_api
+                    .upsert_kv(UpsertKVReq::new(
+                        &key,
+                        MatchSeq::Exact(value.seq),
+                        Operation::Delete,
+                        None,
+                    ))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn set_assignment(
+        &self,
+        principal: &PrincipalIdentity,
+        group: Option<&str>,
+    ) -> Result<()> {
+        let key = self.assignment_key(principal)?;
+        let val = match group {
+            Some(group) => Operation::Update(serde_json::to_vec(group)?),
+            None => Operation::Delete,
+        };
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), val, None))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_assignment(&self, principal: &PrincipalIdentity) -> Result<Option<String>> {
+        let key = self.assignment_key(principal)?;
+        match self.kv_api.get_kv(&key).await? {
+            Some(value) => Ok(Some(serde_json::from_slice::<String>(&value.data)?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -19,3 +19,4 @@ mod setting;
 mod stage;
 mod udf;
 mod user;
+mod workload_group;
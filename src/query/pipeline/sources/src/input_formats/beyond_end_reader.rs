@@ -26,7 +26,8 @@ pub struct BeyondEndReader {
     pub ctx: Arc<InputContext>,
     pub split_info: Arc<SplitInfo>,
     pub path: String,
-    pub record_delimiter_end: u8,
+    /// The bytes ending the last record of the split, which are not returned.
+    pub record_delimiter: Vec<u8>,
 }
 
 impl BeyondEndReader {
@@ -46,33 +47,29 @@ impl BeyondEndReader {
             let offset = offset + size;
             let limit = size as usize;
             let mut reader = operator.range_reader(&self.path, offset..).await?;
-            let mut num_read_total = 0;
             loop {
                 let num_read = reader.read(&mut buf[..]).await?;
                 if num_read == 0 {
                     break;
                 }
-                num_read_total += num_read;
 
-                if let Some(idx) = buf[..num_read].find_byte(self.record_delimiter_end) {
-                    if res.is_empty() {
-                        buf.truncate(idx);
-                        return Ok(buf);
-                    } else {
-                        res.extend_from_slice(&buf[..idx]);
-                        break;
-                    }
-                } else {
-                    if num_read_total > limit {
-                        return Err(ErrorCode::BadBytes(format!(
-                            "no record delimiter '{}' find in {}[{}..{}]",
-                            self.record_delimiter_end,
-                            self.path,
-                            offset,
-                            offset + size,
-                        )));
-                    }
-                    res.extend_from_slice(&buf[..num_read])
+                // The delimiter may be split by two reads.
+                let search_from = res
+                    .len()
+                    .saturating_sub(self.record_delimiter.len().saturating_sub(1));
+                res.extend_from_slice(&buf[..num_read]);
+                if let Some(idx) = res[search_from..].find(&self.record_delimiter) {
+                    res.truncate(search_from + idx);
+                    break;
+                }
+                if res.len() > limit {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "no record delimiter {:?} find in {}[{}..{}]",
+                        self.record_delimiter.as_bstr(),
+                        self.path,
+                        offset,
+                        offset + size,
+                    )));
                 }
             }
             return Ok(res);
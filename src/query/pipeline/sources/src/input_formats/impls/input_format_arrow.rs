@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+use opendal::Operator;
+
+use crate::input_formats::impls::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+// The IPC file format starts with the magic, the stream format starts with a message.
+const ARROW_FILE_MAGIC: &[u8] = b"ARROW1";
+
+/// Arrow IPC files and streams, a file is not splittable and is decoded as a whole.
+///
+/// The columns of the table are matched to the fields of the record batches by name,
+/// and the arrays are cast to the types of the columns.
+pub struct InputFormatArrow {}
+
+impl InputFormatArrow {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_schema(data: &[u8]) -> Result<ArrowSchema> {
+        let mut reader = Cursor::new(data);
+        if data.starts_with(ARROW_FILE_MAGIC) {
+            Ok(read_file_metadata(&mut reader)?.schema)
+        } else {
+            Ok(read_stream_metadata(&mut reader)?.schema)
+        }
+    }
+
+    fn read_chunks(data: &[u8]) -> Result<(ArrowSchema, Vec<Chunk<Box<dyn Array>>>)> {
+        let mut reader = Cursor::new(data);
+        if data.starts_with(ARROW_FILE_MAGIC) {
+            let metadata = read_file_metadata(&mut reader)?;
+            let schema = metadata.schema.clone();
+            let chunks = FileReader::new(reader, metadata, None, None)
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok((schema, chunks))
+        } else {
+            let metadata = read_stream_metadata(&mut reader)?;
+            let schema = metadata.schema.clone();
+            let mut chunks = vec![];
+            for state in StreamReader::new(reader, metadata, None) {
+                match state? {
+                    StreamState::Some(chunk) => chunks.push(chunk),
+                    // The whole stream is in memory, it never waits for more data.
+                    StreamState::Waiting => break,
+                }
+            }
+            Ok((schema, chunks))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormatTextBase for InputFormatArrow {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Arrow
+    }
+
+    // The values are decoded by columns, the field decoder is not used.
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_file_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let data = op.read(path).await?;
+        let arrow_schema =
+            Self::read_schema(&data).map_err(|e| e.add_message_back(format!(" ({path})")))?;
+        Ok(Arc::new(TableSchema::from(&arrow_schema)))
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let path = &batch.split_info.file.path;
+        let (file_schema, chunks) =
+            Self::read_chunks(&batch.data).map_err(|e| e.add_message_back(format!(" ({path})")))?;
+
+        let schema = &builder.ctx.schema;
+        let arrow_fields = schema.to_arrow().fields;
+        let mut indices = Vec::with_capacity(arrow_fields.len());
+        for field in arrow_fields.iter() {
+            let index = file_schema
+                .fields
+                .iter()
+                .position(|f| f.name.eq_ignore_ascii_case(&field.name))
+                .ok_or_else(|| {
+                    ErrorCode::BadBytes(format!(
+                        "couldn't find column {} in arrow file ({path})",
+                        field.name
+                    ))
+                })?;
+            indices.push(index);
+        }
+        let data_types = schema
+            .fields()
+            .iter()
+            .map(|f| DataType::from(f.data_type()))
+            .collect::<Vec<_>>();
+
+        for chunk in chunks {
+            for (((column, index), field), data_type) in builder
+                .mutable_columns
+                .iter_mut()
+                .zip(indices.iter())
+                .zip(arrow_fields.iter())
+                .zip(data_types.iter())
+            {
+                let array = &chunk.arrays()[*index];
+                let array = if array.data_type() != field.data_type() {
+                    cast(array.as_ref(), field.data_type(), CastOptions::default())?
+                } else {
+                    array.clone()
+                };
+                column.append_column(&Column::from_arrow(array.as_ref(), data_type));
+            }
+        }
+        Ok(HashMap::new())
+    }
+}
@@ -0,0 +1,669 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use bstr::ByteSlice;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+use opendal::Operator;
+
+use crate::input_formats::input_split::DynData;
+use crate::input_formats::AligningStateTextBased;
+use crate::input_formats::BeyondEndReader;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// Avro object container files.
+///
+/// A file is split on the sync markers between the data blocks, a split decodes the blocks
+/// following the sync markers which start in the split. The header of the file is read when
+/// the splits are created, because the splits other than the first one need it to decode
+/// the blocks.
+///
+/// The records are converted to json values with the writer schema of the file,
+/// and the fields are matched to the columns of the table by name.
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_row(
+        field_decoder: &FieldJsonAstDecoder,
+        record: Vec<(String, Value)>,
+        record_schema: &[Schema],
+        positions: &[Option<usize>],
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
+        let mut values = record.into_iter().map(|(_, v)| Some(v)).collect::<Vec<_>>();
+        for ((field, column), position) in schema
+            .fields()
+            .iter()
+            .zip(columns.iter_mut())
+            .zip(positions.iter())
+        {
+            match position.and_then(|i| values[i].take().map(|v| (v, &record_schema[i]))) {
+                Some((value, value_schema)) => {
+                    let json = avro_to_json(value, Some(value_schema))?;
+                    field_decoder.read_field(column, &json).map_err(|e| {
+                        ErrorCode::BadBytes(format!(
+                            "{}. column={} value={}",
+                            e,
+                            field.name(),
+                            json
+                        ))
+                    })?;
+                }
+                None => column.push_default(),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateAvro;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn is_splittable() -> bool {
+        true
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateAvro::try_create(ctx, split_info)
+    }
+
+    #[async_backtrace::framed]
+    async fn read_split_format_info(
+        &self,
+        path: &str,
+        op: &Operator,
+    ) -> Result<Option<Arc<dyn DynData>>> {
+        // The header is usually small, read more only if the schema is large.
+        let size = op.stat(path).await?.content_length() as usize;
+        let mut len = 4096;
+        loop {
+            let data = op.range_read(path, 0..len.min(size) as u64).await?;
+            if let Some(header) = AvroHeader::parse(&data, path)? {
+                return Ok(Some(Arc::new(header)));
+            }
+            if len >= size {
+                return Err(avro_error("the header is incomplete", path, 0));
+            }
+            len *= 4;
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_file_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let data = op.read(path).await?;
+        let reader = Reader::new(&data[..]).map_err(|e| avro_error(&e.to_string(), path, 0))?;
+        match reader.writer_schema() {
+            Schema::Record { fields, .. } => {
+                let fields = fields
+                    .iter()
+                    .map(|f| TableField::new(&f.name, avro_to_table_type(&f.schema)))
+                    .collect();
+                Ok(Arc::new(TableSchema::new(fields)))
+            }
+            _ => Err(avro_error("the schema must be a record", path, 0)),
+        }
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+
+        let reader =
+            Reader::new(&batch.data[..]).map_err(|e| avro_error(&e.to_string(), path, 0))?;
+        let record_fields = match reader.writer_schema() {
+            Schema::Record { fields, .. } => fields.clone(),
+            _ => return Err(avro_error("the schema must be a record", path, 0)),
+        };
+        let record_schema = record_fields
+            .iter()
+            .map(|f| f.schema.clone())
+            .collect::<Vec<_>>();
+
+        // The position of each column of the table in the records.
+        let positions = builder
+            .ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                record_fields.iter().position(|f| {
+                    if field_decoder.ident_case_sensitive {
+                        &f.name == field.name()
+                    } else {
+                        f.name.eq_ignore_ascii_case(field.name())
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut num_rows = 0usize;
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        for (row, value) in reader.enumerate() {
+            let value = value.map_err(|e| avro_error(&e.to_string(), path, row))?;
+            let record = match value {
+                Value::Record(record) => record,
+                _ => return Err(avro_error("the value must be a record", path, row)),
+            };
+            if let Err(e) = Self::read_row(
+                field_decoder,
+                record,
+                &record_schema,
+                &positions,
+                columns,
+                &builder.ctx.schema,
+            ) {
+                match builder.ctx.on_error_mode {
+                    OnErrorMode::Continue => {
+                        Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
+                        continue;
+                    }
+                    OnErrorMode::AbortNum(n) => {
+                        Self::on_error_abort(columns, num_rows, n, &builder.ctx.on_error_count, e)
+                            .map_err(|e| avro_error(&e.message(), path, row))?;
+                        continue;
+                    }
+                    _ => return Err(avro_error(&e.message(), path, row)),
+                }
+            }
+            num_rows += 1;
+        }
+        Ok(error_map)
+    }
+}
+
+/// The header of an Avro file, including the magic, the metadata and the sync marker.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct AvroHeader {
+    data: Vec<u8>,
+    sync_marker: Vec<u8>,
+}
+
+#[typetag::serde(name = "avro_split")]
+impl DynData for AvroHeader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+const SYNC_MARKER_SIZE: usize = 16;
+
+impl AvroHeader {
+    /// Parse the header at the start of the data, returns none if the data is incomplete.
+    fn parse(data: &[u8], path: &str) -> Result<Option<Self>> {
+        if data.len() < AVRO_MAGIC.len() {
+            return Ok(None);
+        }
+        if !data.starts_with(AVRO_MAGIC) {
+            return Err(avro_error("not an Avro object container file", path, 0));
+        }
+        let mut pos = AVRO_MAGIC.len();
+        // The metadata is a map, encoded as blocks of key-value pairs ending with an empty block.
+        loop {
+            let Some(count) = read_long(data, &mut pos) else {
+                return Ok(None);
+            };
+            if count == 0 {
+                break;
+            }
+            if count < 0 && read_long(data, &mut pos).is_none() {
+                return Ok(None);
+            }
+            for _ in 0..count.unsigned_abs() * 2 {
+                let Some(len) = read_long(data, &mut pos) else {
+                    return Ok(None);
+                };
+                pos += len.max(0) as usize;
+            }
+        }
+        if data.len() < pos + SYNC_MARKER_SIZE {
+            return Ok(None);
+        }
+        let end = pos + SYNC_MARKER_SIZE;
+        Ok(Some(AvroHeader {
+            data: data[..end].to_vec(),
+            sync_marker: data[pos..end].to_vec(),
+        }))
+    }
+}
+
+// Read a zigzag encoded long, returns none if the data is incomplete.
+fn read_long(data: &[u8], pos: &mut usize) -> Option<i64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let b = *data.get(*pos)?;
+        *pos += 1;
+        value |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift >= 64 {
+            return None;
+        }
+    }
+    Some((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+pub struct AligningStateAvro {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    // The header read when the splits are created, or parsed from the start of the file.
+    header: Option<AvroHeader>,
+    // Unaligned bytes and the offset of them in the file.
+    buf: Vec<u8>,
+    buf_offset: usize,
+    // Whether the start of the first block of the split is found.
+    started: bool,
+    // Whether the blocks of the split are all aligned.
+    finished: bool,
+    batch_id: usize,
+}
+
+impl AligningStateAvro {
+    pub fn try_create(ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+        let header = split_info
+            .format_info
+            .as_ref()
+            .and_then(|info| info.as_any().downcast_ref::<AvroHeader>())
+            .cloned();
+        Ok(Self {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            header,
+            buf: vec![],
+            buf_offset: split_info.offset,
+            started: false,
+            finished: false,
+            batch_id: 0,
+        })
+    }
+
+    // The blocks following the sync markers which start before the end belong to the split.
+    fn split_end(&self) -> usize {
+        if self.split_info.num_file_splits > 1 {
+            self.split_info.offset + self.split_info.size
+        } else {
+            usize::MAX
+        }
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.buf.drain(..len);
+        self.buf_offset += len;
+    }
+
+    // Find the sync marker starting in the split, the blocks of the split follow it.
+    fn find_start(&mut self) -> Result<()> {
+        let path = &self.split_info.file.path;
+        let Some(header) = &self.header else {
+            // The file is not split, the blocks follow the header.
+            if let Some(header) = AvroHeader::parse(&self.buf, path)? {
+                let len = header.data.len();
+                self.header = Some(header);
+                self.consume(len);
+                self.started = true;
+            }
+            return Ok(());
+        };
+
+        let end = self.split_end();
+        match self.buf.find(&header.sync_marker) {
+            Some(idx) if self.buf_offset + idx < end => {
+                self.consume(idx + SYNC_MARKER_SIZE);
+                self.started = true;
+            }
+            Some(_) => self.finished = true,
+            None => {
+                // Keep the bytes which may be the start of a sync marker.
+                let keep = self.buf.len().min(SYNC_MARKER_SIZE - 1);
+                self.consume(self.buf.len() - keep);
+                if self.buf_offset >= end {
+                    self.finished = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Take the complete blocks of the split, the last block may miss its sync marker if it
+    // is the last one read beyond the end of the split.
+    fn take_blocks(&mut self, is_flush: bool) -> Result<Option<RowBatch>> {
+        let path = self.split_info.file.path.clone();
+        let end = self.split_end();
+        let header = self.header.clone().expect("must be started");
+        let mut data = vec![];
+        let mut pos = 0;
+        while !self.finished {
+            // The block starts after a sync marker which starts in the split.
+            if (self.buf_offset + pos).saturating_sub(SYNC_MARKER_SIZE) >= end {
+                self.finished = true;
+                break;
+            }
+            let mut block_end = pos;
+            let (Some(_), Some(size)) = (
+                read_long(&self.buf, &mut block_end),
+                read_long(&self.buf, &mut block_end),
+            ) else {
+                break;
+            };
+            let block_end = block_end + size.max(0) as usize;
+            if self.buf.len() < block_end {
+                break;
+            }
+            if self.buf.len() >= block_end + SYNC_MARKER_SIZE {
+                if self.buf[block_end..block_end + SYNC_MARKER_SIZE] != header.sync_marker[..] {
+                    return Err(avro_error(
+                        "the sync marker does not match the header",
+                        &path,
+                        0,
+                    ));
+                }
+            } else if !is_flush {
+                break;
+            }
+            data.extend_from_slice(&self.buf[pos..block_end]);
+            data.extend_from_slice(&header.sync_marker);
+            pos = (block_end + SYNC_MARKER_SIZE).min(self.buf.len());
+        }
+        self.consume(pos);
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let mut batch_data = header.data;
+        batch_data.extend_from_slice(&data);
+        let batch = RowBatch {
+            data: batch_data,
+            row_ends: vec![],
+            field_ends: vec![],
+            split_info: self.split_info.clone(),
+            batch_id: self.batch_id,
+            start_offset_in_split: 0,
+            start_row_in_split: 0,
+            start_row_of_split: self.split_info.start_row_text(),
+        };
+        self.batch_id += 1;
+        Ok(Some(batch))
+    }
+
+    fn align_buf(&mut self, is_flush: bool) -> Result<Vec<RowBatch>> {
+        if !self.started && !self.finished {
+            self.find_start()?;
+        }
+        if !self.started {
+            if is_flush && self.header.is_none() && !self.buf.is_empty() {
+                let path = &self.split_info.file.path;
+                return Err(avro_error("the header is incomplete", path, 0));
+            }
+            return Ok(vec![]);
+        }
+        Ok(self.take_blocks(is_flush)?.into_iter().collect())
+    }
+}
+
+impl AligningStateTextBased for AligningStateAvro {
+    fn is_splittable() -> bool {
+        true
+    }
+
+    fn align(&mut self, buf: &[u8]) -> Result<Vec<RowBatch>> {
+        self.buf.extend_from_slice(buf);
+        self.align_buf(false)
+    }
+
+    fn align_flush(&mut self) -> Result<Vec<RowBatch>> {
+        let batches = self.align_buf(true)?;
+        if self.started && !self.finished && !self.buf.is_empty() {
+            let path = &self.split_info.file.path;
+            return Err(avro_error("the last block is incomplete", path, 0));
+        }
+        self.buf.clear();
+        Ok(batches)
+    }
+
+    fn read_beyond_end(&self) -> Option<BeyondEndReader> {
+        // Read the rest of the last block, which ends with the next sync marker. The sync
+        // marker which starts the first block may also cross the end of the split.
+        let header = self.header.as_ref()?;
+        if self.finished {
+            return None;
+        }
+        Some(BeyondEndReader {
+            ctx: self.ctx.clone(),
+            split_info: self.split_info.clone(),
+            path: self.split_info.file.path.clone(),
+            record_delimiter: header.sync_marker.clone(),
+        })
+    }
+}
+
+// Convert the avro value to json, the schema is the writer schema of the value if known.
+fn avro_to_json(value: Value, schema: Option<&Schema>) -> Result<serde_json::Value> {
+    let json = match (value, schema) {
+        (Value::Null, _) => serde_json::Value::Null,
+        (Value::Boolean(v), _) => serde_json::Value::Bool(v),
+        (Value::Int(v) | Value::Date(v) | Value::TimeMillis(v), _) => v.into(),
+        (Value::Long(v) | Value::TimeMicros(v) | Value::TimestampMicros(v), _) => v.into(),
+        (Value::TimestampMillis(v), _) => (v * 1000).into(),
+        (Value::Float(v), _) => float_to_json(v as f64),
+        (Value::Double(v), _) => float_to_json(v),
+        (Value::String(v) | Value::Enum(_, v), _) => serde_json::Value::String(v),
+        (Value::Bytes(v) | Value::Fixed(_, v), _) => {
+            serde_json::Value::String(String::from_utf8_lossy(&v).into_owned())
+        }
+        (Value::Uuid(v), _) => serde_json::Value::String(v.to_string()),
+        (Value::Decimal(v), schema) => {
+            let scale = match schema {
+                Some(Schema::Decimal { scale, .. }) => *scale,
+                _ => 0,
+            };
+            let bytes = Vec::<u8>::try_from(&v).map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+            serde_json::Value::String(decimal_to_string(&bytes, scale)?)
+        }
+        (Value::Union(i, v), schema) => {
+            let variant = match schema {
+                Some(Schema::Union(union)) => union.variants().get(i as usize),
+                _ => None,
+            };
+            avro_to_json(*v, variant)?
+        }
+        (Value::Array(items), schema) => {
+            let item_schema = match schema {
+                Some(Schema::Array(item_schema)) => Some(item_schema.as_ref()),
+                _ => None,
+            };
+            serde_json::Value::Array(
+                items
+                    .into_iter()
+                    .map(|v| avro_to_json(v, item_schema))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        (Value::Map(items), schema) => {
+            let value_schema = match schema {
+                Some(Schema::Map(value_schema)) => Some(value_schema.as_ref()),
+                _ => None,
+            };
+            serde_json::Value::Object(
+                items
+                    .into_iter()
+                    .map(|(k, v)| Ok((k, avro_to_json(v, value_schema)?)))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        (Value::Record(fields), schema) => {
+            let field_schemas = match schema {
+                Some(Schema::Record { fields, .. }) => fields.iter().map(|f| &f.schema).collect(),
+                _ => vec![],
+            };
+            serde_json::Value::Object(
+                fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (k, v))| Ok((k, avro_to_json(v, field_schemas.get(i).copied())?)))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        (value, _) => {
+            serde_json::Value::try_from(value).map_err(|e| ErrorCode::BadBytes(e.to_string()))?
+        }
+    };
+    Ok(json)
+}
+
+fn float_to_json(v: f64) -> serde_json::Value {
+    serde_json::Number::from_f64(v)
+        .map(serde_json::Value::Number)
+        .unwrap_or(serde_json::Value::Null)
+}
+
+// The unscaled value of an avro decimal is a big-endian two's-complement integer.
+fn decimal_to_string(bytes: &[u8], scale: usize) -> Result<String> {
+    if bytes.len() > 16 {
+        return Err(ErrorCode::BadBytes(format!(
+            "decimal with {} bytes is too large",
+            bytes.len()
+        )));
+    }
+    let fill = if bytes.first().map_or(false, |b| b & 0x80 != 0) {
+        0xff
+    } else {
+        0
+    };
+    let mut buf = [fill; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    let n = i128::from_be_bytes(buf);
+
+    let digits = n.unsigned_abs().to_string();
+    let sign = if n < 0 { "-" } else { "" };
+    if scale == 0 {
+        return Ok(format!("{sign}{digits}"));
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    Ok(format!("{sign}{int_part}.{frac_part}"))
+}
+
+fn avro_to_table_type(schema: &Schema) -> TableDataType {
+    match schema {
+        Schema::Null => TableDataType::Null,
+        Schema::Boolean => TableDataType::Boolean,
+        Schema::Int | Schema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        Schema::Long | Schema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        Schema::Float => TableDataType::Number(NumberDataType::Float32),
+        Schema::Double => TableDataType::Number(NumberDataType::Float64),
+        Schema::Bytes
+        | Schema::String
+        | Schema::Fixed { .. }
+        | Schema::Enum { .. }
+        | Schema::Uuid
+        | Schema::Duration => TableDataType::String,
+        Schema::Date => TableDataType::Date,
+        Schema::TimestampMillis | Schema::TimestampMicros => TableDataType::Timestamp,
+        Schema::Decimal {
+            precision, scale, ..
+        } => match DecimalDataType::from_size(DecimalSize {
+            precision: *precision as u8,
+            scale: *scale as u8,
+        }) {
+            Ok(decimal) => TableDataType::Decimal(decimal),
+            Err(_) => TableDataType::String,
+        },
+        Schema::Array(item) => TableDataType::Array(Box::new(avro_to_table_type(item))),
+        Schema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_to_table_type(value)],
+        })),
+        Schema::Record { fields, .. } => TableDataType::Tuple {
+            fields_name: fields.iter().map(|f| f.name.clone()).collect(),
+            fields_type: fields
+                .iter()
+                .map(|f| avro_to_table_type(&f.schema))
+                .collect(),
+        },
+        Schema::Union(union) => {
+            let variants = union.variants();
+            match variants {
+                [Schema::Null, schema] | [schema, Schema::Null] => {
+                    TableDataType::Nullable(Box::new(avro_to_table_type(schema)))
+                }
+                _ => TableDataType::Variant,
+            }
+        }
+        _ => TableDataType::Variant,
+    }
+}
+
+fn avro_error(msg: &str, path: &str, row: usize) -> ErrorCode {
+    let row = row + 1;
+    let msg = format!("fail to parse Avro {}:{} {} ", path, row, msg);
+
+    ErrorCode::BadBytes(msg)
+}
@@ -0,0 +1,482 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::mem;
+use std::sync::Arc;
+
+use bstr::ByteSlice;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::JsonFileFormatParams;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+use opendal::Operator;
+
+use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::AligningStateCommon;
+use crate::input_formats::AligningStateTextBased;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+// infer_schema reads at most the first 1MB of the file and the first 1000 rows.
+const INFER_SCHEMA_MAX_BYTES: u64 = 1024 * 1024;
+const INFER_SCHEMA_MAX_ROWS: usize = 1000;
+
+/// JSON files which are not newline-delimited, such as a top-level array of objects.
+///
+/// The file is scanned as a stream, only the bytes of the rows are kept, so the whole
+/// file is never buffered.
+pub struct InputFormatJson {
+    params: JsonFileFormatParams,
+}
+
+impl InputFormatJson {
+    pub fn create(params: JsonFileFormatParams) -> Self {
+        Self { params }
+    }
+}
+
+struct Frame {
+    is_array: bool,
+    // the container is the target or one of its ancestors
+    on_path: bool,
+    // for objects, the key of the current member and whether the next string is a key
+    key: Vec<u8>,
+    expect_key: bool,
+}
+
+/// Find the rows in the JSON text, the bytes are fed batch by batch.
+///
+/// With `strip_outer_array`, the elements of the array selected by the path are the rows,
+/// otherwise the values selected by the path (the top-level values if the path is empty)
+/// are the rows.
+struct JsonRowScanner {
+    path: Vec<Vec<u8>>,
+    strip_outer_array: bool,
+
+    stack: Vec<Frame>,
+    in_string: bool,
+    escaped: bool,
+    reading_key: bool,
+    // the depth of the container of the row being read
+    row_depth: Option<usize>,
+}
+
+impl JsonRowScanner {
+    fn try_create(params: &JsonFileFormatParams) -> Result<Self> {
+        Ok(Self {
+            path: parse_json_path(&params.json_path)?,
+            strip_outer_array: params.strip_outer_array,
+            stack: vec![],
+            in_string: false,
+            escaped: false,
+            reading_key: false,
+            row_depth: None,
+        })
+    }
+
+    /// Append the bytes of the rows in `buf` to `data`, and the end of each finished row to `row_ends`.
+    fn scan(&mut self, buf: &[u8], data: &mut Vec<u8>, row_ends: &mut Vec<usize>) -> Result<()> {
+        for &b in buf {
+            if self.in_string {
+                if self.row_depth.is_some() {
+                    data.push(b);
+                }
+                if self.escaped {
+                    self.escaped = false;
+                } else if b == b'\\' {
+                    self.escaped = true;
+                } else if b == b'"' {
+                    self.in_string = false;
+                    self.reading_key = false;
+                    continue;
+                }
+                if self.reading_key {
+                    if let Some(frame) = self.stack.last_mut() {
+                        frame.key.push(b);
+                    }
+                }
+                continue;
+            }
+
+            if let Some(depth) = self.row_depth {
+                if self.stack.len() == depth
+                    && (matches!(b, b',' | b']' | b'}') || b.is_ascii_whitespace())
+                {
+                    self.row_depth = None;
+                    row_ends.push(data.len());
+                } else {
+                    data.push(b);
+                    self.scan_structure(b)?;
+                    continue;
+                }
+            }
+
+            if b.is_ascii_whitespace() {
+                continue;
+            }
+            if self.is_row_start(b) {
+                self.row_depth = Some(self.stack.len());
+                data.push(b);
+            }
+            self.scan_structure(b)?;
+        }
+        Ok(())
+    }
+
+    fn is_row_start(&self, b: u8) -> bool {
+        if matches!(b, b',' | b':' | b']' | b'}') {
+            return false;
+        }
+        let depth = self.stack.len();
+        match self.stack.last() {
+            // an element of the target array
+            Some(frame) if frame.is_array && frame.on_path && depth == self.path.len() + 1 => {
+                self.strip_outer_array
+            }
+            // the value selected by the path
+            Some(frame)
+                if !frame.is_array
+                    && frame.on_path
+                    && !frame.expect_key
+                    && depth == self.path.len()
+                    && frame.key == self.path[depth - 1] =>
+            {
+                !(self.strip_outer_array && b == b'[')
+            }
+            None if self.path.is_empty() => !(self.strip_outer_array && b == b'['),
+            _ => false,
+        }
+    }
+
+    fn scan_structure(&mut self, b: u8) -> Result<()> {
+        match b {
+            b'"' => {
+                self.in_string = true;
+                if self.row_depth.is_none() {
+                    if let Some(frame) = self.stack.last_mut() {
+                        if !frame.is_array && frame.on_path && frame.expect_key {
+                            frame.key.clear();
+                            self.reading_key = true;
+                        }
+                    }
+                }
+            }
+            b':' => {
+                if let Some(frame) = self.stack.last_mut() {
+                    frame.expect_key = false;
+                }
+            }
+            b',' => {
+                if let Some(frame) = self.stack.last_mut() {
+                    frame.expect_key = !frame.is_array;
+                }
+            }
+            b'{' | b'[' => {
+                let depth = self.stack.len();
+                let on_path = self.row_depth.is_none()
+                    && match self.stack.last() {
+                        None => true,
+                        Some(frame) => {
+                            !frame.is_array
+                                && frame.on_path
+                                && !frame.expect_key
+                                && depth <= self.path.len()
+                                && frame.key == self.path[depth - 1]
+                        }
+                    };
+                self.stack.push(Frame {
+                    is_array: b == b'[',
+                    on_path,
+                    key: vec![],
+                    expect_key: b == b'{',
+                });
+            }
+            b'}' | b']' => match self.stack.pop() {
+                Some(frame) if frame.is_array == (b == b']') => {}
+                _ => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "invalid json: unexpected '{}'",
+                        b as char
+                    )));
+                }
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+// The path is a list of keys, such as `$.data.items` or `data.items`.
+fn parse_json_path(json_path: &str) -> Result<Vec<Vec<u8>>> {
+    let path = json_path.trim();
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let path = path.strip_prefix('.').unwrap_or(path);
+    if path.is_empty() {
+        return Ok(vec![]);
+    }
+    path.split('.')
+        .map(|key| {
+            if key.is_empty() {
+                Err(ErrorCode::BadArguments(format!(
+                    "invalid json_path '{}'",
+                    json_path
+                )))
+            } else {
+                Ok(key.as_bytes().to_vec())
+            }
+        })
+        .collect()
+}
+
+pub struct AligningStateJson {
+    split_info: Arc<SplitInfo>,
+    common: AligningStateCommon,
+    scanner: JsonRowScanner,
+    // the bytes of the row which is not finished in the last batch
+    tail_of_last_batch: Vec<u8>,
+}
+
+impl AligningStateJson {
+    pub fn try_create(ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+        let params = JsonFileFormatParams::downcast_unchecked(&ctx.file_format_params);
+        Ok(Self {
+            split_info: split_info.clone(),
+            common: AligningStateCommon::create(split_info, false, 0),
+            scanner: JsonRowScanner::try_create(params)?,
+            tail_of_last_batch: vec![],
+        })
+    }
+
+    fn new_batch(&mut self, data: Vec<u8>, row_ends: Vec<usize>) -> RowBatch {
+        let batch = RowBatch {
+            data,
+            row_ends,
+            field_ends: vec![],
+            split_info: self.split_info.clone(),
+            batch_id: self.common.batch_id,
+            start_offset_in_split: self.common.offset,
+            start_row_in_split: self.common.rows,
+            start_row_of_split: self.split_info.start_row_text(),
+        };
+        self.common.offset += batch.data.len();
+        self.common.rows += batch.row_ends.len();
+        self.common.batch_id += 1;
+        batch
+    }
+}
+
+impl AligningStateTextBased for AligningStateJson {
+    fn align(&mut self, buf: &[u8]) -> Result<Vec<RowBatch>> {
+        let mut data = mem::take(&mut self.tail_of_last_batch);
+        let mut row_ends = vec![];
+        self.scanner.scan(buf, &mut data, &mut row_ends)?;
+        match row_ends.last() {
+            None => {
+                self.tail_of_last_batch = data;
+                Ok(vec![])
+            }
+            Some(&batch_end) => {
+                self.tail_of_last_batch = data.split_off(batch_end);
+                let batch = self.new_batch(data, row_ends);
+                tracing::debug!(
+                    "align batch {}, {} bytes to {} rows",
+                    batch.batch_id,
+                    batch.data.len(),
+                    batch.row_ends.len(),
+                );
+                Ok(vec![batch])
+            }
+        }
+    }
+
+    fn align_flush(&mut self) -> Result<Vec<RowBatch>> {
+        // the last row, a top-level value without a following whitespace,
+        // or an incomplete value which is reported by the decoder.
+        if self.tail_of_last_batch.is_empty() {
+            Ok(vec![])
+        } else {
+            let data = mem::take(&mut self.tail_of_last_batch);
+            let end = data.len();
+            Ok(vec![self.new_batch(data, vec![end])])
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormatTextBase for InputFormatJson {
+    type AligningState = AligningStateJson;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Json
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateJson::try_create(ctx, split_info)
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_file_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let size = op.stat(path).await?.content_length();
+        let data = op
+            .range_read(path, 0..size.min(INFER_SCHEMA_MAX_BYTES))
+            .await?;
+
+        let mut scanner = JsonRowScanner::try_create(&self.params)?;
+        let mut rows = vec![];
+        let mut row_ends = vec![];
+        scanner.scan(&data, &mut rows, &mut row_ends)?;
+        if size <= INFER_SCHEMA_MAX_BYTES && row_ends.last() != Some(&rows.len()) {
+            row_ends.push(rows.len());
+        }
+
+        let mut fields: Vec<(String, Option<TableDataType>)> = vec![];
+        let mut start = 0;
+        for end in row_ends.into_iter().take(INFER_SCHEMA_MAX_ROWS) {
+            let row = rows[start..end].trim();
+            start = end;
+            if row.is_empty() {
+                continue;
+            }
+            let value: serde_json::Value = serde_json::from_slice(row)
+                .map_err(|e| ErrorCode::BadBytes(format!("{} ({})", e, path)))?;
+            let object = value.as_object().ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "infer_schema of JSON requires the rows to be objects ({})",
+                    path
+                ))
+            })?;
+            for (name, value) in object {
+                let data_type = infer_json_type(value);
+                match fields.iter_mut().find(|(n, _)| n == name) {
+                    Some((_, ty)) => *ty = merge_json_type(ty.take(), data_type),
+                    None => fields.push((name.clone(), data_type)),
+                }
+            }
+        }
+
+        let fields = fields
+            .into_iter()
+            .map(|(name, ty)| {
+                let ty = ty.unwrap_or(TableDataType::Variant);
+                TableField::new(&name, ty.wrap_nullable())
+            })
+            .collect();
+        Ok(Arc::new(TableSchema::new(fields)))
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+
+        let columns = &mut builder.mutable_columns;
+        let mut start = 0usize;
+        let mut num_rows = 0usize;
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        for (i, end) in batch.row_ends.iter().enumerate() {
+            let buf = batch.data[start..*end].trim();
+            if !buf.is_empty() {
+                if let Err(e) =
+                    InputFormatNDJson::read_row(field_decoder, buf, columns, &builder.ctx.schema)
+                {
+                    match builder.ctx.on_error_mode {
+                        OnErrorMode::Continue => {
+                            Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
+                            start = *end;
+                            continue;
+                        }
+                        OnErrorMode::AbortNum(n) => {
+                            Self::on_error_abort(
+                                columns,
+                                num_rows,
+                                n,
+                                &builder.ctx.on_error_count,
+                                e,
+                            )
+                            .map_err(|e| batch.error(&e.message(), &builder.ctx, start, i))?;
+
+                            start = *end;
+                            continue;
+                        }
+                        _ => return Err(batch.error(&e.message(), &builder.ctx, start, i)),
+                    }
+                }
+            }
+            start = *end;
+            num_rows += 1;
+        }
+        Ok(error_map)
+    }
+}
+
+fn infer_json_type(value: &serde_json::Value) -> Option<TableDataType> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::Bool(_) => Some(TableDataType::Boolean),
+        serde_json::Value::Number(n) if n.is_i64() => {
+            Some(TableDataType::Number(NumberDataType::Int64))
+        }
+        serde_json::Value::Number(n) if n.is_u64() => {
+            Some(TableDataType::Number(NumberDataType::UInt64))
+        }
+        serde_json::Value::Number(_) => Some(TableDataType::Number(NumberDataType::Float64)),
+        serde_json::Value::String(_) => Some(TableDataType::String),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => Some(TableDataType::Variant),
+    }
+}
+
+// The numbers of different types are merged to Float64, other different types are merged to Variant.
+fn merge_json_type(
+    lhs: Option<TableDataType>,
+    rhs: Option<TableDataType>,
+) -> Option<TableDataType> {
+    match (lhs, rhs) {
+        (None, ty) | (ty, None) => ty,
+        (Some(lhs), Some(rhs)) if lhs == rhs => Some(lhs),
+        (Some(TableDataType::Number(_)), Some(TableDataType::Number(_))) => {
+            Some(TableDataType::Number(NumberDataType::Float64))
+        }
+        _ => Some(TableDataType::Variant),
+    }
+}
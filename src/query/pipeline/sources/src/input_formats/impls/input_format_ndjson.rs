@@ -42,7 +42,7 @@ impl InputFormatNDJson {
     pub fn create() -> Self {
         Self {}
     }
-    fn read_row(
+    pub(crate) fn read_row(
         field_decoder: &FieldJsonAstDecoder,
         buf: &[u8],
         columns: &mut [ColumnBuilder],
@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+use common_storage::infer_orc_schema;
+use common_storage::read_orc_metadata;
+use common_storage::read_orc_schema_async;
+use common_storage::read_orc_stripe;
+use opendal::Operator;
+
+use crate::input_formats::impls::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// ORC files, a file is not splittable and is decoded as a whole.
+///
+/// The columns of the table are matched to the top-level fields of the file by name,
+/// and the values are cast to the types of the columns. The columns not in the file
+/// are filled with the default values.
+pub struct InputFormatOrc {}
+
+impl InputFormatOrc {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormatTextBase for InputFormatOrc {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Orc
+    }
+
+    // The values are decoded by columns, the field decoder is not used.
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_file_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let arrow_schema = read_orc_schema_async(op, path).await?;
+        Ok(Arc::new(TableSchema::from(&arrow_schema)))
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let path = &batch.split_info.file.path;
+        let metadata =
+            read_orc_metadata(&batch.data).map_err(|e| e.add_message_back(format!(" ({path})")))?;
+        let file_schema =
+            infer_orc_schema(&metadata).map_err(|e| e.add_message_back(format!(" ({path})")))?;
+
+        let schema = &builder.ctx.schema;
+        let arrow_fields = schema.to_arrow().fields;
+        let data_types = schema
+            .fields()
+            .iter()
+            .map(|f| DataType::from(f.data_type()))
+            .collect::<Vec<_>>();
+        let columns = &mut builder.mutable_columns;
+        let mut reader = Cursor::new(&batch.data[..]);
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        for stripe in 0..metadata.footer.stripes.len() {
+            // The whole stripe is skipped on error, the columns are not appended yet.
+            let arrays = match read_orc_stripe(
+                &mut reader,
+                &metadata,
+                &file_schema,
+                stripe,
+                &arrow_fields,
+            ) {
+                Ok(arrays) => arrays,
+                Err(e) => {
+                    let num_rows = columns.first().map_or(0, |c| c.len());
+                    match builder.ctx.on_error_mode {
+                        OnErrorMode::Continue => {
+                            Self::on_error_continue(columns, num_rows, e, &mut error_map);
+                            continue;
+                        }
+                        OnErrorMode::AbortNum(n) => {
+                            Self::on_error_abort(
+                                columns,
+                                num_rows,
+                                n,
+                                &builder.ctx.on_error_count,
+                                e,
+                            )
+                            .map_err(|e| e.add_message_back(format!(" ({path})")))?;
+                            continue;
+                        }
+                        _ => return Err(e.add_message_back(format!(" ({path})"))),
+                    }
+                }
+            };
+            let stripe_rows = metadata.footer.stripes[stripe].number_of_rows() as usize;
+            for ((column, array), data_type) in
+                columns.iter_mut().zip(arrays).zip(data_types.iter())
+            {
+                match array {
+                    Some(array) => {
+                        column.append_column(&Column::from_arrow(array.as_ref(), data_type))
+                    }
+                    // The column is not in the file.
+                    None => (0..stripe_rows).for_each(|_| column.push_default()),
+                }
+            }
+        }
+        Ok(error_map)
+    }
+}
@@ -42,6 +42,7 @@ use crate::input_formats::input_pipeline::AligningStateTrait;
 use crate::input_formats::input_pipeline::BlockBuilderTrait;
 use crate::input_formats::input_pipeline::InputFormatPipe;
 use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::DynData;
 use crate::input_formats::input_split::FileInfo;
 use crate::input_formats::split_by_size;
 use crate::input_formats::BeyondEndReader;
@@ -211,11 +212,12 @@ impl AligningStateTextBased for AligningStateRowDelimiter {
             ctx: self.ctx.clone(),
             split_info: self.split_info.clone(),
             path: self.split_info.file.path.clone(),
-            record_delimiter_end: self.record_delimiter_end,
+            record_delimiter: vec![self.record_delimiter_end],
         })
     }
 }
 
+#[async_trait::async_trait]
 pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
     type AligningState: AligningStateTextBased;
 
@@ -244,6 +246,23 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
         batch: RowBatch,
     ) -> Result<HashMap<u16, InputError>>;
 
+    /// Read the information shared by the splits of a file, such as the header of the file,
+    /// which is needed to decode the splits other than the first one.
+    async fn read_split_format_info(
+        &self,
+        _path: &str,
+        _op: &Operator,
+    ) -> Result<Option<Arc<dyn DynData>>> {
+        Ok(None)
+    }
+
+    /// Infer the schema of the file, only for the formats which carry the schema in the file.
+    async fn infer_file_schema(&self, _path: &str, _op: &Operator) -> Result<TableSchemaRef> {
+        Err(ErrorCode::Unimplemented(
+            "infer_schema is not implemented for this format yet.",
+        ))
+    }
+
     fn on_error_continue(
         columns: &mut Vec<ColumnBuilder>,
         num_rows: usize,
@@ -313,7 +332,7 @@ impl<T: InputFormatTextBase> InputFormat for T {
         &self,
         file_infos: Vec<StageFileInfo>,
         stage_info: &StageInfo,
-        _op: &Operator,
+        op: &Operator,
         _settings: &Arc<Settings>,
     ) -> Result<Vec<Arc<SplitInfo>>> {
         let mut infos = vec![];
@@ -329,6 +348,11 @@ impl<T: InputFormatTextBase> InputFormat for T {
             if compress_alg.is_none() && T::is_splittable() && split_size > 0 {
                 let split_offsets = split_by_size(size, split_size);
                 let num_file_splits = split_offsets.len();
+                let format_info = if num_file_splits > 1 {
+                    self.read_split_format_info(&path, op).await?
+                } else {
+                    None
+                };
                 tracing::debug!(
                     "split file {} of size {} to {} {} bytes splits",
                     path,
@@ -349,7 +373,7 @@ impl<T: InputFormatTextBase> InputFormat for T {
                         offset,
                         size,
                         num_file_splits,
-                        format_info: None,
+                        format_info: format_info.clone(),
                     }));
                 }
             } else {
@@ -373,10 +397,8 @@ impl<T: InputFormatTextBase> InputFormat for T {
     }
 
     #[async_backtrace::framed]
-    async fn infer_schema(&self, _path: &str, _op: &Operator) -> Result<TableSchemaRef> {
-        Err(ErrorCode::Unimplemented(
-            "infer_schema is not implemented for this format yet.",
-        ))
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        self.infer_file_schema(path, op).await
     }
 
     fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod sort;
+pub mod sort;
 pub mod transform;
 pub mod transform_accumulating;
 pub mod transform_accumulating_async;
@@ -34,6 +34,7 @@ pub use transform_async::*;
 pub use transform_block_compact::*;
 pub use transform_compact::*;
 pub use transform_dummy::*;
+pub use transform_multi_sort_merge::try_add_multi_sort_merge;
 pub use transform_sort::*;
 pub use transform_sort_merge::sort_merge;
 pub use transform_sort_partial::*;
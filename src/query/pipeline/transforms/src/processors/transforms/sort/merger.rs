@@ -0,0 +1,183 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Value;
+
+use super::Cursor;
+use super::RowConverter;
+use super::Rows;
+
+/// A stream of sorted blocks, the rows of the stream are sorted across the blocks.
+#[async_trait::async_trait]
+pub trait SortedStream: Send {
+    /// Returns the next block of the stream, none if the stream is exhausted.
+    async fn next(&mut self) -> Result<Option<DataBlock>>;
+}
+
+/// Merge the sorted streams into a sorted stream with a min-heap (k-way merge).
+///
+/// Only the current block of each stream is held in memory, so the streams can be
+/// restored from storage lazily, e.g. the sorted runs spilled by an external sort.
+pub struct HeapMerger<R, Converter, S>
+where
+    R: Rows,
+    Converter: RowConverter<R>,
+    S: SortedStream,
+{
+    row_converter: Converter,
+    order_by_cols: Vec<usize>,
+    block_size: usize,
+    /// If true, append the order column to the output blocks, which is required by
+    /// [`crate::processors::transforms::try_add_multi_sort_merge`].
+    gen_order_col: bool,
+
+    streams: Vec<S>,
+    /// The current block of each stream.
+    blocks: Vec<DataBlock>,
+    /// Heap that yields [`Cursor`] in increasing order.
+    heap: BinaryHeap<Reverse<Cursor<R>>>,
+    /// The streams whose current block is drained, the next block needs to be pulled.
+    pending_streams: VecDeque<usize>,
+    /// The accumulated slices for the next output block.
+    in_progress: Vec<DataBlock>,
+    in_progress_rows: usize,
+}
+
+impl<R, Converter, S> HeapMerger<R, Converter, S>
+where
+    R: Rows,
+    Converter: RowConverter<R>,
+    S: SortedStream,
+{
+    pub fn create(
+        row_converter: Converter,
+        order_by_cols: Vec<usize>,
+        block_size: usize,
+        gen_order_col: bool,
+        streams: Vec<S>,
+    ) -> Self {
+        let num_streams = streams.len();
+        HeapMerger {
+            row_converter,
+            order_by_cols,
+            block_size,
+            gen_order_col,
+            streams,
+            blocks: vec![DataBlock::empty(); num_streams],
+            heap: BinaryHeap::with_capacity(num_streams),
+            pending_streams: (0..num_streams).collect(),
+            in_progress: vec![],
+            in_progress_rows: 0,
+        }
+    }
+
+    fn convert_rows(&mut self, block: &DataBlock) -> Result<R> {
+        let columns = self
+            .order_by_cols
+            .iter()
+            .map(|i| block.get_by_offset(*i).clone())
+            .collect::<Vec<_>>();
+        self.row_converter.convert(&columns, block.num_rows())
+    }
+
+    // Pull the next block of the streams whose current block is drained.
+    async fn poll_pending_streams(&mut self) -> Result<()> {
+        while let Some(index) = self.pending_streams.pop_front() {
+            match self.streams[index].next().await? {
+                Some(block) if block.is_empty() => {
+                    self.pending_streams.push_front(index);
+                }
+                Some(block) => {
+                    let block = block.convert_to_full();
+                    let rows = self.convert_rows(&block)?;
+                    self.blocks[index] = block;
+                    self.heap.push(Reverse(Cursor::new(index, rows)));
+                }
+                None => {
+                    self.blocks[index] = DataBlock::empty();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the next sorted block, none if all the streams are exhausted.
+    #[async_backtrace::framed]
+    pub async fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        while self.in_progress_rows < self.block_size {
+            // Every stream which is not exhausted must have a cursor in the heap,
+            // otherwise the order of the heap is not correct.
+            self.poll_pending_streams().await?;
+
+            let mut cursor = match self.heap.pop() {
+                Some(Reverse(cursor)) => cursor,
+                None => break,
+            };
+
+            let start = cursor.row_index;
+            let limit = self.block_size - self.in_progress_rows;
+            match self.heap.peek() {
+                // If the last row of current block is smaller than the next cursor,
+                // we can drain the whole block.
+                Some(Reverse(next_cursor)) if !cursor.last().le(&next_cursor.current()) => {
+                    while !cursor.is_finished()
+                        && cursor.row_index - start < limit
+                        && cursor.le(next_cursor)
+                    {
+                        cursor.advance();
+                    }
+                }
+                _ => {
+                    while !cursor.is_finished() && cursor.row_index - start < limit {
+                        cursor.advance();
+                    }
+                }
+            }
+
+            let index = cursor.input_index;
+            let end = cursor.row_index;
+            if end > start {
+                self.in_progress.push(self.blocks[index].slice(start..end));
+                self.in_progress_rows += end - start;
+            }
+            if cursor.is_finished() {
+                self.pending_streams.push_back(index);
+            } else {
+                self.heap.push(Reverse(cursor));
+            }
+        }
+
+        if self.in_progress.is_empty() {
+            return Ok(None);
+        }
+        let mut block = DataBlock::concat(&std::mem::take(&mut self.in_progress))?;
+        self.in_progress_rows = 0;
+
+        if self.gen_order_col {
+            let order_col = self.convert_rows(&block)?.to_column();
+            block.add_column(BlockEntry {
+                data_type: order_col.data_type(),
+                value: Value::Column(order_col),
+            });
+        }
+        Ok(Some(block))
+    }
+}
@@ -34,23 +34,13 @@ pub fn build_full_sort_pipeline(
     prof_info: Option<(u32, ProfSpanSetRef)>,
     after_exchange: bool,
 ) -> Result<()> {
-    // Partial sort
-    if limit.is_none() || !after_exchange {
-        // If the sort plan is after an exchange plan, the blocks are already partially sorted on other nodes.
-        pipeline.add_transform(|input, output| {
-            let transform =
-                TransformSortPartial::try_create(input, output, limit, sort_desc.clone())?;
-            if let Some((plan_id, prof)) = &prof_info {
-                Ok(ProcessorPtr::create(ProfileWrapper::create(
-                    transform,
-                    *plan_id,
-                    prof.clone(),
-                )))
-            } else {
-                Ok(ProcessorPtr::create(transform))
-            }
-        })?;
-    }
+    build_partial_sort_pipeline(
+        pipeline,
+        sort_desc.clone(),
+        limit,
+        prof_info.clone(),
+        after_exchange,
+    )?;
 
     // Merge sort
     let need_multi_merge = pipeline.output_len() > 1;
@@ -93,3 +83,31 @@ pub fn build_full_sort_pipeline(
 
     Ok(())
 }
+
+/// Sort each block of the pipelines, the blocks are merged by the following transforms.
+pub fn build_partial_sort_pipeline(
+    pipeline: &mut Pipeline,
+    sort_desc: Vec<SortColumnDescription>,
+    limit: Option<usize>,
+    prof_info: Option<(u32, ProfSpanSetRef)>,
+    after_exchange: bool,
+) -> Result<()> {
+    if limit.is_none() || !after_exchange {
+        // If the sort plan is after an exchange plan, the blocks are already partially sorted on other nodes.
+        pipeline.add_transform(|input, output| {
+            let transform =
+                TransformSortPartial::try_create(input, output, limit, sort_desc.clone())?;
+            if let Some((plan_id, prof)) = &prof_info {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    *plan_id,
+                    prof.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+    }
+
+    Ok(())
+}
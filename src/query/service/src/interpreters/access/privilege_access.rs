@@ -109,6 +109,32 @@ impl AccessChecker for PrivilegeAccess {
                     .await?
             }
 
+            // Inverted Index.
+            Plan::CreateInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+
             // Virtual Column.
             Plan::CreateVirtualColumns(plan) => {
                 session
@@ -304,6 +330,18 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::RefreshTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::TruncateTable(plan) => {
                 session
                     .validate_privilege(
@@ -401,6 +439,28 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                let mut privileges = vec![];
+                if plan.has_insert() {
+                    privileges.push(UserPrivilegeType::Insert);
+                }
+                if plan.has_update() {
+                    privileges.push(UserPrivilegeType::Update);
+                }
+                if plan.has_delete() {
+                    privileges.push(UserPrivilegeType::Delete);
+                }
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        privileges,
+                    )
+                    .await?;
+            }
             Plan::CreateView(plan) => {
                 session
                     .validate_privilege(
@@ -510,7 +570,12 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::RemoveStage(_)
             | Plan::CreateFileFormat(_)
             | Plan::DropFileFormat(_)
-            | Plan::ShowFileFormats(_) => {
+            | Plan::ShowFileFormats(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::AssignWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
+            | Plan::ShowWorkloadGroups(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
@@ -529,6 +594,8 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            // the privileges are checked by the statements of the transaction
+            Plan::Begin | Plan::Commit | Plan::Rollback => {}
         }
 
         Ok(())
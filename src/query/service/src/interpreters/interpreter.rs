@@ -15,6 +15,8 @@
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use common_base::runtime::MemStat;
+use common_base::runtime::ThreadTracker;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -31,6 +33,8 @@ use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::SourcePipeBuilder;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadPermit;
+use crate::sessions::WorkloadQueueManager;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
 use crate::stream::PullingExecutorStream;
@@ -58,6 +62,26 @@ pub trait Interpreter: Sync + Send {
             return Err(err);
         }
 
+        // Wait in the queue of the workload group, the slot is held until the query finished.
+        let workload_permit = match WorkloadQueueManager::instance().acquire(&ctx).await {
+            Ok(permit) => permit,
+            Err(err) => {
+                InterpreterMetrics::record_query_error(&ctx);
+                log_query_finished(&ctx, Some(err.clone()));
+                return Err(err);
+            }
+        };
+
+        // Take the threads of the query from the cpu quota of the user until the query finished.
+        let cpu_quota_permit = match SessionManager::instance().acquire_cpu_quota(&ctx).await {
+            Ok(permit) => permit,
+            Err(err) => {
+                InterpreterMetrics::record_query_error(&ctx);
+                log_query_finished(&ctx, Some(err.clone()));
+                return Err(err);
+            }
+        };
+
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
@@ -74,14 +98,24 @@ pub trait Interpreter: Sync + Send {
             return Ok(Box::pin(DataBlockStream::create(None, vec![])));
         }
 
+        // Memory used by the executor threads is tracked by the query, limited by the user quota
+        // and the memory share of the workload group.
+        let mem_stat = create_query_mem_stat(&ctx, &workload_permit);
+
         let query_ctx = ctx.clone();
+        let query_mem_stat = mem_stat.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
+            let may_error = may_error
+                .clone()
+                .map(|error| check_memory_quota(&query_mem_stat, &workload_permit, error));
             InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
             log_query_finished(&query_ctx, may_error.clone());
+            drop(workload_permit);
+            drop(cpu_quota_permit);
 
             match may_error {
                 None => Ok(()),
-                Some(error) => Err(error.clone()),
+                Some(error) => Err(error),
             }
         });
 
@@ -90,6 +124,9 @@ pub trait Interpreter: Sync + Send {
         build_res.set_max_threads(settings.get_max_threads()? as usize);
         let settings = ExecutorSettings::try_create(&settings, query_id)?;
 
+        // The executor threads are spawned in this scope and report to the memory stat of the query.
+        let _guard = mem_stat.map(|mem_stat| ThreadTracker::enter(Some(mem_stat)));
+
         if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
             pipelines.push(build_res.main_pipeline);
@@ -123,6 +160,68 @@ pub trait Interpreter: Sync + Send {
 
 pub type InterpreterPtr = Arc<dyn Interpreter>;
 
+fn create_query_mem_stat(
+    ctx: &QueryContext,
+    workload_permit: &Option<WorkloadPermit>,
+) -> Option<Arc<MemStat>> {
+    let max_memory = match ctx.get_current_user() {
+        Ok(user) => user.quota.max_memory_in_bytes,
+        Err(_) => return None,
+    };
+    let group_mem_stat = workload_permit
+        .as_ref()
+        .and_then(|permit| permit.mem_stat());
+    if max_memory == 0 && group_mem_stat.is_none() {
+        return None;
+    }
+
+    let name = format!("Query-{}", ctx.get_id());
+    let mem_stat = match group_mem_stat {
+        Some(group_mem_stat) => MemStat::create_child(name, Some(group_mem_stat)),
+        None => MemStat::create(name),
+    };
+    mem_stat.set_exact_limit(max_memory as i64);
+    Some(mem_stat)
+}
+
+/// The allocation that exceeds the limit panics in the processor, report it as quota exceeded.
+fn check_memory_quota(
+    mem_stat: &Option<Arc<MemStat>>,
+    workload_permit: &Option<WorkloadPermit>,
+    error: ErrorCode,
+) -> ErrorCode {
+    if error.code() != ErrorCode::PANIC_ERROR {
+        return error;
+    }
+
+    if let Some(mem_stat) = mem_stat {
+        let limit = mem_stat.get_limit();
+        if limit > 0 && mem_stat.get_peak_memory_usage() > limit {
+            return ErrorCode::UserQuotaExceeded(format!(
+                "Max memory quota exceeded: {}, {}",
+                limit,
+                error.message()
+            ));
+        }
+    }
+
+    if let Some(permit) = workload_permit {
+        if let Some(group_mem_stat) = permit.mem_stat() {
+            let limit = group_mem_stat.get_limit();
+            if limit > 0 && group_mem_stat.get_peak_memory_usage() > limit {
+                return ErrorCode::UserQuotaExceeded(format!(
+                    "Memory share of workload group {} exceeded: {}, {}",
+                    permit.group_name(),
+                    limit,
+                    error.message()
+                ));
+            }
+        }
+    }
+
+    error
+}
+
 fn log_query_start(ctx: &QueryContext) {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
@@ -140,6 +239,11 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
 
+    // A failed statement aborts the explicit transaction of the session.
+    if error.is_some() {
+        ctx.txn_mgr().lock().set_fail();
+    }
+
     session.get_status().write().query_finish();
     if session.get_type().is_user_session() {
         SessionManager::instance().status.write().query_finish(now)
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use tracing::error;
@@ -22,6 +24,8 @@ use tracing::error;
 use super::interpreter_catalog_create::CreateCatalogInterpreter;
 use super::interpreter_index_create::CreateIndexInterpreter;
 use super::interpreter_index_drop::DropIndexInterpreter;
+use super::interpreter_inverted_index_create::CreateInvertedIndexInterpreter;
+use super::interpreter_inverted_index_drop::DropInvertedIndexInterpreter;
 use super::interpreter_share_desc::DescShareInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
@@ -43,6 +47,7 @@ use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 use crate::sql::plans::Plan;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -53,6 +58,34 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        // The statements are rejected until the aborted transaction ends.
+        if !matches!(plan, Plan::Commit | Plan::Rollback)
+            && ctx.txn_mgr().lock().state() == TxnState::Fail
+        {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "Current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+
+        // These statements update the table meta directly, they can't be rolled back.
+        if matches!(
+            plan,
+            Plan::TruncateTable(_)
+                | Plan::RevertTable(_)
+                | Plan::AddTableColumn(_)
+                | Plan::DropTableColumn(_)
+                | Plan::ModifyTableColumn(_)
+                | Plan::CreateInvertedIndex(_)
+                | Plan::DropInvertedIndex(_)
+                | Plan::RefreshTable(_)
+        ) && ctx.txn_mgr().lock().is_active()
+        {
+            return Err(ErrorCode::UnsupportedInTransaction(format!(
+                "{} is not supported in an explicit transaction",
+                plan
+            )));
+        }
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
@@ -190,6 +223,10 @@ impl InterpreterFactory {
             Plan::ReclusterTable(recluster_table) => Ok(Arc::new(
                 ReclusterTableInterpreter::try_create(ctx, *recluster_table.clone())?,
             )),
+            Plan::RefreshTable(refresh_table) => Ok(Arc::new(RefreshTableInterpreter::try_create(
+                ctx,
+                *refresh_table.clone(),
+            )?)),
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
@@ -234,6 +271,14 @@ impl InterpreterFactory {
                 *index.clone(),
             )?)),
 
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+
             // Virtual columns
             Plan::CreateVirtualColumns(create_virtual_columns) => Ok(Arc::new(
                 CreateVirtualColumnsInterpreter::try_create(ctx, *create_virtual_columns.clone())?,
@@ -278,6 +323,11 @@ impl InterpreterFactory {
                 *update.clone(),
             )?)),
 
+            Plan::MergeInto(merge_into) => Ok(Arc::new(MergeIntoInterpreter::try_create(
+                ctx,
+                *merge_into.clone(),
+            )?)),
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
@@ -320,6 +370,23 @@ impl InterpreterFactory {
                 ShowFileFormatsInterpreter::try_create(ctx, *show_file_formats.clone())?,
             )),
 
+            // Workload groups
+            Plan::CreateWorkloadGroup(create_workload_group) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *create_workload_group.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(alter_workload_group) => Ok(Arc::new(
+                AlterWorkloadGroupInterpreter::try_create(ctx, *alter_workload_group.clone())?,
+            )),
+            Plan::AssignWorkloadGroup(assign_workload_group) => Ok(Arc::new(
+                AssignWorkloadGroupInterpreter::try_create(ctx, *assign_workload_group.clone())?,
+            )),
+            Plan::DropWorkloadGroup(drop_workload_group) => Ok(Arc::new(
+                DropWorkloadGroupInterpreter::try_create(ctx, *drop_workload_group.clone())?,
+            )),
+            Plan::ShowWorkloadGroups(show_workload_groups) => Ok(Arc::new(
+                ShowWorkloadGroupsInterpreter::try_create(ctx, *show_workload_groups.clone())?,
+            )),
+
             // Grant
             Plan::GrantPriv(grant_priv) => Ok(Arc::new(GrantPrivilegeInterpreter::try_create(
                 ctx,
@@ -373,6 +440,11 @@ impl InterpreterFactory {
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
 
+            // transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Rollback => Ok(Arc::new(RollbackInterpreter::try_create(ctx)?)),
+
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
                 CreateShareEndpointInterpreter::try_create(ctx, *p.clone())?,
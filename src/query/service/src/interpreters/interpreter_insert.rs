@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::VecDeque;
 use std::io::BufRead;
 use std::io::Cursor;
@@ -21,6 +22,7 @@ use std::sync::Arc;
 
 use aho_corasick::AhoCorasick;
 use common_ast::parser::parse_comma_separated_exprs;
+use common_ast::parser::token::TokenKind;
 use common_ast::parser::tokenize_sql;
 use common_catalog::table::AppendMode;
 use common_exception::ErrorCode;
@@ -29,6 +31,7 @@ use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 use common_formats::FastFieldDecoderValues;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
@@ -123,7 +126,7 @@ impl Interpreter for InsertInterpreter {
             InsertInputSource::Stage(_) => {
                 unreachable!()
             }
-            InsertInputSource::Values(data) => {
+            InsertInputSource::Values(data, params) => {
                 let settings = self.ctx.get_settings();
 
                 build_res.main_pipeline.add_source(
@@ -132,6 +135,7 @@ impl Interpreter for InsertInterpreter {
                             NameResolutionContext::try_from(settings.as_ref())?;
                         let inner = ValueSource::new(
                             data.to_string(),
+                            params.clone(),
                             self.ctx.clone(),
                             name_resolution_ctx,
                             plan.schema(),
@@ -282,6 +286,7 @@ impl Interpreter for InsertInterpreter {
 
 pub struct ValueSource {
     data: String,
+    params: Vec<Scalar>,
     ctx: Arc<dyn TableContext>,
     name_resolution_ctx: NameResolutionContext,
     bind_context: BindContext,
@@ -325,6 +330,7 @@ impl AsyncSource for ValueSource {
 impl ValueSource {
     pub fn new(
         data: String,
+        params: Vec<Scalar>,
         ctx: Arc<dyn TableContext>,
         name_resolution_ctx: NameResolutionContext,
         schema: DataSchemaRef,
@@ -334,6 +340,7 @@ impl ValueSource {
 
         Self {
             data,
+            params,
             ctx,
             name_resolution_ctx,
             schema,
@@ -361,6 +368,7 @@ impl ValueSource {
 
         let format = self.ctx.get_format_settings()?;
         let field_decoder = FastFieldDecoderValues::create_for_insert(format);
+        let mut next_param = 0;
 
         for row in 0.. {
             let _ = reader.ignore_white_spaces();
@@ -379,6 +387,7 @@ impl ValueSource {
                 positions,
                 &mut bind_context,
                 self.metadata.clone(),
+                &mut next_param,
             )
             .await?;
         }
@@ -400,6 +409,7 @@ impl ValueSource {
         positions: &mut VecDeque<usize>,
         bind_context: &mut BindContext,
         metadata: MetadataRef,
+        next_param: &mut usize,
     ) -> Result<()> {
         let _ = reader.ignore_white_spaces();
         let col_size = columns.len();
@@ -458,6 +468,17 @@ impl ValueSource {
                 let tokens = tokenize_sql(sql)?;
                 let exprs = parse_comma_separated_exprs(&tokens[1..tokens.len()], sql_dialect)?;
 
+                // The parameters of prepared statement are assigned to the placeholders in order.
+                let mut params = BTreeMap::new();
+                for token in tokens.iter().filter(|t| t.kind == TokenKind::Placeholder) {
+                    let param = self.params.get(*next_param).ok_or_else(|| {
+                        ErrorCode::BadArguments("Not enough parameters for the placeholders")
+                    })?;
+                    params.insert(token.span.start, param.clone());
+                    *next_param += 1;
+                }
+                metadata.write().set_parameters(params);
+
                 let values = bind_context
                     .exprs_to_scalar(
                         exprs,
@@ -0,0 +1,278 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::plan::split_row_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::ScalarRef;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::plans::MergeIntoPlan;
+use common_storages_fuse::operations::MatchedRowIds;
+use common_storages_fuse::FuseTable;
+use futures_util::TryStreamExt;
+use table_lock::TableLockHandlerWrapper;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::stream::PullingExecutorStream;
+
+/// interprets MergeIntoPlan
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeIntoPlan,
+}
+
+impl MergeIntoInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeIntoPlan) -> Result<Self> {
+        Ok(MergeIntoInterpreter { ctx, plan })
+    }
+
+    /// Execute `source LEFT OUTER JOIN target` and evaluate the clauses on the joined rows
+    /// block by block, returns the row ids of the target rows to be removed and the new
+    /// rows to be appended.
+    async fn execute_input(
+        &self,
+        schema: &TableSchemaRef,
+    ) -> Result<(MatchedRowIds, Vec<DataBlock>)> {
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            *self.plan.bind_context.clone(),
+            *self.plan.input.clone(),
+            self.plan.metadata.clone(),
+            None,
+            false,
+        )?;
+        let physical_plan = select_interpreter.build_physical_plan().await?;
+        let pipeline = select_interpreter.build_pipeline(physical_plan).await?;
+
+        let settings = self.ctx.get_settings();
+        let query_id = self.ctx.get_id();
+        let settings = ExecutorSettings::try_create(&settings, query_id)?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(pipeline, settings)?;
+        self.ctx.set_executor(pulling_executor.get_inner())?;
+        let mut stream = PullingExecutorStream::create(pulling_executor)?;
+
+        let mut matched_row_ids = MatchedRowIds::new();
+        let mut new_blocks = Vec::new();
+        while let Some(block) = stream.try_next().await? {
+            self.evaluate_clauses(&block, schema, &mut matched_row_ids, &mut new_blocks)?;
+        }
+
+        for offsets in matched_row_ids.values_mut() {
+            offsets.sort_unstable();
+            if offsets.windows(2).any(|pair| pair[0] == pair[1]) {
+                return Err(ErrorCode::BadArguments(
+                    "A row of the target table is matched by multiple rows of the source, which can't be updated or deleted more than once",
+                ));
+            }
+        }
+        Ok((matched_row_ids, new_blocks))
+    }
+
+    /// Evaluate the clauses on a block of the joined rows, collects the row ids of the target
+    /// rows to be removed and the new rows to be appended.
+    fn evaluate_clauses(
+        &self,
+        block: &DataBlock,
+        schema: &TableSchemaRef,
+        matched_row_ids: &mut MatchedRowIds,
+        new_blocks: &mut Vec<DataBlock>,
+    ) -> Result<()> {
+        let mut matched_rows = vec![vec![]; self.plan.matched_clauses.len()];
+        let mut unmatched_rows = vec![vec![]; self.plan.unmatched_clauses.len()];
+
+        let row_id_entry = block.get_by_offset(self.plan.row_id_offset);
+        for row in 0..block.num_rows() {
+            match row_id_entry.value.index(row) {
+                Some(ScalarRef::Number(NumberScalar::UInt64(row_id))) => {
+                    let clause = self
+                        .plan
+                        .matched_clauses
+                        .iter()
+                        .position(|clause| Self::is_satisfied(block, clause.condition, row));
+                    if let Some(clause) = clause {
+                        let (prefix, offset) = split_row_id(row_id);
+                        matched_row_ids.entry(prefix).or_default().push(offset);
+                        matched_rows[clause].push(row as u32);
+                    }
+                }
+                _ => {
+                    let clause = self
+                        .plan
+                        .unmatched_clauses
+                        .iter()
+                        .position(|clause| Self::is_satisfied(block, clause.condition, row));
+                    if let Some(clause) = clause {
+                        unmatched_rows[clause].push(row as u32);
+                    }
+                }
+            }
+        }
+
+        for (clause, rows) in self.plan.matched_clauses.iter().zip(matched_rows) {
+            if let Some(offsets) = &clause.update {
+                if !rows.is_empty() {
+                    new_blocks.push(self.project_rows(block, &rows, offsets, schema)?);
+                }
+            }
+        }
+        for (clause, rows) in self.plan.unmatched_clauses.iter().zip(unmatched_rows) {
+            if !rows.is_empty() {
+                new_blocks.push(self.project_rows(block, &rows, &clause.values, schema)?);
+            }
+        }
+        Ok(())
+    }
+
+    // A clause without condition is always satisfied, a NULL condition is not satisfied.
+    fn is_satisfied(block: &DataBlock, condition: Option<usize>, row: usize) -> bool {
+        match condition {
+            Some(offset) => matches!(
+                block.get_by_offset(offset).value.index(row),
+                Some(ScalarRef::Boolean(true))
+            ),
+            None => true,
+        }
+    }
+
+    // Take the rows and cast the values to the types of the table fields.
+    fn project_rows(
+        &self,
+        block: &DataBlock,
+        rows: &[u32],
+        offsets: &[usize],
+        schema: &TableSchemaRef,
+    ) -> Result<DataBlock> {
+        let block = block.take(rows)?;
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let mut columns = Vec::with_capacity(offsets.len());
+        for (field, offset) in schema.fields().iter().zip(offsets) {
+            let entry = block.get_by_offset(*offset);
+            let dest_type = DataType::from(field.data_type());
+            let expr = Expr::ColumnRef {
+                span: None,
+                id: *offset,
+                data_type: entry.data_type.clone(),
+                display_name: field.name().clone(),
+            };
+            let value = if entry.data_type != dest_type {
+                evaluator.run(&Expr::Cast {
+                    span: None,
+                    is_try: false,
+                    expr: Box::new(expr),
+                    dest_type: dest_type.clone(),
+                })?
+            } else {
+                entry.value.clone()
+            };
+            columns.push(BlockEntry::new(dest_type, value));
+        }
+        Ok(DataBlock::new(columns, block.num_rows()))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", name = "merge_into_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        let table_info = tbl.get_table_info().clone();
+
+        // Add table lock heartbeat.
+        let handler = TableLockHandlerWrapper::instance(self.ctx.clone());
+        let mut heartbeat = handler
+            .try_lock(self.ctx.clone(), table_info.clone())
+            .await?;
+
+        // refresh table.
+        let tbl = self
+            .ctx
+            .refresh_table(catalog_name, db_name, tbl_name)
+            .await?;
+        if tbl.schema() != table_info.schema() {
+            heartbeat.shutdown().await?;
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "The schema of table {}.{} is changed while merging into it, please retry",
+                db_name, tbl_name
+            )));
+        }
+
+        // The `_row_id` of a row depends on the position of its block in the snapshot, so
+        // the join must read the snapshot which is mutated.
+        self.plan
+            .metadata
+            .write()
+            .replace_table(catalog_name, db_name, tbl_name, tbl.clone());
+
+        let mut build_res = PipelineBuildResult::create();
+        let (matched_row_ids, new_blocks) = match self.execute_input(&tbl.schema()).await {
+            Ok(res) => res,
+            Err(e) => {
+                heartbeat.shutdown().await?;
+                return Err(e);
+            }
+        };
+        if !matched_row_ids.is_empty() || !new_blocks.is_empty() {
+            let fuse_table = FuseTable::try_from_table(tbl.as_ref())?;
+            fuse_table
+                .do_merge_into(
+                    self.ctx.clone(),
+                    matched_row_ids,
+                    new_blocks,
+                    &mut build_res.main_pipeline,
+                )
+                .await?;
+        }
+
+        if build_res.main_pipeline.is_empty() {
+            heartbeat.shutdown().await?;
+        } else {
+            build_res.main_pipeline.set_on_finished(move |may_error| {
+                // shutdown table lock heartbeat.
+                GlobalIORuntime::instance().block_on(async move { heartbeat.shutdown().await })?;
+                match may_error {
+                    None => Ok(()),
+                    Some(error_code) => Err(error_code.clone()),
+                }
+            });
+        }
+
+        Ok(build_res)
+    }
+}
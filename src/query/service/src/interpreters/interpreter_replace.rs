@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::plans::InsertInputSource;
 use common_sql::plans::Plan;
@@ -113,8 +114,8 @@ impl ReplaceInterpreter {
         schema: DataSchemaRef,
     ) -> Result<PipelineBuildResult> {
         match source {
-            InsertInputSource::Values(data) => {
-                self.connect_value_source(ctx.clone(), schema.clone(), data)
+            InsertInputSource::Values(data, params) => {
+                self.connect_value_source(ctx.clone(), schema.clone(), data, params)
             }
 
             InsertInputSource::SelectPlan(plan) => {
@@ -139,6 +140,7 @@ impl ReplaceInterpreter {
         ctx: Arc<QueryContext>,
         schema: DataSchemaRef,
         value_data: &str,
+        params: &[Scalar],
     ) -> Result<PipelineBuildResult> {
         let mut build_res = PipelineBuildResult::create();
         let settings = ctx.get_settings();
@@ -147,6 +149,7 @@ impl ReplaceInterpreter {
                 let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
                 let inner = ValueSource::new(
                     value_data.to_string(),
+                    params.to_vec(),
                     ctx.clone(),
                     name_resolution_ctx,
                     schema.clone(),
@@ -36,16 +36,25 @@ use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
 use common_users::UserApiProvider;
 use once_cell::sync::Lazy;
 use storages_common_cache::LoadParams;
+use storages_common_index::InvertedIndex;
+use storages_common_index::NgramIndex;
+use storages_common_index::VectorIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_EXTERNAL_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_NAME;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_OWNER;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_DISTANCE;
 use tracing::error;
 
 use crate::interpreters::InsertInterpreter;
@@ -218,6 +227,7 @@ impl CreateTableInterpreter {
             storage_params: self.plan.storage_params.clone(),
             part_prefix: self.plan.part_prefix.clone(),
             options: self.plan.options.clone(),
+            engine_options: self.plan.engine_options.clone(),
             default_cluster_key: None,
             field_comments: self.plan.field_comments.clone(),
             drop_on: None,
@@ -238,6 +248,10 @@ impl CreateTableInterpreter {
                 )));
             }
         }
+        // check the columns of n-gram index, inverted index and vector index.
+        NgramIndex::parse_options(&table_meta.schema, &table_meta.options)?;
+        InvertedIndex::parse_options(&table_meta.schema, &table_meta.options)?;
+        VectorIndex::parse_options(&table_meta.schema, &table_meta.options)?;
 
         if let Some(cluster_key) = &self.plan.cluster_key {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
@@ -265,12 +279,18 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_INVERTED_INDEX_NAME);
+    r.insert(OPT_KEY_INVERTED_INDEX_COLUMNS);
+    r.insert(OPT_KEY_VECTOR_INDEX_COLUMNS);
+    r.insert(OPT_KEY_VECTOR_INDEX_DISTANCE);
 
     r.insert(OPT_KEY_SNAPSHOT_LOCATION);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_OWNER);
 
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_EXTERNAL_LOCATION);
@@ -14,24 +14,52 @@
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 
-use common_ast::ast::ModifyColumnAction;
+use common_ast::parser::quote::quote_ident;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::tokenize_sql;
+use common_base::runtime::GlobalIORuntime;
 use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::TableDataType;
+use common_expression::TableField;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
+use common_sql::binder::INTERNAL_COLUMN_FACTORY;
+use common_sql::executor::DistributedInsertSelect;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::field_default_value;
+use common_sql::plans::ModifyColumnAction;
 use common_sql::plans::ModifyTableColumnPlan;
+use common_sql::plans::Plan;
+use common_sql::Planner;
+use common_storages_fuse::FuseStorageFormat;
+use common_storages_fuse::FuseTable;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use common_users::UserApiProvider;
 use data_mask_feature::get_datamask_handler;
+use storages_common_index::InvertedIndex;
+use storages_common_index::NgramIndex;
+use storages_common_index::VectorIndex;
+use storages_common_table_meta::table::set_widened_column_source;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
+use table_lock::TableLockHandlerWrapper;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_distributed_pipeline;
+use crate::schedulers::build_local_pipeline;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
@@ -92,6 +120,359 @@ impl ModifyTableColumnInterpreter {
         new_table_meta.column_mask_policy = Some(column_mask_policy);
         Ok(new_table_meta)
     }
+
+    fn do_rename_column(&self, table_meta: TableMeta, new_name: &str) -> Result<TableMeta> {
+        let column = &self.plan.column;
+        let mut new_schema = table_meta.schema.as_ref().clone();
+        let index = new_schema.index_of(column)?;
+        if new_schema.index_of(new_name).is_ok() {
+            return Err(ErrorCode::AddColumnExistError(format!(
+                "column {} already exist",
+                new_name
+            )));
+        }
+        if INTERNAL_COLUMN_FACTORY.exist(new_name) {
+            return Err(ErrorCode::TableWithInternalColumnName(format!(
+                "Cannot rename a column to the same name as internal column: {}",
+                new_name
+            )));
+        }
+        // Cluster keys and computed columns refer to columns by name.
+        self.check_column_not_referenced(&table_meta, "rename")?;
+
+        new_schema.fields[index].name = new_name.to_string();
+
+        let mut new_table_meta = table_meta;
+        new_table_meta.schema = Arc::new(new_schema);
+        if let Some(column_mask_policy) = &mut new_table_meta.column_mask_policy {
+            if let Some(mask_name) = column_mask_policy.remove(column) {
+                column_mask_policy.insert(new_name.to_string(), mask_name);
+            }
+        }
+        // The index options refer to columns by name as well.
+        for key in [
+            OPT_KEY_INVERTED_INDEX_COLUMNS,
+            OPT_KEY_NGRAM_INDEX_COLUMNS,
+            OPT_KEY_VECTOR_INDEX_COLUMNS,
+        ] {
+            if let Some(columns) = new_table_meta.options.get_mut(key) {
+                *columns = columns
+                    .split(',')
+                    .map(|name| {
+                        if name.trim() == column {
+                            new_name
+                        } else {
+                            name.trim()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+            }
+        }
+        check_index_options(&new_table_meta)?;
+        Ok(new_table_meta)
+    }
+
+    fn do_set_default(
+        &self,
+        table_meta: TableMeta,
+        default_expr: Option<String>,
+    ) -> Result<TableMeta> {
+        let mut new_schema = table_meta.schema.as_ref().clone();
+        let index = new_schema.index_of(&self.plan.column)?;
+        let field = &mut new_schema.fields[index];
+        if field.computed_expr().is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Cannot set default value of computed column {}",
+                self.plan.column
+            )));
+        }
+        field.default_expr = default_expr;
+        if field.default_expr().is_some() {
+            let _ = field_default_value(self.ctx.clone(), field)?;
+        }
+
+        let mut new_table_meta = table_meta;
+        new_table_meta.schema = Arc::new(new_schema);
+        Ok(new_table_meta)
+    }
+
+    fn do_set_comment(&self, table_meta: TableMeta, comment: &str) -> Result<TableMeta> {
+        let index = table_meta.schema.index_of(&self.plan.column)?;
+
+        let mut new_table_meta = table_meta;
+        let num_fields = new_table_meta.schema.num_fields();
+        if new_table_meta.field_comments.len() < num_fields {
+            new_table_meta
+                .field_comments
+                .resize(num_fields, String::new());
+        }
+        new_table_meta.field_comments[index] = comment.to_string();
+        Ok(new_table_meta)
+    }
+
+    // Widening the data type of a column (e.g. `Int32` to `Int64`) or changing the data type
+    // of a column without data only touches the table meta. Otherwise all the blocks are
+    // rewritten with the new schema: the column is read as the old type, cast to the new type
+    // and the table is overwritten, holding the table lock.
+    #[async_backtrace::framed]
+    async fn do_set_data_type(
+        &self,
+        table: &Arc<dyn Table>,
+        data_type: &TableDataType,
+    ) -> Result<PipelineBuildResult> {
+        // Only fuse tables support rewriting the blocks.
+        let _ = FuseTable::try_from_table(table.as_ref())?;
+
+        // Add table lock heartbeat.
+        let handler = TableLockHandlerWrapper::instance(self.ctx.clone());
+        let mut heartbeat = handler
+            .try_lock(self.ctx.clone(), table.get_table_info().clone())
+            .await?;
+
+        let mut build_res = match self.build_set_data_type(data_type).await {
+            Ok(build_res) => build_res,
+            Err(e) => {
+                heartbeat.shutdown().await?;
+                return Err(e);
+            }
+        };
+
+        if build_res.main_pipeline.is_empty() {
+            heartbeat.shutdown().await?;
+        } else {
+            build_res.main_pipeline.set_on_finished(move |may_error| {
+                // shutdown table lock heartbeat.
+                GlobalIORuntime::instance().block_on(async move { heartbeat.shutdown().await })?;
+                match may_error {
+                    None => Ok(()),
+                    Some(error_code) => Err(error_code.clone()),
+                }
+            });
+        }
+        Ok(build_res)
+    }
+
+    #[async_backtrace::framed]
+    async fn build_set_data_type(&self, data_type: &TableDataType) -> Result<PipelineBuildResult> {
+        // refresh table.
+        let table = self
+            .ctx
+            .refresh_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        let table_info = table.get_table_info();
+        let schema = table.schema();
+        let column = &self.plan.column;
+        let index = schema.index_of(column)?;
+        let field = schema.field(index);
+        if field.data_type() == data_type {
+            return Ok(PipelineBuildResult::create());
+        }
+        if field.computed_expr().is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Cannot change data type of computed column {}",
+                column
+            )));
+        }
+        if let Some(column_mask_policy) = &table_info.meta.column_mask_policy {
+            if column_mask_policy.contains_key(column) {
+                return Err(ErrorCode::UnmatchColumnDataType(format!(
+                    "Cannot change data type of column {} with a masking policy",
+                    column
+                )));
+            }
+        }
+        self.check_column_not_referenced(&table_info.meta, "change data type of")?;
+
+        // The column gets a new column id, so the old data is never read as the new type.
+        let mut new_schema = schema.as_ref().clone();
+        let new_field = TableField::new(column, data_type.clone())
+            .with_default_expr(field.default_expr().cloned());
+        if new_field.default_expr().is_some() {
+            let _ = field_default_value(self.ctx.clone(), &new_field)?;
+        }
+        let new_field = new_field.build_column_id(&mut new_schema.next_column_id);
+        let new_column_id = new_field.column_id();
+        new_schema.fields[index] = new_field;
+
+        // The blocks of the native format are always rewritten.
+        let is_widening = is_widening_cast(field.data_type(), data_type)
+            && matches!(fuse_table.get_storage_format(), FuseStorageFormat::Parquet);
+        if is_widening {
+            set_widened_column_source(&mut new_schema, new_column_id, field)?;
+        }
+
+        let mut new_table_info = table_info.clone();
+        new_table_info.meta.schema = Arc::new(new_schema.clone());
+        check_index_options(&new_table_info.meta)?;
+
+        if is_widening || table_info.meta.statistics.number_of_rows == 0 {
+            self.update_table_meta(table_info, new_table_info.meta)
+                .await?;
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let quote = |ident: &str| quote_ident(ident, '`', true);
+        let select_columns = schema
+            .remove_computed_fields()
+            .fields()
+            .iter()
+            .map(|field| quote(field.name()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!(
+            "SELECT {} FROM {}.{}.{}",
+            select_columns,
+            quote(&self.plan.catalog),
+            quote(&self.plan.database),
+            quote(&self.plan.table)
+        );
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let (select_plan, _) = planner.plan_sql(&sql).await?;
+        let (input, select_column_bindings) = match &select_plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => {
+                let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
+                (builder.build(s_expr).await?, bind_context.columns.clone())
+            }
+            _ => unreachable!(),
+        };
+
+        let insert_plan =
+            PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+                input: Box::new(input),
+                catalog: self.plan.catalog.clone(),
+                table_info: new_table_info.clone(),
+                select_schema: select_plan.schema(),
+                select_column_bindings,
+                insert_schema: Arc::new(DataSchema::from(&new_schema.remove_computed_fields())),
+                cast_needed: true,
+            }));
+
+        let mut build_res = if !insert_plan.is_distributed_plan() {
+            build_local_pipeline(&self.ctx, &insert_plan, false).await
+        } else {
+            build_distributed_pipeline(&self.ctx, &insert_plan, false).await
+        }?;
+
+        // Commit the new blocks with the new schema, replacing all the existing blocks.
+        // A concurrent insertion may still commit despite the table lock; the commit is
+        // aborted instead of retried, since the retry would commit the new blocks on top of
+        // the refreshed table, which has the old schema.
+        let new_table = self
+            .ctx
+            .get_catalog(&self.plan.catalog)?
+            .get_table_by_info(&new_table_info)?;
+        FuseTable::try_from_table(new_table.as_ref())?.do_commit(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            None,
+            true,
+            Some(Duration::ZERO),
+        )?;
+
+        Ok(build_res)
+    }
+
+    fn check_column_not_referenced(&self, table_meta: &TableMeta, operation: &str) -> Result<()> {
+        let column = &self.plan.column;
+        if let Some(cluster_key) = &table_meta.default_cluster_key {
+            if expr_references_column(cluster_key, column)? {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Cannot {} column {} which is used by the cluster key",
+                    operation, column
+                )));
+            }
+        }
+        for field in table_meta.schema.fields() {
+            if let Some(computed_expr) = field.computed_expr() {
+                if expr_references_column(computed_expr.expr(), column)? {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Cannot {} column {} which is used by computed column {}",
+                        operation,
+                        column,
+                        field.name()
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn update_table_meta(
+        &self,
+        table_info: &TableInfo,
+        new_table_meta: TableMeta,
+    ) -> Result<()> {
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
+
+        let table_id = table_info.ident.table_id;
+        let table_version = table_info.ident.seq;
+
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(table_version),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+// The indexed columns must still exist with a supported data type.
+fn check_index_options(table_meta: &TableMeta) -> Result<()> {
+    InvertedIndex::parse_options(&table_meta.schema, &table_meta.options)?;
+    NgramIndex::parse_options(&table_meta.schema, &table_meta.options)?;
+    VectorIndex::parse_options(&table_meta.schema, &table_meta.options)?;
+    Ok(())
+}
+
+// Whether all the values of `from` can be cast to `to` without loss,
+// so the blocks are read as `from` and cast to `to` instead of being rewritten.
+fn is_widening_cast(from: &TableDataType, to: &TableDataType) -> bool {
+    match (from, to) {
+        (TableDataType::Nullable(from), TableDataType::Nullable(to)) => is_widening_cast(from, to),
+        (TableDataType::Nullable(_), _) => false,
+        (from, TableDataType::Nullable(to)) => from == to.as_ref() || is_widening_cast(from, to),
+        (TableDataType::Number(from), TableDataType::Number(to)) => from.can_lossless_cast_to(*to),
+        (TableDataType::String, TableDataType::Variant) => true,
+        _ => false,
+    }
+}
+
+fn expr_references_column(expr: &str, column: &str) -> Result<bool> {
+    let tokens = tokenize_sql(expr)?;
+    Ok(tokens.iter().any(|token| match token.kind {
+        TokenKind::Ident => token.text().eq_ignore_ascii_case(column),
+        TokenKind::QuotedString => {
+            let text = token.text();
+            text.len() >= 2
+                && (text.starts_with('"') || text.starts_with('`'))
+                && text[1..text.len() - 1] == *column
+        }
+        _ => false,
+    }))
 }
 
 #[async_trait::async_trait]
@@ -133,7 +514,6 @@ impl Interpreter for ModifyTableColumnInterpreter {
             )));
         }
 
-        let catalog = self.ctx.get_catalog(catalog_name)?;
         let table_meta = table.get_table_info().meta.clone();
 
         let new_table_meta = match &self.plan.action {
@@ -141,29 +521,18 @@ impl Interpreter for ModifyTableColumnInterpreter {
                 self.do_set_data_mask_policy(table, table_meta, mask_name.clone())
                     .await?
             }
+            ModifyColumnAction::SetDataType(data_type) => {
+                return self.do_set_data_type(table, data_type).await;
+            }
+            ModifyColumnAction::SetDefault(default_expr) => {
+                self.do_set_default(table_meta, Some(default_expr.clone()))?
+            }
+            ModifyColumnAction::DropDefault => self.do_set_default(table_meta, None)?,
+            ModifyColumnAction::Comment(comment) => self.do_set_comment(table_meta, comment)?,
+            ModifyColumnAction::Rename(new_name) => self.do_rename_column(table_meta, new_name)?,
         };
 
-        let table_id = table_info.ident.table_id;
-        let table_version = table_info.ident.seq;
-
-        let req = UpdateTableMetaReq {
-            table_id,
-            seq: MatchSeq::Exact(table_version),
-            new_table_meta,
-            copied_files: None,
-            deduplicated_label: None,
-        };
-
-        let res = catalog.update_table_meta(table_info, req).await?;
-
-        if let Some(share_table_info) = res.share_table_info {
-            save_share_table_info(
-                &self.ctx.get_tenant(),
-                self.ctx.get_data_operator()?.operator(),
-                share_table_info,
-            )
-            .await?;
-        }
+        self.update_table_meta(table_info, new_table_meta).await?;
 
         Ok(PipelineBuildResult::create())
     }
@@ -0,0 +1,73 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::RefreshTablePlan;
+use common_storages_stage::ExternalTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshTablePlan,
+}
+
+impl RefreshTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshTablePlan) -> Result<Self> {
+        Ok(RefreshTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshTableInterpreter {
+    fn name(&self) -> &str {
+        "RefreshTableInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(plan.tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+        // Only the files of external tables are listed by the table.
+        ExternalTable::try_from_table(table.as_ref())?;
+        let table_info = table.get_table_info();
+
+        // Bump the version of the table, the nodes holding the files of
+        // the old version will list the files again.
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.updated_on = Utc::now();
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+        ExternalTable::clear_files_cache(table_info.ident.table_id);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
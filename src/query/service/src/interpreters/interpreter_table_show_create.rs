@@ -24,6 +24,7 @@ use common_expression::DataSchemaRef;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_sql::plans::ShowCreateTablePlan;
+use common_storages_stage::ExternalTable;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_internal_opt_key;
@@ -156,6 +157,27 @@ impl Interpreter for ShowCreateTableInterpreter {
         let table_engine = format!(") ENGINE={}", engine);
         table_create_sql.push_str(table_engine.as_str());
 
+        if let Ok(external_table) = ExternalTable::try_from_table(table.as_ref()) {
+            let options = external_table.options();
+            table_create_sql.push_str(
+                format!(
+                    " LOCATION = {} FILE_FORMAT = ({})",
+                    options.location(),
+                    options.file_format
+                )
+                .as_str(),
+            );
+            if let Some(pattern) = &options.pattern {
+                table_create_sql
+                    .push_str(format!(" PATTERN = '{}'", pattern.replace('\'', "\\'")).as_str());
+            }
+            if !options.partition_by.is_empty() {
+                table_create_sql.push_str(
+                    format!(" PARTITION BY ({})", options.partition_by.join(", ")).as_str(),
+                );
+            }
+        }
+
         let table_info = table.get_table_info();
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::Catalog;
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_storages_fuse::FuseTable;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryAffect;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+
+    // Publish the new metas of the tables mutated in the transaction together.
+    #[async_backtrace::framed]
+    async fn commit(&self) -> Result<()> {
+        let (state, req, table_infos) = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            (txn_mgr.state(), txn_mgr.req(), txn_mgr.table_infos())
+        };
+
+        match (state, req) {
+            (TxnState::Fail, _) => Err(ErrorCode::CurrentTransactionIsAborted(
+                "Current transaction is aborted, it has been rolled back",
+            )),
+            (_, Some((catalog, req))) => {
+                info!(
+                    "committing transaction of {} tables",
+                    req.update_table_metas.len()
+                );
+                let catalog = self.ctx.get_catalog(&catalog)?;
+                catalog.update_multi_table_meta(req).await?;
+                // The transaction is committed, a failed purge is picked up by the later GC.
+                if let Err(e) = self
+                    .purge_transient_tables(catalog.as_ref(), table_infos)
+                    .await
+                {
+                    warn!("purge of the transient tables failed, the error : {}", e);
+                }
+                Ok(())
+            }
+            (_, None) => Ok(()),
+        }
+    }
+
+    // The purge of the historical data of the transient tables is deferred to the commit.
+    #[async_backtrace::framed]
+    async fn purge_transient_tables(
+        &self,
+        catalog: &dyn Catalog,
+        table_infos: Vec<TableInfo>,
+    ) -> Result<()> {
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        for table_info in table_infos {
+            let table = catalog.get_table_by_info(&table_info)?;
+            if let Ok(tbl) = FuseTable::try_from_table(table.as_ref()) {
+                if tbl.transient() {
+                    tbl.purge_transient_history(&ctx).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let res = self.commit().await;
+        // The transaction ends no matter the commit succeeds or not.
+        self.ctx.txn_mgr().lock().clear();
+        self.ctx
+            .set_affect(QueryAffect::Transaction { active: false });
+        res?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -69,8 +69,7 @@ impl Interpreter for UpdateInterpreter {
         // refresh table.
         let tbl = self
             .ctx
-            .get_catalog(catalog_name)?
-            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .refresh_table(catalog_name, db_name, tbl_name)
             .await?;
 
         let (filter, col_indices) = if let Some(scalar) = &self.plan.selection {
@@ -18,7 +18,6 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::UserGrantSet;
 use common_meta_app::principal::UserInfo;
-use common_meta_app::principal::UserQuota;
 use common_meta_types::MatchSeq;
 use common_sql::plans::CreateUserPlan;
 use common_users::UserApiProvider;
@@ -69,7 +68,7 @@ impl Interpreter for CreateUserInterpreter {
             name: plan.user.username,
             hostname: plan.user.hostname,
             grants: UserGrantSet::empty(),
-            quota: UserQuota::no_limit(),
+            quota: plan.user_quota,
             option: plan.user_option,
         };
         user_mgr
@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::AssignWorkloadGroupPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sessions::WorkloadQueueManager;
+
+#[derive(Debug)]
+pub struct AssignWorkloadGroupInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AssignWorkloadGroupPlan,
+}
+
+impl AssignWorkloadGroupInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AssignWorkloadGroupPlan) -> Result<Self> {
+        Ok(AssignWorkloadGroupInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AssignWorkloadGroupInterpreter {
+    fn name(&self) -> &str {
+        "AssignWorkloadGroupInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        if plan.unassign {
+            let current = user_mgr
+                .get_workload_group_assignment(&tenant, &plan.principal)
+                .await?;
+            if current.as_deref() != Some(plan.group.as_str()) {
+                return Err(ErrorCode::IllegalWorkloadGroup(format!(
+                    "{} is not assigned to workload group {}",
+                    plan.principal.to_string().trim_start(),
+                    plan.group
+                )));
+            }
+            user_mgr
+                .set_workload_group_assignment(&tenant, &plan.principal, None)
+                .await?;
+        } else {
+            // Make sure the group exists, a principal is assigned to at most one group.
+            user_mgr.get_workload_group(&tenant, &plan.group).await?;
+            user_mgr
+                .set_workload_group_assignment(&tenant, &plan.principal, Some(&plan.group))
+                .await?;
+        }
+
+        WorkloadQueueManager::instance().invalidate(&tenant);
+        Ok(PipelineBuildResult::create())
+    }
+}
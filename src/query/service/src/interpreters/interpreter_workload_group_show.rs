@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::ShowWorkloadGroupsPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sessions::WorkloadQueueManager;
+
+#[derive(Debug)]
+pub struct ShowWorkloadGroupsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowWorkloadGroupsPlan,
+}
+
+impl ShowWorkloadGroupsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowWorkloadGroupsPlan) -> Result<Self> {
+        Ok(ShowWorkloadGroupsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowWorkloadGroupsInterpreter {
+    fn name(&self) -> &str {
+        "ShowWorkloadGroupsInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let user_mgr = UserApiProvider::instance();
+        let tenant = self.ctx.get_tenant();
+        let mut groups = user_mgr.get_workload_groups(&tenant).await?;
+
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // The running and queued queries are counted on the current node.
+        let status = groups
+            .iter()
+            .map(|x| WorkloadQueueManager::instance().get_status(&tenant, &x.name))
+            .collect::<Vec<_>>();
+
+        let names = groups
+            .iter()
+            .map(|x| x.name.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(groups.iter().map(|x| x.max_concurrency).collect()),
+            UInt64Type::from_data(groups.iter().map(|x| x.memory_share).collect()),
+            UInt64Type::from_data(groups.iter().map(|x| x.queue_timeout).collect()),
+            UInt64Type::from_data(status.iter().map(|x| x.0 as u64).collect()),
+            UInt64Type::from_data(status.iter().map(|x| x.1 as u64).collect()),
+        ])])
+    }
+}
@@ -39,7 +39,10 @@ mod interpreter_file_format_show;
 mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_insert;
+mod interpreter_inverted_index_create;
+mod interpreter_inverted_index_drop;
 mod interpreter_kill;
+mod interpreter_merge_into;
 mod interpreter_metrics;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
@@ -77,12 +80,16 @@ mod interpreter_table_exists;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
+mod interpreter_table_refresh;
 mod interpreter_table_rename;
 mod interpreter_table_revert;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_vacuum;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
+mod interpreter_txn_rollback;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -102,6 +109,11 @@ mod interpreter_virtual_columns_alter;
 mod interpreter_virtual_columns_create;
 mod interpreter_virtual_columns_drop;
 mod interpreter_virtual_columns_generate;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_assign;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
+mod interpreter_workload_group_show;
 
 pub use access::ManagementModeAccess;
 pub use common::append2table;
@@ -125,6 +137,7 @@ pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_merge_into::MergeIntoInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
@@ -159,11 +172,15 @@ pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
+pub use interpreter_table_refresh::RefreshTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
+pub use interpreter_txn_rollback::RollbackInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
@@ -183,3 +200,8 @@ pub use interpreter_virtual_columns_alter::AlterVirtualColumnsInterpreter;
 pub use interpreter_virtual_columns_create::CreateVirtualColumnsInterpreter;
 pub use interpreter_virtual_columns_drop::DropVirtualColumnsInterpreter;
 pub use interpreter_virtual_columns_generate::GenerateVirtualColumnsInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_assign::AssignWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
+pub use interpreter_workload_group_show::ShowWorkloadGroupsInterpreter;
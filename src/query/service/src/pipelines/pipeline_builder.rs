@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -41,7 +43,10 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_sinks::EmptySink;
 use common_pipeline_sinks::Sinker;
 use common_pipeline_sinks::UnionReceiveSink;
+use common_pipeline_sources::BlocksSource;
 use common_pipeline_transforms::processors::transforms::build_full_sort_pipeline;
+use common_pipeline_transforms::processors::transforms::build_partial_sort_pipeline;
+use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
 use common_pipeline_transforms::processors::ProfileWrapper;
 use common_profile::ProfSpanSetRef;
 use common_sql::evaluator::BlockOperator;
@@ -61,6 +66,8 @@ use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::ProjectSet;
 use common_sql::executor::RangeJoin;
+use common_sql::executor::RecursiveCteScan;
+use common_sql::executor::RecursiveUnion;
 use common_sql::executor::RowFetch;
 use common_sql::executor::RuntimeFilterSource;
 use common_sql::executor::Sort;
@@ -73,6 +80,7 @@ use common_sql::IndexType;
 use common_storage::DataOperator;
 use common_storages_fuse::operations::build_row_fetcher_pipeline;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
+use parking_lot::Mutex;
 use petgraph::matrix_graph::Zero;
 
 use super::processors::transforms::FrameBound;
@@ -82,12 +90,15 @@ use crate::api::DefaultExchangeInjector;
 use crate::api::ExchangeInjector;
 use crate::interpreters::fill_missing_columns;
 use crate::pipelines::processors::transforms::build_partition_bucket;
+use crate::pipelines::processors::transforms::try_create_transform_sort_spill;
 use crate::pipelines::processors::transforms::AggregateInjector;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
 use crate::pipelines::processors::transforms::HashJoinDesc;
+use crate::pipelines::processors::transforms::HashJoinSpiller;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RangeJoinState;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
+use crate::pipelines::processors::transforms::SortSpiller;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
@@ -96,6 +107,7 @@ use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
 use crate::pipelines::processors::transforms::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::TransformRangeJoinRight;
+use crate::pipelines::processors::transforms::TransformRecursiveUnion;
 use crate::pipelines::processors::transforms::TransformWindow;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::JoinHashTable;
@@ -125,6 +137,9 @@ pub struct PipelineBuilder {
     enable_profiling: bool,
     prof_span_set: ProfSpanSetRef,
     exchange_injector: Arc<dyn ExchangeInjector>,
+
+    // Working tables of the recursive ctes, keyed by the cte index
+    pub recursive_cte_tables: HashMap<IndexType, Arc<Vec<DataBlock>>>,
 }
 
 impl PipelineBuilder {
@@ -142,6 +157,7 @@ impl PipelineBuilder {
             prof_span_set,
             exchange_injector: DefaultExchangeInjector::create(),
             index: None,
+            recursive_cte_tables: HashMap::new(),
         }
     }
 
@@ -192,6 +208,10 @@ impl PipelineBuilder {
                 self.build_runtime_filter_source(runtime_filter_source)
             }
             PhysicalPlan::RangeJoin(range_join) => self.build_range_join(range_join),
+            PhysicalPlan::RecursiveUnion(recursive_union) => {
+                self.build_recursive_union(recursive_union)
+            }
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
         }
     }
 
@@ -230,11 +250,12 @@ impl PipelineBuilder {
         state: Arc<RangeJoinState>,
     ) -> Result<()> {
         let right_side_context = QueryContext::create_from(self.ctx.clone());
-        let right_side_builder = PipelineBuilder::create(
+        let mut right_side_builder = PipelineBuilder::create(
             right_side_context,
             self.enable_profiling,
             self.prof_span_set.clone(),
         );
+        right_side_builder.recursive_cte_tables = self.recursive_cte_tables.clone();
         let mut right_res = right_side_builder.finalize(&range_join.right)?;
         right_res.main_pipeline.add_sink(|input| {
             let transform = Sinker::<TransformRangeJoinRight>::create(
@@ -280,11 +301,12 @@ impl PipelineBuilder {
         join_state: Arc<JoinHashTable>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
-        let build_side_builder = PipelineBuilder::create(
+        let mut build_side_builder = PipelineBuilder::create(
             build_side_context,
             self.enable_profiling,
             self.prof_span_set.clone(),
         );
+        build_side_builder.recursive_cte_tables = self.recursive_cte_tables.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -292,6 +314,7 @@ impl PipelineBuilder {
             let transform = TransformHashJoinBuild::create(
                 input,
                 TransformHashJoinBuild::attach(join_state.clone())?,
+                HashJoinSpiller::create(join_state.clone(), true)?,
             );
 
             if self.enable_profiling {
@@ -1018,6 +1041,22 @@ impl PipelineBuilder {
             None
         };
 
+        // The sort with limit only keeps the top rows in memory, it doesn't need to spill.
+        let spilling_threshold = self
+            .ctx
+            .get_settings()
+            .get_sort_spilling_bytes_threshold_per_proc()?;
+        if limit.is_none() && spilling_threshold != 0 {
+            return self.build_spilling_sort_pipeline(
+                input_schema,
+                sort_desc,
+                block_size,
+                spilling_threshold,
+                prof_info,
+                after_exchange,
+            );
+        }
+
         build_full_sort_pipeline(
             &mut self.main_pipeline,
             input_schema,
@@ -1029,6 +1068,60 @@ impl PipelineBuilder {
         )
     }
 
+    // External merge sort, the sorted runs are spilled to storage if they exceed the threshold.
+    fn build_spilling_sort_pipeline(
+        &mut self,
+        input_schema: DataSchemaRef,
+        sort_desc: Vec<SortColumnDescription>,
+        block_size: usize,
+        spilling_threshold: usize,
+        prof_info: Option<(u32, ProfSpanSetRef)>,
+        after_exchange: bool,
+    ) -> Result<()> {
+        build_partial_sort_pipeline(
+            &mut self.main_pipeline,
+            sort_desc.clone(),
+            None,
+            prof_info.clone(),
+            after_exchange,
+        )?;
+
+        let need_multi_merge = self.main_pipeline.output_len() > 1;
+        let spiller = SortSpiller::create(&self.ctx);
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = try_create_transform_sort_spill(
+                input,
+                output,
+                input_schema.clone(),
+                sort_desc.clone(),
+                block_size,
+                need_multi_merge,
+                spiller.clone(),
+                spilling_threshold,
+            )?;
+            if let Some((plan_id, prof)) = &prof_info {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    *plan_id,
+                    prof.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+
+        if need_multi_merge {
+            try_add_multi_sort_merge(
+                &mut self.main_pipeline,
+                input_schema,
+                block_size,
+                None,
+                sort_desc,
+            )?;
+        }
+        Ok(())
+    }
+
     fn build_limit(&mut self, limit: &Limit) -> Result<()> {
         self.build_pipeline(&limit.input)?;
 
@@ -1073,6 +1166,7 @@ impl PipelineBuilder {
                 TransformHashJoinProbe::attach(state.clone())?,
                 &join.join_type,
                 !join.non_equi_conditions.is_empty(),
+                HashJoinSpiller::create(state.clone(), false)?,
             )?;
 
             if self.enable_profiling {
@@ -1135,8 +1229,9 @@ impl PipelineBuilder {
         union_plan: &UnionAll,
     ) -> Result<Receiver<DataBlock>> {
         let union_ctx = QueryContext::create_from(self.ctx.clone());
-        let pipeline_builder =
+        let mut pipeline_builder =
             PipelineBuilder::create(union_ctx, self.enable_profiling, self.prof_span_set.clone());
+        pipeline_builder.recursive_cte_tables = self.recursive_cte_tables.clone();
         let mut build_res = pipeline_builder.finalize(input)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -1190,6 +1285,53 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub fn build_recursive_union(&mut self, recursive_union: &RecursiveUnion) -> Result<()> {
+        self.build_pipeline(&recursive_union.left)?;
+        // The recursive term is evaluated on a single processor.
+        self.main_pipeline.resize(1)?;
+        self.main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                let transform = TransformRecursiveUnion::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    self.ctx.clone(),
+                    recursive_union,
+                    self.enable_profiling,
+                    self.prof_span_set.clone(),
+                    self.recursive_cte_tables.clone(),
+                )?;
+
+                if self.enable_profiling {
+                    Ok(ProcessorPtr::create(ProfileWrapper::create(
+                        transform,
+                        recursive_union.plan_id,
+                        self.prof_span_set.clone(),
+                    )))
+                } else {
+                    Ok(ProcessorPtr::create(transform))
+                }
+            })
+    }
+
+    pub fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let working_table = self
+            .recursive_cte_tables
+            .get(&scan.cte_index)
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Working table of recursive cte {} is not found",
+                    scan.cte_index
+                ))
+            })?;
+        let blocks = Arc::new(Mutex::new(
+            working_table.iter().cloned().collect::<VecDeque<_>>(),
+        ));
+        self.main_pipeline.add_source(
+            |output| BlocksSource::create(self.ctx.clone(), output, blocks.clone()),
+            1,
+        )
+    }
+
     pub fn build_distributed_insert_select(
         &mut self,
         insert_select: &DistributedInsertSelect,
@@ -1272,10 +1414,12 @@ impl PipelineBuilder {
         // scan t1     scan t2
         for _ in 0..output_size / 2 {
             let input = InputPort::create();
+            let join_state = self.join_state.as_ref().unwrap().clone();
             items.push(PipeItem::create(
                 ProcessorPtr::create(TransformHashJoinBuild::create(
                     input.clone(),
-                    TransformHashJoinBuild::attach(self.join_state.as_ref().unwrap().clone())?,
+                    TransformHashJoinBuild::attach(join_state.clone())?,
+                    HashJoinSpiller::create(join_state, true)?,
                 )),
                 vec![input],
                 vec![],
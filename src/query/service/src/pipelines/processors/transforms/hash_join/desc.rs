@@ -82,6 +82,22 @@ impl HashJoinDesc {
         })
     }
 
+    /// Create the desc of the join of a spilled partition. The `has_null` of mark join
+    /// is decided by all the rows of the build side, it's copied to the partition.
+    pub fn create_partition_desc(&self) -> Result<HashJoinDesc> {
+        Ok(HashJoinDesc {
+            build_keys: self.build_keys.clone(),
+            probe_keys: self.probe_keys.clone(),
+            join_type: self.join_type.clone(),
+            other_predicate: self.other_predicate.clone(),
+            marker_join_desc: MarkJoinDesc {
+                has_null: RwLock::new(*self.marker_join_desc.has_null.read()),
+            },
+            from_correlated_subquery: self.from_correlated_subquery,
+            join_state: JoinState::create()?,
+        })
+    }
+
     fn join_predicate(non_equi_conditions: &[RemoteExpr]) -> Result<Option<Expr>> {
         non_equi_conditions
             .iter()
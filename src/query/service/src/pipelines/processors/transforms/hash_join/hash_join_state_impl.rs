@@ -464,6 +464,13 @@ impl HashJoinState for JoinHashTable {
             // Divide the outer scan phase into multiple tasks.
             self.generate_outer_scan_task()?;
 
+            // Divide the spilled partitions into multiple tasks.
+            if let Some(spill_state) = &self.spill_state {
+                if spill_state.is_spilled() {
+                    spill_state.generate_tasks();
+                }
+            }
+
             let mut probe_done = self.probe_done.lock();
             *probe_done = true;
             self.probe_done_notify.notify_waiters();
@@ -748,6 +755,12 @@ impl HashJoinState for JoinHashTable {
         let markers = row_ptrs.iter().map(|r| r.marker.unwrap()).collect();
         let marker_block = self.create_marker_block(*has_null, markers)?;
         let build_block = self.row_space.gather(&row_ptrs, &data_blocks, &num_rows)?;
-        Ok(vec![self.merge_eq_block(&marker_block, &build_block)?])
+        let mut result_blocks = vec![self.merge_eq_block(&marker_block, &build_block)?];
+
+        // The results of the spilled partitions.
+        if let Some(spill_state) = &self.spill_state {
+            result_blocks.extend(std::mem::take(&mut *spill_state.mark_join_blocks.lock()));
+        }
+        Ok(result_blocks)
     }
 }
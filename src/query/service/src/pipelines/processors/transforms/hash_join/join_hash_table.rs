@@ -48,6 +48,7 @@ use parking_lot::RwLock;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
 use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
+use crate::pipelines::processors::transforms::hash_join::spill::HashJoinSpillState;
 use crate::pipelines::processors::transforms::hash_join::util::build_schema_wrap_nullable;
 use crate::pipelines::processors::transforms::hash_join::util::probe_schema_wrap_nullable;
 use crate::sessions::QueryContext;
@@ -111,6 +112,8 @@ pub struct JoinHashTable {
     pub(crate) finalize_tasks: Arc<RwLock<VecDeque<(usize, usize)>>>,
     /// OuterScan tasks
     pub(crate) outer_scan_tasks: Arc<RwLock<VecDeque<usize>>>,
+    /// Spilling, it's none if spilling is disabled
+    pub(crate) spill_state: Option<Arc<HashJoinSpillState>>,
 }
 
 impl JoinHashTable {
@@ -126,13 +129,44 @@ impl JoinHashTable {
             .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).data_type().clone())
             .collect::<Vec<_>>();
         let method = DataBlock::choose_hash_method_with_types(&hash_key_types, false)?;
-        Ok(Arc::new(JoinHashTable::try_create(
-            ctx,
+        let mut join_hash_table = JoinHashTable::try_create(
+            ctx.clone(),
             build_schema,
             probe_schema,
             hash_join_desc,
             method,
-        )?))
+        )?;
+        join_hash_table.spill_state = HashJoinSpillState::try_create(&ctx)?;
+        Ok(Arc::new(join_hash_table))
+    }
+
+    /// Create the join of a spilled partition, which is built and probed in memory.
+    pub(crate) fn create_partition_join(&self) -> Result<Arc<JoinHashTable>> {
+        let mut join_hash_table = JoinHashTable::try_create(
+            self.ctx.clone(),
+            self.row_space.data_schema.clone(),
+            self.probe_schema.clone(),
+            self.hash_join_desc.create_partition_desc()?,
+            (*self.method).clone(),
+        )?;
+        join_hash_table.interrupt = self.interrupt.clone();
+        Ok(Arc::new(join_hash_table))
+    }
+
+    /// Move out the rows of the build side in memory, they are spilled instead.
+    pub(crate) fn take_build_blocks(&self) -> Result<Vec<DataBlock>> {
+        let mut data_blocks = {
+            let mut chunks = self.row_space.chunks.write();
+            let outer_scan_bitmap = unsafe { &mut *self.outer_scan_bitmap.get() };
+            outer_scan_bitmap.clear();
+            chunks
+                .drain(..)
+                .map(|chunk| chunk.data_block)
+                .collect::<Vec<_>>()
+        };
+        let mut buffer = self.row_space.buffer.write();
+        data_blocks.append(&mut buffer);
+        Ok(data_blocks)
     }
 
     pub fn try_create(
@@ -179,6 +213,7 @@ impl JoinHashTable {
             build_worker_num: Arc::new(AtomicU32::new(0)),
             finalize_tasks: Arc::new(RwLock::new(VecDeque::new())),
             outer_scan_tasks: Arc::new(RwLock::new(VecDeque::new())),
+            spill_state: None,
         })
     }
 
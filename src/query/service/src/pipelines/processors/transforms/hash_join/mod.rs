@@ -21,6 +21,7 @@ mod probe_join;
 mod probe_state;
 mod result_blocks;
 pub(crate) mod row;
+mod spill;
 mod util;
 
 pub use desc::HashJoinDesc;
@@ -29,3 +30,4 @@ pub use join_hash_table::FixedKeyHashJoinHashTable;
 pub use join_hash_table::JoinHashTable;
 pub use probe_state::ProbeState;
 pub use result_blocks::*;
+pub use spill::HashJoinSpiller;
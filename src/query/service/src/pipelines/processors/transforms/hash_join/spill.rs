@@ -0,0 +1,654 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_base::base::GlobalUniqName;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::type_check::check_function;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::AnyType;
+use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_storage::DataOperator;
+use opendal::Operator;
+use parking_lot::Mutex;
+use tracing::error;
+use tracing::info;
+
+use super::ProbeState;
+use crate::pipelines::processors::HashJoinState;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::JoinType;
+
+/// The number of bits of the hash used to partition the rows at each level.
+const SPILL_PARTITION_BITS: usize = 3;
+const SPILL_PARTITION_NUM: usize = 1 << SPILL_PARTITION_BITS;
+/// A partition still exceeding the threshold is repartitioned with the next bits of the
+/// hash, the partitions at the max level are joined in memory anyway, e.g. a hot key.
+const SPILL_MAX_LEVEL: usize = 4;
+
+#[derive(Clone, Debug)]
+pub struct SpillFile {
+    location: String,
+    columns_layout: Vec<usize>,
+    num_rows: usize,
+}
+
+/// The spilled rows of both sides with the same hash bits, which are joined on their own.
+#[derive(Default)]
+struct SpillPartition {
+    level: usize,
+    build_files: Vec<SpillFile>,
+    probe_files: Vec<SpillFile>,
+    // The probe files of cross join are shared by all the partitions,
+    // they are deleted once the last partition is joined.
+    shared_probe_files: Option<Arc<AtomicUsize>>,
+}
+
+/// The spilling state shared by the build and probe processors of a hash join.
+///
+/// Once the build side exceeds `spilling_bytes_threshold_per_proc` bytes per processor,
+/// the rows of both sides are scattered into partitions by the hash of the join keys
+/// and spilled to the storage. After the probe side is spilled, the partitions are
+/// joined one by one in memory by the probe processors, and the partitions which
+/// are still too large are repartitioned recursively.
+pub struct HashJoinSpillState {
+    operator: Operator,
+    location_prefix: String,
+    threshold: usize,
+    spilled: AtomicBool,
+    build_bytes: AtomicUsize,
+    next_partition_id: AtomicU64,
+    // (level, partition id) -> spilled partition
+    partitions: Mutex<BTreeMap<(usize, u64), SpillPartition>>,
+    cross_probe_files: Mutex<Vec<SpillFile>>,
+    tasks: Mutex<VecDeque<SpillPartition>>,
+    // The results of left mark join from the spilled partitions.
+    pub(crate) mark_join_blocks: Mutex<Vec<DataBlock>>,
+}
+
+impl HashJoinSpillState {
+    pub fn try_create(ctx: &Arc<QueryContext>) -> Result<Option<Arc<HashJoinSpillState>>> {
+        let threshold = ctx.get_settings().get_spilling_bytes_threshold_per_proc()?;
+        if threshold == 0 {
+            return Ok(None);
+        }
+        Ok(Some(Arc::new(HashJoinSpillState {
+            operator: DataOperator::instance().operator(),
+            location_prefix: format!("_hash_join_spill/{}", ctx.get_tenant()),
+            threshold,
+            spilled: AtomicBool::new(false),
+            build_bytes: AtomicUsize::new(0),
+            next_partition_id: AtomicU64::new(0),
+            partitions: Mutex::new(BTreeMap::new()),
+            cross_probe_files: Mutex::new(vec![]),
+            tasks: Mutex::new(VecDeque::new()),
+            mark_join_blocks: Mutex::new(vec![]),
+        })))
+    }
+
+    pub fn is_spilled(&self) -> bool {
+        self.spilled.load(Ordering::SeqCst)
+    }
+
+    /// Divide the spilled partitions into tasks of the probe processors, it's called
+    /// after all the probe processors have spilled their rows.
+    pub fn generate_tasks(&self) {
+        let mut partitions = self.partitions.lock();
+        let mut tasks = self.tasks.lock();
+        let cross_probe_files = std::mem::take(&mut *self.cross_probe_files.lock());
+        let shared_count = Arc::new(AtomicUsize::new(partitions.len()));
+        for (_, mut partition) in std::mem::take(&mut *partitions) {
+            if !cross_probe_files.is_empty() {
+                partition.probe_files = cross_probe_files.clone();
+                partition.shared_probe_files = Some(shared_count.clone());
+            }
+            tasks.push_back(partition);
+        }
+    }
+
+    fn spill_task(&self) -> Option<SpillPartition> {
+        self.tasks.lock().pop_front()
+    }
+
+    fn add_files(
+        &self,
+        is_build_side: bool,
+        is_cross: bool,
+        files: BTreeMap<(usize, u64), Vec<SpillFile>>,
+    ) {
+        if is_cross && !is_build_side {
+            let mut cross_probe_files = self.cross_probe_files.lock();
+            cross_probe_files.extend(files.into_values().flatten());
+            return;
+        }
+        let mut partitions = self.partitions.lock();
+        for ((level, id), files) in files {
+            let partition = partitions.entry((level, id)).or_default();
+            partition.level = level;
+            match is_build_side {
+                true => partition.build_files.extend(files),
+                false => partition.probe_files.extend(files),
+            }
+        }
+    }
+}
+
+/// The spilled partition being joined in memory by a probe processor.
+struct RestoringPartition {
+    join: Arc<JoinHashTable>,
+    probe_files: VecDeque<SpillFile>,
+    shared_probe_files: Option<(Vec<SpillFile>, Arc<AtomicUsize>)>,
+}
+
+/// Spills the rows of a build or probe processor of a hash join, and restores
+/// the spilled partitions on the probe side.
+pub struct HashJoinSpiller {
+    join: Arc<JoinHashTable>,
+    state: Arc<HashJoinSpillState>,
+    func_ctx: FunctionContext,
+    is_build_side: bool,
+    is_cross: bool,
+    hash_keys: Vec<Expr>,
+    // (level, partition id) -> (buffered blocks, bytes)
+    buffers: BTreeMap<(usize, u64), (Vec<DataBlock>, usize)>,
+    pending: Vec<((usize, u64), DataBlock)>,
+    spilled_files: BTreeMap<(usize, u64), Vec<SpillFile>>,
+    restoring: Option<RestoringPartition>,
+    // The shared probe files to be deleted after all the partitions using them are joined.
+    obsolete_files: Vec<SpillFile>,
+}
+
+impl HashJoinSpiller {
+    pub fn create(join: Arc<JoinHashTable>, is_build_side: bool) -> Result<Option<Self>> {
+        let state = match &join.spill_state {
+            Some(state) => state.clone(),
+            None => return Ok(None),
+        };
+        let hash_keys = hash_keys(&join, is_build_side)?;
+        let is_cross = join.hash_join_desc.join_type == JoinType::Cross;
+        Ok(Some(HashJoinSpiller {
+            func_ctx: join.ctx.get_function_context()?,
+            join,
+            state,
+            is_build_side,
+            is_cross,
+            hash_keys,
+            buffers: BTreeMap::new(),
+            pending: vec![],
+            spilled_files: BTreeMap::new(),
+            restoring: None,
+            obsolete_files: vec![],
+        }))
+    }
+
+    pub fn is_spilled(&self) -> bool {
+        self.state.is_spilled()
+    }
+
+    /// Add the block to the build side, the rows are spilled if the build side exceeds the threshold.
+    pub fn build(&mut self, block: DataBlock) -> Result<()> {
+        if !self.state.is_spilled() {
+            let bytes = self
+                .state
+                .build_bytes
+                .fetch_add(block.memory_size(), Ordering::SeqCst)
+                + block.memory_size();
+            self.join.build(block)?;
+
+            let worker_num = self.join.build_worker_num.load(Ordering::Relaxed) as usize;
+            let limit = self.state.threshold * worker_num.max(1);
+            if bytes > limit && !self.state.spilled.swap(true, Ordering::SeqCst) {
+                info!(
+                    "Hash join build side exceeds {} bytes, start spilling",
+                    limit
+                );
+            }
+            // Check the flag again, it may be set by other processors after the rows are
+            // added, then the rows are moved out here.
+            if !self.state.is_spilled() {
+                return Ok(());
+            }
+        } else {
+            self.partition(block, 0)?;
+        }
+
+        // Move out the rows in memory, including those built by other processors.
+        for block in self.join.take_build_blocks()? {
+            self.partition(block, 0)?;
+        }
+        Ok(())
+    }
+
+    /// Spill the block of the probe side.
+    pub fn probe(&mut self, block: DataBlock) -> Result<()> {
+        self.partition(block, 0)
+    }
+
+    fn partition(&mut self, block: DataBlock, level: usize) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+        let block = self.wrap_nullable(block.convert_to_full());
+        if self.is_cross {
+            // The rows of cross join can't be partitioned by hash, each spill file of the build
+            // side is a partition, which is joined with all the rows of the probe side.
+            let id = match self.is_build_side {
+                true => self
+                    .buffers
+                    .keys()
+                    .next()
+                    .map(|(_, id)| *id)
+                    .unwrap_or_else(|| {
+                        self.state.next_partition_id.fetch_add(1, Ordering::Relaxed)
+                    }),
+                false => 0,
+            };
+            return self.buffer(block, (0, id), self.state.threshold);
+        }
+
+        if self.is_build_side && self.join.hash_join_desc.join_type == JoinType::RightMark {
+            self.mark_has_null(&block)?;
+        }
+        let hashes = self.hashes(&block)?;
+        let shift = level * SPILL_PARTITION_BITS;
+        let indices = hashes
+            .iter()
+            .map(|hash| ((hash >> shift) as usize) & (SPILL_PARTITION_NUM - 1))
+            .collect::<Vec<_>>();
+        // The partition id is the lower bits of the hash up to the level.
+        let prefix = hashes[0] & ((1 << shift) - 1);
+        let limit = self.state.threshold / SPILL_PARTITION_NUM;
+        for (idx, block) in DataBlock::scatter(&block, &indices, SPILL_PARTITION_NUM)?
+            .into_iter()
+            .enumerate()
+        {
+            if !block.is_empty() {
+                let id = prefix | ((idx as u64) << shift);
+                self.buffer(block, (level, id), limit)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn buffer(&mut self, block: DataBlock, key: (usize, u64), limit: usize) -> Result<()> {
+        let (blocks, bytes) = self.buffers.entry(key).or_default();
+        *bytes += block.memory_size();
+        blocks.push(block);
+        if *bytes >= limit.max(1) {
+            let (blocks, _) = self.buffers.remove(&key).unwrap();
+            self.pending.push((key, DataBlock::concat(&blocks)?));
+        }
+        Ok(())
+    }
+
+    // Wrap the columns to nullable the same as the hash table does.
+    fn wrap_nullable(&self, block: DataBlock) -> DataBlock {
+        let join_type = &self.join.hash_join_desc.join_type;
+        let need_wrap = match self.is_build_side {
+            true => matches!(join_type, JoinType::Left | JoinType::Full),
+            false => matches!(join_type, JoinType::Right | JoinType::Full),
+        };
+        if !need_wrap {
+            return block;
+        }
+        let mut validity = MutableBitmap::new();
+        validity.extend_constant(block.num_rows(), true);
+        let validity: Bitmap = validity.into();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|c| JoinHashTable::set_validity(c, validity.len(), &validity))
+            .collect::<Vec<_>>();
+        DataBlock::new(columns, block.num_rows())
+    }
+
+    // The marker of right mark join depends on whether the build keys contain null,
+    // which is decided by all the rows of the build side.
+    fn mark_has_null(&self, block: &DataBlock) -> Result<()> {
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        if let Some(expr) = self.join.hash_join_desc.build_keys.first() {
+            let column = evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), block.num_rows());
+            if let Some(validity) = column.validity().1 {
+                if validity.unset_bits() > 0 {
+                    *self.join.hash_join_desc.marker_join_desc.has_null.write() = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn hashes(&self, block: &DataBlock) -> Result<Vec<u64>> {
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let num_rows = block.num_rows();
+        let mut hashers = vec![DefaultHasher::default(); num_rows];
+        for expr in &self.hash_keys {
+            let values = hash_values(&evaluator.run(expr)?, num_rows)?;
+            for (hasher, value) in hashers.iter_mut().zip(values) {
+                hasher.write_u64(value);
+            }
+        }
+        Ok(hashers.into_iter().map(|hasher| hasher.finish()).collect())
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Spill all the buffered rows.
+    pub fn finish(&mut self) -> Result<()> {
+        for (key, (blocks, _)) in std::mem::take(&mut self.buffers) {
+            self.pending.push((key, DataBlock::concat(&blocks)?));
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn spill_pending(&mut self) -> Result<()> {
+        for (key, block) in std::mem::take(&mut self.pending) {
+            let file = self.write_file(block).await?;
+            self.spilled_files.entry(key).or_default().push(file);
+        }
+        Ok(())
+    }
+
+    /// Publish the spilled files of the processor to the shared state.
+    pub fn register(&mut self) {
+        let files = std::mem::take(&mut self.spilled_files);
+        self.state
+            .add_files(self.is_build_side, self.is_cross, files);
+    }
+
+    #[async_backtrace::framed]
+    async fn write_file(&self, block: DataBlock) -> Result<SpillFile> {
+        let instant = Instant::now();
+        let num_rows = block.num_rows();
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        let mut data = vec![];
+        for entry in block.columns() {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let column_data = serialize_column(&column);
+            columns_layout.push(column_data.len());
+            data.extend(column_data);
+        }
+
+        let location = format!(
+            "{}/{}",
+            self.state.location_prefix,
+            GlobalUniqName::unique()
+        );
+        self.state.operator.write(&location, data).await?;
+        info!(
+            "Write hash join spill {} successfully, elapsed: {:?}",
+            location,
+            instant.elapsed()
+        );
+        Ok(SpillFile {
+            location,
+            columns_layout,
+            num_rows,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_file(&self, file: &SpillFile, delete: bool) -> Result<DataBlock> {
+        let instant = Instant::now();
+        let data = self.state.operator.read(&file.location).await?;
+        if delete {
+            self.delete_file(file).await;
+        }
+        info!(
+            "Read hash join spill {} successfully, elapsed: {:?}",
+            &file.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(file.columns_layout.len());
+        for column_layout in &file.columns_layout {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Cannot deserialize hash join spill file {}",
+                        file.location
+                    ))
+                })?;
+            columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+            begin += column_layout;
+        }
+        Ok(DataBlock::new(columns, file.num_rows))
+    }
+
+    #[async_backtrace::framed]
+    async fn delete_file(&self, file: &SpillFile) {
+        if let Err(cause) = self.state.operator.delete(&file.location).await {
+            error!(
+                "Cannot delete spill file {}, cause: {:?}",
+                &file.location, cause
+            );
+        }
+    }
+
+    /// The join of the spilled partition being restored, if any.
+    pub fn partition_join(&self) -> Option<Arc<JoinHashTable>> {
+        self.restoring
+            .as_ref()
+            .map(|partition| partition.join.clone())
+    }
+
+    /// Take the next spilled partition, and build its hash table in memory. The partitions
+    /// exceeding the threshold are repartitioned at the next level. Returns false if all the
+    /// partitions are joined.
+    #[async_backtrace::framed]
+    pub async fn restore_next(&mut self) -> Result<bool> {
+        for file in std::mem::take(&mut self.obsolete_files) {
+            self.delete_file(&file).await;
+        }
+
+        while let Some(partition) = self.state.spill_task() {
+            let mut build_blocks = Vec::with_capacity(partition.build_files.len());
+            let mut bytes = 0;
+            for file in &partition.build_files {
+                let block = self.read_file(file, true).await?;
+                bytes += block.memory_size();
+                build_blocks.push(block);
+            }
+
+            if !self.is_cross && bytes > self.state.threshold && partition.level < SPILL_MAX_LEVEL {
+                self.repartition(partition, build_blocks).await?;
+                continue;
+            }
+
+            let join = self.join.create_partition_join()?;
+            join.build_attach()?;
+            for block in build_blocks {
+                join.build(block)?;
+            }
+            join.build_done()?;
+            while let Some(task) = join.finalize_task() {
+                join.finalize(task)?;
+            }
+            join.finalize_done()?;
+            join.probe_attach()?;
+
+            let shared_probe_files = partition
+                .shared_probe_files
+                .map(|count| (partition.probe_files.clone(), count));
+            self.restoring = Some(RestoringPartition {
+                join,
+                probe_files: VecDeque::from(partition.probe_files),
+                shared_probe_files,
+            });
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    // Scatter the rows of both sides of the partition with the next bits of the hash.
+    #[async_backtrace::framed]
+    async fn repartition(
+        &mut self,
+        partition: SpillPartition,
+        build_blocks: Vec<DataBlock>,
+    ) -> Result<()> {
+        let level = partition.level + 1;
+        info!(
+            "Repartition hash join spill at level {}, {} build files, {} probe files",
+            level,
+            partition.build_files.len(),
+            partition.probe_files.len()
+        );
+
+        let mut files = BTreeMap::<(usize, u64), SpillPartition>::new();
+        for is_build_side in [true, false] {
+            self.is_build_side = is_build_side;
+            self.hash_keys = hash_keys(&self.join, is_build_side)?;
+
+            if is_build_side {
+                for block in &build_blocks {
+                    self.partition(block.clone(), level)?;
+                }
+            } else {
+                for file in &partition.probe_files {
+                    let block = self.read_file(file, true).await?;
+                    self.partition(block, level)?;
+                    self.spill_pending().await?;
+                }
+            }
+            self.finish()?;
+            self.spill_pending().await?;
+
+            for (key, spilled_files) in std::mem::take(&mut self.spilled_files) {
+                let child = files.entry(key).or_default();
+                child.level = level;
+                match is_build_side {
+                    true => child.build_files.extend(spilled_files),
+                    false => child.probe_files.extend(spilled_files),
+                }
+            }
+        }
+        self.is_build_side = false;
+
+        let mut tasks = self.state.tasks.lock();
+        for (_, child) in files.into_iter().rev() {
+            tasks.push_front(child);
+        }
+        Ok(())
+    }
+
+    /// Read the next spilled block of the probe side of the partition being restored.
+    #[async_backtrace::framed]
+    pub async fn restore_probe_block(&mut self) -> Result<Option<DataBlock>> {
+        let (file, delete) = match &mut self.restoring {
+            Some(partition) => match partition.probe_files.pop_front() {
+                Some(file) => (file, partition.shared_probe_files.is_none()),
+                None => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        Ok(Some(self.read_file(&file, delete).await?))
+    }
+
+    /// Finish the join of the partition being restored, and returns the results of outer scan.
+    pub fn finish_partition(&mut self, probe_state: &mut ProbeState) -> Result<Vec<DataBlock>> {
+        let partition = match self.restoring.take() {
+            Some(partition) => partition,
+            None => return Ok(vec![]),
+        };
+
+        let join = partition.join;
+        join.probe_done()?;
+        let mut result_blocks = vec![];
+        if join.need_outer_scan() {
+            while let Some(task) = join.outer_scan_task() {
+                result_blocks.extend(join.outer_scan(task, probe_state)?);
+            }
+        }
+        if join.hash_join_desc.join_type == JoinType::LeftMark {
+            let blocks = join.mark_join_blocks()?;
+            self.state.mark_join_blocks.lock().extend(blocks);
+        }
+
+        if let Some((files, count)) = partition.shared_probe_files {
+            if count.fetch_sub(1, Ordering::SeqCst) == 1 {
+                self.obsolete_files = files;
+            }
+        }
+        Ok(result_blocks)
+    }
+}
+
+// siphash of each join key of the side.
+fn hash_keys(join: &JoinHashTable, is_build_side: bool) -> Result<Vec<Expr>> {
+    let keys = match is_build_side {
+        true => &join.hash_join_desc.build_keys,
+        false => &join.hash_join_desc.probe_keys,
+    };
+    keys.iter()
+        .map(|key| check_function(None, "siphash", &[], &[key.clone()], &BUILTIN_FUNCTIONS))
+        .collect()
+}
+
+fn hash_values(column: &Value<AnyType>, rows: usize) -> Result<Vec<u64>> {
+    match column {
+        Value::Scalar(Scalar::Null) => Ok(vec![0; rows]),
+        Value::Scalar(Scalar::Number(NumberScalar::UInt64(x))) => Ok(vec![*x; rows]),
+        Value::Column(c) => {
+            if let Some(column) = NumberType::<u64>::try_downcast_column(c) {
+                Ok(column.iter().copied().collect())
+            } else if let Some(column) = NullableType::<NumberType<u64>>::try_downcast_column(c) {
+                let null_map = column.validity;
+                Ok(column
+                    .column
+                    .iter()
+                    .zip(null_map.iter())
+                    .map(|(x, b)| if b { *x } else { 0 })
+                    .collect())
+            } else {
+                Err(ErrorCode::Internal("Hash value must be UInt64"))
+            }
+        }
+        _ => Err(ErrorCode::Internal("Hash value must be UInt64")),
+    }
+}
@@ -28,9 +28,11 @@ mod transform_add_computed_columns;
 mod transform_add_const_columns;
 mod transform_ie_join;
 mod transform_merge_block;
+mod transform_recursive_union;
 mod transform_resort_addon;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sort_spill;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
@@ -57,6 +59,7 @@ use common_pipeline_transforms::processors::transforms::transform_compact;
 use common_pipeline_transforms::processors::transforms::transform_sort_partial;
 pub use hash_join::FixedKeyHashJoinHashTable;
 pub use hash_join::HashJoinDesc;
+pub use hash_join::HashJoinSpiller;
 pub use hash_join::HashJoinState;
 pub use hash_join::JoinHashTable;
 pub use range_join::RangeJoinState;
@@ -78,11 +81,14 @@ pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_union::TransformRecursiveUnion;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::try_create_transform_sort_spill;
+pub use transform_sort_spill::SortSpiller;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
@@ -20,6 +20,7 @@ use common_exception::Result;
 use common_expression::DataBlock;
 use common_sql::plans::JoinType;
 
+use super::hash_join::HashJoinSpiller;
 use super::hash_join::ProbeState;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
@@ -34,6 +35,10 @@ enum HashJoinStep {
     Finalize,
     Probe,
     OuterScan,
+    // Spill the probe side, after the build side is spilled.
+    Spill,
+    // Join the spilled partitions.
+    Restore,
 }
 
 pub struct TransformHashJoinProbe {
@@ -47,6 +52,9 @@ pub struct TransformHashJoinProbe {
     probe_state: ProbeState,
     block_size: u64,
     outer_scan_finished: bool,
+    spiller: Option<HashJoinSpiller>,
+    probe_exhausted: bool,
+    restore_finished: bool,
 }
 
 impl TransformHashJoinProbe {
@@ -57,6 +65,7 @@ impl TransformHashJoinProbe {
         join_state: Arc<dyn HashJoinState>,
         join_type: &JoinType,
         with_conjunct: bool,
+        spiller: Option<HashJoinSpiller>,
     ) -> Result<Box<dyn Processor>> {
         let default_block_size = ctx.get_settings().get_max_block_size()?;
         Ok(Box::new(TransformHashJoinProbe {
@@ -69,6 +78,9 @@ impl TransformHashJoinProbe {
             probe_state: ProbeState::create(join_type, with_conjunct, ctx.get_function_context()?),
             block_size: default_block_size,
             outer_scan_finished: false,
+            spiller,
+            probe_exhausted: false,
+            restore_finished: false,
         }))
     }
 
@@ -89,6 +101,21 @@ impl TransformHashJoinProbe {
             .extend(self.join_state.outer_scan(task, &mut self.probe_state)?);
         Ok(())
     }
+
+    fn is_spilled(&self) -> bool {
+        self.spiller
+            .as_ref()
+            .map_or(false, |spiller| spiller.is_spilled())
+    }
+
+    fn push_input_data(&mut self, data: DataBlock) {
+        // Split data to `block_size` rows per sub block.
+        let (sub_blocks, remain_block) = data.split_by_rows(self.block_size as usize);
+        self.input_data.extend(sub_blocks);
+        if let Some(remain) = remain_block {
+            self.input_data.push_back(remain);
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -128,12 +155,7 @@ impl Processor for TransformHashJoinProbe {
 
                 if self.input_port.has_data() {
                     let data = self.input_port.pull_data().unwrap()?;
-                    // Split data to `block_size` rows per sub block.
-                    let (sub_blocks, remain_block) = data.split_by_rows(self.block_size as usize);
-                    self.input_data.extend(sub_blocks);
-                    if let Some(remain) = remain_block {
-                        self.input_data.push_back(remain);
-                    }
+                    self.push_input_data(data);
                     return Ok(Event::Sync);
                 }
 
@@ -175,6 +197,62 @@ impl Processor for TransformHashJoinProbe {
                     }
                 }
             }
+            HashJoinStep::Spill => {
+                if self.output_port.is_finished() {
+                    self.input_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                if matches!(&self.spiller, Some(spiller) if spiller.has_pending()) {
+                    self.input_port.set_not_need_data();
+                    return Ok(Event::Async);
+                }
+
+                if !self.input_data.is_empty() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.has_data() {
+                    let data = self.input_port.pull_data().unwrap()?;
+                    self.input_data.push_back(data);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.is_finished() {
+                    // Spill the rest rows and wait for all the probe processors.
+                    return Ok(Event::Async);
+                }
+
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+            HashJoinStep::Restore => {
+                if self.output_port.is_finished() {
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                if !self.output_data_blocks.is_empty() {
+                    let data = self.output_data_blocks.pop_front().unwrap();
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                if self.restore_finished {
+                    self.output_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                if !self.input_data.is_empty() || self.probe_exhausted {
+                    return Ok(Event::Sync);
+                }
+
+                // Restore the next partition or the next probe block of the partition.
+                Ok(Event::Async)
+            }
         }
     }
 
@@ -201,6 +279,33 @@ impl Processor for TransformHashJoinProbe {
                 }
                 Ok(())
             }
+            HashJoinStep::Spill => {
+                if let (Some(data), Some(spiller)) =
+                    (self.input_data.pop_front(), &mut self.spiller)
+                {
+                    spiller.probe(data)?;
+                }
+                Ok(())
+            }
+            HashJoinStep::Restore => {
+                let spiller = match &mut self.spiller {
+                    Some(spiller) => spiller,
+                    None => return Ok(()),
+                };
+                if let Some(data) = self.input_data.pop_front() {
+                    if let Some(join) = spiller.partition_join() {
+                        let data = data.convert_to_full();
+                        self.probe_state.clear();
+                        self.output_data_blocks
+                            .extend(join.probe(&data, &mut self.probe_state)?);
+                    }
+                } else if self.probe_exhausted {
+                    self.probe_exhausted = false;
+                    self.output_data_blocks
+                        .extend(spiller.finish_partition(&mut self.probe_state)?);
+                }
+                Ok(())
+            }
         }
     }
 
@@ -209,7 +314,10 @@ impl Processor for TransformHashJoinProbe {
         match self.step {
             HashJoinStep::Build => {
                 self.join_state.wait_finalize_finish().await?;
-                self.step = HashJoinStep::Probe;
+                self.step = match self.is_spilled() {
+                    true => HashJoinStep::Spill,
+                    false => HashJoinStep::Probe,
+                };
             }
             HashJoinStep::Finalize => unreachable!(),
             HashJoinStep::Probe => {
@@ -217,6 +325,31 @@ impl Processor for TransformHashJoinProbe {
                 self.step = HashJoinStep::OuterScan;
             }
             HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::Spill => {
+                if let Some(spiller) = &mut self.spiller {
+                    if spiller.has_pending() {
+                        return spiller.spill_pending().await;
+                    }
+                    spiller.finish()?;
+                    spiller.spill_pending().await?;
+                    spiller.register();
+                }
+                self.join_state.probe_done()?;
+                self.join_state.wait_probe_finish().await?;
+                self.step = HashJoinStep::Restore;
+            }
+            HashJoinStep::Restore => {
+                if let Some(spiller) = &mut self.spiller {
+                    if spiller.partition_join().is_none() {
+                        self.restore_finished = !spiller.restore_next().await?;
+                    } else {
+                        match spiller.restore_probe_block().await? {
+                            Some(data) => self.push_input_data(data),
+                            None => self.probe_exhausted = true,
+                        }
+                    }
+                }
+            }
         };
         Ok(())
     }
@@ -229,12 +362,15 @@ pub struct TransformHashJoinBuild {
     step: HashJoinStep,
     join_state: Arc<dyn HashJoinState>,
     finalize_finished: bool,
+    spiller: Option<HashJoinSpiller>,
+    spill_finished: bool,
 }
 
 impl TransformHashJoinBuild {
     pub fn create(
         input_port: Arc<InputPort>,
         join_state: Arc<dyn HashJoinState>,
+        spiller: Option<HashJoinSpiller>,
     ) -> Box<dyn Processor> {
         Box::new(TransformHashJoinBuild {
             input_port,
@@ -242,6 +378,8 @@ impl TransformHashJoinBuild {
             step: HashJoinStep::Build,
             join_state,
             finalize_finished: false,
+            spiller,
+            spill_finished: false,
         })
     }
 
@@ -264,11 +402,28 @@ impl Processor for TransformHashJoinBuild {
     fn event(&mut self) -> Result<Event> {
         match self.step {
             HashJoinStep::Build => {
+                if let Some(spiller) = &mut self.spiller {
+                    if spiller.has_pending() {
+                        return Ok(Event::Async);
+                    }
+                }
+
                 if self.input_data.is_some() {
                     return Ok(Event::Sync);
                 }
 
                 if self.input_port.is_finished() {
+                    if let Some(spiller) = &mut self.spiller {
+                        if spiller.is_spilled() && !self.spill_finished {
+                            // Spill the rest rows before the build is done.
+                            self.spill_finished = true;
+                            spiller.finish()?;
+                            if spiller.has_pending() {
+                                return Ok(Event::Async);
+                            }
+                            spiller.register();
+                        }
+                    }
                     self.join_state.build_done()?;
                     return Ok(Event::Async);
                 }
@@ -290,6 +445,8 @@ impl Processor for TransformHashJoinBuild {
             },
             HashJoinStep::Probe => unreachable!(),
             HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::Spill => unreachable!(),
+            HashJoinStep::Restore => unreachable!(),
         }
     }
 
@@ -301,7 +458,10 @@ impl Processor for TransformHashJoinBuild {
         match self.step {
             HashJoinStep::Build => {
                 if let Some(data_block) = self.input_data.take() {
-                    self.join_state.build(data_block)?;
+                    match &mut self.spiller {
+                        Some(spiller) => spiller.build(data_block)?,
+                        None => self.join_state.build(data_block)?,
+                    }
                 }
                 Ok(())
             }
@@ -315,12 +475,23 @@ impl Processor for TransformHashJoinBuild {
             }
             HashJoinStep::Probe => unreachable!(),
             HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::Spill => unreachable!(),
+            HashJoinStep::Restore => unreachable!(),
         }
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         if let HashJoinStep::Build = &self.step {
+            if let Some(spiller) = &mut self.spiller {
+                if spiller.has_pending() {
+                    spiller.spill_pending().await?;
+                    if self.spill_finished {
+                        spiller.register();
+                    }
+                    return Ok(());
+                }
+            }
             self.join_state.wait_build_finish().await?;
             self.step = HashJoinStep::Finalize;
         }
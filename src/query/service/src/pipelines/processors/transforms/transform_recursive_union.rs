@@ -0,0 +1,297 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::RecursiveUnion;
+use common_sql::IndexType;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Evaluate a recursive cte.
+///
+/// The rows of the anchor are read from the input port, then the recursive term is
+/// executed repeatedly with the rows produced by the previous iteration as its
+/// working table, until an iteration produces no new rows.
+///
+/// Each iteration runs in its own pipeline, which is executed on a blocking thread of the
+/// IO runtime so that it doesn't hold the thread of the executor running this processor.
+pub struct TransformRecursiveUnion {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    ctx: Arc<QueryContext>,
+    plan: RecursiveUnion,
+    enable_profiling: bool,
+    prof_span_set: ProfSpanSetRef,
+    // Working tables of the outer recursive ctes, the recursive term may reference them.
+    recursive_cte_tables: HashMap<IndexType, Arc<Vec<DataBlock>>>,
+
+    left_offsets: Vec<usize>,
+    right_offsets: Vec<usize>,
+    // Rows seen so far, only used by `UNION`.
+    distinct_rows: Option<HashSet<Vec<Scalar>>>,
+
+    input_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
+    // Rows produced by the anchor or the last iteration.
+    working_table: Vec<DataBlock>,
+    anchor_finished: bool,
+    iterations: u64,
+    max_iterations: u64,
+    // Rows produced so far, including the anchor.
+    rows: u64,
+    max_rows: u64,
+}
+
+impl TransformRecursiveUnion {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        ctx: Arc<QueryContext>,
+        plan: &RecursiveUnion,
+        enable_profiling: bool,
+        prof_span_set: ProfSpanSetRef,
+        recursive_cte_tables: HashMap<IndexType, Arc<Vec<DataBlock>>>,
+    ) -> Result<Box<dyn Processor>> {
+        let left_schema = plan.left.output_schema()?;
+        let right_schema = plan.right.output_schema()?;
+        let left_offsets = plan
+            .pairs
+            .iter()
+            .map(|(left, _)| left_schema.index_of(left))
+            .collect::<Result<Vec<_>>>()?;
+        let right_offsets = plan
+            .pairs
+            .iter()
+            .map(|(_, right)| right_schema.index_of(right))
+            .collect::<Result<Vec<_>>>()?;
+        let max_iterations = ctx.get_settings().get_max_recursive_cte_iterations()?;
+        let max_rows = ctx.get_settings().get_max_recursive_cte_rows()?;
+
+        Ok(Box::new(TransformRecursiveUnion {
+            input,
+            output,
+            ctx,
+            plan: plan.clone(),
+            enable_profiling,
+            prof_span_set,
+            recursive_cte_tables,
+            left_offsets,
+            right_offsets,
+            distinct_rows: plan.distinct.then(HashSet::new),
+            input_data: None,
+            output_data: VecDeque::new(),
+            working_table: vec![],
+            anchor_finished: false,
+            iterations: 0,
+            max_iterations,
+            rows: 0,
+            max_rows,
+        }))
+    }
+
+    // Project the block to the output columns, and remove the rows seen before if
+    // the union is distinct.
+    fn add_block(&mut self, block: DataBlock, offsets: &[usize]) -> Result<()> {
+        let num_rows = block.num_rows();
+        let columns = offsets
+            .iter()
+            .map(|offset| block.get_by_offset(*offset).clone())
+            .collect::<Vec<_>>();
+        let mut block = DataBlock::new(columns, num_rows);
+
+        if let Some(distinct_rows) = &mut self.distinct_rows {
+            let mut indices = Vec::with_capacity(num_rows);
+            for row in 0..num_rows {
+                let key = block
+                    .columns()
+                    .iter()
+                    .map(|entry| entry.value.index(row).unwrap().to_owned())
+                    .collect::<Vec<_>>();
+                if distinct_rows.insert(key) {
+                    indices.push(row as u32);
+                }
+            }
+            if indices.len() < num_rows {
+                block = block.take(&indices)?;
+            }
+        }
+
+        self.rows += block.num_rows() as u64;
+        if self.max_rows != 0 && self.rows > self.max_rows {
+            return Err(exceeds_max_rows(self.max_rows));
+        }
+        if !block.is_empty() {
+            self.working_table.push(block.clone());
+            self.output_data.push_back(block);
+        }
+        Ok(())
+    }
+
+    // Execute the recursive term once on the working table.
+    async fn iterate(&mut self) -> Result<()> {
+        self.iterations += 1;
+        if self.max_iterations != 0 && self.iterations > self.max_iterations {
+            return Err(ErrorCode::AbortedQuery(format!(
+                "Recursive cte exceeds the max number of iterations {}, please check the terminating condition of the recursive term or increase the setting `max_recursive_cte_iterations`",
+                self.max_iterations
+            )));
+        }
+
+        let mut recursive_cte_tables = self.recursive_cte_tables.clone();
+        recursive_cte_tables.insert(
+            self.plan.cte_index,
+            Arc::new(std::mem::take(&mut self.working_table)),
+        );
+        let ctx = self.ctx.clone();
+        let plan = self.plan.right.clone();
+        let enable_profiling = self.enable_profiling;
+        let prof_span_set = self.prof_span_set.clone();
+        // The rows the iteration can produce before the limit is exceeded, the rows
+        // removed by `UNION` are checked in `add_block`.
+        let remaining_rows = match self.max_rows {
+            0 => None,
+            max_rows => Some(max_rows.saturating_sub(self.rows)),
+        };
+        let max_rows = self.max_rows;
+
+        let blocks = GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                let mut pipeline_builder = PipelineBuilder::create(
+                    QueryContext::create_from(ctx.clone()),
+                    enable_profiling,
+                    prof_span_set,
+                );
+                pipeline_builder.recursive_cte_tables = recursive_cte_tables;
+                let mut build_res = pipeline_builder.finalize(&plan)?;
+
+                let settings = ctx.get_settings();
+                build_res.set_max_threads(settings.get_max_threads()? as usize);
+                let settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+                let mut executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+                executor.start();
+
+                let mut rows = 0;
+                let mut blocks = vec![];
+                while let Some(block) = executor.pull_data()? {
+                    rows += block.num_rows() as u64;
+                    if matches!(remaining_rows, Some(remaining) if rows > remaining) {
+                        let cause = exceeds_max_rows(max_rows);
+                        executor.finish(Some(cause.clone()));
+                        return Err(cause);
+                    }
+                    blocks.push(block);
+                }
+                Ok(blocks)
+            })
+            .await?;
+
+        let right_offsets = self.right_offsets.clone();
+        for block in blocks {
+            self.add_block(block, &right_offsets)?;
+        }
+        Ok(())
+    }
+}
+
+fn exceeds_max_rows(max_rows: u64) -> ErrorCode {
+    ErrorCode::AbortedQuery(format!(
+        "Recursive cte exceeds the max number of rows {}, please check the terminating condition of the recursive term or increase the setting `max_recursive_cte_rows`",
+        max_rows
+    ))
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformRecursiveUnion {
+    fn name(&self) -> String {
+        "TransformRecursiveUnion".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(output_data) = self.output_data.pop_front() {
+            self.output.push_data(Ok(output_data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if !self.anchor_finished {
+            if self.input.has_data() {
+                self.input_data = Some(self.input.pull_data().unwrap()?);
+                return Ok(Event::Sync);
+            }
+
+            if !self.input.is_finished() {
+                self.input.set_need_data();
+                return Ok(Event::NeedData);
+            }
+            self.anchor_finished = true;
+        }
+
+        // Iterate until no new rows are produced.
+        if self.working_table.is_empty() {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+        Ok(Event::Async)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(input_data) = self.input_data.take() {
+            let left_offsets = self.left_offsets.clone();
+            return self.add_block(input_data, &left_offsets);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        self.iterate().await
+    }
+}
@@ -0,0 +1,457 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::GlobalUniqName;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::row::RowConverter as CommonRowConverter;
+use common_expression::types::string::StringColumn;
+use common_expression::types::DataType;
+use common_expression::types::DateType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::with_number_mapped_type;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort::HeapMerger;
+use common_pipeline_transforms::processors::transforms::sort::RowConverter;
+use common_pipeline_transforms::processors::transforms::sort::Rows;
+use common_pipeline_transforms::processors::transforms::sort::SimpleRowConverter;
+use common_pipeline_transforms::processors::transforms::sort::SimpleRows;
+use common_pipeline_transforms::processors::transforms::sort::SortedStream;
+use common_storage::DataOperator;
+use opendal::Operator;
+use tracing::error;
+use tracing::info;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Writes the sorted runs to storage and reads them back.
+#[derive(Clone)]
+pub struct SortSpiller {
+    operator: Operator,
+    location_prefix: String,
+}
+
+impl SortSpiller {
+    pub fn create(ctx: &Arc<QueryContext>) -> Self {
+        SortSpiller {
+            operator: DataOperator::instance().operator(),
+            location_prefix: format!("_sort_spill/{}", ctx.get_tenant()),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, block: DataBlock) -> Result<SpillFile> {
+        let instant = Instant::now();
+        let num_rows = block.num_rows();
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        let mut data = vec![];
+        for entry in block.columns() {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let column_data = serialize_column(&column);
+            columns_layout.push(column_data.len());
+            data.extend(column_data);
+        }
+
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        self.operator.write(&location, data).await?;
+        info!(
+            "Write sort spill {} successfully, elapsed: {:?}",
+            location,
+            instant.elapsed()
+        );
+        Ok(SpillFile {
+            location,
+            columns_layout,
+            num_rows,
+        })
+    }
+
+    // The file is deleted after it's read, it's read only once by the merge.
+    #[async_backtrace::framed]
+    async fn read(&self, file: &SpillFile) -> Result<DataBlock> {
+        let instant = Instant::now();
+        let data = self.operator.read(&file.location).await?;
+        if let Err(cause) = self.operator.delete(&file.location).await {
+            error!(
+                "Cannot delete sort spill file {}, cause: {:?}",
+                &file.location, cause
+            );
+        }
+        info!(
+            "Read sort spill {} successfully, elapsed: {:?}",
+            &file.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(file.columns_layout.len());
+        for column_layout in &file.columns_layout {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Cannot deserialize sort spill file {}",
+                        file.location
+                    ))
+                })?;
+            columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+            begin += column_layout;
+        }
+        Ok(DataBlock::new(columns, file.num_rows))
+    }
+}
+
+/// A sorted block of a spilled run.
+struct SpillFile {
+    location: String,
+    columns_layout: Vec<usize>,
+    num_rows: usize,
+}
+
+/// A run of sorted blocks, the run is either in memory or spilled to storage.
+enum SortedRun {
+    Memory(VecDeque<DataBlock>),
+    Spilled {
+        spiller: SortSpiller,
+        files: VecDeque<SpillFile>,
+    },
+}
+
+#[async_trait::async_trait]
+impl SortedStream for SortedRun {
+    #[async_backtrace::framed]
+    async fn next(&mut self) -> Result<Option<DataBlock>> {
+        match self {
+            SortedRun::Memory(blocks) => Ok(blocks.pop_front()),
+            SortedRun::Spilled { spiller, files } => match files.pop_front() {
+                Some(file) => Ok(Some(spiller.read(&file).await?)),
+                None => Ok(None),
+            },
+        }
+    }
+}
+
+enum State {
+    /// Collect the sorted blocks of the input.
+    Consume,
+    /// The collected blocks exceed the threshold, merge them into a sorted run and spill it.
+    Spill,
+    /// The input is finished, merge the spilled runs and the collected blocks.
+    Merge,
+    Finished,
+}
+
+/// External merge sort, the input blocks are sorted by [`TransformSortPartial`].
+///
+/// The sorted blocks are collected in memory until they exceed the threshold, then
+/// they are merged into a sorted run which is spilled to storage. After the input is
+/// finished, the spilled runs are restored block by block and merged with the blocks
+/// in memory by a k-way merge, so the memory is bounded by the threshold and a block
+/// of each run.
+///
+/// [`TransformSortPartial`]: common_pipeline_transforms::processors::transforms::TransformSortPartial
+pub struct TransformSortSpill<R, Converter>
+where
+    R: Rows,
+    Converter: RowConverter<R>,
+{
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    schema: DataSchemaRef,
+    sort_desc: Vec<SortColumnDescription>,
+    block_size: usize,
+    /// If the next transform is the multi-pipelines merge sort, generate the order column.
+    gen_order_col: bool,
+
+    spiller: SortSpiller,
+    threshold: usize,
+
+    blocks: Vec<DataBlock>,
+    num_bytes: usize,
+    runs: Vec<SortedRun>,
+    merger: Option<HeapMerger<R, Converter, SortedRun>>,
+    output_data: Option<DataBlock>,
+    state: State,
+}
+
+impl<R, Converter> TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + Sync + 'static,
+    Converter: RowConverter<R> + Send + Sync + 'static,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        sort_desc: Vec<SortColumnDescription>,
+        block_size: usize,
+        gen_order_col: bool,
+        spiller: SortSpiller,
+        threshold: usize,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformSortSpill::<R, Converter> {
+            input,
+            output,
+            schema,
+            sort_desc,
+            block_size,
+            gen_order_col,
+            spiller,
+            threshold,
+            blocks: vec![],
+            num_bytes: 0,
+            runs: vec![],
+            merger: None,
+            output_data: None,
+            state: State::Consume,
+        })
+    }
+
+    fn create_merger(
+        &self,
+        runs: Vec<SortedRun>,
+        gen_order_col: bool,
+    ) -> Result<HeapMerger<R, Converter, SortedRun>> {
+        let row_converter = Converter::create(self.sort_desc.clone(), self.schema.clone())?;
+        let order_by_cols = self.sort_desc.iter().map(|desc| desc.offset).collect();
+        Ok(HeapMerger::create(
+            row_converter,
+            order_by_cols,
+            self.block_size,
+            gen_order_col,
+            runs,
+        ))
+    }
+
+    // Each collected block is sorted, so it's a run itself.
+    fn take_memory_runs(&mut self) -> Vec<SortedRun> {
+        self.num_bytes = 0;
+        std::mem::take(&mut self.blocks)
+            .into_iter()
+            .map(|block| SortedRun::Memory(VecDeque::from(vec![block])))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl<R, Converter> Processor for TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + Sync + 'static,
+    Converter: RowConverter<R> + Send + Sync + 'static,
+{
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data.take() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.state {
+            State::Spill | State::Merge => return Ok(Event::Async),
+            State::Finished => {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+            State::Consume => {}
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+            if !data_block.is_empty() {
+                self.num_bytes += data_block.memory_size();
+                self.blocks.push(data_block);
+            }
+            if self.num_bytes > self.threshold {
+                self.state = State::Spill;
+                return Ok(Event::Async);
+            }
+        }
+
+        if self.input.is_finished() {
+            let mut runs = std::mem::take(&mut self.runs);
+            runs.extend(self.take_memory_runs());
+            self.merger = Some(self.create_merger(runs, self.gen_order_col)?);
+            self.state = State::Merge;
+            return Ok(Event::Async);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Consume) {
+            State::Spill => {
+                let runs = self.take_memory_runs();
+                let mut merger = self.create_merger(runs, false)?;
+                let mut files = VecDeque::new();
+                while let Some(block) = merger.next_block().await? {
+                    files.push_back(self.spiller.write(block).await?);
+                }
+                self.runs.push(SortedRun::Spilled {
+                    spiller: self.spiller.clone(),
+                    files,
+                });
+                Ok(())
+            }
+            State::Merge => {
+                let merger = self.merger.as_mut().unwrap();
+                match merger.next_block().await? {
+                    Some(block) => {
+                        self.output_data = Some(block);
+                        self.state = State::Merge;
+                    }
+                    None => {
+                        self.merger = None;
+                        self.state = State::Finished;
+                    }
+                }
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+}
+
+type SimpleDateSortSpill = TransformSortSpill<SimpleRows<DateType>, SimpleRowConverter<DateType>>;
+type SimpleTimestampSortSpill =
+    TransformSortSpill<SimpleRows<TimestampType>, SimpleRowConverter<TimestampType>>;
+type SimpleStringSortSpill =
+    TransformSortSpill<SimpleRows<StringType>, SimpleRowConverter<StringType>>;
+type CommonSortSpill = TransformSortSpill<StringColumn, CommonRowConverter>;
+
+#[allow(clippy::too_many_arguments)]
+pub fn try_create_transform_sort_spill(
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    schema: DataSchemaRef,
+    sort_desc: Vec<SortColumnDescription>,
+    block_size: usize,
+    gen_order_col: bool,
+    spiller: SortSpiller,
+    threshold: usize,
+) -> Result<Box<dyn Processor>> {
+    // The rows must be the same as the ones of the multi-pipelines merge sort,
+    // see `try_add_multi_sort_merge`.
+    if sort_desc.len() == 1 {
+        let sort_type = schema.field(sort_desc[0].offset).data_type();
+        Ok(match sort_type {
+            DataType::Number(num_ty) => with_number_mapped_type!(|NUM_TYPE| match num_ty {
+                NumberDataType::NUM_TYPE => TransformSortSpill::<
+                    SimpleRows<NumberType<NUM_TYPE>>,
+                    SimpleRowConverter<NumberType<NUM_TYPE>>,
+                >::create(
+                    input,
+                    output,
+                    schema,
+                    sort_desc,
+                    block_size,
+                    gen_order_col,
+                    spiller,
+                    threshold,
+                ),
+            }),
+            DataType::Date => SimpleDateSortSpill::create(
+                input,
+                output,
+                schema,
+                sort_desc,
+                block_size,
+                gen_order_col,
+                spiller,
+                threshold,
+            ),
+            DataType::Timestamp => SimpleTimestampSortSpill::create(
+                input,
+                output,
+                schema,
+                sort_desc,
+                block_size,
+                gen_order_col,
+                spiller,
+                threshold,
+            ),
+            DataType::String => SimpleStringSortSpill::create(
+                input,
+                output,
+                schema,
+                sort_desc,
+                block_size,
+                gen_order_col,
+                spiller,
+                threshold,
+            ),
+            _ => CommonSortSpill::create(
+                input,
+                output,
+                schema,
+                sort_desc,
+                block_size,
+                gen_order_col,
+                spiller,
+                threshold,
+            ),
+        })
+    } else {
+        Ok(CommonSortSpill::create(
+            input,
+            output,
+            schema,
+            sort_desc,
+            block_size,
+            gen_order_col,
+            spiller,
+            threshold,
+        ))
+    }
+}
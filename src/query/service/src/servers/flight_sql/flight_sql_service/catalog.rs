@@ -14,24 +14,44 @@
 
 use std::sync::Arc;
 
+use arrow_array::builder::BinaryBuilder;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
+use arrow_flight::sql::CommandGetTables;
 use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::IpcMessage;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
+use arrow_schema::SchemaRef;
 use common_catalog::catalog::Catalog;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
+use common_expression::DataSchema;
 use futures_util::stream;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+/// Table types, the same as `information_schema.tables`.
+const TABLE_TYPE_TABLE: &str = "BASE TABLE";
+const TABLE_TYPE_VIEW: &str = "VIEW";
+
 pub(super) struct CatalogInfoProvider {}
 
+#[derive(Default)]
+struct TablesInfo {
+    catalog_names: Vec<String>,
+    database_names: Vec<String>,
+    table_names: Vec<String>,
+    table_types: Vec<String>,
+    table_schemas: Vec<Vec<u8>>,
+}
+
 impl CatalogInfoProvider {
     fn batch_to_get_stream(batch: RecordBatch) -> Result<DoGetStream, Status> {
         let schema = (*batch.schema()).clone();
@@ -44,14 +64,17 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    fn try_new_batch(schema: SchemaRef, columns: Vec<ArrayRef>) -> Result<RecordBatch, Status> {
+        RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))
+    }
+
+    fn list_catalogs(
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)> {
-        let tenant = ctx.get_tenant();
+    ) -> common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
+        let mut catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name
+        {
             vec![(
                 catalog_name.clone(),
                 catalog_mgr.get_catalog(&catalog_name)?,
@@ -63,21 +86,48 @@ impl CatalogInfoProvider {
                 .map(|r| (r.key().to_string(), r.value().clone()))
                 .collect()
         };
+        catalogs.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(catalogs)
+    }
 
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> common_exception::Result<(Vec<String>, Vec<String>)> {
+        let tenant = ctx.get_tenant();
         let mut catalog_names = vec![];
         let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
-        for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(tenant.as_str(), database_name).await?]
-            } else {
-                catalog.list_databases(tenant.as_str()).await?
-            };
+        for (catalog_name, catalog) in Self::list_catalogs(catalog_name)?.into_iter() {
+            let mut dbs = catalog
+                .list_databases(tenant.as_str())
+                .await?
+                .iter()
+                .map(|db| db.name().to_string())
+                .filter(|name| match_pattern(db_schema_filter_pattern.as_deref(), name))
+                .collect::<Vec<_>>();
+            dbs.sort();
             for db in dbs {
-                let db_name = db.name().to_string().into_boxed_str();
-                let db_name: &str = Box::leak(db_name);
+                catalog_names.push(catalog_name.clone());
+                database_names.push(db);
+            }
+        }
+        Ok((catalog_names, database_names))
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        query: &CommandGetTables,
+    ) -> common_exception::Result<TablesInfo> {
+        let tenant = ctx.get_tenant();
+        let mut info = TablesInfo::default();
+        for (catalog_name, catalog) in Self::list_catalogs(query.catalog.clone())?.into_iter() {
+            let dbs = catalog.list_databases(tenant.as_str()).await?;
+            for db in dbs {
+                let db_name = db.name();
+                if !match_pattern(query.db_schema_filter_pattern.as_deref(), db_name) {
+                    continue;
+                }
                 let tables = match catalog.list_tables(tenant.as_str(), db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
@@ -87,41 +137,152 @@ impl CatalogInfoProvider {
                     Err(err) => return Err(err),
                 };
                 for table in tables {
-                    catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    if !match_pattern(query.table_name_filter_pattern.as_deref(), table.name()) {
+                        continue;
+                    }
+                    let table_type = if table.engine() == "VIEW" {
+                        TABLE_TYPE_VIEW
+                    } else {
+                        TABLE_TYPE_TABLE
+                    };
+                    if !query.table_types.is_empty()
+                        && !query.table_types.iter().any(|t| t == table_type)
+                    {
+                        continue;
+                    }
+                    if query.include_schema {
+                        let schema = Schema::from(&DataSchema::from(table.schema()));
+                        let message: IpcMessage =
+                            SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+                                .try_into()
+                                .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+                        info.table_schemas.push(message.0.to_vec());
+                    }
+                    info.catalog_names.push(catalog_name.clone());
+                    info.database_names.push(db_name.to_string());
+                    info.table_names.push(table.name().to_string());
+                    info.table_types.push(table_type.to_string());
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok(info)
     }
 
-    pub(crate) async fn get_tables(
+    pub(crate) fn get_catalogs() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            false,
+        )]));
+        let catalog_names = Self::list_catalogs(None)
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let batch = Self::try_new_batch(schema, vec![Self::string_array(catalog_names)])?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_schemas(
         ctx: Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
     ) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
-            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("catalog_name", DataType::Utf8, true),
             Field::new("db_schema_name", DataType::Utf8, false),
-            Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
+        let (catalog_name, db_schema_name) =
+            Self::get_schemas_internal(ctx, catalog_name, db_schema_filter_pattern)
                 .await
                 .map_err(|e| Status::internal(format!("{e:?}")))?;
-        let batch = RecordBatch::try_new(schema, vec![
+        let batch = Self::try_new_batch(schema, vec![
             Self::string_array(catalog_name),
             Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
-        ])
-        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        ])?;
         Self::batch_to_get_stream(batch)
     }
 
+    pub(crate) async fn get_tables(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetTables,
+    ) -> Result<DoGetStream, Status> {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("table_type", DataType::Utf8, false),
+        ];
+        if query.include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+        }
+        let info = Self::get_tables_internal(ctx.clone(), &query)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut columns = vec![
+            Self::string_array(info.catalog_names),
+            Self::string_array(info.database_names),
+            Self::string_array(info.table_names),
+            Self::string_array(info.table_types),
+        ];
+        if query.include_schema {
+            let mut builder = BinaryBuilder::new();
+            for v in &info.table_schemas {
+                builder.append_value(v);
+            }
+            columns.push(Arc::new(builder.finish()));
+        }
+        let batch = Self::try_new_batch(Arc::new(Schema::new(fields)), columns)?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let batch = Self::try_new_batch(schema, vec![Self::string_array(vec![
+            TABLE_TYPE_TABLE.to_string(),
+            TABLE_TYPE_VIEW.to_string(),
+        ])])?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// Primary keys are not supported, the result is always empty like `information_schema.key_column_usage`.
+    pub(crate) fn get_primary_keys() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
+    /// Foreign keys are not supported, the result of exported keys, imported keys and cross
+    /// reference is always empty.
+    pub(crate) fn get_foreign_keys() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -130,3 +291,32 @@ impl CatalogInfoProvider {
         Arc::new(builder.finish())
     }
 }
+
+/// Match the name with the filter pattern of Flight SQL, which is the same as `LIKE`:
+/// `%` means any sequence of characters and `_` means any single character.
+fn match_pattern(pattern: Option<&str>, name: &str) -> bool {
+    let pattern = match pattern {
+        None => return true,
+        Some(pattern) => pattern.chars().collect::<Vec<_>>(),
+    };
+    let name = name.chars().collect::<Vec<_>>();
+
+    // matched[j] means whether `name[..j]` matches the pattern scanned so far.
+    let mut matched = vec![false; name.len() + 1];
+    matched[0] = true;
+    for p in pattern {
+        let mut next = vec![false; name.len() + 1];
+        if p == '%' {
+            next[0] = matched[0];
+            for j in 1..=name.len() {
+                next[j] = next[j - 1] || matched[j];
+            }
+        } else {
+            for j in 1..=name.len() {
+                next[j] = matched[j - 1] && (p == '_' || p == name[j - 1]);
+            }
+        }
+        matched = next;
+    }
+    matched[name.len()]
+}
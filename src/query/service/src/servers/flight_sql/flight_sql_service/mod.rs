@@ -22,19 +22,22 @@ mod sql_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
-use common_sql::plans::Plan;
-use common_sql::PlanExtras;
-use dashmap::DashMap;
+use common_expression::Scalar;
+use common_sql::PreparedStatement;
 use futures::Stream;
 use parking_lot::Mutex;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
 
+use crate::servers::http::v1::query::expirable::Expirable;
 use crate::servers::http::v1::ExpiringMap;
+use crate::servers::http::v1::ExpiringState;
 use crate::sessions::Session;
 
 #[macro_export]
@@ -49,9 +52,44 @@ pub(crate) use status;
 
 type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
 
+/// Statements not accessed for this long are removed, in case the client never fetches the
+/// ticket of `get_flight_info_statement` or closes the prepared statement.
+const STATEMENT_MAX_IDLE: Duration = Duration::from_secs(360);
+
+/// Statement created by `do_action_create_prepared_statement` or `get_flight_info_statement`.
+struct FlightSqlStatement {
+    /// Id of the session creating the statement, which is the only session allowed to use it.
+    session_id: String,
+    stmt: PreparedStatement,
+    /// Rows of parameters bound by `do_put`.
+    params: Mutex<Vec<Vec<Scalar>>>,
+    last_access: Mutex<Instant>,
+}
+
+impl FlightSqlStatement {
+    fn new(session_id: String, stmt: PreparedStatement) -> Arc<Self> {
+        Arc::new(FlightSqlStatement {
+            session_id,
+            stmt,
+            params: Mutex::new(vec![]),
+            last_access: Mutex::new(Instant::now()),
+        })
+    }
+}
+
+impl Expirable for Arc<FlightSqlStatement> {
+    fn expire_state(&self) -> ExpiringState {
+        ExpiringState::Idle {
+            idle_time: Instant::now() - *self.last_access.lock(),
+        }
+    }
+
+    fn on_expire(&self) {}
+}
+
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    statements: Mutex<ExpiringMap<Uuid, Arc<FlightSqlStatement>>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -59,7 +97,7 @@ impl FlightSqlServiceImpl {
     pub fn create() -> Self {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
-            statements: Arc::new(Default::default()),
+            statements: Mutex::new(Default::default()),
         }
     }
 }
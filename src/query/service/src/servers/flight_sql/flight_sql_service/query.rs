@@ -12,29 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use arrow_flight::utils::flight_data_to_arrow_batch;
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
 use arrow_ipc::writer;
 use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field as ArrowField;
 use arrow_schema::Schema as ArrowSchema;
 use common_base::base::tokio;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataSchema;
+use common_expression::Scalar;
 use common_sql::plans::Plan;
 use common_sql::PlanExtras;
 use common_sql::Planner;
+use common_sql::PreparedStatement;
 use common_storages_fuse::TableContext;
 use futures::Stream;
 use futures::StreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use tonic::Streaming;
 
 use super::status;
 use super::DoGetStream;
@@ -82,6 +89,139 @@ impl FlightSqlServiceImpl {
         planner.plan_sql(query).await
     }
 
+    #[async_backtrace::framed]
+    pub(super) async fn prepare_sql(
+        &self,
+        session: &Arc<Session>,
+        query: &str,
+    ) -> Result<PreparedStatement> {
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        let mut planner = Planner::new(context.clone());
+        planner.prepare_sql(query).await
+    }
+
+    /// Parameters are sent as strings, which can be casted to any type.
+    pub(super) fn parameter_schema(num_params: usize) -> ArrowSchema {
+        let fields = (1..=num_params)
+            .map(|i| ArrowField::new(format!("${i}"), ArrowDataType::Utf8, true))
+            .collect::<Vec<_>>();
+        ArrowSchema::new(fields)
+    }
+
+    /// Read the parameters sent by `do_put`, each row of the record batches is a group of parameters.
+    #[async_backtrace::framed]
+    pub(super) async fn read_parameters(
+        mut stream: Streaming<FlightData>,
+        num_params: usize,
+    ) -> Result<Vec<Vec<Scalar>>> {
+        // The first message carrying the schema is consumed to get the command, so the schema
+        // returned by `do_action_create_prepared_statement` is used unless another one is sent.
+        let mut schema = Arc::new(Self::parameter_schema(num_params));
+        let dictionaries_by_id = HashMap::new();
+        let mut params = vec![];
+        while let Some(data) = stream
+            .message()
+            .await
+            .map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?
+        {
+            if data.data_header.is_empty() {
+                continue;
+            }
+            if let Ok(s) = ArrowSchema::try_from(&data) {
+                schema = Arc::new(s);
+                continue;
+            }
+            let batch = flight_data_to_arrow_batch(&data, schema.clone(), &dictionaries_by_id)
+                .map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?;
+            let (block, _) = DataBlock::from_record_batch(&batch)
+                .map_err(|e| ErrorCode::BadBytes(format!("{e:?}")))?;
+            for row in 0..block.num_rows() {
+                let values = block
+                    .columns()
+                    .iter()
+                    .map(|entry| entry.value.index(row).unwrap().to_owned())
+                    .collect();
+                params.push(values);
+            }
+        }
+        Ok(params)
+    }
+
+    #[async_backtrace::framed]
+    pub(super) async fn plan_prepared(
+        &self,
+        session: &Arc<Session>,
+        stmt: &PreparedStatement,
+        params: &[Scalar],
+    ) -> Result<(Plan, PlanExtras)> {
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        let mut planner = Planner::new(context.clone());
+        planner.plan_prepared(stmt, params).await
+    }
+
+    /// Get the groups of parameters to execute the statement with, a statement without
+    /// placeholders is executed once with no parameters.
+    pub(super) fn parameter_groups(
+        stmt: &PreparedStatement,
+        params: &[Vec<Scalar>],
+    ) -> Result<Vec<Vec<Scalar>>> {
+        if stmt.num_params() == 0 {
+            return Ok(vec![vec![]]);
+        }
+        if params.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Parameters of prepared statement are not bound: {}",
+                stmt.sql()
+            )));
+        }
+        Ok(params.to_vec())
+    }
+
+    /// Execute the query of a statement, which must be bound with one group of parameters at most.
+    #[async_backtrace::framed]
+    pub(super) async fn execute_statement_query(
+        &self,
+        session: Arc<Session>,
+        stmt: &PreparedStatement,
+        params: &[Vec<Scalar>],
+    ) -> Result<DoGetStream> {
+        let groups = Self::parameter_groups(stmt, params)?;
+        if groups.len() != 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Query expects one group of parameters, but got {}",
+                groups.len()
+            )));
+        }
+        let (plan, plan_extras) = self.plan_prepared(&session, stmt, &groups[0]).await?;
+        self.execute_query(session, &plan, &plan_extras).await
+    }
+
+    /// Execute a statement once for each group of the bound parameters.
+    #[async_backtrace::framed]
+    pub(super) async fn execute_statement_update(
+        &self,
+        session: Arc<Session>,
+        stmt: &PreparedStatement,
+        params: &[Vec<Scalar>],
+    ) -> Result<i64> {
+        let mut affected_rows = 0;
+        for group in Self::parameter_groups(stmt, params)? {
+            let (plan, plan_extras) = self.plan_prepared(&session, stmt, &group).await?;
+            affected_rows += self
+                .execute_update(session.clone(), &plan, &plan_extras)
+                .await?;
+        }
+        Ok(affected_rows)
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn execute_update(
         &self,
@@ -15,6 +15,7 @@
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use arrow_flight::flight_descriptor::DescriptorType;
 use arrow_flight::flight_service_server::FlightService;
@@ -53,9 +54,10 @@ use arrow_flight::PutResult;
 use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::Schema as ArrowSchema;
 use common_base::base::uuid::Uuid;
 use common_exception::Result;
-use common_expression::DataSchema;
+use common_sql::PreparedStatement;
 use futures::Stream;
 use prost::Message;
 use tonic::metadata::MetadataValue;
@@ -66,7 +68,11 @@ use tonic::Status;
 use tonic::Streaming;
 
 use super::status;
+use super::CatalogInfoProvider;
+use super::FlightSqlStatement;
+use super::STATEMENT_MAX_IDLE;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::sessions::Session;
 
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
     message
@@ -109,6 +115,84 @@ fn simple_flight_info<T: ProstMessageExt>(message: T) -> Response<FlightInfo> {
     Response::new(info)
 }
 
+fn flight_info_with_schema<T: ProstMessageExt>(
+    message: T,
+    schema: &ArrowSchema,
+) -> Result<Response<FlightInfo>, Status> {
+    let loc = Location {
+        uri: "grpc+tcp://127.0.0.1".to_string(),
+    };
+    let buf = message.as_any().encode_to_vec().into();
+    let ticket = Ticket { ticket: buf };
+    let endpoint = FlightEndpoint {
+        ticket: Some(ticket),
+        location: vec![loc],
+    };
+    let endpoints = vec![endpoint];
+
+    let message = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| status!("Unable to serialize schema", e))?;
+    let IpcMessage(schema_bytes) = message;
+
+    let flight_desc = FlightDescriptor {
+        r#type: DescriptorType::Cmd.into(),
+        cmd: Default::default(),
+        path: vec![],
+    };
+    let info = FlightInfo {
+        schema: schema_bytes,
+        flight_descriptor: Some(flight_desc),
+        endpoint: endpoints,
+        total_records: -1,
+        total_bytes: -1,
+    };
+    Ok(Response::new(info))
+}
+
+fn decode_handle(handle: &[u8]) -> Result<Uuid, Status> {
+    Uuid::from_slice(handle).map_err(|e| Status::internal(format!("Error decoding handle: {e}")))
+}
+
+impl FlightSqlServiceImpl {
+    fn add_statement(&self, session: &Arc<Session>, stmt: PreparedStatement) -> Uuid {
+        let handle = Uuid::new_v4();
+        self.statements.lock().insert(
+            handle,
+            FlightSqlStatement::new(session.get_id(), stmt),
+            Some(STATEMENT_MAX_IDLE),
+        );
+        handle
+    }
+
+    /// Get the statement created by the session, the statements of other sessions are
+    /// treated as not found.
+    fn get_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: &Uuid,
+    ) -> Result<Arc<FlightSqlStatement>, Status> {
+        match self.statements.lock().get(handle) {
+            Some(statement) if statement.session_id == session.get_id() => {
+                *statement.last_access.lock() = Instant::now();
+                Ok(statement)
+            }
+            _ => Err(Status::not_found(format!(
+                "prepared statement not found: {handle}"
+            ))),
+        }
+    }
+
+    fn remove_statement(&self, session: &Arc<Session>, handle: &Uuid) {
+        let mut statements = self.statements.lock();
+        if let Some(statement) = statements.get(handle) {
+            if statement.session_id == session.get_id() {
+                statements.remove(handle);
+            }
+        }
+    }
+}
+
 impl NamedService for FlightSqlServiceImpl {
     const NAME: &'static str = "FlightSqlService";
 }
@@ -179,9 +263,10 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let statement = self.get_statement(&session, &handle)?;
+        let params = statement.params.lock().clone();
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_statement_query(session, &statement.stmt, &params)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -194,9 +279,21 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        tracing::info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        tracing::info!("get_flight_info_statement(query={})", query.query);
+        let session = self.get_session(&request)?;
+        let stmt = self
+            .prepare_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let schema = stmt.schema().as_ref().into();
+
+        // the statement is removed after executed by `do_get_statement`, or expired if the
+        // ticket is never fetched.
+        let handle = self.add_statement(&session, stmt);
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        flight_info_with_schema(ticket, &schema)
     }
 
     #[async_backtrace::framed]
@@ -205,47 +302,14 @@ impl FlightSqlService for FlightSqlServiceImpl {
         cmd: CommandPreparedStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        let _session = self.get_session(&request);
-        let handle = Uuid::from_slice(cmd.prepared_statement_handle.as_ref())
-            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+        let session = self.get_session(&request)?;
+        let handle = decode_handle(cmd.prepared_statement_handle.as_ref())?;
 
         tracing::info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
-        let loc = Location {
-            uri: "grpc+tcp://127.0.0.1".to_string(),
-        };
-        let fetch = FetchResults {
-            handle: handle.to_string(),
-        };
-        let buf = fetch.as_any().encode_to_vec().into();
-        let ticket = Ticket { ticket: buf };
-        let endpoint = FlightEndpoint {
-            ticket: Some(ticket),
-            location: vec![loc],
-        };
-        let endpoints = vec![endpoint];
-
-        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
-            .try_into()
-            .map_err(|e| status!("Unable to serialize schema", e))?;
-        let IpcMessage(schema_bytes) = message;
-
-        let flight_desc = FlightDescriptor {
-            r#type: DescriptorType::Cmd.into(),
-            cmd: Default::default(),
-            path: vec![],
-        };
-        let info = FlightInfo {
-            schema: schema_bytes,
-            flight_descriptor: Some(flight_desc),
-            endpoint: endpoints,
-            total_records: -1,
-            total_bytes: -1,
-        };
-        let resp = Response::new(info);
-        Ok(resp)
+        let statement = self.get_statement(&session, &handle)?;
+        let schema = statement.stmt.schema().as_ref().into();
+        flight_info_with_schema(cmd, &schema)
     }
 
     #[async_backtrace::framed]
@@ -307,48 +371,44 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     // do_get
@@ -356,42 +416,74 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = decode_handle(ticket.statement_handle.as_ref())?;
+
+        tracing::info!("do_get_statement with handle={handle}");
+
+        let statement = self.get_statement(&session, &handle)?;
+        self.remove_statement(&session, &handle);
+        let params = statement.params.lock().clone();
+        let stream = self
+            .execute_statement_query(session, &statement.stmt, &params)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = decode_handle(query.prepared_statement_handle.as_ref())?;
+
+        tracing::info!("do_get_prepared_statement with handle={handle}");
+
+        let statement = self.get_statement(&session, &handle)?;
+        let params = statement.params.lock().clone();
+        let stream = self
+            .execute_statement_query(session, &statement.stmt, &params)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
         _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(CatalogInfoProvider::get_catalogs()?))
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        tracing::info!("do_get_schemas({query:?})");
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            CatalogInfoProvider::get_schemas(
+                context,
+                query.catalog.clone(),
+                query.db_schema_filter_pattern.clone(),
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -407,8 +499,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
         Ok(Response::new(
-            super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
+            CatalogInfoProvider::get_tables(context.clone(), query).await?,
         ))
     }
 
@@ -416,10 +507,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
@@ -436,46 +528,44 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(CatalogInfoProvider::get_primary_keys()?))
     }
 
     #[async_backtrace::framed]
     async fn do_get_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(CatalogInfoProvider::get_foreign_keys()?))
     }
 
     #[async_backtrace::framed]
     async fn do_get_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(CatalogInfoProvider::get_foreign_keys()?))
     }
 
     #[async_backtrace::framed]
     async fn do_get_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "do_get_cross_reference not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(CatalogInfoProvider::get_foreign_keys()?))
     }
 
     // called by rust FlightSqlServiceClient, which is used in unit test.
@@ -507,21 +597,33 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<Streaming<FlightData>>,
     ) -> Result<Response<<Self as FlightService>::DoPutStream>, Status> {
         let session = self.get_session(&request)?;
-        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
-            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+        let handle = decode_handle(query.prepared_statement_handle.as_ref())?;
 
         tracing::info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
-        let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+        let statement = self.get_statement(&session, &handle)?;
+        let stmt = &statement.stmt;
+        let params = Self::read_parameters(request.into_inner(), stmt.num_params())
             .await
-            .map_err(|e| status!("fail to execute", e))?;
-        let result = DoPutUpdateResult { record_count };
-        let result = PutResult {
-            app_metadata: result.as_any().encode_to_vec().into(),
+            .map_err(|e| status!("fail to read parameters", e))?;
+
+        // JDBC client call put to execute statements without result set. The schema may be
+        // unknown for a query until the parameters are bound, e.g. `LIMIT ?`.
+        let results = if !stmt.has_result_set() {
+            let record_count = self
+                .execute_statement_update(session, stmt, &params)
+                .await
+                .map_err(|e| status!("fail to execute", e))?;
+            let result = DoPutUpdateResult { record_count };
+            vec![Ok(PutResult {
+                app_metadata: result.as_any().encode_to_vec().into(),
+            })]
+        } else {
+            // parameters are bound for the following `do_get_prepared_statement`
+            *statement.params.lock() = params;
+            vec![]
         };
-        let result = futures::stream::iter(vec![Ok(result)]);
+        let result = futures::stream::iter(results);
         return Ok(Response::new(Box::pin(result)));
     }
 
@@ -533,14 +635,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<Streaming<FlightData>>,
     ) -> Result<i64, Status> {
         let session = self.get_session(&request)?;
-        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
-            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+        let handle = decode_handle(query.prepared_statement_handle.as_ref())?;
 
         tracing::info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let statement = self.get_statement(&session, &handle)?;
+        let params = Self::read_parameters(request.into_inner(), statement.stmt.num_params())
+            .await
+            .map_err(|e| status!("fail to read parameters", e))?;
         let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_statement_update(session, &statement.stmt, &params)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -556,35 +660,31 @@ impl FlightSqlService for FlightSqlServiceImpl {
     ) -> Result<ActionCreatePreparedStatementResult, Status> {
         let session = self.get_session(&request)?;
         let sql = query.query.clone();
-        let handle = Uuid::new_v4();
-        let plan = self
-            .plan_sql(&session, &sql)
+        // JDBC client use call put when schema.fields == 0
+        let stmt = self
+            .prepare_sql(&session, &sql)
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
-        tracing::info!(
-            "do_action_create_prepared_statement with handler={handle} query={:?}",
-            query.query
-        );
-        // JDBC client use call put when schema.fields == 0
-        let data_schema = if plan.0.has_result_set() {
-            plan.0.schema()
-        } else {
-            Arc::new(DataSchema::empty())
-        };
+        let data_schema = stmt.schema();
+        let schema = (&*data_schema).into();
+        let parameter_schema = Self::parameter_schema(stmt.num_params());
+        let handle = self.add_statement(&session, stmt);
         tracing::info!(
             "do_action_create_prepared_statement with handler={handle}, query={:?}, return schema={data_schema:?}",
             query.query
         );
-        let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
         let IpcMessage(schema_bytes) = message;
+        let message = SchemaAsIpc::new(&parameter_schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(parameter_schema_bytes) = message;
         let res = ActionCreatePreparedStatementResult {
             prepared_statement_handle: handle.as_bytes().to_vec().into(),
             dataset_schema: schema_bytes,
-            parameter_schema: Default::default(), // TODO: parameters
+            parameter_schema: parameter_schema_bytes,
         };
         Ok(res)
     }
@@ -595,24 +695,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: ActionClosePreparedStatementRequest,
         request: Request<Action>,
     ) {
-        let handle = query.prepared_statement_handle.as_ref();
-        if let Ok(handle) = std::str::from_utf8(handle) {
-            tracing::info!(
-                "do_action_close_prepared_statement with handle {:?}",
-                handle
-            );
-            match Uuid::try_parse(handle) {
-                Ok(handle) => {
-                    if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
-                    }
-                }
-                Err(e) => {
-                    Status::internal(format!(
-                        "do_action_close_prepared_statement Error decoding handle: {e} {handle:?}"
-                    ));
+        match decode_handle(query.prepared_statement_handle.as_ref()) {
+            Ok(handle) => {
+                tracing::info!("do_action_close_prepared_statement with handle {handle}");
+                if let Ok(session) = self.get_session(&request) {
+                    self.remove_statement(&session, &handle);
                 }
             }
+            Err(e) => {
+                tracing::error!("do_action_close_prepared_statement: {e}");
+            }
         }
     }
 
@@ -50,6 +50,10 @@ use crate::sessions::short_sql;
 use crate::sessions::QueryAffect;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+
+// How long the session of an explicit transaction is kept on the server without queries.
+const TXN_SESSION_KEEP_SECS: u64 = 3600;
 
 fn default_as_true() -> bool {
     true
@@ -135,10 +139,14 @@ pub struct HttpSessionConf {
     pub keep_server_session_secs: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub settings: Option<BTreeMap<String, String>>,
+    /// The server session which holds the explicit transaction, the following queries
+    /// with this conf run in it until the transaction is committed or rolled back.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_session_id: Option<String>,
 }
 
 impl HttpSessionConf {
-    fn apply_affect(&self, affect: &QueryAffect) -> HttpSessionConf {
+    fn apply_affect(&self, affect: &QueryAffect, session_id: &str) -> HttpSessionConf {
         let mut ret = self.clone();
         match affect {
             QueryAffect::UseDB { name } => {
@@ -154,6 +162,13 @@ impl HttpSessionConf {
                     settings.insert(key.to_string(), value.to_string());
                 }
             }
+            QueryAffect::Transaction { active } => {
+                ret.txn_session_id = if *active {
+                    Some(session_id.to_string())
+                } else {
+                    None
+                };
+            }
             _ => {}
         }
         ret
@@ -216,7 +231,11 @@ impl HttpQuery {
     ) -> Result<Arc<HttpQuery>> {
         let http_query_manager = HttpQueryManager::instance();
 
-        let session = if let Some(id) = &request.session_id {
+        let txn_session_id = request
+            .session
+            .as_ref()
+            .and_then(|conf| conf.txn_session_id.as_ref());
+        let session = if let Some(id) = request.session_id.as_ref().or(txn_session_id) {
             let session = http_query_manager.get_session(id).await.ok_or_else(|| {
                 ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
             })?;
@@ -264,13 +283,21 @@ impl HttpQuery {
                 }
             }
             if let Some(secs) = session_conf.keep_server_session_secs {
-                if secs > 0 && request.session_id.is_none() {
+                if secs > 0 && request.session_id.is_none() && txn_session_id.is_none() {
                     http_query_manager
                         .add_session(session.clone(), Duration::from_secs(secs))
                         .await;
                 }
             }
         };
+        let session_kept = request.session_id.is_some()
+            || txn_session_id.is_some()
+            || request
+                .session
+                .as_ref()
+                .and_then(|conf| conf.keep_server_session_secs)
+                .unwrap_or(0)
+                > 0;
 
         let session_id = session.get_id().clone();
 
@@ -305,6 +332,14 @@ impl HttpQuery {
         let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone()).await?;
         let schema = InterpreterFactory::get(ctx.clone(), &plan).await?.schema();
 
+        // The explicit transaction lives in the session, keep the session on the server,
+        // so that the following queries with the `txn_session_id` run in the transaction.
+        if matches!(plan, Plan::Begin) && !session_kept {
+            http_query_manager
+                .add_session(session.clone(), Duration::from_secs(TXN_SESSION_KEEP_SECS))
+                .await;
+        }
+
         let http_query_runtime_instance = GlobalQueryRuntime::instance();
         http_query_runtime_instance
             .runtime()
@@ -365,7 +400,7 @@ impl HttpQuery {
         let state = self.get_state().await;
         let session_conf = self.request.session.clone().unwrap_or_default();
         let session_conf = if let Some(affect) = &state.affect {
-            Some(session_conf.apply_affect(affect))
+            Some(session_conf.apply_affect(affect, &self.session_id))
         } else {
             Some(session_conf)
         };
@@ -24,21 +24,29 @@ use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
+use common_expression::types::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 use common_expression::SendableDataBlockStream;
 use common_io::prelude::FormatSettings;
+use common_sql::plans::Plan;
+use common_sql::PlanExtras;
 use common_sql::Planner;
 use common_users::CertifiedInfo;
 use common_users::UserApiProvider;
 use futures_util::StreamExt;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
+use opensrv_mysql::ValueInner;
 use rand::RngCore;
 use tracing::error;
 use tracing::info;
@@ -47,6 +55,7 @@ use tracing::Instrument;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -171,7 +180,26 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
             ));
         }
 
-        self.base.do_execute(id, param, writer).await
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+
+        let instant = Instant::now();
+        let query_result = self.base.do_execute(id, param).await;
+
+        let format = self.base.session.get_format_settings();
+
+        let mut write_result = writer.write(query_result, &format).await;
+
+        if let Err(cause) = write_result {
+            let suffix = format!("(while in prepared statement {})", id);
+            write_result = Err(cause.add_message_back(suffix));
+        }
+
+        histogram!(
+            super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
+            instant.elapsed()
+        );
+
+        write_result
     }
 
     /// https://dev.mysql.com/doc/internals/en/com-stmt-close.html
@@ -270,34 +298,73 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
     }
 
     #[async_backtrace::framed]
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+    async fn do_prepare(&mut self, query: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
+        let prepared = async {
+            let context = self.session.create_query_context().await?;
+            let mut planner = Planner::new(context);
+            let stmt = planner.prepare_sql(query).await?;
+            let columns = convert_schema(&stmt.schema(), true)?;
+            Ok::<_, ErrorCode>((stmt, columns))
+        }
+        .await;
+
+        match prepared {
+            Ok((stmt, columns)) => {
+                // Types of parameters are unknown until executed, the client sends them with values.
+                let params = vec![
+                    Column {
+                        table: "".to_string(),
+                        column: "?".to_string(),
+                        coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                        colflags: ColumnFlags::empty(),
+                    };
+                    stmt.num_params()
+                ];
+                let id = self.session.add_prepared_statement(stmt);
+                info!("Prepared statement {}: {}", id, query);
+                writer.reply(id, &params, &columns).await?;
+            }
+            Err(error) => {
+                let error = error.display_with_sql(query);
+                error!("OnPrepare Error: {:?}", error);
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                    .await?;
+            }
+        }
         Ok(())
     }
 
     #[async_backtrace::framed]
     async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
-        writer: QueryResultWriter<'_, W>,
-    ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        id: u32,
+        param: ParamParser<'_>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        let stmt = self.session.get_prepared_statement(id).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("Unknown prepared statement id: {}", id))
+        })?;
+
+        let params = param
+            .into_iter()
+            .map(|param| convert_param(param.value.into_inner()))
+            .collect::<Result<Vec<_>>>()?;
+
+        async {
+            info!("Execute prepared statement {}: {}", id, stmt.sql());
+            let context = self.session.create_query_context().await?;
+            let mut planner = Planner::new(context.clone());
+            let (plan, extras) = planner.plan_prepared(&stmt, &params).await?;
+            Self::exec_plan(context, &plan, &extras, stmt.sql()).await
+        }
+        .await
+        .map_err(|err| err.display_with_sql(stmt.sql()))
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.session.remove_prepared_statement(id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -337,33 +404,41 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
+                Self::exec_plan(context, &plan, &extras, query).await
+            }
+        }
+    }
 
-                context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = interpreter.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+    #[async_backtrace::framed]
+    async fn exec_plan(
+        context: Arc<QueryContext>,
+        plan: &Plan,
+        extras: &PlanExtras,
+        query: &str,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), plan).await;
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = interpreter.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -451,6 +526,71 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
     }
 }
 
+/// Convert the parameter of `COM_STMT_EXECUTE`.
+///
+/// Dates and times are converted to strings, so that they are parsed with the timezone of session.
+fn convert_param(value: ValueInner) -> Result<Scalar> {
+    let scalar = match value {
+        ValueInner::NULL => Scalar::Null,
+        ValueInner::Bytes(v) => Scalar::String(v.to_vec()),
+        ValueInner::Int(v) => Scalar::Number(NumberScalar::Int64(v)),
+        ValueInner::UInt(v) => Scalar::Number(NumberScalar::UInt64(v)),
+        ValueInner::Double(v) => Scalar::Number(NumberScalar::Float64(v.into())),
+        ValueInner::Date(v) | ValueInner::Datetime(v) => {
+            Scalar::String(decode_datetime(v)?.into_bytes())
+        }
+        ValueInner::Time(v) => Scalar::String(decode_time(v)?.into_bytes()),
+    };
+    Ok(scalar)
+}
+
+/// Decode the binary encoding of `DATE`, `DATETIME` and `TIMESTAMP`.
+///
+/// https://dev.mysql.com/doc/internals/en/binary-protocol-value.html
+fn decode_datetime(buf: &[u8]) -> Result<String> {
+    match buf.len() {
+        0 => Ok("0000-00-00".to_string()),
+        4 | 7 | 11 => {
+            let year = u16::from_le_bytes([buf[0], buf[1]]);
+            let mut s = format!("{:04}-{:02}-{:02}", year, buf[2], buf[3]);
+            if buf.len() > 4 {
+                s.push_str(&format!(" {:02}:{:02}:{:02}", buf[4], buf[5], buf[6]));
+            }
+            if buf.len() > 7 {
+                let micros = u32::from_le_bytes([buf[7], buf[8], buf[9], buf[10]]);
+                s.push_str(&format!(".{:06}", micros));
+            }
+            Ok(s)
+        }
+        len => Err(ErrorCode::BadBytes(format!(
+            "Invalid length of datetime parameter: {}",
+            len
+        ))),
+    }
+}
+
+/// Decode the binary encoding of `TIME`.
+fn decode_time(buf: &[u8]) -> Result<String> {
+    match buf.len() {
+        0 => Ok("00:00:00".to_string()),
+        8 | 12 => {
+            let sign = if buf[0] == 1 { "-" } else { "" };
+            let days = u32::from_le_bytes([buf[1], buf[2], buf[3], buf[4]]);
+            let hours = days * 24 + buf[5] as u32;
+            let mut s = format!("{}{:02}:{:02}:{:02}", sign, hours, buf[6], buf[7]);
+            if buf.len() > 8 {
+                let micros = u32::from_le_bytes([buf[8], buf[9], buf[10], buf[11]]);
+                s.push_str(&format!(".{:06}", micros));
+            }
+            Ok(s)
+        }
+        len => Err(ErrorCode::BadBytes(format!(
+            "Invalid length of time parameter: {}",
+            len
+        ))),
+    }
+}
+
 struct ContextProgressReporter {
     context: Arc<QueryContext>,
     instant: Instant,
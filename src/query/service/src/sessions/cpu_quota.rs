@@ -0,0 +1,160 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_base::base::tokio::sync::Semaphore;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::future::Either;
+
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+
+// How often a query waiting for the cpu quota checks if it is killed.
+const WAIT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The cpu quota of a user on this query node, the threads of the running queries of the user
+/// are acquired from the semaphore, so that they never exceed the quota all together.
+pub(in crate::sessions) struct UserCpuQuota {
+    max_cpu: u64,
+    semaphore: Arc<Semaphore>,
+}
+
+/// Held by a query until it finished, the threads are given back to the cpu quota of the user
+/// when dropped.
+pub struct CpuQuotaPermit {
+    threads: u64,
+    held: Arc<AtomicBool>,
+    _first: OwnedSemaphorePermit,
+    _rest: Option<OwnedSemaphorePermit>,
+}
+
+impl CpuQuotaPermit {
+    /// The number of the threads granted to the query.
+    pub fn threads(&self) -> u64 {
+        self.threads
+    }
+}
+
+impl Drop for CpuQuotaPermit {
+    fn drop(&mut self) {
+        self.held.store(false, Ordering::Release);
+    }
+}
+
+impl SessionManager {
+    /// Acquire the threads of the query from the cpu quota of the current user.
+    ///
+    /// The query waits until the other queries of the user give back a thread, then runs with
+    /// the threads left in the quota, no more than its max threads. Returns None if the cpu of
+    /// the user is not limited.
+    #[async_backtrace::framed]
+    pub async fn acquire_cpu_quota(
+        &self,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<Option<CpuQuotaPermit>> {
+        let user = match ctx.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return Ok(None),
+        };
+        let max_cpu = user.quota.max_cpu;
+        if max_cpu == 0 {
+            return Ok(None);
+        }
+
+        let held = ctx.get_cpu_quota_held();
+        if held.swap(true, Ordering::AcqRel) {
+            return Ok(None);
+        }
+
+        let key = format!("{}/{}", ctx.get_tenant(), user.identity());
+        let semaphore = self.get_cpu_quota(key, max_cpu);
+
+        ctx.set_queued(true);
+        let res = Self::wait_cpu_quota(ctx, semaphore.clone()).await;
+        ctx.set_queued(false);
+        let first = match res {
+            Ok(permit) => permit,
+            Err(e) => {
+                held.store(false, Ordering::Release);
+                return Err(e);
+            }
+        };
+
+        // The max threads of the query is already capped by the quota.
+        let max_threads = ctx.get_settings().get_max_threads()?.clamp(1, max_cpu);
+        let available = semaphore.available_permits() as u64;
+        let (threads, rest) = match (max_threads - 1).min(available) {
+            0 => (1, None),
+            n => match semaphore.try_acquire_many_owned(n as u32) {
+                Ok(permit) => (1 + n, Some(permit)),
+                Err(_) => (1, None),
+            },
+        };
+        ctx.get_settings().set_max_threads(threads)?;
+
+        Ok(Some(CpuQuotaPermit {
+            threads,
+            held,
+            _first: first,
+            _rest: rest,
+        }))
+    }
+
+    // The semaphore is recreated if the quota of the user is altered, the queries running with
+    // the old one give their threads back to it. The semaphores not held by any query are evicted.
+    fn get_cpu_quota(&self, key: String, max_cpu: u64) -> Arc<Semaphore> {
+        let mut quotas = self.cpu_quotas.lock();
+        quotas.retain(|k, quota| k == &key || Arc::strong_count(&quota.semaphore) > 1);
+        match quotas.get(&key) {
+            Some(quota) if quota.max_cpu == max_cpu => quota.semaphore.clone(),
+            _ => {
+                let permits = max_cpu.min(u32::MAX as u64) as usize;
+                let semaphore = Arc::new(Semaphore::new(permits));
+                quotas.insert(key, UserCpuQuota {
+                    max_cpu,
+                    semaphore: semaphore.clone(),
+                });
+                semaphore
+            }
+        }
+    }
+
+    async fn wait_cpu_quota(
+        ctx: &Arc<QueryContext>,
+        semaphore: Arc<Semaphore>,
+    ) -> Result<OwnedSemaphorePermit> {
+        // The acquiring future is kept across the checks to hold the position in the queue.
+        let mut acquire = Box::pin(semaphore.acquire_owned());
+        loop {
+            let tick = Box::pin(tokio::time::sleep(WAIT_CHECK_INTERVAL));
+            match futures::future::select(acquire, tick).await {
+                Either::Left((permit, _)) => {
+                    return permit.map_err(|e| ErrorCode::Internal(e.to_string()));
+                }
+                Either::Right((_, pending)) => {
+                    ctx.check_aborting()?;
+                    acquire = pending;
+                }
+            }
+        }
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cpu_quota;
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
@@ -22,8 +23,11 @@ mod session_mgr;
 mod session_mgr_status;
 mod session_status;
 mod session_type;
+mod storage_quota;
+mod workload_queue;
 
 pub use common_catalog::table_context::TableContext;
+pub use cpu_quota::CpuQuotaPermit;
 pub use query_affect::QueryAffect;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::short_sql;
@@ -35,3 +39,7 @@ pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use storage_quota::check_storage_quota;
+pub use storage_quota::release_storage_quota;
+pub use workload_queue::WorkloadPermit;
+pub use workload_queue::WorkloadQueueManager;
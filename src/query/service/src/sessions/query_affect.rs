@@ -32,4 +32,8 @@ pub enum QueryAffect {
         values: Vec<String>,
         is_globals: Vec<bool>,
     },
+    // An explicit transaction begins or ends.
+    Transaction {
+        active: bool,
+    },
 }
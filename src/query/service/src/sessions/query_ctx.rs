@@ -19,6 +19,7 @@ use std::collections::VecDeque;
 use std::future::Future;
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -36,12 +37,14 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::date_helper::TzFactory;
 use common_expression::FunctionContext;
+use common_io::prelude::BinaryFormat;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
@@ -70,7 +73,9 @@ use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::sessions::check_storage_quota;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::release_storage_quota;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
@@ -114,6 +119,22 @@ impl QueryContext {
         })
     }
 
+    // The max threads of the query can not exceed the cpu quota of the current user.
+    fn apply_cpu_quota(&self) {
+        let max_cpu = match self.shared.get_current_user() {
+            Ok(user) => user.quota.max_cpu,
+            Err(_) => return,
+        };
+        if max_cpu == 0 {
+            return;
+        }
+        if let Ok(max_threads) = self.query_settings.get_max_threads() {
+            if max_threads > max_cpu {
+                let _ = self.query_settings.set_max_threads(max_cpu);
+            }
+        }
+    }
+
     // Build fuse/system normal table by table info.
     fn build_table_by_table_info(
         &self,
@@ -203,6 +224,17 @@ impl QueryContext {
         self.shared.set_affect(affect)
     }
 
+    /// Get the latest version of the table, it's not cached in the query context.
+    #[async_backtrace::framed]
+    pub async fn refresh_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        self.shared.refresh_table(catalog, database, table).await
+    }
+
     pub fn set_id(&self, id: String) {
         *self.shared.init_query_id.write() = id;
     }
@@ -211,6 +243,18 @@ impl QueryContext {
         self.shared.set_executor(weak_ptr)
     }
 
+    /// Mark the query as waiting in the queue of its workload group or for the cpu quota of
+    /// its user, shown in `system.processes`.
+    pub fn set_queued(&self, queued: bool) {
+        self.shared.set_queued(queued)
+    }
+
+    // Set while the query holds the threads from the cpu quota of the user, the nested
+    // queries sharing the context don't take the threads again.
+    pub(in crate::sessions) fn get_cpu_quota_held(&self) -> Arc<AtomicBool> {
+        self.shared.cpu_quota_held.clone()
+    }
+
     pub fn attach_stage(&self, attachment: StageAttachment) {
         self.shared.attach_stage(attachment);
     }
@@ -395,7 +439,15 @@ impl TableContext for QueryContext {
         let timezone = tz.parse::<Tz>().map_err(|_| {
             ErrorCode::InvalidTimezone("Timezone has been checked and should be valid")
         })?;
-        let format = FormatSettings { timezone };
+        let binary_format = self
+            .query_settings
+            .get_binary_format()?
+            .parse::<BinaryFormat>()
+            .map_err(ErrorCode::InvalidArgument)?;
+        let format = FormatSettings {
+            timezone,
+            binary_format,
+        };
         Ok(format)
     }
 
@@ -436,6 +488,8 @@ impl TableContext for QueryContext {
                 self.query_settings.unchecked_apply_changes(session_change);
             }
         }
+        // The max threads may be changed after the session settings are applied.
+        self.apply_cpu_quota();
         self.query_settings.clone()
     }
 
@@ -531,6 +585,10 @@ impl TableContext for QueryContext {
         Ok(self.shared.data_operator.clone())
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.txn_mgr()
+    }
+
     #[async_backtrace::framed]
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams> {
         match StageFileFormatType::from_str(name) {
@@ -563,6 +621,20 @@ impl TableContext for QueryContext {
         self.shared.get_table(catalog, database, table).await
     }
 
+    #[async_backtrace::framed]
+    async fn check_storage_quota(&self, table_info: &TableInfo, added_bytes: i64) -> Result<()> {
+        check_storage_quota(self, table_info, added_bytes).await
+    }
+
+    #[async_backtrace::framed]
+    async fn release_storage_quota(
+        &self,
+        table_info: &TableInfo,
+        reserved_bytes: i64,
+    ) -> Result<()> {
+        release_storage_quota(self, table_info, reserved_bytes).await
+    }
+
     #[async_backtrace::framed]
     async fn filter_out_copied_files(
         &self,
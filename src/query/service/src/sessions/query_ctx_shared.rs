@@ -22,7 +22,9 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_catalog::catalog::Catalog;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::OnErrorMode;
@@ -91,6 +93,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
+    // Waiting in the queue of the workload group or for the cpu quota of the user.
+    pub(in crate::sessions) queued: Arc<AtomicBool>,
+    // Holding the threads from the cpu quota of the user.
+    pub(in crate::sessions) cpu_quota_held: Arc<AtomicBool>,
 }
 
 impl QueryContextShared {
@@ -123,6 +129,8 @@ impl QueryContextShared {
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
+            queued: Arc::new(AtomicBool::new(false)),
+            cpu_quota_held: Arc::new(AtomicBool::new(false)),
         }))
     }
 
@@ -271,6 +279,7 @@ impl QueryContextShared {
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(catalog)?;
         let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let cache_table = self.apply_txn_table(catalog.as_ref(), cache_table)?;
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -280,6 +289,37 @@ impl QueryContextShared {
         }
     }
 
+    /// Get the latest version of the table, bypassing the table cache of the query.
+    #[async_backtrace::framed]
+    pub async fn refresh_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let tenant = self.get_tenant();
+        let catalog = self.catalog_manager.get_catalog(catalog)?;
+        let table = catalog.get_table(tenant.as_str(), database, table).await?;
+        self.apply_txn_table(catalog.as_ref(), table)
+    }
+
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.session.txn_mgr()
+    }
+
+    // The table mutated in the explicit transaction is seen with its uncommitted meta.
+    fn apply_txn_table(
+        &self,
+        catalog: &dyn Catalog,
+        table: Arc<dyn Table>,
+    ) -> Result<Arc<dyn Table>> {
+        let table_info = self.session.txn_mgr().lock().get_table_info(table.get_id());
+        match table_info {
+            Some(table_info) => catalog.get_table_by_info(&table_info),
+            None => Ok(table),
+        }
+    }
+
     /// Init runtime when first get
     pub fn try_get_runtime(&self) -> Result<Arc<Runtime>> {
         let mut query_runtime = self.runtime.write();
@@ -373,6 +413,14 @@ impl QueryContextShared {
         let status = self.status.read();
         status.clone()
     }
+
+    pub fn set_queued(&self, queued: bool) {
+        self.queued.store(queued, Ordering::Release);
+    }
+
+    pub fn is_queued(&self) -> bool {
+        self.queued.load(Ordering::Acquire)
+    }
 }
 
 impl Drop for QueryContextShared {
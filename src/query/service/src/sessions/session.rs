@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,6 +27,7 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_sql::PreparedStatement;
 use common_users::RoleCacheManager;
 use common_users::BUILTIN_ROLE_PUBLIC;
 use parking_lot::RwLock;
@@ -365,6 +367,22 @@ impl Session {
         self.session_ctx
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
+
+    pub fn add_prepared_statement(self: &Arc<Self>, stmt: PreparedStatement) -> u32 {
+        self.session_ctx.add_prepared_statement(stmt)
+    }
+
+    pub fn get_prepared_statement(self: &Arc<Self>, id: u32) -> Option<Arc<PreparedStatement>> {
+        self.session_ctx.get_prepared_statement(id)
+    }
+
+    pub fn remove_prepared_statement(self: &Arc<Self>, id: u32) {
+        self.session_ctx.remove_prepared_statement(id)
+    }
+
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
 }
 
 impl Drop for Session {
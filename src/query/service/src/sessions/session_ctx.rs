@@ -16,16 +16,20 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_sql::PreparedStatement;
 use parking_lot::RwLock;
 
 use super::SessionType;
@@ -57,6 +61,11 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // Prepared statements of MySQL protocol, they live until closed or the session ends.
+    prepared_statements: RwLock<HashMap<u32, Arc<PreparedStatement>>>,
+    next_statement_id: AtomicU32,
+    // The explicit transaction of the session, it lives across the statements.
+    txn_mgr: TxnManagerRef,
     typ: SessionType,
 }
 
@@ -75,6 +84,9 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            prepared_statements: Default::default(),
+            next_statement_id: AtomicU32::new(1),
+            txn_mgr: TxnManager::init(),
             typ,
         }))
     }
@@ -280,4 +292,26 @@ impl SessionContext {
         let lock = self.query_ids_results.read();
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
+
+    // Save the prepared statement and return its id.
+    pub fn add_prepared_statement(&self, stmt: PreparedStatement) -> u32 {
+        let id = self.next_statement_id.fetch_add(1, Ordering::Relaxed);
+        let mut lock = self.prepared_statements.write();
+        lock.insert(id, Arc::new(stmt));
+        id
+    }
+
+    pub fn get_prepared_statement(&self, id: u32) -> Option<Arc<PreparedStatement>> {
+        let lock = self.prepared_statements.read();
+        lock.get(&id).cloned()
+    }
+
+    pub fn remove_prepared_statement(&self, id: u32) {
+        let mut lock = self.prepared_statements.write();
+        lock.remove(&id);
+    }
+
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
 }
@@ -64,6 +64,7 @@ impl Session {
         match status.get_query_context_shared() {
             _ if status.get_abort() => String::from("Aborting"),
             None => String::from("Idle"),
+            Some(shared) if shared.is_queued() => String::from("Queued"),
             Some(_) => String::from("Query"),
         }
     }
@@ -33,9 +33,11 @@ use common_metrics::label_gauge;
 use common_settings::Settings;
 use futures::future::Either;
 use futures::StreamExt;
+use parking_lot::Mutex;
 use parking_lot::RwLock;
 use tracing::info;
 
+use crate::sessions::cpu_quota::UserCpuQuota;
 use crate::sessions::session::Session;
 use crate::sessions::ProcessInfo;
 use crate::sessions::SessionContext;
@@ -54,6 +56,9 @@ pub struct SessionManager {
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+
+    // tenant/user identity -> the cpu quota of the user on this node.
+    pub(in crate::sessions) cpu_quotas: Mutex<HashMap<String, UserCpuQuota>>,
 }
 
 impl SessionManager {
@@ -71,6 +76,7 @@ impl SessionManager {
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            cpu_quotas: Mutex::new(HashMap::new()),
         })
     }
 
@@ -0,0 +1,170 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::TableInfo;
+use common_users::UserApiProvider;
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use storages_common_table_meta::table::OPT_KEY_OWNER;
+
+use crate::sessions::QueryContext;
+
+// How long the storage usage of a user is trusted before the tables are listed again.
+const USAGE_TTL: Duration = Duration::from_secs(60);
+
+struct StorageUsage {
+    bytes: u64,
+    refreshed_at: Instant,
+}
+
+/// The compressed bytes of the tables owned by each user, keyed by (tenant, owner).
+///
+/// The usage is computed by listing the tables of the tenant once per `USAGE_TTL`, and is
+/// adjusted by the commits of this node in between, so that a commit does not list the tables.
+static STORAGE_USAGE: Lazy<DashMap<(String, String), StorageUsage>> = Lazy::new(DashMap::new);
+
+/// Check that committing `added_bytes` to the table keeps the tables of its owner within the
+/// storage quota of the owner, no matter which user writes the table. The tables created
+/// before the owner is recorded are counted in the quota of the current user.
+///
+/// The bytes are reserved in the usage once checked. If the commit is retried, the reservation
+/// is released by `release_storage_quota` first, other failed commits are corrected by the
+/// next refresh.
+pub async fn check_storage_quota(
+    ctx: &QueryContext,
+    table_info: &TableInfo,
+    added_bytes: i64,
+) -> Result<()> {
+    let Some((key, max_storage)) = get_storage_quota(ctx, table_info).await? else {
+        return Ok(());
+    };
+
+    let stale = match STORAGE_USAGE.get(&key) {
+        Some(usage) => usage.refreshed_at.elapsed() > USAGE_TTL,
+        None => true,
+    };
+    if stale {
+        let bytes = list_storage_usage(ctx, &key.0, &key.1, table_info.catalog()).await?;
+        STORAGE_USAGE.insert(key.clone(), StorageUsage {
+            bytes,
+            refreshed_at: Instant::now(),
+        });
+    }
+
+    let mut usage = STORAGE_USAGE
+        .get_mut(&key)
+        .ok_or_else(|| ErrorCode::Internal("storage usage must be cached"))?;
+    if added_bytes > 0 && usage.bytes + added_bytes as u64 > max_storage {
+        return Err(ErrorCode::UserQuotaExceeded(format!(
+            "Max storage quota exceeded: {}, the tables of user {} use {} bytes, appending {} bytes",
+            max_storage, key.1, usage.bytes, added_bytes
+        )));
+    }
+    usage.bytes = usage.bytes.saturating_add_signed(added_bytes);
+    Ok(())
+}
+
+/// Give back the bytes reserved by `check_storage_quota` for a commit which did not change the
+/// table, so that the retried commit does not count them twice.
+pub async fn release_storage_quota(
+    ctx: &QueryContext,
+    table_info: &TableInfo,
+    reserved_bytes: i64,
+) -> Result<()> {
+    let Some((key, _)) = get_storage_quota(ctx, table_info).await? else {
+        return Ok(());
+    };
+    if let Some(mut usage) = STORAGE_USAGE.get_mut(&key) {
+        usage.bytes = usage
+            .bytes
+            .saturating_add_signed(reserved_bytes.saturating_neg());
+    }
+    Ok(())
+}
+
+// The key of the storage usage of the owner of the table and the storage quota of the owner,
+// None if the storage is not limited.
+async fn get_storage_quota(
+    ctx: &QueryContext,
+    table_info: &TableInfo,
+) -> Result<Option<((String, String), u64)>> {
+    // The internal queries without a user are not limited.
+    let Ok(current_user) = ctx.get_current_user() else {
+        return Ok(None);
+    };
+    let tenant = ctx.get_tenant();
+    let owner = match table_info.options().get(OPT_KEY_OWNER) {
+        Some(owner) => owner.clone(),
+        None => current_user.identity().to_string(),
+    };
+    let max_storage = get_owner(current_user, &tenant, &owner)
+        .await?
+        .quota
+        .max_storage_in_bytes;
+    if max_storage == 0 {
+        return Ok(None);
+    }
+    Ok(Some(((tenant, owner), max_storage)))
+}
+
+async fn get_owner(current_user: UserInfo, tenant: &str, owner: &str) -> Result<UserInfo> {
+    if current_user.identity().to_string() == owner {
+        return Ok(current_user);
+    }
+    let Some(identity) = parse_user_identity(owner) else {
+        return Ok(current_user);
+    };
+    match UserApiProvider::instance().get_user(tenant, identity).await {
+        Ok(user) => Ok(user),
+        // The owner is dropped, count the table in the quota of the current user.
+        Err(e) if e.code() == ErrorCode::UNKNOWN_USER => Ok(current_user),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parse the identity formatted as `'name'@'host'`.
+fn parse_user_identity(s: &str) -> Option<UserIdentity> {
+    let s = s.strip_prefix('\'')?.strip_suffix('\'')?;
+    let (name, host) = s.rsplit_once("'@'")?;
+    Some(UserIdentity::new(name, host))
+}
+
+async fn list_storage_usage(
+    ctx: &QueryContext,
+    tenant: &str,
+    owner: &str,
+    catalog: &str,
+) -> Result<u64> {
+    let catalog = ctx.get_catalog(catalog)?;
+    let mut used = 0;
+    for db in catalog.list_databases(tenant).await? {
+        if db.get_db_info().meta.from_share.is_some() {
+            continue;
+        }
+        for table in catalog.list_tables(tenant, db.name()).await? {
+            if table.options().get(OPT_KEY_OWNER).map(|s| s.as_str()) == Some(owner) {
+                used += table.get_table_info().meta.statistics.compressed_data_bytes;
+            }
+        }
+    }
+    Ok(used)
+}
@@ -0,0 +1,328 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::GlobalInstance;
+use common_base::runtime::MemStat;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::WorkloadGroup;
+use common_users::UserApiProvider;
+use futures::future::Either;
+use parking_lot::Mutex;
+use tracing::info;
+
+use crate::sessions::QueryContext;
+
+// The kinds of the statements which are admitted by the workload groups,
+// other statements such as DDL, SET and KILL are never queued.
+const ADMITTED_QUERY_KINDS: &[&str] = &[
+    "Query",
+    "ExplainAnalyze",
+    "Insert",
+    "Replace",
+    "Copy",
+    "Delete",
+    "Update",
+    "MergeInto",
+    "CreateTable",
+    "OptimizeTable",
+    "ReclusterTable",
+    "AnalyzeTable",
+];
+
+// How often a queued query checks if it is killed.
+const QUEUE_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+// How long the workload group of a user and role is trusted before it is read from the meta
+// again. The changes made on this node invalidate the cache at once, the changes made on other
+// nodes take effect within the TTL.
+const GROUP_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// The queue of a workload group on this query node.
+pub struct WorkloadQueue {
+    group: WorkloadGroup,
+    // None if the concurrency of the group is not limited.
+    semaphore: Option<Arc<Semaphore>>,
+    // The memory used by the running queries of the group, None if not limited.
+    mem_stat: Option<Arc<MemStat>>,
+    running: AtomicUsize,
+    queued: AtomicUsize,
+}
+
+impl WorkloadQueue {
+    fn create(group: WorkloadGroup, server_memory: u64) -> Arc<WorkloadQueue> {
+        let semaphore = match group.max_concurrency {
+            0 => None,
+            n => Some(Arc::new(Semaphore::new(n as usize))),
+        };
+        let mem_stat = match group.memory_share {
+            0 => None,
+            share => {
+                let mem_stat = MemStat::create_child(format!("WorkloadGroup-{}", group.name), None);
+                mem_stat.set_limit((server_memory / 100 * share) as i64);
+                Some(mem_stat)
+            }
+        };
+        Arc::new(WorkloadQueue {
+            group,
+            semaphore,
+            mem_stat,
+            running: AtomicUsize::new(0),
+            queued: AtomicUsize::new(0),
+        })
+    }
+
+    /// The number of the running and the queued queries.
+    pub fn status(&self) -> (usize, usize) {
+        (
+            self.running.load(Ordering::Relaxed),
+            self.queued.load(Ordering::Relaxed),
+        )
+    }
+}
+
+/// Held by a query admitted by its workload group, the slot is released when dropped.
+pub struct WorkloadPermit {
+    queue: Arc<WorkloadQueue>,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl WorkloadPermit {
+    pub fn group_name(&self) -> &str {
+        &self.queue.group.name
+    }
+
+    pub fn mem_stat(&self) -> Option<Arc<MemStat>> {
+        self.queue.mem_stat.clone()
+    }
+}
+
+impl Drop for WorkloadPermit {
+    fn drop(&mut self) {
+        self.queue.running.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+struct CachedGroup {
+    group: Option<WorkloadGroup>,
+    cached_at: Instant,
+}
+
+// (tenant, user identity, current role)
+type GroupCacheKey = (String, String, Option<String>);
+
+pub struct WorkloadQueueManager {
+    // tenant/group name -> queue
+    queues: Mutex<HashMap<String, Arc<WorkloadQueue>>>,
+    // the workload group of a user and role
+    groups: Mutex<HashMap<GroupCacheKey, CachedGroup>>,
+}
+
+impl WorkloadQueueManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(WorkloadQueueManager {
+            queues: Mutex::new(HashMap::new()),
+            groups: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    /// Forget the cached workload groups of the tenant, called after the workload groups
+    /// or the assignments of the tenant are changed.
+    pub fn invalidate(&self, tenant: &str) {
+        self.groups.lock().retain(|(t, _, _), _| t != tenant);
+    }
+
+    pub fn instance() -> Arc<WorkloadQueueManager> {
+        GlobalInstance::get()
+    }
+
+    /// The number of the running and the queued queries of the group on this node.
+    pub fn get_status(&self, tenant: &str, group: &str) -> (usize, usize) {
+        let queues = self.queues.lock();
+        match queues.get(&format!("{}/{}", tenant, group)) {
+            Some(queue) => queue.status(),
+            None => (0, 0),
+        }
+    }
+
+    // The queue is recreated if the group is altered, the queries running in the old queue
+    // release their slots to the old one.
+    //
+    // The idle queues are evicted here. The queries running or queued hold the queue, and the
+    // queue is only cloned with the lock held, so a queue referenced only by the map is idle.
+    fn get_queue(
+        &self,
+        tenant: &str,
+        group: WorkloadGroup,
+        server_memory: u64,
+    ) -> Arc<WorkloadQueue> {
+        let key = format!("{}/{}", tenant, group.name);
+        let mut queues = self.queues.lock();
+        queues.retain(|k, queue| k == &key || Arc::strong_count(queue) > 1);
+        match queues.get(&key) {
+            Some(queue) if queue.group == group => queue.clone(),
+            _ => {
+                let queue = WorkloadQueue::create(group, server_memory);
+                queues.insert(key, queue.clone());
+                queue
+            }
+        }
+    }
+
+    /// Wait until the query is admitted by the workload group of the current user or role.
+    ///
+    /// Returns None if the query is not in any workload group.
+    #[async_backtrace::framed]
+    pub async fn acquire(&self, ctx: &Arc<QueryContext>) -> Result<Option<WorkloadPermit>> {
+        if !ADMITTED_QUERY_KINDS.contains(&ctx.get_query_kind().as_str()) {
+            return Ok(None);
+        }
+        let group = match self.get_workload_group(ctx).await? {
+            None => return Ok(None),
+            Some(group) => group,
+        };
+
+        let server_memory = ctx.get_settings().get_max_memory_usage()?;
+        let queue = self.get_queue(&ctx.get_tenant(), group, server_memory);
+        let semaphore = match &queue.semaphore {
+            None => {
+                queue.running.fetch_add(1, Ordering::Relaxed);
+                return Ok(Some(WorkloadPermit {
+                    queue,
+                    _permit: None,
+                }));
+            }
+            Some(semaphore) => semaphore.clone(),
+        };
+
+        queue.queued.fetch_add(1, Ordering::Relaxed);
+        ctx.set_queued(true);
+        let res = Self::wait(ctx, &queue.group, semaphore).await;
+        ctx.set_queued(false);
+        queue.queued.fetch_sub(1, Ordering::Relaxed);
+
+        let permit = res?;
+        queue.running.fetch_add(1, Ordering::Relaxed);
+        Ok(Some(WorkloadPermit {
+            queue,
+            _permit: Some(permit),
+        }))
+    }
+
+    async fn wait(
+        ctx: &Arc<QueryContext>,
+        group: &WorkloadGroup,
+        semaphore: Arc<Semaphore>,
+    ) -> Result<OwnedSemaphorePermit> {
+        let start = Instant::now();
+        let timeout = match group.queue_timeout {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        };
+
+        // The acquiring future is kept across the checks to hold the position in the queue.
+        let mut acquire = Box::pin(semaphore.acquire_owned());
+        loop {
+            let tick = Box::pin(tokio::time::sleep(QUEUE_CHECK_INTERVAL));
+            match futures::future::select(acquire, tick).await {
+                Either::Left((permit, _)) => {
+                    info!(
+                        "query {} admitted by workload group {} after queued for {:?}",
+                        ctx.get_id(),
+                        group.name,
+                        start.elapsed()
+                    );
+                    return permit.map_err(|e| ErrorCode::Internal(e.to_string()));
+                }
+                Either::Right((_, pending)) => {
+                    ctx.check_aborting()?;
+                    if matches!(timeout, Some(timeout) if start.elapsed() >= timeout) {
+                        return Err(ErrorCode::WorkloadGroupQueueTimeout(format!(
+                            "Query waited in the queue of workload group {} for more than {} seconds",
+                            group.name, group.queue_timeout
+                        )));
+                    }
+                    acquire = pending;
+                }
+            }
+        }
+    }
+
+    async fn get_workload_group(&self, ctx: &Arc<QueryContext>) -> Result<Option<WorkloadGroup>> {
+        let user = match ctx.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return Ok(None),
+        };
+        let key = (
+            ctx.get_tenant(),
+            user.identity().to_string(),
+            ctx.get_current_role().map(|role| role.name),
+        );
+        if let Some(cached) = self.groups.lock().get(&key) {
+            if cached.cached_at.elapsed() < GROUP_CACHE_TTL {
+                return Ok(cached.group.clone());
+            }
+        }
+
+        let cached_at = Instant::now();
+        let group = Self::read_workload_group(&key.0, user.identity(), key.2.clone()).await?;
+        self.groups.lock().insert(key, CachedGroup {
+            group: group.clone(),
+            cached_at,
+        });
+        Ok(group)
+    }
+
+    // The group of the user takes precedence over the group of the current role.
+    async fn read_workload_group(
+        tenant: &str,
+        user: UserIdentity,
+        role: Option<String>,
+    ) -> Result<Option<WorkloadGroup>> {
+        let user_mgr = UserApiProvider::instance();
+        let mut principals = vec![PrincipalIdentity::User(user)];
+        if let Some(role) = role {
+            principals.push(PrincipalIdentity::Role(role));
+        }
+        for principal in principals {
+            let name = match user_mgr
+                .get_workload_group_assignment(tenant, &principal)
+                .await?
+            {
+                None => continue,
+                Some(name) => name,
+            };
+            match user_mgr.get_workload_group(tenant, &name).await {
+                Ok(group) => return Ok(Some(group)),
+                Err(e) if e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(None)
+    }
+}
@@ -181,6 +181,7 @@ impl TestFixture {
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
             ]
             .into(),
+            engine_options: Default::default(),
             field_comments: vec![],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
@@ -204,6 +205,7 @@ impl TestFixture {
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
             ]
             .into(),
+            engine_options: Default::default(),
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
@@ -238,6 +240,7 @@ impl TestFixture {
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
             ]
             .into(),
+            engine_options: Default::default(),
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
@@ -281,6 +284,7 @@ impl TestFixture {
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
             ]
             .into(),
+            engine_options: Default::default(),
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
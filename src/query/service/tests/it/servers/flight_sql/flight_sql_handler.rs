@@ -17,11 +17,16 @@
 use std::fs;
 use std::io::Write;
 
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetTables;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
 use common_base::base::tokio;
 use common_config::InnerConfig;
@@ -75,6 +80,34 @@ async fn run_query(
     Ok(res)
 }
 
+async fn fetch_batches(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> std::result::Result<Vec<RecordBatch>, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    flight_data_to_batches(&flight_data)
+}
+
+fn string_column(batches: &[RecordBatch], name: &str) -> Vec<String> {
+    batches
+        .iter()
+        .flat_map(|batch| {
+            let column = batch
+                .column_by_name(name)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            column
+                .iter()
+                .map(|v| v.unwrap_or_default().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -148,3 +181,134 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_metadata() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        run_query(&mut client, "create table meta_t1(a int)")
+            .await
+            .unwrap();
+        run_query(&mut client, "create view meta_v1 as select * from meta_t1")
+            .await
+            .unwrap();
+
+        let info = client.get_catalogs().await.unwrap();
+        let batches = fetch_batches(&mut client, info).await.unwrap();
+        assert!(string_column(&batches, "catalog_name").contains(&"default".to_string()));
+
+        let info = client
+            .get_db_schemas(CommandGetDbSchemas {
+                catalog: Some("default".to_string()),
+                db_schema_filter_pattern: Some("def%".to_string()),
+            })
+            .await
+            .unwrap();
+        let batches = fetch_batches(&mut client, info).await.unwrap();
+        assert_eq!(string_column(&batches, "db_schema_name"), vec!["default"]);
+
+        let info = client
+            .get_tables(CommandGetTables {
+                catalog: Some("default".to_string()),
+                db_schema_filter_pattern: Some("default".to_string()),
+                table_name_filter_pattern: Some("meta_%".to_string()),
+                table_types: vec![],
+                include_schema: true,
+            })
+            .await
+            .unwrap();
+        let batches = fetch_batches(&mut client, info).await.unwrap();
+        let mut tables = string_column(&batches, "table_name")
+            .into_iter()
+            .zip(string_column(&batches, "table_type"))
+            .collect::<Vec<_>>();
+        tables.sort();
+        assert_eq!(tables, vec![
+            ("meta_t1".to_string(), "BASE TABLE".to_string()),
+            ("meta_v1".to_string(), "VIEW".to_string()),
+        ]);
+        assert!(batches[0].column_by_name("table_schema").is_some());
+
+        let info = client.get_table_types().await.unwrap();
+        let batches = fetch_batches(&mut client, info).await.unwrap();
+        assert_eq!(string_column(&batches, "table_type"), vec![
+            "BASE TABLE",
+            "VIEW"
+        ]);
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_of_other_session() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path.clone()).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let mut other = client_with_uds(path).await;
+        other.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        let mut stmt = client.prepare("select 1".to_string()).await.unwrap();
+        let flight_info = stmt.execute().await.unwrap();
+        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+
+        // The handle of a statement is only valid in the session creating it.
+        let err = other.do_get(ticket.clone()).await.unwrap_err();
+        assert!(err.to_string().contains("prepared statement not found"));
+        let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+        assert_eq!(batches[0].num_rows(), 1);
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
@@ -540,6 +540,50 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_txn() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let route = create_endpoint().await?;
+
+    let json = serde_json::json!({"sql": "create table t_txn(a int) engine=fuse", "pagination": {"wait_time_secs": 3}});
+    let (status, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+
+    let json = serde_json::json!({"sql": "begin"});
+    let (status, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    let session = result.session.unwrap();
+    assert_eq!(session.txn_session_id, result.session_id);
+
+    // the inserted rows are only visible in the transaction before commit.
+    let json = serde_json::json!({"sql": "insert into t_txn values (1),(2)", "session": session, "pagination": {"wait_time_secs": 3}});
+    let (status, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+
+    let json = serde_json::json!({"sql": "select * from t_txn", "session": session});
+    let (_, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(result.data.len(), 2, "{:?}", result);
+
+    let json = serde_json::json!({"sql": "select * from t_txn"});
+    let (_, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(result.data.len(), 0, "{:?}", result);
+
+    let json = serde_json::json!({"sql": "commit", "session": session, "pagination": {"wait_time_secs": 3}});
+    let (status, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.session.unwrap().txn_session_id, None);
+
+    let json = serde_json::json!({"sql": "select * from t_txn"});
+    let (_, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(result.data.len(), 2, "{:?}", result);
+    Ok(())
+}
+
 // Wait for https://github.com/datafuselabs/databend/issues/7831 to be fixed, then remove ignore
 #[ignore]
 #[tokio::test(flavor = "current_thread")]
@@ -1184,6 +1228,7 @@ async fn test_affect() -> Result<()> {
                     ("max_threads".to_string(), "1".to_string()),
                     ("timezone".to_string(), "Asia/Shanghai".to_string()),
                 ])),
+                txn_session_id: None,
             }),
         ),
         (
@@ -1196,6 +1241,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                txn_session_id: None,
             }),
         ),
         (
@@ -1210,6 +1256,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                txn_session_id: None,
             }),
         ),
     ];
@@ -49,6 +49,144 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let stmt = connection
+        .prep("SELECT ? + 1, ?, ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(stmt.num_params(), 3);
+
+    for (num, text) in [(1i64, "a"), (-3, "it's")] {
+        let rows: Vec<(i64, String, Option<String>)> = connection
+            .exec(&stmt, (num, text, None::<String>))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+        assert_eq!(rows, vec![(num + 1, text.to_string(), None)]);
+    }
+
+    connection
+        .close(stmt)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close failed")?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement_with_table() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    connection
+        .query_drop("CREATE TABLE t_prepared(a INT, b STRING)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table failed")?;
+
+    let insert = connection
+        .prep("INSERT INTO t_prepared VALUES (?, ?), (? + 1, concat(?, '!'))")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    connection
+        .exec_drop(&insert, (1i64, "x", 1i64, "y"))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+
+    let select = connection
+        .prep("SELECT a, b FROM t_prepared WHERE a >= ? ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    // The bound plan is reused by the second execution.
+    for (min, expected) in [(0i64, vec![(1, "x"), (2, "y!")]), (2, vec![(2, "y!")])] {
+        let rows: Vec<(i64, String)> = connection
+            .exec(&select, (min,))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+        let expected = expected
+            .into_iter()
+            .map(|(a, b)| (a, b.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, expected);
+    }
+
+    // The plan is bound again after the table is changed.
+    connection
+        .exec_drop(&insert, (3i64, "z", 3i64, "w"))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    let rows: Vec<(i64, String)> = connection
+        .exec(&select, (2i64,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![
+        (2, "y!".to_string()),
+        (3, "z".to_string()),
+        (4, "w!".to_string())
+    ]);
+
+    let limit = connection
+        .prep("SELECT a FROM t_prepared ORDER BY a LIMIT ? OFFSET ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let rows: Vec<i64> = connection
+        .exec(&limit, (2i64, 1i64))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![2, 3]);
+
+    // The plan is bound again after the settings used by binder are changed.
+    let to_string = connection
+        .prep("SELECT to_string(to_timestamp(?))")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let rows: Vec<String> = connection
+        .exec(&to_string, (0i64,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert!(rows[0].starts_with("1970-01-01 00:00:00"));
+    connection
+        .query_drop("SET timezone = 'Asia/Shanghai'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Set timezone failed")?;
+    let rows: Vec<String> = connection
+        .exec(&to_string, (0i64,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert!(rows[0].starts_with("1970-01-01 08:00:00"));
+
+    // The parameters of DDL are bound as literals in the AST.
+    let create_view = connection
+        .prep("CREATE VIEW v_prepared AS SELECT ? AS c")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    connection
+        .exec_drop(&create_view, ("it's -- not a comment",))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    let rows: Vec<String> = connection
+        .query("SELECT c FROM v_prepared")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(rows, vec!["it's -- not a comment".to_string()]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let _guard =
@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::create_query_context;
+use databend_query::test_kits::create_query_context_with_session;
+
+async fn set_max_cpu(ctx: &Arc<QueryContext>, max_cpu: u64) -> Result<()> {
+    let mut user = ctx.get_current_user()?;
+    user.name = "cpu_quota_user".to_string();
+    user.quota.max_cpu = max_cpu;
+    ctx.get_current_session().set_authed_user(user, None).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_cpu_quota_concurrent_queries() -> Result<()> {
+    let (guard, ctx1) = create_query_context().await?;
+    let (guard, ctx2) = create_query_context_with_session(SessionType::Dummy, Some(guard)).await?;
+    set_max_cpu(&ctx1, 4).await?;
+    set_max_cpu(&ctx2, 4).await?;
+    ctx1.get_settings().set_max_threads(3)?;
+
+    let manager = SessionManager::instance();
+    let permit1 = manager.acquire_cpu_quota(&ctx1).await?;
+    assert_eq!(permit1.as_ref().map(|p| p.threads()), Some(3));
+    assert_eq!(ctx1.get_settings().get_max_threads()?, 3);
+
+    // The second query of the user runs with the thread left in the quota.
+    let permit2 = manager.acquire_cpu_quota(&ctx2).await?;
+    assert_eq!(permit2.as_ref().map(|p| p.threads()), Some(1));
+    assert_eq!(ctx2.get_settings().get_max_threads()?, 1);
+
+    // No thread is left, the third query waits until a query finishes.
+    let (_guard, ctx3) = create_query_context_with_session(SessionType::Dummy, Some(guard)).await?;
+    set_max_cpu(&ctx3, 4).await?;
+    let queued = {
+        let ctx3 = ctx3.clone();
+        let manager = manager.clone();
+        tokio::spawn(async move { manager.acquire_cpu_quota(&ctx3).await })
+    };
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert!(!queued.is_finished());
+
+    drop(permit1);
+    let permit3 = queued.await.unwrap()?;
+    assert_eq!(permit3.as_ref().map(|p| p.threads()), Some(3));
+    assert_eq!(ctx3.get_settings().get_max_threads()?, 3);
+
+    // The nested queries sharing the context don't take the threads again.
+    assert!(manager.acquire_cpu_quota(&ctx2).await?.is_none());
+    drop(permit2);
+    drop(permit3);
+    let permit2 = manager.acquire_cpu_quota(&ctx2).await?;
+    assert_eq!(permit2.as_ref().map(|p| p.threads()), Some(1));
+
+    Ok(())
+}
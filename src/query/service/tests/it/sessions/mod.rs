@@ -11,7 +11,9 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+mod cpu_quota;
 mod query_ctx;
 mod session;
 mod session_context;
 mod session_setting;
+mod workload_queue;
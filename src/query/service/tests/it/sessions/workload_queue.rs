@@ -0,0 +1,126 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::WorkloadGroup;
+use common_users::UserApiProvider;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::sessions::WorkloadQueueManager;
+use databend_query::test_kits::create_query_context;
+use databend_query::test_kits::create_query_context_with_session;
+
+async fn assign_workload_group(ctx: &Arc<QueryContext>, group: WorkloadGroup) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let user_mgr = UserApiProvider::instance();
+    let principal = PrincipalIdentity::User(ctx.get_current_user()?.identity());
+    let name = group.name.clone();
+    user_mgr.add_workload_group(&tenant, group, false).await?;
+    user_mgr
+        .set_workload_group_assignment(&tenant, &principal, Some(&name))
+        .await?;
+    WorkloadQueueManager::instance().invalidate(&tenant);
+    Ok(())
+}
+
+fn process_state(ctx: &Arc<QueryContext>) -> Option<String> {
+    let session_id = ctx.get_current_session().get_id();
+    SessionManager::instance()
+        .processes_info()
+        .into_iter()
+        .find(|process| process.id == session_id)
+        .map(|process| process.state)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_workload_queue() -> Result<()> {
+    let (guard, ctx1) = create_query_context().await?;
+    let (_guard, ctx2) = create_query_context_with_session(SessionType::Dummy, Some(guard)).await?;
+    ctx1.attach_query_str("Query".to_string(), "select 1".to_string());
+    ctx2.attach_query_str("Query".to_string(), "select 2".to_string());
+
+    let mut group = WorkloadGroup::new("g1");
+    group.max_concurrency = 1;
+    assign_workload_group(&ctx1, group).await?;
+
+    let manager = WorkloadQueueManager::instance();
+    let tenant = ctx1.get_tenant();
+    let permit = manager.acquire(&ctx1).await?;
+    assert_eq!(permit.as_ref().map(|p| p.group_name()), Some("g1"));
+    assert_eq!(manager.get_status(&tenant, "g1"), (1, 0));
+
+    // The second query waits until the first one finishes.
+    let queued = {
+        let ctx2 = ctx2.clone();
+        let manager = manager.clone();
+        tokio::spawn(async move { manager.acquire(&ctx2).await })
+    };
+    let mut state = None;
+    for _ in 0..50 {
+        state = process_state(&ctx2);
+        if state.as_deref() == Some("Queued") {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(state.as_deref(), Some("Queued"));
+    assert_eq!(manager.get_status(&tenant, "g1"), (1, 1));
+    assert!(!queued.is_finished());
+
+    drop(permit);
+    let permit = queued.await.unwrap()?;
+    assert!(permit.is_some());
+    assert_eq!(process_state(&ctx2).as_deref(), Some("Query"));
+    assert_eq!(manager.get_status(&tenant, "g1"), (1, 0));
+
+    drop(permit);
+    assert_eq!(manager.get_status(&tenant, "g1"), (0, 0));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_workload_queue_timeout() -> Result<()> {
+    let (guard, ctx1) = create_query_context().await?;
+    let (_guard, ctx2) = create_query_context_with_session(SessionType::Dummy, Some(guard)).await?;
+    ctx1.attach_query_str("Query".to_string(), "select 1".to_string());
+    ctx2.attach_query_str("Query".to_string(), "select 2".to_string());
+
+    let mut group = WorkloadGroup::new("g2");
+    group.max_concurrency = 1;
+    group.queue_timeout = 1;
+    assign_workload_group(&ctx1, group).await?;
+
+    let manager = WorkloadQueueManager::instance();
+    let _permit = manager.acquire(&ctx1).await?;
+    match manager.acquire(&ctx2).await {
+        Err(e) => assert_eq!(e.code(), ErrorCode::WORKLOAD_GROUP_QUEUE_TIMEOUT),
+        Ok(_) => panic!("the query must time out in the queue"),
+    }
+    assert_eq!(manager.get_status(&ctx1.get_tenant(), "g2"), (1, 0));
+
+    // The statements not admitted by the workload groups are never queued.
+    ctx2.attach_query_str("Set".to_string(), "set max_threads = 1".to_string());
+    assert!(manager.acquire(&ctx2).await?.is_none());
+
+    Ok(())
+}
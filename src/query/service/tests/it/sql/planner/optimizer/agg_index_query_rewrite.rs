@@ -75,6 +75,7 @@ fn create_table_plan(fixture: &TestFixture) -> CreateTablePlan {
             (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
         ]
         .into(),
+        engine_options: Default::default(),
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_base::base::tokio;
 use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::Scalar;
 use common_storages_factory::Table;
 use common_storages_fuse::FuseTable;
 use common_storages_fuse::TableContext;
@@ -117,3 +119,27 @@ async fn test_fuse_snapshot_analyze_purge() -> Result<()> {
         .await?;
     check_data_dir(&fixture, case_name, 1, 1, 1, 1, 1, Some(()), Some(())).await
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_snapshot_analyze_histogram() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    do_insertions(&fixture).await?;
+    analyze_table(&fixture).await?;
+
+    // after the overwrite, all the values of column `id` are 1
+    let table = fixture.latest_default_table().await?;
+    let num_rows = table.get_table_info().meta.statistics.number_of_rows;
+    let provider = table.column_statistics_provider().await?;
+    let stats = provider.column_statistics(0).unwrap();
+    assert_eq!(stats.number_of_distinct_values, 1);
+
+    let histogram = stats.histogram.unwrap();
+    assert_eq!(histogram.len(), 1);
+    assert_eq!(
+        histogram[0].upper_bound,
+        Scalar::Number(NumberScalar::Int32(1))
+    );
+    assert_eq!(histogram[0].num_values as u64, num_rows);
+    assert_eq!(histogram[0].num_distinct as u64, 1);
+    Ok(())
+}
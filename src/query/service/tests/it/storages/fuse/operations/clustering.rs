@@ -50,6 +50,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
             (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
         ]
         .into(),
+        engine_options: Default::default(),
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
@@ -29,6 +29,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -525,6 +526,10 @@ impl TableContext for CtxDelegation {
         self.ctx.get_data_operator()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
     async fn get_file_format(&self, _name: &str) -> Result<FileFormatParams> {
         todo!()
     }
@@ -538,6 +543,20 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn check_storage_quota(&self, table_info: &TableInfo, added_bytes: i64) -> Result<()> {
+        self.ctx.check_storage_quota(table_info, added_bytes).await
+    }
+
+    async fn release_storage_quota(
+        &self,
+        table_info: &TableInfo,
+        reserved_bytes: i64,
+    ) -> Result<()> {
+        self.ctx
+            .release_storage_quota(table_info, reserved_bytes)
+            .await
+    }
+
     async fn filter_out_copied_files(
         &self,
         _catalog_name: &str,
@@ -112,6 +112,12 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("binary_format", DefaultSettingValue {
+                    value: UserSettingValue::String("hex".to_owned()),
+                    desc: "Sets the text encoding of BINARY values. Available values include \"hex\" and \"base64\".",
+                    possible_values: Some(vec!["hex", "base64"]),
+                    display_in_show_settings: true,
+                }),
                 ("group_by_two_level_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(20000),
                     desc: "Sets the number of keys in a GROUP BY operation that will trigger a two-level aggregation.",
@@ -267,7 +273,13 @@ impl DefaultSettings {
                 }),
                 ("spilling_bytes_threshold_per_proc", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
-                    desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
+                    desc: "Sets the maximum amount of memory in bytes that an aggregator or a hash join can use before spilling data to storage during query execution.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("sort_spilling_bytes_threshold_per_proc", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of memory in bytes that a sort can use before spilling sorted runs to storage, 0 disables the spilling.",
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
@@ -295,6 +307,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("max_recursive_cte_iterations", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum number of iterations a recursive CTE can run before the query fails. Setting it to 0 means no limit.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("max_recursive_cte_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10_000_000),
+                    desc: "Sets the maximum number of rows a recursive CTE can produce before the query fails. Setting it to 0 means no limit.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
 
                 // enterprise license related settings
                 ("enterprise_license", DefaultSettingValue {
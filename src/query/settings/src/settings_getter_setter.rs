@@ -181,6 +181,10 @@ impl Settings {
         self.try_get_string("timezone")
     }
 
+    pub fn get_binary_format(&self) -> Result<String> {
+        self.try_get_string("binary_format")
+    }
+
     // Get group by two level threshold
     pub fn get_group_by_two_level_threshold(&self) -> Result<u64> {
         self.try_get_u64("group_by_two_level_threshold")
@@ -318,6 +322,14 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_sort_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("sort_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn set_sort_spilling_bytes_threshold_per_proc(&self, value: usize) -> Result<()> {
+        self.try_set_u64("sort_spilling_bytes_threshold_per_proc", value as u64)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -342,6 +354,14 @@ impl Settings {
         self.try_get_u64("parquet_fast_read_bytes")
     }
 
+    pub fn get_max_recursive_cte_iterations(&self) -> Result<u64> {
+        self.try_get_u64("max_recursive_cte_iterations")
+    }
+
+    pub fn get_max_recursive_cte_rows(&self) -> Result<u64> {
+        self.try_get_u64("max_recursive_cte_rows")
+    }
+
     pub fn set_table_lock_expire_secs(&self, val: u64) -> Result<()> {
         self.try_set_u64("table_lock_expire_secs", val)
     }
@@ -43,6 +43,8 @@ use crate::executor::ExchangeSource;
 use crate::executor::FragmentKind;
 use crate::executor::RangeJoin;
 use crate::executor::RangeJoinType;
+use crate::executor::RecursiveCteScan;
+use crate::executor::RecursiveUnion;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Window;
 use crate::planner::MetadataRef;
@@ -149,6 +151,10 @@ fn to_format_tree(
             runtime_filter_source_to_format_tree(plan, metadata, prof_span_set)
         }
         PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::RecursiveUnion(plan) => {
+            recursive_union_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan, metadata),
     }
 }
 
@@ -197,6 +203,12 @@ fn table_scan_to_format_tree(
         .as_ref()
         .and_then(|extras| extras.agg_index.as_ref());
 
+    let vector_index = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_index.as_ref());
+
     let mut children = vec![FormatTreeNode::new(format!("table: {table_name}"))];
 
     // Part stats.
@@ -243,6 +255,13 @@ fn table_scan_to_format_tree(
         };
         children.push(FormatTreeNode::new(text));
     }
+    // Vector index
+    if let Some(vector_index) = vector_index {
+        children.push(FormatTreeNode::new(format!(
+            "vector index: [column: {}, distance: {}, limit: {}]",
+            vector_index.column_name, vector_index.func_name, vector_index.limit
+        )));
+    }
 
     let output_columns = plan.source.output_schema.fields();
 
@@ -852,6 +871,61 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_union_to_format_tree(
+    plan: &RecursiveUnion,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "cte: {}",
+        metadata.read().recursive_cte_name(plan.cte_index)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.extend(vec![
+        to_format_tree(&plan.left, metadata, prof_span_set)?,
+        to_format_tree(&plan.right, metadata, prof_span_set)?,
+    ]);
+
+    let name = if plan.distinct {
+        "RecursiveUnion"
+    } else {
+        "RecursiveUnionAll"
+    };
+    Ok(FormatTreeNode::with_children(name.to_string(), children))
+}
+
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &MetadataRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "cte: {}",
+        metadata.read().recursive_cte_name(plan.cte_index)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -70,6 +70,8 @@ use crate::executor::LagLeadDefault;
 use crate::executor::LagLeadFunctionDesc;
 use crate::executor::PhysicalJoinType;
 use crate::executor::PhysicalPlan;
+use crate::executor::RecursiveCteScan;
+use crate::executor::RecursiveUnion;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::SortDesc;
 use crate::executor::UnionAll;
@@ -989,6 +991,57 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::RecursiveUnion(op) => {
+                let left_plan = self.build(s_expr.child(0)?).await?;
+                let right_plan = self.build(s_expr.child(1)?).await?;
+                let left_schema = left_plan.output_schema()?;
+
+                // The outputs of both sides have been cast to the same types in binder.
+                let fields = op
+                    .pairs
+                    .iter()
+                    .map(|(l, _)| {
+                        let field = left_schema.field_with_name(&l.to_string())?;
+                        Ok(DataField::new(&l.to_string(), field.data_type().clone()))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let pairs = op
+                    .pairs
+                    .iter()
+                    .map(|(l, r)| (l.to_string(), r.to_string()))
+                    .collect::<Vec<_>>();
+
+                Ok(PhysicalPlan::RecursiveUnion(RecursiveUnion {
+                    plan_id: self.next_plan_id(),
+                    left: Box::new(left_plan),
+                    right: Box::new(right_plan),
+                    cte_index: op.cte_index,
+                    pairs,
+                    distinct: op.distinct,
+                    schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::RecursiveCteScan(scan) => {
+                let fields = {
+                    let metadata = self.metadata.read();
+                    scan.columns
+                        .iter()
+                        .map(|index| {
+                            DataField::new(&index.to_string(), metadata.column(*index).data_type())
+                        })
+                        .collect::<Vec<_>>()
+                };
+
+                Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_index: scan.cte_index,
+                    output_schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -1489,6 +1542,7 @@ impl PhysicalPlanBuilder {
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index,
+            vector_index: scan.vector_index.clone(),
         })
     }
 
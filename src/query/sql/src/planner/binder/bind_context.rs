@@ -178,6 +178,17 @@ pub struct BindContext {
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// If the cte is declared with `WITH RECURSIVE`
+    pub recursive: bool,
+    /// The working table of a recursive cte, only set while binding its recursive term
+    pub working_table: Option<WorkingTable>,
+}
+
+/// Rows produced by the previous iteration of a recursive cte.
+#[derive(Clone, Debug)]
+pub struct WorkingTable {
+    pub cte_index: IndexType,
+    pub columns: Vec<(String, DataType)>,
 }
 
 impl BindContext {
@@ -39,6 +39,7 @@ use tracing::warn;
 
 use crate::binder::wrap_cast;
 use crate::normalize_identifier;
+use crate::planner::prepared_statement::format_params;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CallPlan;
@@ -55,6 +56,7 @@ use crate::plans::RewriteKind;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowWorkloadGroupsPlan;
 use crate::plans::UseDatabasePlan;
 use crate::BindContext;
 use crate::ColumnBinding;
@@ -153,7 +155,8 @@ impl<'a> Binder {
             Statement::Query(query) => {
                 let (s_expr, bind_context) = self.bind_query(bind_context, query).await?;
                 let formatted_ast = if self.ctx.get_settings().get_enable_query_result_cache()? {
-                    Some(format_statement(stmt.clone())?)
+                    let params = self.metadata.read().parameters();
+                    Some(format_statement(stmt.clone())? + &format_params(&params))
                 } else {
                     None
                 };
@@ -265,6 +268,8 @@ impl<'a> Binder {
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumns(stmt) => self.bind_create_virtual_columns(stmt).await?,
@@ -348,6 +353,14 @@ impl<'a> Binder {
                 }
                 self.bind_update(bind_context, stmt).await?
             },
+            Statement::MergeInto(stmt) => {
+                if let Some(hints) = &stmt.hints {
+                    if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
+                        warn!("In MERGE INTO resolve optimize hints {:?} failed, err: {:?}", hints, e);
+                    }
+                }
+                self.bind_merge_into(bind_context, stmt).await?
+            },
 
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
@@ -356,6 +369,12 @@ impl<'a> Binder {
             })),
             Statement::Revoke(stmt) => self.bind_revoke(stmt).await?,
 
+            // Workload groups
+            Statement::CreateWorkloadGroup(stmt) => self.bind_create_workload_group(stmt).await?,
+            Statement::AlterWorkloadGroup(stmt) => self.bind_alter_workload_group(stmt).await?,
+            Statement::DropWorkloadGroup(stmt) => self.bind_drop_workload_group(stmt).await?,
+            Statement::ShowWorkloadGroups => Plan::ShowWorkloadGroups(Box::new(ShowWorkloadGroupsPlan {})),
+
             // File Formats
             Statement::CreateFileFormat{  if_not_exists, name, file_format_options} =>  {
                 if StageFileFormatType::from_str(name).is_ok() {
@@ -468,6 +487,10 @@ impl<'a> Binder {
                     .await?
             }
 
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Rollback => Plan::Rollback,
+
             // share statements
             Statement::CreateShareEndpoint(stmt) => {
                 self.bind_create_share_endpoint(stmt).await?
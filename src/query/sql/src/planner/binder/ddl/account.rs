@@ -23,6 +23,7 @@ use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 use common_users::UserApiProvider;
 
 use crate::plans::AlterUserPlan;
@@ -156,13 +157,16 @@ impl Binder {
             user_options,
         } = stmt;
         let mut user_option = UserOption::default();
+        let mut user_quota = UserQuota::no_limit();
         for option in user_options {
             option.apply(&mut user_option);
+            option.apply_quota(&mut user_quota);
         }
         let plan = CreateUserPlan {
             user: user.clone(),
             auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
             user_option,
+            user_quota,
             if_not_exists: *if_not_exists,
         };
         Ok(Plan::CreateUser(Box::new(plan)))
@@ -210,18 +214,26 @@ impl Binder {
         };
 
         let mut user_option = user_info.option.clone();
+        let mut user_quota = user_info.quota.clone();
         for option in user_options {
             option.apply(&mut user_option);
+            option.apply_quota(&mut user_quota);
         }
         let new_user_option = if user_option == user_info.option {
             None
         } else {
             Some(user_option)
         };
+        let new_user_quota = if user_quota == user_info.quota {
+            None
+        } else {
+            Some(user_quota)
+        };
         let plan = AlterUserPlan {
             user: user_info.identity(),
             auth_info: new_auth_info,
             user_option: new_user_option,
+            user_quota: new_user_quota,
         };
 
         Ok(Plan::AlterUser(Box::new(plan)))
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateInvertedIndexStmt;
 use common_ast::ast::DropIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
 use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
@@ -21,10 +23,13 @@ use common_ast::ast::SetExpr;
 use common_ast::ast::TableReference;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableDataType;
 
 use crate::binder::Binder;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::Plan;
 use crate::BindContext;
 
@@ -97,6 +102,88 @@ impl Binder {
         Ok(Plan::DropIndex(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let index_name = self.normalize_object_identifier(index_name);
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support create inverted index",
+                table_info.engine()
+            )));
+        }
+        let schema = table_info.schema();
+        let mut column_names = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = self.normalize_object_identifier(column);
+            let field = schema.field_with_name(&column)?;
+            if field.computed_expr().is_some()
+                || !matches!(field.data_type().remove_nullable(), TableDataType::String)
+            {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Inverted index column {} must be of type String, but got {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+            if !column_names.contains(&column) {
+                column_names.push(column);
+            }
+        }
+
+        Ok(Plan::CreateInvertedIndex(Box::new(
+            CreateInvertedIndexPlan {
+                if_not_exists: *if_not_exists,
+                index_name,
+                catalog,
+                database,
+                table,
+                columns: column_names,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let index_name = self.normalize_object_identifier(index_name);
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        Ok(Plan::DropInvertedIndex(Box::new(DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        })))
+    }
+
     fn check_index_support(query: &Query) -> Result<()> {
         let err = Err(ErrorCode::UnsupportedIndex(format!(
             "Currently create index just support simple query, like: {}",
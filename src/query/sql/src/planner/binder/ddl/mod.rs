@@ -24,3 +24,4 @@ mod stage;
 mod table;
 mod view;
 mod virtual_column;
+mod workload_group;
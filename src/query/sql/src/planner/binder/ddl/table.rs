@@ -29,8 +29,10 @@ use common_ast::ast::DropTableStmt;
 use common_ast::ast::Engine;
 use common_ast::ast::ExistsTableStmt;
 use common_ast::ast::Expr;
+use common_ast::ast::ExternalTableLocation;
 use common_ast::ast::Identifier;
 use common_ast::ast::Literal;
+use common_ast::ast::ModifyColumnAction as AstModifyColumnAction;
 use common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use common_ast::ast::OptimizeTableStmt;
 use common_ast::ast::RenameTableStmt;
@@ -48,6 +50,7 @@ use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Dialect;
+use common_catalog::plan::ParquetReadOptions;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -58,21 +61,27 @@ use common_expression::ComputedExpr;
 use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::FileFormatParams;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
+use common_storages_parquet::ParquetTable;
+use common_storages_stage::ExternalTableOptions;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use parking_lot::RwLock;
 use storages_common_table_meta::table::is_reserved_opt_key;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_OWNER;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use tracing::debug;
 
+use crate::binder::copy::parse_stage_location_v2;
 use crate::binder::location::parse_uri_location;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::Binder;
@@ -93,11 +102,13 @@ use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::ModifyColumnAction;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
+use crate::plans::RefreshTablePlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
@@ -328,6 +339,7 @@ impl Binder {
             transient,
             engine,
             uri_location,
+            external_location,
         } = stmt;
 
         let (catalog, database, table) =
@@ -375,8 +387,31 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        if external_location.is_some() && engine != Engine::External {
+            return Err(ErrorCode::BadArguments(
+                "LOCATION is only supported for EXTERNAL engine table",
+            ));
+        }
+
         // Build table schema
+        let mut engine_options = BTreeMap::new();
         let (schema, field_comments) = match (&source, &as_query) {
+            _ if engine == Engine::External => {
+                // `CREATE TABLE ... ENGINE = EXTERNAL LOCATION = @stage/path`
+                let location = external_location.as_ref().ok_or_else(|| {
+                    ErrorCode::BadArguments("LOCATION is required for EXTERNAL engine table")
+                })?;
+                if as_query.is_some() {
+                    return Err(ErrorCode::BadArguments(
+                        "CREATE TABLE AS SELECT is not supported for EXTERNAL engine table",
+                    ));
+                }
+                let (schema, field_comments, external_options) =
+                    self.analyze_external_table(location, source).await?;
+                Self::validate_create_table_schema(&schema)?;
+                engine_options = BTreeMap::try_from(external_options)?;
+                (schema, field_comments)
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -441,6 +476,10 @@ impl Binder {
                 .await?;
             let db_id = db.get_db_info().ident.db_id;
             options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+            options.insert(
+                OPT_KEY_OWNER.to_owned(),
+                self.ctx.get_current_user()?.identity().to_string(),
+            );
 
             let config = GlobalConfig::instance();
             let is_blocking_fs = matches!(
@@ -506,6 +545,7 @@ impl Binder {
             storage_params,
             part_prefix,
             options,
+            engine_options,
             field_comments,
             cluster_key,
             as_select: if let Some(query) = as_query {
@@ -625,13 +665,50 @@ impl Binder {
                     field_comments,
                 })))
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => Ok(Plan::ModifyTableColumn(Box::new(ModifyTableColumnPlan {
+                catalog,
+                database,
+                table,
+                column: normalize_identifier(old_column, &self.name_resolution_ctx).name,
+                action: ModifyColumnAction::Rename(
+                    normalize_identifier(new_column, &self.name_resolution_ctx).name,
+                ),
+            }))),
             AlterTableAction::ModifyColumn { column, action } => {
+                let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+                let action = match action {
+                    AstModifyColumnAction::SetMaskingPolicy(mask_name) => {
+                        ModifyColumnAction::SetMaskingPolicy(mask_name.clone())
+                    }
+                    AstModifyColumnAction::SetDataType(type_name) => {
+                        ModifyColumnAction::SetDataType(resolve_type_name(type_name)?)
+                    }
+                    AstModifyColumnAction::SetDefault(default_expr) => {
+                        let schema = self
+                            .ctx
+                            .get_table(&catalog, &database, &table)
+                            .await?
+                            .schema();
+                        let field = schema.field_with_name(&column)?;
+                        let default_expr = self
+                            .analyze_default_expr(default_expr, field.data_type(), true)
+                            .await?;
+                        ModifyColumnAction::SetDefault(default_expr)
+                    }
+                    AstModifyColumnAction::DropDefault => ModifyColumnAction::DropDefault,
+                    AstModifyColumnAction::Comment(comment) => {
+                        ModifyColumnAction::Comment(comment.clone())
+                    }
+                };
                 Ok(Plan::ModifyTableColumn(Box::new(ModifyTableColumnPlan {
                     catalog,
                     database,
                     table,
-                    column: column.to_string(),
-                    action: action.clone(),
+                    column,
+                    action,
                 })))
             }
             AlterTableAction::DropColumn { column } => {
@@ -668,6 +745,12 @@ impl Binder {
                     table,
                 },
             ))),
+            AlterTableAction::RefreshTable => Ok(Plan::RefreshTable(Box::new(RefreshTablePlan {
+                tenant,
+                catalog,
+                database,
+                table,
+            }))),
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -911,12 +994,16 @@ impl Binder {
         })))
     }
 
+    /// Bind a column default expression and cast it to the column type.
+    /// Deterministic expressions are folded to a constant. If `require_constant` is set,
+    /// non-deterministic expressions are rejected.
     #[async_backtrace::framed]
-    async fn analyze_create_table_schema_by_columns(
+    async fn analyze_default_expr(
         &self,
-        columns: &[ColumnDefinition],
-        is_add_column: bool,
-    ) -> Result<(TableSchemaRef, Vec<String>)> {
+        default_expr: &Expr,
+        data_type: &TableDataType,
+        require_constant: bool,
+    ) -> Result<String> {
         let mut bind_context = BindContext::new();
         let mut scalar_binder = ScalarBinder::new(
             &mut bind_context,
@@ -925,7 +1012,42 @@ impl Binder {
             self.metadata.clone(),
             &[],
         );
+        let (expr, _) = scalar_binder.bind(default_expr).await?;
+        let cast_expr = ScalarExpr::CastExpr(CastExpr {
+            span: expr.span(),
+            is_try: data_type.is_nullable(),
+            target_type: Box::new(DataType::from(data_type)),
+            argument: Box::new(expr),
+        })
+        .as_expr()?;
+
+        // Added or modified columns are not allowed to use expressions,
+        // as the default values of the existing rows will be generated at each query.
+        if require_constant && !cast_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::SemanticError(format!(
+                "default expression `{}` is not a valid constant. Please provide a valid constant expression as the default value.",
+                cast_expr.sql_display(),
+            )));
+        }
+        let expr = if cast_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            let (fold_to_constant, _) = ConstantFolder::fold(
+                &cast_expr,
+                &self.ctx.get_function_context()?,
+                &BUILTIN_FUNCTIONS,
+            );
+            fold_to_constant
+        } else {
+            cast_expr
+        };
+        Ok(expr.sql_display())
+    }
 
+    #[async_backtrace::framed]
+    async fn analyze_create_table_schema_by_columns(
+        &self,
+        columns: &[ColumnDefinition],
+        is_add_column: bool,
+    ) -> Result<(TableSchemaRef, Vec<String>)> {
         let mut has_computed = false;
         let mut fields = Vec::with_capacity(columns.len());
         let mut fields_comments = Vec::with_capacity(columns.len());
@@ -938,35 +1060,10 @@ impl Binder {
             if let Some(expr) = &column.expr {
                 match expr {
                     ColumnExpr::Default(default_expr) => {
-                        let (expr, _) = scalar_binder.bind(default_expr).await?;
-                        let is_try = schema_data_type.is_nullable();
-                        let cast_expr = ScalarExpr::CastExpr(CastExpr {
-                            span: expr.span(),
-                            is_try,
-                            target_type: Box::new(DataType::from(&schema_data_type)),
-                            argument: Box::new(expr),
-                        })
-                        .as_expr()?;
-
-                        // Added columns are not allowed to use expressions,
-                        // as the default values will be generated at at each query.
-                        if is_add_column && !cast_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
-                            return Err(ErrorCode::SemanticError(format!(
-                                "default expression `{}` is not a valid constant. Please provide a valid constant expression as the default value.",
-                                cast_expr.sql_display(),
-                            )));
-                        }
-                        let expr = if cast_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
-                            let (fold_to_constant, _) = ConstantFolder::fold(
-                                &cast_expr,
-                                &self.ctx.get_function_context()?,
-                                &BUILTIN_FUNCTIONS,
-                            );
-                            fold_to_constant
-                        } else {
-                            cast_expr
-                        };
-                        field = field.with_default_expr(Some(expr.sql_display()));
+                        let default_expr = self
+                            .analyze_default_expr(default_expr, &schema_data_type, is_add_column)
+                            .await?;
+                        field = field.with_default_expr(Some(default_expr));
                     }
                     _ => {
                         has_computed = true;
@@ -1122,6 +1219,145 @@ impl Binder {
         }
     }
 
+    // Resolve the stage, file format and schema of an external table.
+    //
+    // The schema of parquet files can be inferred from the first file if the columns are absent,
+    // and the partition columns are always placed after the columns of the files.
+    #[async_backtrace::framed]
+    async fn analyze_external_table(
+        &self,
+        location: &ExternalTableLocation,
+        source: &Option<CreateTableSource>,
+    ) -> Result<(TableSchemaRef, Vec<String>, ExternalTableOptions)> {
+        if location.location.name == "~" {
+            return Err(ErrorCode::BadArguments(
+                "EXTERNAL engine table can not be created on the user stage",
+            ));
+        }
+        let (mut stage_info, path) =
+            parse_stage_location_v2(&self.ctx, &location.location.name, &location.location.path)
+                .await?;
+        if !location.file_format.is_empty() {
+            stage_info.file_format_params =
+                self.try_resolve_file_format(&location.file_format).await?;
+        }
+        let partition_by = location
+            .partition_by
+            .iter()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .collect::<Vec<_>>();
+        let options = ExternalTableOptions {
+            stage: stage_info.stage_name.clone(),
+            path,
+            file_format: stage_info.file_format_params.clone(),
+            pattern: location.pattern.clone(),
+            partition_by,
+        };
+
+        let declared = match source {
+            Some(source) => Some(self.analyze_create_table_schema(source).await?),
+            None => None,
+        };
+        if !matches!(options.file_format, FileFormatParams::Parquet(_)) {
+            if !options.partition_by.is_empty() {
+                return Err(ErrorCode::BadArguments(
+                    "PARTITION BY is only supported for parquet external tables",
+                ));
+            }
+            let (schema, field_comments) = declared.ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Column definitions are required for external tables of file format {}",
+                    options.file_format.get_type().to_string()
+                ))
+            })?;
+            return Ok((schema, field_comments, options));
+        }
+
+        let file_schema = ParquetTable::create(
+            stage_info,
+            options.files_info(),
+            ParquetReadOptions::default(),
+            None,
+        )
+        .await?
+        .schema();
+        for name in &options.partition_by {
+            if file_schema.field_with_name(name).is_ok() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Partition column {name} is also a column of the files"
+                )));
+            }
+        }
+
+        let is_partition = |name: &String| options.partition_by.contains(name);
+        let (mut fields, mut field_comments) = match &declared {
+            Some((schema, comments)) => {
+                let mut fields = vec![];
+                let mut field_comments = vec![];
+                for (i, field) in schema.fields().iter().enumerate() {
+                    if !is_partition(field.name()) {
+                        fields.push(field.clone());
+                        field_comments.push(comments.get(i).cloned().unwrap_or_default());
+                    }
+                }
+                let declared_types = fields
+                    .iter()
+                    .map(|f| (f.name(), f.data_type()))
+                    .collect::<Vec<_>>();
+                let file_types = file_schema
+                    .fields()
+                    .iter()
+                    .map(|f| (f.name(), f.data_type()))
+                    .collect::<Vec<_>>();
+                if declared_types != file_types {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Columns of the table do not match the columns of the files: {}",
+                        file_schema
+                            .fields()
+                            .iter()
+                            .map(|f| format!("{} {}", f.name(), f.data_type().sql_name()))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )));
+                }
+                (fields, field_comments)
+            }
+            None => (file_schema.fields().clone(), vec![
+                String::new();
+                file_schema.fields().len()
+            ]),
+        };
+
+        // Partition columns are strings parsed from the paths, NULL if the path has no value.
+        for name in &options.partition_by {
+            let declared_field = declared.as_ref().and_then(|(schema, comments)| {
+                let i = schema.index_of(name).ok()?;
+                Some((schema.field(i).clone(), comments.get(i).cloned()))
+            });
+            match declared_field {
+                Some((field, comment)) => {
+                    if field.data_type().remove_nullable() != TableDataType::String {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Partition column {name} must be of type String, but got {}",
+                            field.data_type().sql_name()
+                        )));
+                    }
+                    fields.push(field);
+                    field_comments.push(comment.unwrap_or_default());
+                }
+                None => {
+                    fields.push(TableField::new(
+                        name,
+                        TableDataType::Nullable(Box::new(TableDataType::String)),
+                    ));
+                    field_comments.push(String::new());
+                }
+            }
+        }
+
+        Ok((TableSchemaRefExt::create(fields), field_comments, options))
+    }
+
     /// Validate the schema of the table to be created.
     fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
         // Check if there are duplicated column names
@@ -0,0 +1,115 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::AlterWorkloadGroupAction;
+use common_ast::ast::AlterWorkloadGroupStmt;
+use common_ast::ast::CreateWorkloadGroupStmt;
+use common_ast::ast::DropWorkloadGroupStmt;
+use common_ast::ast::WorkloadGroupOption;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+use common_users::UserApiProvider;
+
+use crate::plans::AlterWorkloadGroupPlan;
+use crate::plans::AssignWorkloadGroupPlan;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_workload_group(
+        &mut self,
+        stmt: &CreateWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let CreateWorkloadGroupStmt {
+            if_not_exists,
+            name,
+            options,
+        } = stmt;
+
+        let mut group = WorkloadGroup::new(name);
+        apply_workload_group_options(&mut group, options)?;
+        Ok(Plan::CreateWorkloadGroup(Box::new(
+            CreateWorkloadGroupPlan {
+                if_not_exists: *if_not_exists,
+                group,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_workload_group(
+        &mut self,
+        stmt: &AlterWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let AlterWorkloadGroupStmt { name, action } = stmt;
+
+        let plan = match action {
+            AlterWorkloadGroupAction::Set(options) => {
+                let mut group = UserApiProvider::instance()
+                    .get_workload_group(&self.ctx.get_tenant(), name)
+                    .await?;
+                apply_workload_group_options(&mut group, options)?;
+                Plan::AlterWorkloadGroup(Box::new(AlterWorkloadGroupPlan { group }))
+            }
+            AlterWorkloadGroupAction::AssignTo(principal) => {
+                Plan::AssignWorkloadGroup(Box::new(AssignWorkloadGroupPlan {
+                    group: name.clone(),
+                    principal: principal.clone(),
+                    unassign: false,
+                }))
+            }
+            AlterWorkloadGroupAction::UnassignFrom(principal) => {
+                Plan::AssignWorkloadGroup(Box::new(AssignWorkloadGroupPlan {
+                    group: name.clone(),
+                    principal: principal.clone(),
+                    unassign: true,
+                }))
+            }
+        };
+        Ok(plan)
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_workload_group(
+        &mut self,
+        stmt: &DropWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let DropWorkloadGroupStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropWorkloadGroup(Box::new(DropWorkloadGroupPlan {
+            if_exists: *if_exists,
+            name: name.clone(),
+        })))
+    }
+}
+
+fn apply_workload_group_options(
+    group: &mut WorkloadGroup,
+    options: &[WorkloadGroupOption],
+) -> Result<()> {
+    for option in options {
+        option.apply(group);
+    }
+    if group.memory_share > 100 {
+        return Err(ErrorCode::IllegalWorkloadGroup(format!(
+            "MEMORY_SHARE of workload group {} must be a percentage between 0 and 100, got {}",
+            group.name, group.memory_share
+        )));
+    }
+    Ok(())
+}
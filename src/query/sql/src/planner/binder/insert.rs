@@ -100,7 +100,10 @@ impl Binder {
             } => {
                 if format.to_uppercase() == "VALUES" {
                     let data = rest_str.trim_end_matches(';').trim_start().to_owned();
-                    Ok(InsertInputSource::Values(data))
+                    Ok(InsertInputSource::Values(
+                        data,
+                        self.metadata.read().parameters(),
+                    ))
                 } else {
                     Ok(InsertInputSource::StreamingWithFormat(format, start, None))
                 }
@@ -130,7 +133,10 @@ impl Binder {
                             )
                             .await;
                     }
-                    None => Ok(InsertInputSource::Values(values_str)),
+                    None => Ok(InsertInputSource::Values(
+                        values_str,
+                        self.metadata.read().parameters(),
+                    )),
                 }
             }
             InsertSource::Select { query } => {
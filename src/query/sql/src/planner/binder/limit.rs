@@ -18,6 +18,8 @@ use common_ast::ast::Expr;
 use common_ast::ast::Literal;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
 
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
@@ -25,12 +27,13 @@ use crate::plans::Limit;
 
 impl Binder {
     pub(super) fn analyze_limit(
+        &self,
         limit: Option<&Expr>,
         offset: &Option<Expr>,
     ) -> Result<(Option<usize>, usize)> {
         let limit_cnt = match limit {
             Some(limit) => Some(
-                Self::bind_limit_argument(limit)
+                self.bind_limit_argument(limit)
                     .ok_or_else(|| ErrorCode::SemanticError("Invalid LIMIT expression"))?
                     as usize,
             ),
@@ -38,7 +41,7 @@ impl Binder {
         };
 
         let offset_cnt = if let Some(offset) = offset {
-            Self::bind_limit_argument(offset)
+            self.bind_limit_argument(offset)
                 .ok_or_else(|| ErrorCode::SemanticError("Invalid OFFSET expression"))?
                 as usize
         } else {
@@ -53,15 +56,21 @@ impl Binder {
         SExpr::create_unary(Arc::new(limit_plan.into()), Arc::new(child))
     }
 
-    /// So far, we only support integer literal as limit argument.
-    /// So we will try to extract the integer value from the AST directly.
+    /// So far, we only support integer literal or placeholder of prepared statement as limit
+    /// argument. So we will try to extract the integer value from the AST directly.
     /// In the future it's possible to treat the argument as an expression.
-    fn bind_limit_argument(expr: &Expr) -> Option<u64> {
+    fn bind_limit_argument(&self, expr: &Expr) -> Option<u64> {
         match expr {
             Expr::Literal {
                 lit: Literal::UInt64(value),
                 ..
             } => Some(*value),
+            Expr::Placeholder { span } => match self.metadata.read().parameter(*span)? {
+                Scalar::Number(NumberScalar::UInt64(value)) => Some(*value),
+                Scalar::Number(NumberScalar::Int64(value)) => u64::try_from(*value).ok(),
+                Scalar::String(s) => std::str::from_utf8(s).ok()?.trim().parse().ok(),
+                _ => None,
+            },
             _ => None,
         }
     }
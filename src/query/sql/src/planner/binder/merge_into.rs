@@ -0,0 +1,421 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::ast::MergeOption;
+use common_ast::ast::TableReference;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ROW_ID_COL_NAME;
+
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::binder::INTERNAL_COLUMN_FACTORY;
+use crate::field_default_value;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
+use crate::plans::MatchedEvaluator;
+use crate::plans::MergeIntoPlan;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::UnmatchedEvaluator;
+use crate::BindContext;
+use crate::ColumnBinding;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table: table_ident,
+            target_alias,
+            source,
+            join_expr,
+            merge_options,
+            ..
+        } = stmt;
+
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(catalog, database, table_ident);
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        if !table.support_row_id_column() {
+            return Err(ErrorCode::from_string(
+                "table doesn't support row_id, so it can't be the target of merge into".to_string(),
+            ));
+        }
+        let schema = table.schema();
+        if let Some(field) = schema
+            .fields()
+            .iter()
+            .find(|field| field.computed_expr().is_some())
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Merge into table with computed column '{}' is not supported yet",
+                field.name()
+            )));
+        }
+
+        // Bind `source LEFT OUTER JOIN target`, the `_row_id` of target is null
+        // if a source row has no matched target row.
+        let target = TableReference::Table {
+            span: table_ident.span,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table_ident.clone(),
+            alias: target_alias.clone(),
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        };
+        let (source_expr, source_context) = self.bind_table_reference(bind_context, source).await?;
+        let (mut target_expr, mut target_context) =
+            self.bind_single_table(bind_context, &target).await?;
+        let mut scan = match &*target_expr.plan {
+            RelOperator::Scan(scan) => scan.clone(),
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The target of merge into must be a table, but got {}",
+                    table_name
+                ))
+                .set_span(table_ident.span));
+            }
+        };
+        let table_index = scan.table_index;
+        let internal_column = INTERNAL_COLUMN_FACTORY
+            .get_internal_column(ROW_ID_COL_NAME)
+            .unwrap();
+        let row_id_index = self
+            .metadata
+            .write()
+            .add_internal_column(table_index, internal_column.clone());
+        scan.columns.insert(row_id_index);
+        target_expr.plan = Arc::new(scan.into());
+        target_context.add_column_binding(ColumnBinding {
+            database_name: Some(database_name.clone()),
+            table_name: Some(table_name.clone()),
+            column_position: None,
+            table_index: Some(table_index),
+            column_name: ROW_ID_COL_NAME.to_string(),
+            index: row_id_index,
+            data_type: Box::new(internal_column.data_type()),
+            visibility: Visibility::InVisible,
+            virtual_computed_expr: None,
+        });
+
+        let join = Join {
+            op: JoinOperator::LeftOuter,
+            condition: JoinCondition::On(Box::new(join_expr.clone())),
+            left: Box::new(source.clone()),
+            right: Box::new(target),
+        };
+        let (input, mut join_context) = self
+            .bind_join(
+                bind_context,
+                source_context,
+                target_context,
+                source_expr,
+                target_expr,
+                &join,
+            )
+            .await?;
+
+        // The target columns of the joined rows, which are kept by UPDATE if not assigned.
+        let mut target_columns = HashMap::with_capacity(schema.num_fields());
+        let mut row_id_column = None;
+        for column in join_context.columns.iter() {
+            if column.table_index != Some(table_index) {
+                continue;
+            }
+            if column.index == row_id_index {
+                row_id_column = Some(column.clone());
+            } else if column.visibility == Visibility::Visible {
+                target_columns.insert(column.column_name.clone(), column.clone());
+            }
+        }
+        let row_id_column = row_id_column
+            .ok_or_else(|| ErrorCode::Internal("It's a bug, row_id column not found"))?;
+
+        let mut builder = MergeIntoOutputBuilder::default();
+        let row_id_offset = builder.add_column(row_id_column);
+        let mut matched_clauses = vec![];
+        let mut unmatched_clauses = vec![];
+        for option in merge_options {
+            match option {
+                MergeOption::Match(clause) => {
+                    let condition = self
+                        .bind_merge_into_condition(
+                            &mut join_context,
+                            &mut builder,
+                            &clause.selection,
+                        )
+                        .await?;
+                    let update = match &clause.operation {
+                        MatchOperation::Update { update_list } => {
+                            let mut assignments = HashMap::with_capacity(update_list.len());
+                            for update_expr in update_list {
+                                let col_name = normalize_identifier(
+                                    &update_expr.name,
+                                    &self.name_resolution_ctx,
+                                )
+                                .name;
+                                let index = schema.index_of(&col_name)?;
+                                if assignments.insert(index, &update_expr.expr).is_some() {
+                                    return Err(ErrorCode::BadArguments(format!(
+                                        "Multiple assignments in the single statement to column `{}`",
+                                        col_name
+                                    )));
+                                }
+                            }
+                            let mut offsets = Vec::with_capacity(schema.num_fields());
+                            for (index, field) in schema.fields().iter().enumerate() {
+                                let offset = match assignments.get(&index) {
+                                    Some(expr) => {
+                                        self.bind_merge_into_expr(
+                                            &mut join_context,
+                                            &mut builder,
+                                            expr,
+                                        )
+                                        .await?
+                                    }
+                                    None => {
+                                        let column =
+                                            target_columns.get(field.name()).ok_or_else(|| {
+                                                ErrorCode::Internal(format!(
+                                                    "It's a bug, column {} not found",
+                                                    field.name()
+                                                ))
+                                            })?;
+                                        builder.add_column(column.clone())
+                                    }
+                                };
+                                offsets.push(offset);
+                            }
+                            Some(offsets)
+                        }
+                        MatchOperation::Delete => None,
+                    };
+                    matched_clauses.push(MatchedEvaluator { condition, update });
+                }
+                MergeOption::Unmatch(clause) => {
+                    let condition = self
+                        .bind_merge_into_condition(
+                            &mut join_context,
+                            &mut builder,
+                            &clause.selection,
+                        )
+                        .await?;
+                    let field_indices = if clause.columns.is_empty() {
+                        (0..schema.num_fields()).collect::<Vec<_>>()
+                    } else {
+                        let mut field_indices = Vec::with_capacity(clause.columns.len());
+                        for column in clause.columns.iter() {
+                            let col_name =
+                                normalize_identifier(column, &self.name_resolution_ctx).name;
+                            let index = schema.index_of(&col_name)?;
+                            if field_indices.contains(&index) {
+                                return Err(ErrorCode::BadArguments(format!(
+                                    "Column `{}` is specified more than once",
+                                    col_name
+                                )));
+                            }
+                            field_indices.push(index);
+                        }
+                        field_indices
+                    };
+                    if field_indices.len() != clause.values.len() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Insert clause of merge into has {} columns but {} values",
+                            field_indices.len(),
+                            clause.values.len()
+                        )));
+                    }
+
+                    let mut values = Vec::with_capacity(schema.num_fields());
+                    for (index, field) in schema.fields().iter().enumerate() {
+                        let offset = match field_indices.iter().position(|i| *i == index) {
+                            Some(pos) => {
+                                self.bind_merge_into_expr(
+                                    &mut join_context,
+                                    &mut builder,
+                                    &clause.values[pos],
+                                )
+                                .await?
+                            }
+                            None => {
+                                let value = field_default_value(self.ctx.clone(), field)?;
+                                builder.add_scalar(
+                                    &self.metadata,
+                                    field.name(),
+                                    ScalarExpr::ConstantExpr(ConstantExpr { span: None, value }),
+                                )?
+                            }
+                        };
+                        values.push(offset);
+                    }
+                    unmatched_clauses.push(UnmatchedEvaluator { condition, values });
+                }
+            }
+        }
+
+        let (input, output_context) = builder.build(input);
+        Ok(Plan::MergeInto(Box::new(MergeIntoPlan {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            input: Box::new(input),
+            bind_context: Box::new(output_context),
+            metadata: self.metadata.clone(),
+            row_id_offset,
+            matched_clauses,
+            unmatched_clauses,
+        })))
+    }
+
+    // Bind the condition of the clause, which must be a boolean expression.
+    #[async_backtrace::framed]
+    async fn bind_merge_into_condition(
+        &mut self,
+        join_context: &mut BindContext,
+        builder: &mut MergeIntoOutputBuilder,
+        selection: &Option<Expr>,
+    ) -> Result<Option<usize>> {
+        let expr = match selection {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+        let offset = self
+            .bind_merge_into_expr(join_context, builder, expr)
+            .await?;
+        let data_type = builder.columns[offset].data_type.remove_nullable();
+        if data_type != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Condition of merge into clause must be a boolean expression, but got `{}` of type {}",
+                expr, data_type
+            ))
+            .set_span(expr.span()));
+        }
+        Ok(Some(offset))
+    }
+
+    // Bind the expression on the joined rows, returns its offset in the output.
+    #[async_backtrace::framed]
+    async fn bind_merge_into_expr(
+        &mut self,
+        join_context: &mut BindContext,
+        builder: &mut MergeIntoOutputBuilder,
+        expr: &Expr,
+    ) -> Result<usize> {
+        let mut scalar_binder = ScalarBinder::new(
+            join_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, _) = scalar_binder.bind(expr).await?;
+        if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
+            return Err(ErrorCode::Unimplemented(
+                "Merge into does not support subquery temporarily",
+            ));
+        }
+        match scalar {
+            ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }) => {
+                Ok(builder.add_column(column))
+            }
+            scalar => builder.add_scalar(&self.metadata, &expr.to_string(), scalar),
+        }
+    }
+}
+
+// Collects the output columns of the input of MERGE INTO.
+#[derive(Default)]
+struct MergeIntoOutputBuilder {
+    items: Vec<ScalarItem>,
+    columns: Vec<ColumnBinding>,
+    offsets: HashMap<IndexType, usize>,
+}
+
+impl MergeIntoOutputBuilder {
+    fn add_column(&mut self, column: ColumnBinding) -> usize {
+        if let Some(offset) = self.offsets.get(&column.index) {
+            return *offset;
+        }
+        let offset = self.columns.len();
+        self.offsets.insert(column.index, offset);
+        self.columns.push(column);
+        offset
+    }
+
+    fn add_scalar(
+        &mut self,
+        metadata: &MetadataRef,
+        name: &str,
+        scalar: ScalarExpr,
+    ) -> Result<usize> {
+        let data_type = scalar.data_type()?;
+        let index = metadata
+            .write()
+            .add_derived_column(name.to_string(), data_type.clone());
+        self.items.push(ScalarItem { scalar, index });
+        Ok(self.add_column(ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_position: None,
+            table_index: None,
+            column_name: name.to_string(),
+            index,
+            data_type: Box::new(data_type),
+            visibility: Visibility::Visible,
+            virtual_computed_expr: None,
+        }))
+    }
+
+    fn build(self, input: SExpr) -> (SExpr, BindContext) {
+        let mut bind_context = BindContext::new();
+        bind_context.columns = self.columns;
+        if self.items.is_empty() {
+            return (input, bind_context);
+        }
+        let s_expr = SExpr::create_unary(
+            Arc::new(EvalScalar { items: self.items }.into()),
+            Arc::new(input),
+        );
+        (s_expr, bind_context)
+    }
+}
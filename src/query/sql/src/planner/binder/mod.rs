@@ -29,9 +29,11 @@ mod join;
 mod kill;
 mod limit;
 mod location;
+mod merge_into;
 mod presign;
 mod project;
 mod project_set;
+mod recursive_cte;
 mod replace;
 mod scalar;
 mod scalar_common;
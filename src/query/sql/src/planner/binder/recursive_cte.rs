@@ -0,0 +1,352 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Join;
+use common_ast::ast::Query;
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableReference;
+use common_ast::walk_query;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::wrap_cast;
+use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::binder::CteInfo;
+use crate::binder::Visibility;
+use crate::binder::WorkingTable;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::NameResolutionContext;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::RecursiveCteScan;
+use crate::plans::RecursiveUnion;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+
+/// Max number of times the recursive term is bound to find out the column types of
+/// the working table. If the types are still not stable after that, the output of the
+/// recursive term will be cast to the types of the last pass.
+const MAX_TYPE_INFERENCE_PASSES: usize = 8;
+
+impl Binder {
+    /// Bind a recursive CTE, which must be in the form of
+    /// `anchor UNION [ALL] recursive_term`, and only the recursive term can
+    /// reference the CTE itself.
+    #[async_backtrace::framed]
+    pub(super) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "WITH, ORDER BY, LIMIT and OFFSET are not supported in recursive cte {table_name}"
+            ))
+            .set_span(span));
+        }
+        let (anchor, recursive_term, distinct) = match &query.body {
+            SetExpr::SetOperation(op) if op.op == SetOperator::Union => {
+                (op.left.as_ref(), op.right.as_ref(), !op.all)
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive cte {table_name} must be in the form of `anchor UNION [ALL] recursive_term`"
+                ))
+                .set_span(span));
+            }
+        };
+        if set_expr_references_table(anchor, table_name, &self.name_resolution_ctx) {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference to cte {table_name} must not appear in the anchor"
+            ))
+            .set_span(anchor.span()));
+        }
+
+        let (anchor_expr, anchor_context) =
+            self.bind_set_expr(bind_context, anchor, &[], 0).await?;
+        if cte_info.columns_alias.len() > anchor_context.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "table has {} columns available but {} columns specified",
+                anchor_context.columns.len(),
+                cte_info.columns_alias.len()
+            ))
+            .set_span(span));
+        }
+        let names = anchor_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                cte_info
+                    .columns_alias
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone())
+            })
+            .collect::<Vec<_>>();
+        let cte_index = self
+            .metadata
+            .write()
+            .add_recursive_cte(table_name.to_string());
+
+        // The types of the working table must cover the outputs of both the anchor and
+        // the recursive term, and the output of the recursive term depends on the types
+        // of the working table, so keep binding until the types are stable.
+        let mut types = anchor_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+        // The tables and columns added by a discarded pass must not stay in the metadata.
+        let metadata = self.metadata.read().clone();
+        let mut pass = 0;
+        let (recursive_expr, recursive_context) = loop {
+            if pass > 0 {
+                *self.metadata.write() = metadata.clone();
+            }
+            pass += 1;
+            let mut recursive_bind_context =
+                BindContext::with_parent(Box::new(bind_context.clone()));
+            recursive_bind_context
+                .ctes_map
+                .insert(table_name.to_string(), CteInfo {
+                    columns_alias: names.clone(),
+                    query: query.clone(),
+                    recursive: false,
+                    working_table: Some(WorkingTable {
+                        cte_index,
+                        columns: names.iter().cloned().zip(types.iter().cloned()).collect(),
+                    }),
+                });
+            let (recursive_expr, recursive_context) = self
+                .bind_set_expr(&mut recursive_bind_context, recursive_term, &[], 0)
+                .await?;
+            if recursive_context.columns.len() != types.len() {
+                return Err(ErrorCode::SemanticError(
+                    "SetOperation must have the same number of columns",
+                )
+                .set_span(recursive_term.span()));
+            }
+
+            let mut super_types = Vec::with_capacity(types.len());
+            for (ty, column) in types.iter().zip(recursive_context.columns.iter()) {
+                let super_type = common_super_type(
+                    ty.clone(),
+                    *column.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "column {} of recursive cte {table_name} has type {} in the anchor but type {} in the recursive term",
+                        column.column_name, ty, column.data_type
+                    ))
+                    .set_span(span)
+                })?;
+                super_types.push(super_type);
+            }
+
+            if super_types == types || pass >= MAX_TYPE_INFERENCE_PASSES {
+                break (recursive_expr, recursive_context);
+            }
+            types = super_types;
+        };
+
+        let (anchor_expr, anchor_columns) =
+            self.cast_columns(anchor_expr, &anchor_context.columns, &types)?;
+        let (recursive_expr, recursive_columns) =
+            self.cast_columns(recursive_expr, &recursive_context.columns, &types)?;
+
+        let pairs = anchor_columns
+            .iter()
+            .zip(recursive_columns.iter())
+            .map(|(l, r)| (l.index, r.index))
+            .collect();
+        let s_expr = SExpr::create_binary(
+            Arc::new(
+                RecursiveUnion {
+                    cte_index,
+                    pairs,
+                    distinct,
+                }
+                .into(),
+            ),
+            Arc::new(anchor_expr),
+            Arc::new(recursive_expr),
+        );
+
+        let mut output_context = BindContext::new();
+        output_context.parent = anchor_context.parent;
+        output_context.columns = anchor_columns;
+        output_context.ctes_map = anchor_context.ctes_map;
+        Ok((s_expr, output_context))
+    }
+
+    /// Bind a reference to a recursive CTE inside its recursive term,
+    /// which reads the rows produced by the previous iteration.
+    pub(super) fn bind_working_table(
+        &mut self,
+        bind_context: &BindContext,
+        working_table: &WorkingTable,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut columns = Vec::with_capacity(working_table.columns.len());
+        for (name, data_type) in working_table.columns.iter() {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(name.clone(), data_type.clone());
+            columns.push(index);
+            new_bind_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name: name.clone(),
+                index,
+                data_type: Box::new(data_type.clone()),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+        }
+
+        let s_expr = SExpr::create_leaf(Arc::new(
+            RecursiveCteScan {
+                cte_index: working_table.cte_index,
+                columns,
+            }
+            .into(),
+        ));
+        Ok((s_expr, new_bind_context))
+    }
+
+    // Cast the columns to the target types, columns which already have the target
+    // types are kept as they are.
+    fn cast_columns(
+        &mut self,
+        s_expr: SExpr,
+        columns: &[ColumnBinding],
+        target_types: &[DataType],
+    ) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let mut items = vec![];
+        let mut new_columns = Vec::with_capacity(columns.len());
+        for (column, target_type) in columns.iter().zip(target_types.iter()) {
+            if column.data_type.as_ref() == target_type {
+                new_columns.push(column.clone());
+                continue;
+            }
+            let scalar = wrap_cast(
+                &BoundColumnRef {
+                    span: None,
+                    column: column.clone(),
+                }
+                .into(),
+                target_type,
+            );
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), target_type.clone());
+            items.push(ScalarItem { scalar, index });
+            new_columns.push(ColumnBinding {
+                index,
+                data_type: Box::new(target_type.clone()),
+                ..column.clone()
+            });
+        }
+
+        if items.is_empty() {
+            return Ok((s_expr, new_columns));
+        }
+        let s_expr = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr));
+        Ok((s_expr, new_columns))
+    }
+}
+
+/// Check if the query references the given table, used to decide whether a CTE
+/// is recursive or not.
+pub fn query_references_table(
+    query: &Query,
+    table_name: &str,
+    name_resolution_ctx: &NameResolutionContext,
+) -> bool {
+    let mut visitor = TableReferenceVisitor {
+        table_name,
+        name_resolution_ctx,
+        found: false,
+    };
+    visitor.visit_query(query);
+    visitor.found
+}
+
+fn set_expr_references_table(
+    set_expr: &SetExpr,
+    table_name: &str,
+    name_resolution_ctx: &NameResolutionContext,
+) -> bool {
+    let mut visitor = TableReferenceVisitor {
+        table_name,
+        name_resolution_ctx,
+        found: false,
+    };
+    visitor.visit_set_expr(set_expr);
+    visitor.found
+}
+
+struct TableReferenceVisitor<'a> {
+    table_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    found: bool,
+}
+
+impl<'a, 'ast> Visitor<'ast> for TableReferenceVisitor<'a> {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        match table {
+            TableReference::Table {
+                catalog: None,
+                database: None,
+                table,
+                ..
+            } => {
+                if normalize_identifier(table, self.name_resolution_ctx).name == self.table_name {
+                    self.found = true;
+                }
+            }
+            TableReference::Subquery { subquery, .. } => walk_query(self, subquery),
+            TableReference::Join { join, .. } => self.visit_join(join),
+            _ => {}
+        }
+    }
+
+    fn visit_join(&mut self, join: &'ast Join) {
+        self.visit_table_reference(&join.left);
+        self.visit_table_reference(&join.right);
+    }
+}
@@ -310,6 +310,8 @@ impl Binder {
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     query: cte.query.clone(),
+                    recursive: with.recursive,
+                    working_table: None,
                 };
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
@@ -317,12 +319,12 @@ impl Binder {
 
         let (limit, offset) = if !query.limit.is_empty() {
             if query.limit.len() == 1 {
-                Self::analyze_limit(Some(&query.limit[0]), &query.offset)?
+                self.analyze_limit(Some(&query.limit[0]), &query.offset)?
             } else {
-                Self::analyze_limit(Some(&query.limit[1]), &Some(query.limit[0].clone()))?
+                self.analyze_limit(Some(&query.limit[1]), &Some(query.limit[0].clone()))?
             }
         } else if query.offset.is_some() {
-            Self::analyze_limit(None, &query.offset)?
+            self.analyze_limit(None, &query.offset)?
         } else {
             (None, 0)
         };
@@ -734,6 +736,7 @@ impl<'a> SelectRewriter<'a> {
                 args,
                 params: vec![],
                 window: None,
+                lambda: None,
             }),
             alias,
         }
@@ -35,6 +35,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Sort;
@@ -361,6 +362,19 @@ impl Binder {
                         arguments,
                     }))
                 }
+                ScalarExpr::LambdaFunction(lambda_func) => {
+                    let args = lambda_func
+                        .args
+                        .iter()
+                        .map(|arg| {
+                            self.rewrite_scalar_with_replacement(bind_context, arg, replacement_fn)
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                        args,
+                        ..lambda_func.clone()
+                    }))
+                }
                 ScalarExpr::CastExpr(CastExpr {
                     span,
                     is_try,
@@ -59,6 +59,7 @@ use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_types::MetaId;
+use common_pipeline_sources::input_formats::InputContext;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
@@ -72,6 +73,7 @@ use common_users::UserApiProvider;
 use dashmap::DashMap;
 
 use crate::binder::copy::parse_file_location;
+use crate::binder::recursive_cte::query_references_table;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::table_args::bind_table_args;
 use crate::binder::Binder;
@@ -442,6 +444,7 @@ impl Binder {
                                 params: vec![],
                                 args: params.clone(),
                                 window: None,
+                                lambda: None,
                             }),
                             alias: None,
                         }],
@@ -540,7 +543,7 @@ impl Binder {
                 ParquetTable::create(stage_info.clone(), files_info, read_options, files_to_copy)
                     .await?
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::NdJson(..) | FileFormatParams::Json(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -554,9 +557,27 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
+            FileFormatParams::Avro(..)
+            | FileFormatParams::Orc(..)
+            | FileFormatParams::Arrow(..) => {
+                // The schema is read from the first file.
+                let operator = StageTable::get_op(&stage_info)?;
+                let first_file = files_info.first_file(&operator).await?;
+                let schema = InputContext::get_input_format(&stage_info.file_format_params)?
+                    .infer_schema(&first_file.path, &operator)
+                    .await?;
+                let info = StageTableInfo {
+                    schema,
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: false,
+                };
+                StageTable::try_create(info)?
+            }
             _ => {
                 return Err(ErrorCode::Unimplemented(
-                    "stage table function only support parquet/NDJson format for now",
+                    "stage table function only support parquet/NDJson/JSON/Avro/ORC/Arrow format for now",
                 ));
             }
         };
@@ -681,9 +702,17 @@ impl Binder {
             planning_agg_index: false,
             window_definitions: DashMap::new(),
         };
-        let (s_expr, mut new_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut new_bind_context) = if let Some(working_table) = &cte_info.working_table {
+            self.bind_working_table(bind_context, working_table)?
+        } else if cte_info.recursive
+            && query_references_table(&cte_info.query, table_name, &self.name_resolution_ctx)
+        {
+            self.bind_recursive_cte(span, &mut new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_query(&mut new_bind_context, &cte_info.query)
+                .await?
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -24,6 +24,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::DeletePlan;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
+use crate::plans::MergeIntoPlan;
 use crate::plans::Plan;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
@@ -85,6 +86,7 @@ impl Plan {
                 Ok(format!("{:?}", drop_table_cluster_key))
             }
             Plan::ReclusterTable(recluster_table) => Ok(format!("{:?}", recluster_table)),
+            Plan::RefreshTable(refresh_table) => Ok(format!("{:?}", refresh_table)),
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
             Plan::VacuumTable(vacuum_table) => Ok(format!("{:?}", vacuum_table)),
@@ -99,6 +101,8 @@ impl Plan {
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
+            Plan::CreateInvertedIndex(index) => Ok(format!("{:?}", index)),
+            Plan::DropInvertedIndex(index) => Ok(format!("{:?}", index)),
 
             // Virtual Columns
             Plan::CreateVirtualColumns(create_virtual_columns) => {
@@ -119,6 +123,7 @@ impl Plan {
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::Delete(delete) => format_delete(delete),
             Plan::Update(update) => Ok(format!("{:?}", update)),
+            Plan::MergeInto(merge_into) => format_merge_into(merge_into),
 
             // Stages
             Plan::CreateStage(create_stage) => Ok(format!("{:?}", create_stage)),
@@ -150,9 +155,22 @@ impl Plan {
             Plan::SetVariable(p) => Ok(format!("{:?}", p)),
             Plan::UnSetVariable(p) => Ok(format!("{:?}", p)),
             Plan::SetRole(p) => Ok(format!("{:?}", p)),
+
+            // Workload groups
+            Plan::CreateWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::AlterWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::AssignWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::DropWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::ShowWorkloadGroups(p) => Ok(format!("{:?}", p)),
+
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
 
+            // Transactions
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Rollback => Ok("Rollback".to_string()),
+
             Plan::CreateShareEndpoint(p) => Ok(format!("{:?}", p)),
             Plan::ShowShareEndpoint(p) => Ok(format!("{:?}", p)),
             Plan::DropShareEndpoint(p) => Ok(format!("{:?}", p)),
@@ -218,6 +236,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             order_by: None,
             prewhere: None,
             agg_index: None,
+            vector_index: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
@@ -234,3 +253,11 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
     let res = s_expr.to_format_tree(&delete.metadata).format_pretty()?;
     Ok(format!("DeletePlan:\n{res}"))
 }
+
+fn format_merge_into(merge_into: &MergeIntoPlan) -> Result<String> {
+    let res = merge_into
+        .input
+        .to_format_tree(&merge_into.metadata)
+        .format_pretty()?;
+    Ok(format!("MergeIntoPlan:\n{res}"))
+}
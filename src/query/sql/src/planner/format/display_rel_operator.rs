@@ -76,6 +76,18 @@ impl Display for FormatContext {
                 RelOperator::RuntimeFilterSource(_) => write!(f, "RuntimeFilterSource"),
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
+                RelOperator::RecursiveUnion(op) => {
+                    write!(
+                        f,
+                        "RecursiveUnion: {}",
+                        if op.distinct { "distinct" } else { "all" }
+                    )
+                }
+                RelOperator::RecursiveCteScan(op) => write!(
+                    f,
+                    "RecursiveCteScan: {}",
+                    metadata.read().recursive_cte_name(op.cte_index)
+                ),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -111,6 +123,7 @@ pub fn format_scalar(scalar: &ScalarExpr) -> String {
                     .join(", ")
             )
         }
+        ScalarExpr::LambdaFunction(lambda) => lambda.display_name.clone(),
         ScalarExpr::CastExpr(cast) => {
             format!(
                 "CAST({} AS {})",
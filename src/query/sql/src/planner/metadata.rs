@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt::Debug;
@@ -23,6 +24,7 @@ use common_ast::ast::Expr;
 use common_ast::ast::Literal;
 use common_catalog::plan::InternalColumn;
 use common_catalog::table::Table;
+use common_exception::Span;
 use common_expression::types::DataType;
 use common_expression::ComputedExpr;
 use common_expression::Scalar;
@@ -59,6 +61,10 @@ pub struct Metadata {
     //// Columns that are lazy materialized.
     lazy_columns: HashSet<usize>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Names of the recursive CTEs, indexed by the cte index.
+    recursive_ctes: Vec<String>,
+    /// Values of the `?` placeholders of a prepared statement, indexed by the start of their spans.
+    parameters: BTreeMap<usize, Scalar>,
 }
 
 impl Metadata {
@@ -100,6 +106,22 @@ impl Metadata {
             .map(|table| table.index)
     }
 
+    /// Replace the table of the entries bound to `catalog.database.table_name`, used to
+    /// read a newer snapshot of the table than the one bound.
+    pub fn replace_table(
+        &mut self,
+        catalog: &str,
+        database: &str,
+        table_name: &str,
+        table: Arc<dyn Table>,
+    ) {
+        for entry in self.tables.iter_mut() {
+            if entry.catalog == catalog && entry.database == database && entry.name == table_name {
+                entry.table = table.clone();
+            }
+        }
+    }
+
     pub fn column(&self, index: IndexType) -> &ColumnEntry {
         self.columns
             .get(index)
@@ -237,6 +259,30 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_recursive_cte(&mut self, name: String) -> IndexType {
+        self.recursive_ctes.push(name);
+        self.recursive_ctes.len() - 1
+    }
+
+    pub fn recursive_cte_name(&self, cte_index: IndexType) -> &str {
+        self.recursive_ctes
+            .get(cte_index)
+            .expect("metadata must contain recursive cte")
+    }
+
+    pub fn set_parameters(&mut self, parameters: BTreeMap<usize, Scalar>) {
+        self.parameters = parameters;
+    }
+
+    pub fn parameter(&self, span: Span) -> Option<&Scalar> {
+        self.parameters.get(&span?.start)
+    }
+
+    /// Values of the placeholders in the order of their occurrences.
+    pub fn parameters(&self) -> Vec<Scalar> {
+        self.parameters.values().cloned().collect()
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
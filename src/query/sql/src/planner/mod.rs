@@ -16,6 +16,7 @@ mod format;
 mod metadata;
 #[allow(clippy::module_inception)]
 mod planner;
+mod prepared_statement;
 mod semantic;
 
 pub mod binder;
@@ -39,4 +40,5 @@ pub use metadata::*;
 pub use planner::PlanExtras;
 pub use planner::Planner;
 pub use plans::ScalarExpr;
+pub use prepared_statement::PreparedStatement;
 pub use semantic::*;
@@ -254,7 +254,19 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::RecursiveUnion(p) => {
+                // All the unioned columns are kept, since they are fed back to the
+                // recursive term and used to remove duplicated rows.
+                let left_used = p.pairs.iter().map(|v| v.0).collect();
+                let right_used = p.pairs.iter().map(|v| v.1).collect();
+                Ok(SExpr::create_binary(
+                    Arc::new(RelOperator::RecursiveUnion(p.clone())),
+                    Arc::new(self.keep_required_columns(expr.child(0)?, left_used)?),
+                    Arc::new(self.keep_required_columns(expr.child(1)?, right_used)?),
+                ))
+            }
+
+            RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
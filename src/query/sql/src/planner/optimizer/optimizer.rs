@@ -31,6 +31,7 @@ use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
 use crate::plans::CopyPlan;
+use crate::plans::MergeIntoPlan;
 use crate::plans::Plan;
 use crate::BindContext;
 use crate::IndexType;
@@ -134,6 +135,23 @@ pub fn optimize(
                 into_table => into_table,
             })))
         }
+        Plan::MergeInto(plan) => {
+            // The input of merge into is always executed in local.
+            let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+                enable_distributed_optimization: false,
+            }));
+            let input = optimize_query(
+                ctx,
+                opt_ctx,
+                plan.metadata.clone(),
+                plan.bind_context.clone(),
+                *plan.input.clone(),
+            )?;
+            Ok(Plan::MergeInto(Box::new(MergeIntoPlan {
+                input: Box::new(input),
+                ..*plan
+            })))
+        }
         // Passthrough statements
         _ => Ok(plan),
     }
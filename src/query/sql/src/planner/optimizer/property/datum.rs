@@ -53,6 +53,7 @@ impl Datum {
             }
             Scalar::String(v) => Some(Datum::Bytes(v.clone())),
             Scalar::Date(v) => Some(Datum::Int(*v as i64)),
+            Scalar::Timestamp(v) => Some(Datum::Int(*v)),
             _ => None,
         }
     }
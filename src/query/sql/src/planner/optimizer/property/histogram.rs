@@ -34,18 +34,22 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// The histogram is either built from the samples collected by `ANALYZE TABLE`,
+/// or constructed from NDV(number of distinct values) and the total number
+/// of rows if the table has not been analyzed, which brings the assumption
+/// that the data is uniformly distributed.
+///
+/// The first bucket is a dummy bucket which only records the min value of the column.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// Whether the histogram is built from the real data distribution.
+    pub accuracy: bool,
 }
 
 impl Histogram {
-    pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+    pub fn new(buckets: Vec<HistogramBucket>, accuracy: bool) -> Self {
+        Self { buckets, accuracy }
     }
 
     /// Get number of buckets
@@ -74,6 +78,30 @@ impl Histogram {
     ) -> impl Iterator<Item = &HistogramBucket> + DoubleEndedIterator<Item = &HistogramBucket> {
         self.buckets.iter()
     }
+
+    /// Shrink the histogram to the range `[min, max]`, buckets out of the range are dropped.
+    /// Returns `None` if the bounds are not comparable with the buckets.
+    pub fn prune(&self, min: &Datum, max: &Datum) -> Option<Histogram> {
+        let mut buckets = vec![HistogramBucket::new(min.clone(), 0.0, 0.0)];
+        for idx in 1..self.buckets.len() {
+            let lower_bound = self.buckets[idx - 1].upper_bound();
+            let bucket = &self.buckets[idx];
+            if bucket.upper_bound().compare(min).ok()? == Ordering::Less {
+                continue;
+            }
+            if lower_bound.compare(max).ok()? != Ordering::Less {
+                break;
+            }
+            buckets.push(bucket.clone());
+        }
+        if buckets.len() < 2 {
+            return None;
+        }
+        Some(Histogram {
+            buckets,
+            accuracy: self.accuracy,
+        })
+    }
 }
 
 /// Construct a histogram from NDV and total number of rows.
@@ -96,7 +124,10 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram {
+                buckets: vec![],
+                accuracy: false,
+            })
         };
     }
 
@@ -151,7 +182,10 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram {
+        buckets,
+        accuracy: false,
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -386,6 +386,12 @@ fn update_statistic(
             column_stat.histogram = None;
             return Ok(());
         }
+        if histogram.accuracy {
+            // The histogram is built from the real data, keep its distribution
+            // and only drop the buckets out of the new range.
+            column_stat.histogram = histogram.prune(&new_min, &new_max);
+            return Ok(());
+        }
         column_stat.histogram = Some(histogram_from_ndv(
             new_ndv,
             max(new_num_values, new_ndv),
@@ -41,6 +41,7 @@ use crate::optimizer::rule::rewrite::RulePushDownLimitSort;
 use crate::optimizer::rule::rewrite::RulePushDownLimitUnion;
 use crate::optimizer::rule::rewrite::RulePushDownSortScan;
 use crate::optimizer::rule::rewrite::RuleSplitAggregate;
+use crate::optimizer::rule::rewrite::RuleUseVectorIndex;
 use crate::optimizer::rule::transform::RuleCommuteJoinBaseTable;
 use crate::optimizer::rule::transform::RuleEagerAggregation;
 use crate::optimizer::rule::transform::RuleExchangeJoin;
@@ -56,7 +57,7 @@ impl RuleFactory {
     pub fn create_rule(
         id: RuleID,
         metadata: MetadataRef,
-        _func_ctx: FunctionContext,
+        func_ctx: FunctionContext,
     ) -> Result<RulePtr> {
         match id {
             RuleID::EliminateEvalScalar => Ok(Box::new(RuleEliminateEvalScalar::new())),
@@ -94,6 +95,7 @@ impl RuleFactory {
             RuleID::ExchangeJoin => Ok(Box::new(RuleExchangeJoin::new())),
             RuleID::PushDownPrewhere => Ok(Box::new(RulePushDownPrewhere::new(metadata))),
             RuleID::TryApplyAggIndex => Ok(Box::new(RuleTryApplyAggIndex::new(metadata))),
+            RuleID::UseVectorIndex => Ok(Box::new(RuleUseVectorIndex::new(metadata, func_ctx))),
         }
     }
 }
@@ -28,6 +28,7 @@ use crate::plans::CastExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::RelOperator;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
@@ -194,6 +195,12 @@ fn rewrite_scalar_index(columns: &HashMap<String, IndexType>, scalar: &mut Scala
                 .iter_mut()
                 .for_each(|arg| rewrite_scalar_index(columns, arg));
         }
+        ScalarExpr::LambdaFunction(lambda_func) => {
+            lambda_func
+                .args
+                .iter_mut()
+                .for_each(|arg| rewrite_scalar_index(columns, arg));
+        }
         ScalarExpr::CastExpr(cast) => {
             rewrite_scalar_index(columns, &mut cast.argument);
         }
@@ -521,6 +528,17 @@ impl RewriteInfomartion<'_> {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            ScalarExpr::LambdaFunction(lambda_func) => format!(
+                "{}({}, {})",
+                &lambda_func.func_name,
+                lambda_func
+                    .args
+                    .iter()
+                    .map(|arg| { self.format_scalar(arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                &lambda_func.lambda_display
+            ),
             ScalarExpr::CastExpr(cast) => format!(
                 "CAST({} AS {})",
                 self.format_scalar(&cast.argument),
@@ -834,6 +852,21 @@ fn rewrite_query_item(
                 .into(),
             )
         }
+        ScalarExpr::LambdaFunction(lambda_func) => {
+            let mut new_args = Vec::with_capacity(lambda_func.args.len());
+            for arg in lambda_func.args.iter() {
+                let new_arg = rewrite_by_selection(query_info, arg, index_selection)?;
+                new_args.push(new_arg);
+            }
+            Some(
+                LambdaFunc {
+                    span: None,
+                    args: new_args,
+                    ..lambda_func.clone()
+                }
+                .into(),
+            )
+        }
         ScalarExpr::AggregateFunction(_) => None, /* Aggregate function must appear in index selection. */
         _ => unreachable!(), // Window function and subquery will not appear in index.
     }
@@ -26,6 +26,7 @@ use crate::plans::CastExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
@@ -224,6 +225,18 @@ impl RulePushDownFilterScan {
                     func_name: func.func_name.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda_func) => {
+                let args = lambda_func
+                    .args
+                    .iter()
+                    .map(|arg| Self::replace_view_column(arg, table_entries, column_entries))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    args,
+                    ..lambda_func.clone()
+                }))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let arg = Self::replace_view_column(&cast.argument, table_entries, column_entries)?;
                 Ok(ScalarExpr::CastExpr(CastExpr {
@@ -0,0 +1,228 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::VectorIndexInfo;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::types::F32;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_vector::VectorDistance;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_DISTANCE;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+
+/// Input:  Sort(limit)
+///           \
+///          Expression
+///             \
+///            LogicalGet
+///
+/// Output:
+///         Sort(limit)
+///           \
+///          Expression
+///             \
+///            LogicalGet(padding vector index)
+///
+/// The sort key is the distance between a vector column and a constant vector,
+/// e.g. `ORDER BY cosine_distance(embedding, [...]) LIMIT 10`,
+/// and the vector index is built for the column with the same distance.
+pub struct RuleUseVectorIndex {
+    id: RuleID,
+    metadata: MetadataRef,
+    func_ctx: FunctionContext,
+    patterns: Vec<SExpr>,
+}
+
+impl RuleUseVectorIndex {
+    pub fn new(metadata: MetadataRef, func_ctx: FunctionContext) -> Self {
+        Self {
+            id: RuleID::UseVectorIndex,
+            metadata,
+            func_ctx,
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Sort,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
+                )),
+            )],
+        }
+    }
+
+    fn vector_index_info(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+    ) -> Option<VectorIndexInfo> {
+        let limit = sort.limit?;
+        let sort_item = sort.items.first()?;
+        let item = eval_scalar
+            .items
+            .iter()
+            .find(|item| item.index == sort_item.index)?;
+        let ScalarExpr::FunctionCall(func) = &item.scalar else {
+            return None;
+        };
+        let distance = VectorDistance::from_func_name(&func.func_name)?;
+        // The nearest rows first, and the rows of NULL last.
+        if sort_item.asc == distance.is_similarity()
+            || sort_item.nulls_first
+            || func.arguments.len() != 2
+        {
+            return None;
+        }
+
+        let (column, query) = match (&func.arguments[0], &func.arguments[1]) {
+            (ScalarExpr::BoundColumnRef(column), constant)
+            | (constant, ScalarExpr::BoundColumnRef(column)) => {
+                (column, self.constant_vector(constant)?)
+            }
+            _ => return None,
+        };
+        let column_name = self.indexed_column(column, scan, distance)?;
+
+        Some(VectorIndexInfo {
+            column_name,
+            func_name: func.func_name.clone(),
+            query,
+            limit,
+        })
+    }
+
+    // Name of the column if the vector index is built for it with the distance.
+    fn indexed_column(
+        &self,
+        column: &BoundColumnRef,
+        scan: &Scan,
+        distance: VectorDistance,
+    ) -> Option<String> {
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(base_column) = metadata.column(column.column.index) else {
+            return None;
+        };
+        if base_column.table_index != scan.table_index || base_column.path_indices.is_some() {
+            return None;
+        }
+
+        let table = metadata.table(scan.table_index).table();
+        let options = table.options();
+        let index_distance = match options.get(OPT_KEY_VECTOR_INDEX_DISTANCE) {
+            Some(v) => v.parse::<VectorDistance>().ok()?,
+            None => VectorDistance::Cosine,
+        };
+        let indexed = options
+            .get(OPT_KEY_VECTOR_INDEX_COLUMNS)?
+            .split(',')
+            .any(|name| name.trim().eq_ignore_ascii_case(&base_column.column_name));
+        if index_distance != distance || !indexed {
+            return None;
+        }
+        Some(base_column.column_name.clone())
+    }
+
+    fn constant_vector(&self, scalar: &ScalarExpr) -> Option<Vec<F32>> {
+        let ScalarExpr::ConstantExpr(constant) = scalar else {
+            return None;
+        };
+        let expr = Expr::<usize>::Constant {
+            span: None,
+            scalar: constant.value.clone(),
+            data_type: constant.value.as_ref().infer_data_type(),
+        };
+        let dest_type = DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)));
+        let expr = check_cast(None, false, expr, &dest_type, &BUILTIN_FUNCTIONS).ok()?;
+        match ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS).0 {
+            Expr::Constant {
+                scalar: Scalar::Array(column),
+                ..
+            } => Some(Float32Type::try_downcast_column(&column)?.to_vec()),
+            _ => None,
+        }
+    }
+}
+
+impl Rule for RuleUseVectorIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut scan: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+        // The filter may remove the nearest rows.
+        if scan.vector_index.is_some()
+            || scan.push_down_predicates.is_some()
+            || scan.prewhere.is_some()
+        {
+            return Ok(());
+        }
+
+        if let Some(vector_index) = self.vector_index_info(&sort, &eval_scalar, &scan) {
+            scan.vector_index = Some(vector_index);
+            let scan_expr = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+            let eval_scalar_expr = eval_scalar_expr.replace_children(vec![Arc::new(scan_expr)]);
+            let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar_expr)]);
+            result.set_applied_rule(&self.id);
+            state.add_result(result);
+        }
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
@@ -64,6 +64,7 @@ pub enum RuleID {
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
+    UseVectorIndex,
 
     // Exploration rules
     CommuteJoin,
@@ -111,6 +112,7 @@ impl Display for RuleID {
             RuleID::RightExchangeJoin => write!(f, "RightExchangeJoin"),
             RuleID::ExchangeJoin => write!(f, "ExchangeJoin"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
+            RuleID::UseVectorIndex => write!(f, "UseVectorIndex"),
         }
     }
 }
@@ -26,8 +26,11 @@ use common_ast::Dialect;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use parking_lot::RwLock;
 
+use super::prepared_statement::PlanCacheKey;
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
 use crate::optimizer::optimize;
@@ -40,6 +43,7 @@ use crate::Binder;
 use crate::Metadata;
 use crate::MetadataRef;
 use crate::NameResolutionContext;
+use crate::PreparedStatement;
 
 const PROBE_INSERT_INITIAL_TOKENS: usize = 128;
 const PROBE_INSERT_MAX_TOKENS: usize = 128 * 8;
@@ -159,6 +163,135 @@ impl Planner {
         }
     }
 
+    /// Prepare a statement with `?` placeholders, see [`PreparedStatement`].
+    #[async_backtrace::framed]
+    pub async fn prepare_sql(&mut self, sql: &str) -> Result<PreparedStatement> {
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let mut stmt = PreparedStatement::try_create(sql, sql_dialect)?;
+
+        // Plan with NULL parameters to get the schema of result set. It's fine to fail if there
+        // are parameters, since some statements depend on the values of them, e.g. `LIMIT ?`.
+        let nulls = vec![Scalar::Null; stmt.num_params()];
+        let plan = match self.plan_prepared(&stmt, &nulls).await {
+            Ok((plan, _)) => Some(plan),
+            Err(e) if stmt.num_params() == 0 => return Err(e),
+            Err(_) => None,
+        };
+        // If planning failed, whether there is a result set is guessed from the statement.
+        if let Some(plan) = plan {
+            if plan.has_result_set() {
+                stmt.set_result_set(true, plan.schema());
+            } else {
+                stmt.set_result_set(false, DataSchemaRefExt::create(vec![]));
+            }
+        }
+        Ok(stmt)
+    }
+
+    /// Plan a prepared statement with the parameters.
+    ///
+    /// The bound plan of a query is reused if the parameters have the same types as the last
+    /// execution and the tables are not changed since then, so that only the optimizer runs.
+    #[async_backtrace::framed]
+    pub async fn plan_prepared(
+        &mut self,
+        stmt: &PreparedStatement,
+        params: &[Scalar],
+    ) -> Result<(Plan, PlanExtras)> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+        let typed = stmt.binds_typed_params();
+        let mut statement = if typed {
+            stmt.statement().clone()
+        } else {
+            stmt.bind_statement(params, sql_dialect)?
+        };
+        self.replace_stmt(&mut statement, sql_dialect);
+
+        let key = PlanCacheKey {
+            database: self.ctx.get_current_database(),
+            max_result_rows: settings.get_max_result_rows()?,
+            timezone: settings.get_timezone()?,
+            sql_dialect,
+            unquoted_ident_case_sensitive: settings.get_unquoted_ident_case_sensitive()?,
+            quoted_ident_case_sensitive: settings.get_quoted_ident_case_sensitive()?,
+            param_types: params
+                .iter()
+                .map(|param| param.as_ref().infer_data_type())
+                .collect(),
+        };
+        let cached = match stmt.cached_plan(&key, params) {
+            Some((plan, cacheable)) if typed && self.tables_unchanged(&plan).await => {
+                Some((plan, cacheable))
+            }
+            _ => None,
+        };
+        let (plan, metadata) = match cached {
+            Some((plan, cacheable)) => {
+                if !cacheable {
+                    self.ctx.set_cacheable(false);
+                }
+                let Plan::Query { metadata, .. } = &plan else {
+                    unreachable!("only the plan of query is cached")
+                };
+                let metadata = metadata.clone();
+                (plan, metadata)
+            }
+            None => {
+                let metadata = Arc::new(RwLock::new(Metadata::default()));
+                if typed {
+                    metadata.write().set_parameters(stmt.parameters(params)?);
+                }
+                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+                let binder = Binder::new(
+                    self.ctx.clone(),
+                    CatalogManager::instance(),
+                    name_resolution_ctx,
+                    metadata.clone(),
+                );
+                let plan = binder.bind(&statement).await?;
+                if typed {
+                    stmt.cache_plan(key, &plan, params, self.ctx.get_cacheable());
+                }
+                (plan, metadata)
+            }
+        };
+
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        let optimized_plan = optimize(self.ctx.clone(), opt_ctx, plan)?;
+        Ok((optimized_plan, PlanExtras {
+            metadata,
+            format: stmt.format().cloned(),
+            statement,
+        }))
+    }
+
+    /// Check the tables read by the cached plan are the same versions as the current ones.
+    /// The plans reading views are not reused, since the views may be replaced.
+    async fn tables_unchanged(&self, plan: &Plan) -> bool {
+        let Plan::Query { metadata, .. } = plan else {
+            return false;
+        };
+        let tables = metadata.read().tables().to_vec();
+        for entry in tables {
+            if entry.is_source_of_view() {
+                return false;
+            }
+            let ident = entry.table().get_table_info().ident;
+            match self
+                .ctx
+                .get_table(entry.catalog(), entry.database(), entry.name())
+                .await
+            {
+                Ok(table) if table.get_table_info().ident == ident => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
@@ -16,7 +16,6 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_ast::ast::Engine;
-use common_ast::ast::ModifyColumnAction;
 use common_catalog::table::NavigationPoint;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
@@ -24,6 +23,7 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
@@ -46,6 +46,7 @@ pub struct CreateTablePlan {
     pub storage_params: Option<StorageParams>,
     pub part_prefix: String,
     pub options: TableOptions,
+    pub engine_options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
@@ -208,6 +209,17 @@ pub struct ModifyTableColumnPlan {
     pub action: ModifyColumnAction,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModifyColumnAction {
+    SetMaskingPolicy(String),
+    SetDataType(TableDataType),
+    // The default expression, in the same display form as `TableField::default_expr`.
+    SetDefault(String),
+    DropDefault,
+    Comment(String),
+    Rename(String),
+}
+
 impl ModifyTableColumnPlan {
     pub fn schema(&self) -> DataSchemaRef {
         Arc::new(DataSchema::empty())
@@ -323,3 +335,18 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Refresh the listed files of an external table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshTablePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl RefreshTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
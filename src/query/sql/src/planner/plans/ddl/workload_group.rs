@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::WorkloadGroup;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateWorkloadGroupPlan {
+    pub if_not_exists: bool,
+    pub group: WorkloadGroup,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterWorkloadGroupPlan {
+    pub group: WorkloadGroup,
+}
+
+/// Assign a user or a role to a workload group, or remove the assignment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssignWorkloadGroupPlan {
+    pub group: String,
+    pub principal: PrincipalIdentity,
+    pub unassign: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowWorkloadGroupsPlan {}
+
+impl ShowWorkloadGroupsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("max_concurrency", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("memory_share", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("queue_timeout", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("running", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("queued", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 use common_expression::TableSchemaRef;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_types::MetaId;
@@ -31,8 +32,8 @@ pub enum InsertInputSource {
     StreamingWithFormat(String, usize, Option<Arc<InputContext>>),
     // From outside streaming source with 'FILE_FORMAT = (type=<type_name> ...)
     StreamingWithFileFormat(FileFormatParams, usize, Option<Arc<InputContext>>),
-    // From cloned String and format
-    Values(String),
+    // From cloned String and format, with the parameters of the `?` placeholders in it
+    Values(String, Vec<Scalar>),
     // From stage
     Stage(Box<Plan>),
 }
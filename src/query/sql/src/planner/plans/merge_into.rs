@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::optimizer::SExpr;
+use crate::BindContext;
+use crate::MetadataRef;
+
+/// MERGE INTO is evaluated on the rows of `source LEFT OUTER JOIN target`, the
+/// clauses refer to the columns of the joined rows by their offsets in `bind_context`.
+#[derive(Clone, Debug)]
+pub struct MergeIntoPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub input: Box<SExpr>,
+    pub bind_context: Box<BindContext>,
+    pub metadata: MetadataRef,
+    // offset of the `_row_id` column of the target table, null if not matched.
+    pub row_id_offset: usize,
+    pub matched_clauses: Vec<MatchedEvaluator>,
+    pub unmatched_clauses: Vec<UnmatchedEvaluator>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MatchedEvaluator {
+    // offset of the condition column, the clause is unconditional if it is none.
+    pub condition: Option<usize>,
+    // offsets of the new values of the table fields, none if the matched rows are deleted.
+    pub update: Option<Vec<usize>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UnmatchedEvaluator {
+    pub condition: Option<usize>,
+    // offsets of the values of the table fields.
+    pub values: Vec<usize>,
+}
+
+impl MergeIntoPlan {
+    pub fn has_update(&self) -> bool {
+        self.matched_clauses
+            .iter()
+            .any(|clause| clause.update.is_some())
+    }
+
+    pub fn has_delete(&self) -> bool {
+        self.matched_clauses
+            .iter()
+            .any(|clause| clause.update.is_none())
+    }
+
+    pub fn has_insert(&self) -> bool {
+        !self.unmatched_clauses.is_empty()
+    }
+}
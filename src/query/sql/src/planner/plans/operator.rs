@@ -36,6 +36,8 @@ use crate::optimizer::StatInfo;
 use crate::plans::runtime_filter_source::RuntimeFilterSource;
 use crate::plans::Exchange;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCteScan;
+use crate::plans::RecursiveUnion;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -76,6 +78,8 @@ pub enum RelOp {
     RuntimeFilterSource,
     Window,
     ProjectSet,
+    RecursiveUnion,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -97,6 +101,8 @@ pub enum RelOperator {
     RuntimeFilterSource(RuntimeFilterSource),
     Window(Window),
     ProjectSet(ProjectSet),
+    RecursiveUnion(RecursiveUnion),
+    RecursiveCteScan(RecursiveCteScan),
 
     Pattern(PatternPlan),
 }
@@ -118,6 +124,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveUnion(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -137,6 +145,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveUnion(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -156,6 +166,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveUnion(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -175,6 +187,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveUnion(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
 
@@ -228,6 +242,12 @@ impl Operator for RelOperator {
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveUnion(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -260,7 +280,8 @@ impl From<Join> for RelOperator {
 
 impl TryFrom<RelOperator> for Join {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::Join(value) = value {
             Ok(value)
         } else {
@@ -279,7 +300,8 @@ impl From<EvalScalar> for RelOperator {
 
 impl TryFrom<RelOperator> for EvalScalar {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::EvalScalar(value) = value {
             Ok(value)
         } else {
@@ -298,7 +320,8 @@ impl From<Filter> for RelOperator {
 
 impl TryFrom<RelOperator> for Filter {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::Filter(value) = value {
             Ok(value)
         } else {
@@ -315,7 +338,8 @@ impl From<Aggregate> for RelOperator {
 
 impl TryFrom<RelOperator> for Aggregate {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::Aggregate(value) = value {
             Ok(value)
         } else {
@@ -334,7 +358,8 @@ impl From<Window> for RelOperator {
 
 impl TryFrom<RelOperator> for Window {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::Window(value) = value {
             Ok(value)
         } else {
@@ -351,7 +376,8 @@ impl From<Sort> for RelOperator {
 
 impl TryFrom<RelOperator> for Sort {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::Sort(value) = value {
             Ok(value)
         } else {
@@ -368,7 +394,8 @@ impl From<Limit> for RelOperator {
 
 impl TryFrom<RelOperator> for Limit {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::Limit(value) = value {
             Ok(value)
         } else {
@@ -385,7 +412,8 @@ impl From<PatternPlan> for RelOperator {
 
 impl TryFrom<RelOperator> for PatternPlan {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::Pattern(value) = value {
             Ok(value)
         } else {
@@ -404,7 +432,8 @@ impl From<Exchange> for RelOperator {
 
 impl TryFrom<RelOperator> for Exchange {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::Exchange(value) = value {
             Ok(value)
         } else {
@@ -423,7 +452,8 @@ impl From<UnionAll> for RelOperator {
 
 impl TryFrom<RelOperator> for UnionAll {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::UnionAll(value) = value {
             Ok(value)
         } else {
@@ -442,7 +472,8 @@ impl From<DummyTableScan> for RelOperator {
 
 impl TryFrom<RelOperator> for DummyTableScan {
     type Error = ErrorCode;
-    fn try_from(value: RelOperator) -> Result<Self> {
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
         if let RelOperator::DummyTableScan(value) = value {
             Ok(value)
         } else {
@@ -492,3 +523,43 @@ impl TryFrom<RelOperator> for ProjectSet {
         }
     }
 }
+
+impl From<RecursiveUnion> for RelOperator {
+    fn from(v: RecursiveUnion) -> Self {
+        Self::RecursiveUnion(v)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveUnion {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveUnion(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveUnion",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(v: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(v)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
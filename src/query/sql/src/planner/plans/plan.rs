@@ -26,10 +26,12 @@ use common_expression::DataSchemaRefExt;
 use super::data_mask::CreateDatamaskPolicyPlan;
 use super::CopyIntoTableMode;
 use super::CreateIndexPlan;
+use super::CreateInvertedIndexPlan;
 use super::CreateShareEndpointPlan;
 use super::DescDatamaskPolicyPlan;
 use super::DropDatamaskPolicyPlan;
 use super::DropIndexPlan;
+use super::DropInvertedIndexPlan;
 use super::DropShareEndpointPlan;
 use super::ModifyTableColumnPlan;
 use super::VacuumTablePlan;
@@ -53,7 +55,9 @@ use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnsPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::AssignWorkloadGroupPlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
@@ -65,6 +69,7 @@ use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnsPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
@@ -79,12 +84,15 @@ use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnsPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GenerateVirtualColumnsPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::MergeIntoPlan;
 use crate::plans::OptimizeTablePlan;
+use crate::plans::RefreshTablePlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTablePlan;
@@ -101,6 +109,7 @@ use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::ShowShareEndpointPlan;
+use crate::plans::ShowWorkloadGroupsPlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UnSettingPlan;
 use crate::plans::UndropDatabasePlan;
@@ -169,6 +178,7 @@ pub enum Plan {
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
+    RefreshTable(Box<RefreshTablePlan>),
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
@@ -181,6 +191,7 @@ pub enum Plan {
     Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
+    MergeInto(Box<MergeIntoPlan>),
 
     // Views
     CreateView(Box<CreateViewPlan>),
@@ -190,6 +201,8 @@ pub enum Plan {
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumns(Box<CreateVirtualColumnsPlan>),
@@ -218,6 +231,13 @@ pub enum Plan {
     RevokeRole(Box<RevokeRolePlan>),
     SetRole(Box<SetRolePlan>),
 
+    // Workload groups
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    AssignWorkloadGroup(Box<AssignWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+    ShowWorkloadGroups(Box<ShowWorkloadGroupsPlan>),
+
     // FileFormat
     CreateFileFormat(Box<CreateFileFormatPlan>),
     DropFileFormat(Box<DropFileFormatPlan>),
@@ -236,6 +256,11 @@ pub enum Plan {
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
 
+    // Transactions
+    Begin,
+    Commit,
+    Rollback,
+
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
     ShowShareEndpoint(Box<ShowShareEndpointPlan>),
@@ -314,6 +339,7 @@ impl Display for Plan {
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
+            Plan::RefreshTable(_) => write!(f, "RefreshTable"),
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
             Plan::VacuumTable(_) => write!(f, "VacuumTable"),
@@ -324,6 +350,8 @@ impl Display for Plan {
             Plan::DropView(_) => write!(f, "DropView"),
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
+            Plan::CreateInvertedIndex(_) => write!(f, "CreateInvertedIndex"),
+            Plan::DropInvertedIndex(_) => write!(f, "DropInvertedIndex"),
             Plan::CreateVirtualColumns(_) => write!(f, "CreateVirtualColumns"),
             Plan::AlterVirtualColumns(_) => write!(f, "AlterVirtualColumns"),
             Plan::DropVirtualColumns(_) => write!(f, "DropVirtualColumns"),
@@ -352,12 +380,21 @@ impl Display for Plan {
             Plan::Replace(_) => write!(f, "Replace"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
             Plan::Call(_) => write!(f, "Call"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
             Plan::UnSetVariable(_) => write!(f, "UnSetVariable"),
             Plan::SetRole(_) => write!(f, "SetRole"),
+            Plan::CreateWorkloadGroup(_) => write!(f, "CreateWorkloadGroup"),
+            Plan::AlterWorkloadGroup(_) => write!(f, "AlterWorkloadGroup"),
+            Plan::AssignWorkloadGroup(_) => write!(f, "AssignWorkloadGroup"),
+            Plan::DropWorkloadGroup(_) => write!(f, "DropWorkloadGroup"),
+            Plan::ShowWorkloadGroups(_) => write!(f, "ShowWorkloadGroups"),
             Plan::Kill(_) => write!(f, "Kill"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Rollback => write!(f, "Rollback"),
             Plan::CreateShareEndpoint(_) => write!(f, "CreateShareEndpoint"),
             Plan::ShowShareEndpoint(_) => write!(f, "ShowShareEndpoint"),
             Plan::DropShareEndpoint(_) => write!(f, "DropShareEndpoint"),
@@ -412,6 +449,7 @@ impl Plan {
             Plan::ShowRoles(plan) => plan.schema(),
             Plan::ShowGrants(plan) => plan.schema(),
             Plan::ShowFileFormats(plan) => plan.schema(),
+            Plan::ShowWorkloadGroups(plan) => plan.schema(),
 
             Plan::Insert(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
@@ -444,6 +482,7 @@ impl Plan {
                 | Plan::ShowCreateDatabase(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowFileFormats(_)
+                | Plan::ShowWorkloadGroups(_)
                 | Plan::ShowRoles(_)
                 | Plan::DescShare(_)
                 | Plan::ShowShares(_)
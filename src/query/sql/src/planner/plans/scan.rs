@@ -16,7 +16,9 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::plan::VectorIndexInfo;
 use common_catalog::table::ColumnStatistics;
+use common_catalog::table::HistogramBucket;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
@@ -28,6 +30,8 @@ use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
 use crate::optimizer::Datum;
 use crate::optimizer::Distribution;
+use crate::optimizer::Histogram;
+use crate::optimizer::HistogramBucket as OptimizerHistogramBucket;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -77,6 +81,7 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    pub vector_index: Option<VectorIndexInfo>,
 
     pub statistics: Statistics,
 }
@@ -103,6 +108,7 @@ impl Scan {
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            vector_index: self.vector_index.clone(),
         }
     }
 
@@ -183,13 +189,16 @@ impl Operator for Scan {
                 let min_datum = Datum::from_scalar(&min);
                 let max_datum = Datum::from_scalar(&max);
                 if let (Some(min), Some(max)) = (min_datum, max_datum) {
-                    let histogram = histogram_from_ndv(
-                        col_stat.number_of_distinct_values,
-                        num_rows,
-                        Some((min.clone(), max.clone())),
-                        DEFAULT_HISTOGRAM_BUCKETS,
-                    )
-                    .ok();
+                    let histogram = match &col_stat.histogram {
+                        Some(buckets) => histogram_from_buckets(&min, buckets),
+                        None => histogram_from_ndv(
+                            col_stat.number_of_distinct_values,
+                            num_rows,
+                            Some((min.clone(), max.clone())),
+                            DEFAULT_HISTOGRAM_BUCKETS,
+                        )
+                        .ok(),
+                    };
                     let column_stat = ColumnStat {
                         min,
                         max,
@@ -258,3 +267,22 @@ impl Operator for Scan {
         unreachable!()
     }
 }
+
+/// Convert the histogram collected by `ANALYZE TABLE` to the optimizer's histogram.
+fn histogram_from_buckets(min: &Datum, buckets: &[HistogramBucket]) -> Option<Histogram> {
+    if buckets.is_empty() {
+        return None;
+    }
+    let mut histogram_buckets = Vec::with_capacity(buckets.len() + 1);
+    // The first bucket is a dummy bucket which records the min value of the column.
+    histogram_buckets.push(OptimizerHistogramBucket::new(min.clone(), 0.0, 0.0));
+    for bucket in buckets {
+        let upper_bound = Datum::from_scalar(&bucket.upper_bound)?;
+        histogram_buckets.push(OptimizerHistogramBucket::new(
+            upper_bound,
+            bucket.num_values,
+            bucket.num_distinct,
+        ));
+    }
+    Some(Histogram::new(histogram_buckets, true))
+}
@@ -0,0 +1,610 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use common_ast::ast::AlterTableAction;
+use common_ast::ast::AlterTableStmt;
+use common_ast::ast::AlterViewStmt;
+use common_ast::ast::ColumnDefinition;
+use common_ast::ast::ColumnExpr;
+use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateTableSource;
+use common_ast::ast::CreateTableStmt;
+use common_ast::ast::CreateViewStmt;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
+use common_ast::ast::ModifyColumnAction;
+use common_ast::ast::Statement;
+use common_ast::parser::parse_expr;
+use common_ast::parser::parse_sql;
+use common_ast::parser::token::Token;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
+use common_ast::walk_expr_mut;
+use common_ast::walk_statement_mut;
+use common_ast::Dialect;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
+use common_exception::Range;
+use common_exception::Result;
+use common_expression::types::date::date_to_string;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::DataType;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::WindowFuncType;
+
+/// A statement with `?` placeholders, prepared once and executed many times with parameters.
+///
+/// The statement is parsed once. For queries and DML, the parameters are bound to the
+/// placeholders as typed constants, and the bound plan of a query is cached and reused by
+/// the following executions as long as the tables it reads are not changed.
+///
+/// The other statements (e.g. `CREATE VIEW`) may keep their SQL text, so the parameters of
+/// them are bound by replacing the placeholders with literals in the AST.
+#[derive(Debug, Clone)]
+pub struct PreparedStatement {
+    sql: String,
+    statement: Statement,
+    format: Option<String>,
+    placeholders: Vec<Range>,
+    schema: DataSchemaRef,
+    has_result_set: bool,
+    plan_cache: Arc<Mutex<Option<CachedPlan>>>,
+}
+
+/// The settings a bound plan depends on besides the statement.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PlanCacheKey {
+    pub database: String,
+    pub max_result_rows: u64,
+    pub timezone: String,
+    pub sql_dialect: Dialect,
+    pub unquoted_ident_case_sensitive: bool,
+    pub quoted_ident_case_sensitive: bool,
+    pub param_types: Vec<DataType>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedPlan {
+    key: PlanCacheKey,
+    /// The bound plan before optimization.
+    plan: Plan,
+    /// Whether the result of the plan can be cached, which is set to the context by binder.
+    cacheable: bool,
+}
+
+impl PreparedStatement {
+    /// Tokenize and parse the SQL to find the placeholders and check the syntax.
+    pub fn try_create(sql: &str, dialect: Dialect) -> Result<Self> {
+        let tokens = Tokenizer::new(sql).collect::<Result<Vec<Token>>>()?;
+        let (statement, format) = parse_sql(&tokens, dialect)?;
+        let has_result_set = matches!(
+            statement,
+            Statement::Query(_) | Statement::Explain { .. } | Statement::ExplainAnalyze { .. }
+        );
+
+        let placeholders = tokens
+            .iter()
+            .filter(|token| token.kind == TokenKind::Placeholder)
+            .map(|token| token.span)
+            .collect();
+
+        Ok(PreparedStatement {
+            sql: sql.to_string(),
+            statement,
+            format,
+            placeholders,
+            schema: DataSchemaRefExt::create(vec![]),
+            has_result_set,
+            plan_cache: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    pub fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    pub fn format(&self) -> Option<&String> {
+        self.format.as_ref()
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.placeholders.len()
+    }
+
+    /// Schema of the result set, empty if the statement has no result set or the schema is
+    /// unknown before the parameters are bound.
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    /// Whether the statement has a result set, which is known even if the schema is not.
+    pub fn has_result_set(&self) -> bool {
+        self.has_result_set
+    }
+
+    pub(crate) fn set_result_set(&mut self, has_result_set: bool, schema: DataSchemaRef) {
+        self.has_result_set = has_result_set;
+        self.schema = schema;
+    }
+
+    /// Whether the parameters are bound as typed constants, see [`PreparedStatement`].
+    pub(crate) fn binds_typed_params(&self) -> bool {
+        matches!(
+            self.statement,
+            Statement::Query(_)
+                | Statement::Insert(_)
+                | Statement::Replace(_)
+                | Statement::Delete { .. }
+                | Statement::Update(_)
+        )
+    }
+
+    /// Get the parameters indexed by the start of the spans of placeholders.
+    pub(crate) fn parameters(&self, params: &[Scalar]) -> Result<BTreeMap<usize, Scalar>> {
+        self.check_num_params(params)?;
+        Ok(self
+            .placeholders
+            .iter()
+            .map(|span| span.start)
+            .zip(params.iter().cloned())
+            .collect())
+    }
+
+    /// Get the statement with placeholders replaced by the parameters as literals. Each
+    /// parameter is parsed as an expression on its own, so it can't change the statement.
+    pub(crate) fn bind_statement(&self, params: &[Scalar], dialect: Dialect) -> Result<Statement> {
+        self.check_num_params(params)?;
+
+        let params = params
+            .iter()
+            .map(|param| {
+                let sql = param_to_sql(param)?;
+                let tokens = Tokenizer::new(&sql).collect::<Result<Vec<Token>>>()?;
+                parse_expr(&tokens, dialect)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut statement = self.statement.clone();
+        let mut replacer = PlaceholderReplacer {
+            placeholders: &self.placeholders,
+            params: &params,
+            replaced: 0,
+        };
+        walk_statement_mut(&mut replacer, &mut statement);
+        if replacer.replaced != self.placeholders.len() {
+            return Err(ErrorCode::SyntaxException(
+                "Placeholder `?` is not supported in this statement".to_string(),
+            ));
+        }
+        Ok(statement)
+    }
+
+    fn check_num_params(&self, params: &[Scalar]) -> Result<()> {
+        if params.len() != self.placeholders.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement requires {} parameters, but got {}",
+                self.placeholders.len(),
+                params.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the cached plan with the parameters substituted and whether its result can be
+    /// cached. The caller must check that the tables of the plan are not changed.
+    pub(crate) fn cached_plan(
+        &self,
+        key: &PlanCacheKey,
+        params: &[Scalar],
+    ) -> Option<(Plan, bool)> {
+        let cache = self.plan_cache.lock();
+        let cached = cache.as_ref().filter(|cached| &cached.key == key)?;
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            rewrite_kind,
+            formatted_ast,
+            ignore_result,
+        } = &cached.plan
+        else {
+            return None;
+        };
+
+        let mut new_metadata = metadata.read().clone();
+        new_metadata.set_parameters(self.parameters(params).ok()?);
+        let s_expr = replace_constants(s_expr, &mut |constant| {
+            if let Some(i) = self.placeholder_index(constant) {
+                constant.value = params[i].clone();
+            }
+        });
+        let formatted_ast = formatted_ast
+            .as_ref()
+            .map(|ast| format!("{ast}{}", format_params(params)));
+
+        let plan = Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata: Arc::new(RwLock::new(new_metadata)),
+            bind_context: bind_context.clone(),
+            rewrite_kind: rewrite_kind.clone(),
+            formatted_ast,
+            ignore_result: *ignore_result,
+        };
+        Some((plan, cached.cacheable))
+    }
+
+    /// Cache the bound plan of a query if the parameters can be substituted in it, which
+    /// requires every placeholder to be bound as a constant as it is. It's not the case if the
+    /// parameter is evaluated by the binder (e.g. `LIMIT ?`) or folded into other constants.
+    pub(crate) fn cache_plan(
+        &self,
+        key: PlanCacheKey,
+        plan: &Plan,
+        params: &[Scalar],
+        cacheable: bool,
+    ) {
+        let Plan::Query {
+            s_expr, metadata, ..
+        } = plan
+        else {
+            return;
+        };
+
+        let mut found = vec![false; self.placeholders.len()];
+        let mut reusable = true;
+        replace_constants(s_expr, &mut |constant| {
+            if let Some(i) = self.placeholder_index(constant) {
+                found[i] = true;
+                reusable &= constant.value == params[i];
+            }
+        });
+        if !reusable || found.contains(&false) {
+            return;
+        }
+
+        // The optimizer may add columns to the metadata, so cache a copy of it. The parameters
+        // are removed from the result cache key, and appended again when the plan is reused.
+        let mut plan = plan.clone();
+        if let Plan::Query {
+            metadata: m,
+            formatted_ast,
+            ..
+        } = &mut plan
+        {
+            *m = Arc::new(RwLock::new(metadata.read().clone()));
+            if let Some(ast) = formatted_ast {
+                ast.truncate(ast.len() - format_params(params).len());
+            }
+        }
+        *self.plan_cache.lock() = Some(CachedPlan {
+            key,
+            plan,
+            cacheable,
+        });
+    }
+
+    fn placeholder_index(&self, constant: &ConstantExpr) -> Option<usize> {
+        let span = constant.span?;
+        self.placeholders.iter().position(|p| *p == span)
+    }
+}
+
+/// Replace the placeholders with the parameters, including the ones in the statements whose
+/// expressions are not visited by default.
+struct PlaceholderReplacer<'a> {
+    placeholders: &'a [Range],
+    params: &'a [Expr],
+    replaced: usize,
+}
+
+impl PlaceholderReplacer<'_> {
+    fn visit_columns(&mut self, columns: &mut [ColumnDefinition]) {
+        for column in columns.iter_mut() {
+            match &mut column.expr {
+                Some(ColumnExpr::Default(expr))
+                | Some(ColumnExpr::Virtual(expr))
+                | Some(ColumnExpr::Stored(expr)) => self.visit_expr(expr),
+                None => {}
+            }
+        }
+    }
+}
+
+impl VisitorMut for PlaceholderReplacer<'_> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Placeholder { span: Some(span) } = *expr {
+            if let Some(i) = self.placeholders.iter().position(|p| *p == span) {
+                *expr = self.params[i].clone();
+                self.replaced += 1;
+                return;
+            }
+        }
+        walk_expr_mut(self, expr);
+    }
+
+    fn visit_set_variable(
+        &mut self,
+        _is_global: bool,
+        _variable: &mut Identifier,
+        value: &mut Box<Expr>,
+    ) {
+        self.visit_expr(value);
+    }
+
+    fn visit_create_table(&mut self, stmt: &mut CreateTableStmt) {
+        if let Some(CreateTableSource::Columns(columns)) = &mut stmt.source {
+            self.visit_columns(columns);
+        }
+        if let Some(query) = &mut stmt.as_query {
+            self.visit_query(query);
+        }
+    }
+
+    fn visit_alter_table(&mut self, stmt: &mut AlterTableStmt) {
+        match &mut stmt.action {
+            AlterTableAction::AddColumn { column } => {
+                self.visit_columns(std::slice::from_mut(column))
+            }
+            AlterTableAction::ModifyColumn {
+                action: ModifyColumnAction::SetDefault(expr),
+                ..
+            } => self.visit_expr(expr),
+            _ => {}
+        }
+    }
+
+    fn visit_create_view(&mut self, stmt: &mut CreateViewStmt) {
+        self.visit_query(&mut stmt.query);
+    }
+
+    fn visit_alter_view(&mut self, stmt: &mut AlterViewStmt) {
+        self.visit_query(&mut stmt.query);
+    }
+
+    fn visit_create_index(&mut self, stmt: &mut CreateIndexStmt) {
+        self.visit_query(&mut stmt.query);
+    }
+}
+
+/// Format the parameters as the suffix of the result cache key, since the placeholders are
+/// formatted as `?` in the statement.
+pub(crate) fn format_params(params: &[Scalar]) -> String {
+    let mut s = String::new();
+    for param in params {
+        write!(s, " {param}").unwrap();
+    }
+    s
+}
+
+/// Rebuild the `SExpr` with `f` applied to all the constants in it, including the subqueries.
+fn replace_constants(s_expr: &SExpr, f: &mut impl FnMut(&mut ConstantExpr)) -> SExpr {
+    let mut plan = (*s_expr.plan).clone();
+    match &mut plan {
+        RelOperator::Scan(scan) => {
+            for predicate in scan.push_down_predicates.iter_mut().flatten() {
+                replace_scalar_constants(predicate, f);
+            }
+            for predicate in scan
+                .prewhere
+                .iter_mut()
+                .flat_map(|p| p.predicates.iter_mut())
+            {
+                replace_scalar_constants(predicate, f);
+            }
+        }
+        RelOperator::Join(join) => {
+            for condition in join
+                .left_conditions
+                .iter_mut()
+                .chain(join.right_conditions.iter_mut())
+                .chain(join.non_equi_conditions.iter_mut())
+            {
+                replace_scalar_constants(condition, f);
+            }
+        }
+        RelOperator::EvalScalar(eval_scalar) => {
+            replace_items_constants(&mut eval_scalar.items, f);
+        }
+        RelOperator::Filter(filter) => {
+            for predicate in filter.predicates.iter_mut() {
+                replace_scalar_constants(predicate, f);
+            }
+        }
+        RelOperator::Aggregate(aggregate) => {
+            replace_items_constants(&mut aggregate.group_items, f);
+            replace_items_constants(&mut aggregate.aggregate_functions, f);
+        }
+        RelOperator::Window(window) => {
+            replace_window_func_constants(&mut window.function, f);
+            replace_items_constants(&mut window.arguments, f);
+            replace_items_constants(&mut window.partition_by, f);
+            for order_by in window.order_by.iter_mut() {
+                replace_scalar_constants(&mut order_by.order_by_item.scalar, f);
+            }
+        }
+        RelOperator::ProjectSet(project_set) => {
+            for srf in project_set.srfs.iter_mut() {
+                replace_scalar_constants(&mut srf.scalar, f);
+            }
+        }
+        RelOperator::RuntimeFilterSource(source) => {
+            for scalar in source
+                .left_runtime_filters
+                .values_mut()
+                .chain(source.right_runtime_filters.values_mut())
+            {
+                replace_scalar_constants(scalar, f);
+            }
+        }
+        RelOperator::Sort(_)
+        | RelOperator::Limit(_)
+        | RelOperator::Exchange(_)
+        | RelOperator::UnionAll(_)
+        | RelOperator::DummyTableScan(_)
+        | RelOperator::RecursiveUnion(_)
+        | RelOperator::RecursiveCteScan(_)
+        | RelOperator::Pattern(_) => {}
+    }
+
+    let children = s_expr
+        .children
+        .iter()
+        .map(|child| Arc::new(replace_constants(child, f)))
+        .collect::<Vec<_>>();
+    s_expr
+        .replace_plan(Arc::new(plan))
+        .replace_children(children)
+}
+
+fn replace_items_constants(items: &mut [ScalarItem], f: &mut impl FnMut(&mut ConstantExpr)) {
+    for item in items.iter_mut() {
+        replace_scalar_constants(&mut item.scalar, f);
+    }
+}
+
+fn replace_window_func_constants(func: &mut WindowFuncType, f: &mut impl FnMut(&mut ConstantExpr)) {
+    match func {
+        WindowFuncType::Aggregate(agg) => {
+            for arg in agg.args.iter_mut() {
+                replace_scalar_constants(arg, f);
+            }
+        }
+        WindowFuncType::LagLead(lag_lead) => {
+            replace_scalar_constants(&mut lag_lead.arg, f);
+            if let Some(default) = &mut lag_lead.default {
+                replace_scalar_constants(default, f);
+            }
+        }
+        WindowFuncType::NthValue(nth_value) => {
+            replace_scalar_constants(&mut nth_value.arg, f);
+        }
+        WindowFuncType::RowNumber
+        | WindowFuncType::Rank
+        | WindowFuncType::DenseRank
+        | WindowFuncType::PercentRank => {}
+    }
+}
+
+fn replace_scalar_constants(scalar: &mut ScalarExpr, f: &mut impl FnMut(&mut ConstantExpr)) {
+    match scalar {
+        ScalarExpr::BoundColumnRef(_) => {}
+        ScalarExpr::ConstantExpr(constant) => f(constant),
+        ScalarExpr::WindowFunction(window) => {
+            replace_window_func_constants(&mut window.func, f);
+            for arg in window.partition_by.iter_mut() {
+                replace_scalar_constants(arg, f);
+            }
+            for order_by in window.order_by.iter_mut() {
+                replace_scalar_constants(&mut order_by.expr, f);
+            }
+        }
+        ScalarExpr::AggregateFunction(agg) => {
+            for arg in agg.args.iter_mut() {
+                replace_scalar_constants(arg, f);
+            }
+        }
+        ScalarExpr::FunctionCall(func) => {
+            for arg in func.arguments.iter_mut() {
+                replace_scalar_constants(arg, f);
+            }
+        }
+        ScalarExpr::LambdaFunction(lambda) => {
+            for arg in lambda.args.iter_mut() {
+                replace_scalar_constants(arg, f);
+            }
+        }
+        ScalarExpr::CastExpr(cast) => replace_scalar_constants(&mut cast.argument, f),
+        ScalarExpr::SubqueryExpr(subquery) => {
+            if let Some(child_expr) = &mut subquery.child_expr {
+                replace_scalar_constants(child_expr, f);
+            }
+            subquery.subquery = Box::new(replace_constants(&subquery.subquery, f));
+        }
+    }
+}
+
+/// Render a parameter as SQL literal.
+fn param_to_sql(param: &Scalar) -> Result<String> {
+    let sql = match param {
+        Scalar::Null => Literal::Null.to_string(),
+        Scalar::Boolean(v) => Literal::Boolean(*v).to_string(),
+        Scalar::Number(NumberScalar::Float32(v)) if !v.is_finite() => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unsupported parameter value: {v}"
+            )));
+        }
+        Scalar::Number(NumberScalar::Float64(v)) if !v.is_finite() => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unsupported parameter value: {v}"
+            )));
+        }
+        // Negative numbers are parenthesized, so that `-?` can not become a comment `--`.
+        Scalar::Number(v) => parenthesize_negative(v.to_string()),
+        Scalar::Decimal(v) => parenthesize_negative(v.to_string()),
+        Scalar::String(v) => match String::from_utf8(v.clone()) {
+            Ok(s) => Literal::String(s).to_string(),
+            Err(_) => binary_to_sql(v),
+        },
+        Scalar::Binary(v) => binary_to_sql(v),
+        Scalar::Date(v) => Literal::String(date_to_string(*v, Tz::UTC).to_string()).to_string(),
+        Scalar::Timestamp(v) => {
+            Literal::String(format!("{}+00:00", timestamp_to_string(*v, Tz::UTC))).to_string()
+        }
+        _ => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unsupported parameter value: {param}"
+            )));
+        }
+    };
+    Ok(sql)
+}
+
+fn binary_to_sql(v: &[u8]) -> String {
+    let mut sql = "unhex('".to_string();
+    for b in v {
+        write!(sql, "{:02x}", b).unwrap();
+    }
+    sql.push_str("')");
+    sql
+}
+
+fn parenthesize_negative(s: String) -> String {
+    if s.starts_with('-') {
+        format!("({s})")
+    } else {
+        s
+    }
+}
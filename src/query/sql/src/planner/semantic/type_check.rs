@@ -22,6 +22,7 @@ use common_ast::ast::ColumnID;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
 use common_ast::ast::Query;
@@ -62,6 +63,7 @@ use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use common_functions::GENERAL_WINDOW_FUNCTIONS;
 use common_users::UserApiProvider;
 use simsearch::SimSearch;
@@ -81,6 +83,7 @@ use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
@@ -345,6 +348,7 @@ impl<'a> TypeChecker<'a> {
                             args: args.iter().copied().cloned().collect(),
                             params: vec![],
                             window: None,
+                            lambda: None,
                         })
                         .await?
                     } else {
@@ -570,6 +574,7 @@ impl<'a> TypeChecker<'a> {
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -620,6 +625,7 @@ impl<'a> TypeChecker<'a> {
                 args,
                 params,
                 window,
+                lambda,
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
@@ -694,7 +700,16 @@ impl<'a> TypeChecker<'a> {
                 }
 
                 let name = func_name.to_lowercase();
-                if GENERAL_WINDOW_FUNCTIONS.contains(&name.as_str()) {
+                if let Some(lambda) = lambda {
+                    self.resolve_lambda_function(*span, &name, &args, lambda)
+                        .await?
+                } else if GENERAL_LAMBDA_FUNCTIONS.contains(&name.as_str()) && name != "array_sort"
+                {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "function {name} requires a lambda function as its last argument"
+                    ))
+                    .set_span(*span));
+                } else if GENERAL_WINDOW_FUNCTIONS.contains(&name.as_str()) {
                     // general window function
                     if window.is_none() {
                         return Err(ErrorCode::SemanticError(format!(
@@ -849,12 +864,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -909,6 +919,24 @@ impl<'a> TypeChecker<'a> {
             Expr::Map { span, kvs, .. } => self.resolve_map(*span, kvs).await?,
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs).await?,
+
+            Expr::Placeholder { span } => {
+                // Parameters are bound as constants of their own types, the casts are added by
+                // the functions just like literals.
+                let value = self
+                    .metadata
+                    .read()
+                    .parameter(*span)
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(
+                            "Placeholder `?` is only allowed in prepared statements".to_string(),
+                        )
+                        .set_span(*span)
+                    })?;
+                let data_type = value.as_ref().infer_data_type();
+                Box::new((ConstantExpr { span: *span, value }.into(), data_type))
+            }
         };
 
         Ok(Box::new((scalar, data_type)))
@@ -1178,6 +1206,158 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve higher-order function call with a lambda, e.g. `array_transform(arr, x -> x + 1)`.
+    ///
+    /// The lambda body is resolved within a standalone bind context whose only columns are
+    /// the lambda parameters, so it can not reference any column outside of the lambda.
+    #[async_backtrace::framed]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if !GENERAL_LAMBDA_FUNCTIONS.contains(&func_name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "function {func_name} does not accept lambda function arguments"
+            ))
+            .set_span(span));
+        }
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for lambda function, {func_name} expects 1 argument"
+            ))
+            .set_span(span));
+        }
+
+        let box (arg, arg_type) = self.resolve(args[0]).await?;
+        let inner_ty = match arg_type.remove_nullable() {
+            DataType::Array(box inner_ty) => inner_ty,
+            DataType::Null | DataType::EmptyArray => {
+                // There is no element to apply the lambda function on.
+                let value = match func_name {
+                    "array_transform" | "array_filter" | "array_sort" => {
+                        return Ok(Box::new((arg, arg_type)));
+                    }
+                    "array_any_match" if arg_type != DataType::Null => Scalar::Boolean(false),
+                    _ => Scalar::Null,
+                };
+                let data_type = value.as_ref().infer_data_type();
+                return Ok(Box::new((ConstantExpr { span, value }.into(), data_type)));
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid arguments for lambda function, {func_name} expects an array argument, but got {arg_type}"
+                ))
+                .set_span(span));
+            }
+        };
+
+        let num_params = match func_name {
+            "array_reduce" | "array_sort" => 2,
+            _ => 1,
+        };
+        if lambda.params.len() != num_params {
+            return Err(ErrorCode::SemanticError(format!(
+                "incorrect number of parameters in lambda function, {func_name} expects {num_params} parameter(s), but got {}",
+                lambda.params.len()
+            ))
+            .set_span(span));
+        }
+
+        let mut lambda_context = BindContext::new();
+        for (index, param) in lambda.params.iter().enumerate() {
+            lambda_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name: normalize_identifier(param, self.name_resolution_ctx).name,
+                index,
+                data_type: Box::new(inner_ty.clone()),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+        }
+        let box (lambda_scalar, lambda_type) = TypeChecker::new(
+            &mut lambda_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+        )
+        .resolve(&lambda.expr)
+        .await?;
+
+        let (lambda_scalar, return_type) = match func_name {
+            "array_transform" => (lambda_scalar, DataType::Array(Box::new(lambda_type))),
+            "array_filter" | "array_any_match" => {
+                if !matches!(
+                    lambda_type.remove_nullable(),
+                    DataType::Boolean | DataType::Null
+                ) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "invalid lambda function for {func_name}, the result must be a boolean, but got {lambda_type}"
+                    ))
+                    .set_span(span));
+                }
+                let return_type = if func_name == "array_filter" {
+                    arg_type.remove_nullable()
+                } else {
+                    DataType::Boolean
+                };
+                (lambda_scalar, return_type)
+            }
+            "array_reduce" => (
+                wrap_cast(&lambda_scalar, &inner_ty),
+                inner_ty.wrap_nullable(),
+            ),
+            _ => {
+                // The comparator of `array_sort` returns a negative number if the first
+                // parameter should be placed before the second one.
+                let cmp_type = if lambda_type.is_nullable_or_null() {
+                    DataType::Number(NumberDataType::Int64).wrap_nullable()
+                } else {
+                    DataType::Number(NumberDataType::Int64)
+                };
+                (
+                    wrap_cast(&lambda_scalar, &cmp_type),
+                    arg_type.remove_nullable(),
+                )
+            }
+        };
+        let return_type = if arg_type.is_nullable() {
+            return_type.wrap_nullable()
+        } else {
+            return_type
+        };
+
+        let lambda_expr = lambda_scalar.as_expr()?.project_column_ref(|col| col.index);
+        if lambda_expr
+            .column_refs()
+            .keys()
+            .any(|index| *index >= num_params)
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid lambda function for {func_name}, only the lambda parameters can be referenced in the lambda body"
+            ))
+            .set_span(span));
+        }
+
+        let lambda_func = LambdaFunc {
+            span,
+            func_name: func_name.to_string(),
+            display_name: format!("{func_name}({:#}, {lambda})", args[0]),
+            args: vec![arg],
+            lambda_expr: Box::new(lambda_expr.as_remote_expr()),
+            lambda_display: lambda.to_string(),
+            return_type: Box::new(return_type.clone()),
+        };
+        Ok(Box::new((lambda_func.into(), return_type)))
+    }
+
     /// Resolve general window function call.
     #[async_backtrace::framed]
     async fn resolve_general_window_function(
@@ -1682,6 +1862,28 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        arg: &Expr,
+        interval_kind: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        match interval_kind {
+            ASTIntervalKind::Doy | ASTIntervalKind::Dow => Err(ErrorCode::SemanticError(format!(
+                "Unsupported interval unit {}",
+                interval_kind
+            ))
+            .set_span(span)),
+            _ => {
+                let func_name = format!("to_{}s", interval_kind.to_string().to_lowercase());
+                self.resolve_function(span, &func_name, vec![], &[arg])
+                    .await
+            }
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_date_add(
@@ -1970,6 +2172,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![arg_x.clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     })
                     .await,
                 )
@@ -2006,6 +2209,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![(*arg).clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     };
 
                     new_args.push(is_not_null_expr);
@@ -2889,6 +3093,7 @@ impl<'a> TypeChecker<'a> {
                     args,
                     params,
                     window,
+                    lambda,
                 } => Ok(Expr::FunctionCall {
                     span: *span,
                     distinct: *distinct,
@@ -2899,6 +3104,7 @@ impl<'a> TypeChecker<'a> {
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
                     window: window.clone(),
+                    lambda: lambda.clone(),
                 }),
                 Expr::Case {
                     span,
@@ -3048,8 +3254,11 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             })?)
         }
         TypeName::String => TableDataType::String,
+        TypeName::Binary => TableDataType::Binary,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Interval => TableDataType::Interval,
+        TypeName::TimestampTz => TableDataType::TimestampTz,
         TypeName::Array(item_type) => TableDataType::Array(Box::new(resolve_type_name(item_type)?)),
         TypeName::Map { key_type, val_type } => {
             let key_type = resolve_type_name(key_type)?;
@@ -28,6 +28,7 @@ use storages_common_cache::InMemoryItemCacheHolder;
 use storages_common_cache::NamedCache;
 use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
@@ -49,6 +50,9 @@ pub type TableSnapshotStatisticCache = NamedCache<InMemoryItemCacheHolder<TableS
 pub type BloomIndexFilterCache = NamedCache<InMemoryItemCacheHolder<Xor8Filter>>;
 /// In memory object cache of parquet FileMetaData of bloom index data
 pub type BloomIndexMetaCache = NamedCache<InMemoryItemCacheHolder<BloomIndexMeta>>;
+/// In memory object cache of inverted index.
+/// For each indexed data block, the inverted index of column is cached individually
+pub type InvertedIndexCache = NamedCache<InMemoryItemCacheHolder<InvertedIndex>>;
 /// In memory object cache of parquet FileMetaData of external parquet files
 pub type FileMetaDataCache = NamedCache<InMemoryItemCacheHolder<FileMetaData>>;
 
@@ -128,6 +132,13 @@ impl CachedObject<Xor8Filter> for Xor8Filter {
     }
 }
 
+impl CachedObject<InvertedIndex> for InvertedIndex {
+    type Cache = InvertedIndexCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_inverted_index_cache()
+    }
+}
+
 impl CachedObject<FileMetaData> for FileMetaData {
     type Cache = FileMetaDataCache;
     fn cache() -> Option<Self::Cache> {
@@ -28,6 +28,7 @@ use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
@@ -54,6 +55,7 @@ use crate::filters::V2BloomBlock;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
 use crate::Index;
+use crate::NgramIndex;
 
 #[derive(Clone)]
 pub struct BloomIndexMeta {
@@ -175,11 +177,13 @@ impl BloomIndex {
     /// Create a filter block from source data.
     ///
     /// All input blocks should belong to a Parquet file, e.g. the block array represents the parquet file in memory.
+    /// Besides the filter of values, the n-gram filters are created for the `ngram_fields`.
     pub fn try_create(
         func_ctx: FunctionContext,
         source_schema: TableSchemaRef,
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
+        ngram_fields: &[FieldIndex],
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -296,6 +300,26 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        // create n-gram filter per column
+        for field_index in ngram_fields {
+            let source_columns = data_blocks_tobe_indexed
+                .iter()
+                .map(|block| {
+                    let entry = block.get_by_offset(*field_index);
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, block.num_rows())
+                })
+                .collect::<Vec<_>>();
+            let column = Column::concat(&source_columns);
+            if let Some(filter) = NgramIndex::try_create(&column)? {
+                let filter_name =
+                    NgramIndex::build_filter_column_name(source_schema.field(*field_index));
+                filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+                filters.push(Arc::new(filter));
+            }
+        }
+
         let filter_schema = Arc::new(TableSchema::new(filter_fields));
 
         Ok(Some(Self {
@@ -345,6 +369,18 @@ impl BloomIndex {
                 }
             },
         )?;
+        visit_expr_ngram_predicate(&mut expr, &mut |span, col_name, ngrams, return_type| {
+            // If the column doesn't contain any n-gram of the pattern, we rewrite the expression to `false`.
+            if self.find_ngrams(col_name, ngrams)? == FilterEvalResult::MustFalse {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
 
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
@@ -407,6 +443,19 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns that match the patterns of `col LIKE <constant>`, `col REGEXP <constant>`
+    /// or `position(<constant> IN col) > <constant>` in the expression.
+    pub fn find_ngram_columns(expr: &Expr<String>) -> Result<Vec<String>> {
+        let mut cols = Vec::new();
+        visit_expr_ngram_predicate(&mut expr.clone(), &mut |_, col_name, _, _| {
+            if !cols.iter().any(|col| col == col_name) {
+                cols.push(col_name.to_string());
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -461,6 +510,181 @@ impl BloomIndex {
             Ok(FilterEvalResult::MustFalse)
         }
     }
+
+    fn find_ngrams(&self, column_name: &str, ngrams: &[Vec<u8>]) -> Result<FilterEvalResult> {
+        let filter_column =
+            &NgramIndex::build_filter_column_name(self.source_schema.field_with_name(column_name)?);
+
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a n-gram filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        if NgramIndex::contains_all(&self.filters[idx], ngrams) {
+            Ok(FilterEvalResult::Uncertain)
+        } else {
+            Ok(FilterEvalResult::MustFalse)
+        }
+    }
+}
+
+fn visit_expr_ngram_predicate(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[Vec<u8>], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    if let Some((span, col_name, ngrams, return_type)) = ngram_predicate(expr) {
+        // If the visitor returns a new expression, then replace with the current expression.
+        if !ngrams.is_empty() {
+            if let Some(new_expr) = visitor(span, &col_name, &ngrams, &return_type)? {
+                *expr = new_expr;
+                return Ok(());
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_ngram_predicate(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_ngram_predicate(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+// Find patterns like `Column LIKE <constant>`, `regexp_like(Column, <constant>, 'c')`,
+// or `position(<constant> IN Column) > <constant>`, and the n-grams required by them.
+fn ngram_predicate(expr: &Expr<String>) -> Option<(Span, String, Vec<Vec<u8>>, DataType)> {
+    let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    else {
+        return None;
+    };
+
+    let (col_name, ngrams) = match (function.signature.name.as_str(), args.as_slice()) {
+        (
+            "like",
+            [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(pattern),
+                    ..
+                },
+            ],
+        ) => (id.clone(), NgramIndex::like_ngrams(pattern)),
+        // The regular expressions are case-insensitive by default, the n-grams are
+        // case-sensitive, so only the case-sensitive match type 'c' is pruned.
+        (
+            "regexp_like",
+            [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(pattern),
+                    ..
+                },
+                Expr::Constant {
+                    scalar: Scalar::String(match_type),
+                    ..
+                },
+            ],
+        ) if is_case_sensitive(match_type) => {
+            let pattern = std::str::from_utf8(pattern).ok()?;
+            (id.clone(), NgramIndex::regexp_ngrams(pattern))
+        }
+        // The position is greater than 0, if the substring is found.
+        (
+            "gt",
+            [
+                position,
+                Expr::Constant {
+                    scalar: Scalar::Number(num),
+                    ..
+                },
+            ],
+        ) if is_unsigned(num) => {
+            let (id, substring) = visit_position(position)?;
+            (id, NgramIndex::substring_ngrams(substring))
+        }
+        (
+            "gte",
+            [
+                position,
+                Expr::Constant {
+                    scalar: Scalar::Number(num),
+                    ..
+                },
+            ],
+        ) if is_unsigned(num) && num.is_positive() => {
+            let (id, substring) = visit_position(position)?;
+            (id, NgramIndex::substring_ngrams(substring))
+        }
+        _ => return None,
+    };
+    Some((*span, col_name, ngrams, return_type.clone()))
+}
+
+fn visit_position(expr: &Expr<String>) -> Option<(String, &[u8])> {
+    let expr = match expr {
+        Expr::Cast { expr, .. } => expr.as_ref(),
+        _ => expr,
+    };
+    let Expr::FunctionCall { function, args, .. } = expr else {
+        return None;
+    };
+    match (function.signature.name.as_str(), args.as_slice()) {
+        (
+            "position" | "locate",
+            [
+                Expr::Constant {
+                    scalar: Scalar::String(substring),
+                    ..
+                },
+                Expr::ColumnRef { id, .. },
+            ],
+        )
+        | (
+            "instr",
+            [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(substring),
+                    ..
+                },
+            ],
+        ) => Some((id.clone(), substring.as_slice())),
+        _ => None,
+    }
+}
+
+// The last one of 'c' and 'i' in the match type takes effect, 'i' is the default.
+fn is_case_sensitive(match_type: &[u8]) -> bool {
+    match_type
+        .iter()
+        .rev()
+        .find(|c| matches!(c, b'c' | b'i'))
+        .map_or(false, |c| *c == b'c')
+}
+
+fn is_unsigned(num: &NumberScalar) -> bool {
+    matches!(
+        num,
+        NumberScalar::UInt8(_)
+            | NumberScalar::UInt16(_)
+            | NumberScalar::UInt32(_)
+            | NumberScalar::UInt64(_)
+    )
 }
 
 fn visit_expr_column_eq_constant(
@@ -0,0 +1,297 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_functions::scalars::query_terms;
+use common_functions::scalars::tokenize;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+
+use crate::FilterEvalResult;
+
+const MAGIC: &[u8] = b"DBIX";
+const FORMAT_VERSION: u8 = 1;
+
+/// The inverted index of a block maps each term of an indexed column
+/// to the rows containing it, the terms are split by the tokenizer of `match`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InvertedIndex {
+    postings: BTreeMap<String, Vec<u32>>,
+}
+
+impl InvertedIndex {
+    /// Only `String` columns can be indexed.
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    /// Returns the indexed fields declared by the table options.
+    pub fn parse_options(
+        schema: &TableSchema,
+        options: &BTreeMap<String, String>,
+    ) -> Result<Vec<FieldIndex>> {
+        let Some(columns) = options.get(OPT_KEY_INVERTED_INDEX_COLUMNS) else {
+            return Ok(vec![]);
+        };
+
+        let mut field_indices = Vec::new();
+        for name in columns.split(',').map(|name| name.trim()) {
+            let field_index = schema.index_of(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "inverted index column {} does not exist",
+                    name
+                ))
+            })?;
+            let field = schema.field(field_index);
+            if field.computed_expr().is_some() || !Self::supported_type(field.data_type()) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "inverted index column {} must be of type String, but got {}",
+                    name,
+                    field.data_type()
+                )));
+            }
+            if !field_indices.contains(&field_index) {
+                field_indices.push(field_index);
+            }
+        }
+        Ok(field_indices)
+    }
+
+    /// Build the index of the column, rows of NULL are skipped.
+    pub fn try_create(column: &Column) -> Result<Self> {
+        let (values, validity) = match NullableType::<StringType>::try_downcast_column(column) {
+            Some(nullable) => (nullable.column, Some(nullable.validity)),
+            None => {
+                let values = StringType::try_downcast_column(column)
+                    .ok_or_else(|| ErrorCode::Internal("inverted index column must be String"))?;
+                (values, None)
+            }
+        };
+
+        let mut index = Self::default();
+        for (row, value) in values.iter().enumerate() {
+            if validity.as_ref().map_or(false, |v| !v.get_bit(row)) {
+                continue;
+            }
+            for term in tokenize(value) {
+                let rows = index.postings.entry(term).or_default();
+                if rows.last() != Some(&(row as u32)) {
+                    rows.push(row as u32);
+                }
+            }
+        }
+        Ok(index)
+    }
+
+    pub fn num_terms(&self) -> usize {
+        self.postings.len()
+    }
+
+    /// Rows containing the term, in ascending order.
+    pub fn rows(&self, term: &str) -> &[u32] {
+        self.postings
+            .get(term)
+            .map(|rows| rows.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Returns true if some row may contain all the terms.
+    pub fn may_match(&self, terms: &[String]) -> bool {
+        let mut postings = Vec::with_capacity(terms.len());
+        for term in terms {
+            match self.postings.get(term) {
+                Some(rows) => postings.push(rows),
+                None => return false,
+            }
+        }
+        // Intersect from the shortest posting list.
+        postings.sort_by_key(|rows| rows.len());
+        let Some((first, others)) = postings.split_first() else {
+            return false;
+        };
+        first
+            .iter()
+            .any(|row| others.iter().all(|rows| rows.binary_search(row).is_ok()))
+    }
+
+    /// Columns searched by `match(column, '<query>')` in the filter.
+    pub fn find_match_columns(expr: &Expr<String>) -> Vec<String> {
+        let mut columns = Vec::new();
+        visit_expr_match(&mut expr.clone(), &mut |col_name, _| {
+            if !columns.iter().any(|column| column == col_name) {
+                columns.push(col_name.to_string());
+            }
+            false
+        });
+        columns
+    }
+
+    /// Rewrite `match` to `false` if no row of the column contains all the terms of the query,
+    /// the block can be skipped if the filter is folded to `false`.
+    pub fn apply(
+        func_ctx: &FunctionContext,
+        mut expr: Expr<String>,
+        indexes: &HashMap<String, Arc<Self>>,
+    ) -> FilterEvalResult {
+        visit_expr_match(&mut expr, &mut |col_name, terms| {
+            indexes
+                .get(col_name)
+                .map_or(false, |index| !index.may_match(terms))
+        });
+
+        match ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS).0 {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => FilterEvalResult::MustFalse,
+            _ => FilterEvalResult::Uncertain,
+        }
+    }
+
+    pub fn serialize(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(MAGIC);
+        buf.push(FORMAT_VERSION);
+        buf.extend_from_slice(&(self.postings.len() as u32).to_le_bytes());
+        for (term, rows) in self.postings.iter() {
+            buf.extend_from_slice(&(term.len() as u32).to_le_bytes());
+            buf.extend_from_slice(term.as_bytes());
+            buf.extend_from_slice(&(rows.len() as u32).to_le_bytes());
+            for row in rows.iter() {
+                buf.extend_from_slice(&row.to_le_bytes());
+            }
+        }
+    }
+
+    pub fn deserialize(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader { data, pos: 0 };
+        if reader.read_bytes(MAGIC.len())? != MAGIC {
+            return Err(ErrorCode::StorageOther("Invalid inverted index file"));
+        }
+        let version = reader.read_u8()?;
+        if version != FORMAT_VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "Unsupported inverted index version: {version}"
+            )));
+        }
+
+        let mut postings = BTreeMap::new();
+        let num_terms = reader.read_u32()?;
+        for _ in 0..num_terms {
+            let len = reader.read_u32()? as usize;
+            let term = std::str::from_utf8(reader.read_bytes(len)?)
+                .map_err(|_| ErrorCode::StorageOther("Invalid term in inverted index"))?;
+            let num_rows = reader.read_u32()? as usize;
+            let mut rows = Vec::with_capacity(num_rows);
+            for _ in 0..num_rows {
+                rows.push(reader.read_u32()?);
+            }
+            postings.insert(term.to_string(), rows);
+        }
+        Ok(Self { postings })
+    }
+}
+
+// Find `match(column, '<query>')` and rewrite it to `false` if the visitor returns true.
+fn visit_expr_match(expr: &mut Expr<String>, visitor: &mut impl FnMut(&str, &[String]) -> bool) {
+    if let Some((span, col_name, terms, return_type)) = match_predicate(expr) {
+        if !terms.is_empty() && visitor(&col_name, &terms) {
+            *expr = Expr::Constant {
+                span,
+                scalar: Scalar::Boolean(false),
+                data_type: return_type,
+            };
+        }
+        return;
+    }
+
+    match expr {
+        Expr::Cast { expr, .. } => visit_expr_match(expr, visitor),
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_match(arg, visitor);
+            }
+        }
+        _ => (),
+    }
+}
+
+// Find `match(column, '<query>')`, and the terms of the query.
+fn match_predicate(expr: &Expr<String>) -> Option<(Span, String, Vec<String>, DataType)> {
+    let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    else {
+        return None;
+    };
+    if function.signature.name != "match" {
+        return None;
+    }
+    match args.as_slice() {
+        [
+            Expr::ColumnRef { id, .. },
+            Expr::Constant {
+                scalar: Scalar::String(query),
+                ..
+            },
+        ] => Some((*span, id.clone(), query_terms(query), return_type.clone())),
+        _ => None,
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.pos + len > self.data.len() {
+            return Err(ErrorCode::StorageOther("Unexpected end of inverted index"));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
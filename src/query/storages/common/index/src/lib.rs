@@ -18,12 +18,19 @@
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
+mod ngram_index;
 mod page_index;
 mod range_index;
+mod vector_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
+pub use inverted_index::InvertedIndex;
+pub use ngram_index::NgramIndex;
+pub use ngram_index::NGRAM_SIZE;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
+pub use vector_index::VectorIndex;
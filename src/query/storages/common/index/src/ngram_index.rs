@@ -0,0 +1,173 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::FieldIndex;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+
+use crate::filters::Filter;
+use crate::filters::FilterBuilder;
+use crate::filters::Xor8Builder;
+use crate::filters::Xor8Filter;
+
+/// Number of bytes of a n-gram.
+pub const NGRAM_SIZE: usize = 3;
+
+/// The n-gram index of a string column is a filter of all the n-grams of its values,
+/// stored in the bloom index file next to the filter of the values.
+///
+/// A block can be skipped by `LIKE`, `position` and `regexp` predicates,
+/// if any n-gram of the literal parts of the pattern is not in the filter.
+pub struct NgramIndex;
+
+impl NgramIndex {
+    /// Only `String` columns can be indexed.
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    /// Returns the indexed fields declared by the table options.
+    pub fn parse_options(
+        schema: &TableSchema,
+        options: &BTreeMap<String, String>,
+    ) -> Result<Vec<FieldIndex>> {
+        let Some(columns) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) else {
+            return Ok(vec![]);
+        };
+
+        let mut field_indices = Vec::new();
+        for name in columns.split(',').map(|name| name.trim()) {
+            let field_index = schema.index_of(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!("ngram index column {} does not exist", name))
+            })?;
+            let field = schema.field(field_index);
+            if field.computed_expr().is_some() || !Self::supported_type(field.data_type()) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "ngram index column {} must be of type String, but got {}",
+                    name,
+                    field.data_type()
+                )));
+            }
+            if !field_indices.contains(&field_index) {
+                field_indices.push(field_index);
+            }
+        }
+        Ok(field_indices)
+    }
+
+    /// The filter will be stored with field name 'Ngram(column_id)'
+    pub fn build_filter_column_name(field: &TableField) -> String {
+        format!("Ngram({})", field.column_id())
+    }
+
+    /// Build the filter of all the n-grams of the column.
+    /// Returns None if no value is long enough to have a n-gram.
+    pub fn try_create(column: &Column) -> Result<Option<Xor8Filter>> {
+        let values = match NullableType::<StringType>::try_downcast_column(column) {
+            Some(nullable) => nullable.column,
+            None => StringType::try_downcast_column(column)
+                .ok_or_else(|| ErrorCode::Internal("ngram index column must be String"))?,
+        };
+
+        let mut ngrams = HashSet::new();
+        for value in values.iter() {
+            ngrams.extend(value.windows(NGRAM_SIZE));
+        }
+        if ngrams.is_empty() {
+            return Ok(None);
+        }
+
+        let mut builder = Xor8Builder::create();
+        for ngram in ngrams {
+            builder.add_key(&ngram);
+        }
+        Ok(Some(builder.build()?))
+    }
+
+    /// Returns true if the filter may contain all the n-grams.
+    pub fn contains_all(filter: &Xor8Filter, ngrams: &[Vec<u8>]) -> bool {
+        ngrams.iter().all(|ngram| filter.contains(ngram.as_slice()))
+    }
+
+    /// N-grams of the literal parts of a `LIKE` pattern, which are contained by every matched string.
+    pub fn like_ngrams(pattern: &[u8]) -> Vec<Vec<u8>> {
+        let mut segments = vec![];
+        let mut segment = vec![];
+        let mut chars = pattern.iter();
+        while let Some(c) = chars.next() {
+            match c {
+                b'\\' => {
+                    if let Some(c) = chars.next() {
+                        segment.push(*c);
+                    }
+                }
+                b'%' | b'_' => segments.push(std::mem::take(&mut segment)),
+                _ => segment.push(*c),
+            }
+        }
+        segments.push(segment);
+        Self::segments_ngrams(&segments)
+    }
+
+    /// N-grams of the substring, which are contained by every string containing it.
+    pub fn substring_ngrams(substring: &[u8]) -> Vec<Vec<u8>> {
+        Self::segments_ngrams(&[substring.to_vec()])
+    }
+
+    /// N-grams of the literal prefix of a regular expression, e.g. `error` of `error: \d+`.
+    ///
+    /// Patterns with alternations are ignored, the prefix may not be required by them.
+    pub fn regexp_ngrams(pattern: &str) -> Vec<Vec<u8>> {
+        if pattern.contains('|') {
+            return vec![];
+        }
+
+        let mut prefix = String::new();
+        for c in pattern.strip_prefix('^').unwrap_or(pattern).chars() {
+            match c {
+                // The previous char may be absent.
+                '*' | '?' | '{' => {
+                    prefix.pop();
+                    break;
+                }
+                '.' | '+' | '[' | ']' | '(' | ')' | '}' | '^' | '$' | '\\' => break,
+                _ => prefix.push(c),
+            }
+        }
+        Self::segments_ngrams(&[prefix.into_bytes()])
+    }
+
+    fn segments_ngrams(segments: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        let mut ngrams = Vec::<Vec<u8>>::new();
+        for segment in segments {
+            for ngram in segment.windows(NGRAM_SIZE) {
+                if !ngrams.iter().any(|v| v == ngram) {
+                    ngrams.push(ngram.to_vec());
+                }
+            }
+        }
+        ngrams
+    }
+}
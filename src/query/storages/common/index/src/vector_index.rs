@@ -0,0 +1,119 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_arrow::arrow::buffer::Buffer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::ArrayType;
+use common_expression::types::Float32Type;
+use common_expression::types::NullableType;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::types::F32;
+use common_expression::Column;
+use common_expression::FieldIndex;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_vector::HnswIndex;
+use common_vector::VectorDistance;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_DISTANCE;
+
+/// The vector index of a block is a HNSW graph per indexed column,
+/// used to find the nearest rows of a constant vector.
+pub struct VectorIndex;
+
+impl VectorIndex {
+    /// Only `Array(Float32)` columns can be indexed.
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        match data_type.remove_nullable() {
+            TableDataType::Array(box inner) => {
+                inner == TableDataType::Number(NumberDataType::Float32)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the distance and the indexed fields declared by the table options,
+    /// or None if the table has no vector index.
+    pub fn parse_options(
+        schema: &TableSchema,
+        options: &BTreeMap<String, String>,
+    ) -> Result<Option<(VectorDistance, Vec<FieldIndex>)>> {
+        let distance = match options.get(OPT_KEY_VECTOR_INDEX_DISTANCE) {
+            Some(v) => v.parse::<VectorDistance>()?,
+            None => VectorDistance::Cosine,
+        };
+        let Some(columns) = options.get(OPT_KEY_VECTOR_INDEX_COLUMNS) else {
+            return Ok(None);
+        };
+
+        let mut field_indices = Vec::new();
+        for name in columns.split(',').map(|name| name.trim()) {
+            let field_index = schema.index_of(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "vector index column {} does not exist",
+                    name
+                ))
+            })?;
+            let field = schema.field(field_index);
+            if field.computed_expr().is_some() || !Self::supported_type(field.data_type()) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "vector index column {} must be of type Array(Float32), but got {}",
+                    name,
+                    field.data_type()
+                )));
+            }
+            if !field_indices.contains(&field_index) {
+                field_indices.push(field_index);
+            }
+        }
+        if field_indices.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some((distance, field_indices)))
+    }
+
+    /// Build the index of the column, rows of NULL are skipped.
+    /// Returns None if there is no vector or the vectors have different dimensions.
+    pub fn try_create(distance: VectorDistance, column: &Column) -> Result<Option<HnswIndex>> {
+        let (values, validity) =
+            match NullableType::<ArrayType<Float32Type>>::try_downcast_column(column) {
+                Some(nullable) => (nullable.column, Some(nullable.validity)),
+                None => {
+                    let values =
+                        ArrayType::<Float32Type>::try_downcast_column(column).ok_or_else(|| {
+                            ErrorCode::Internal("vector index column must be Array(Float32)")
+                        })?;
+                    (values, None)
+                }
+            };
+
+        let mut index: Option<HnswIndex> = None;
+        for (row, vector) in values.iter().enumerate() {
+            if validity.as_ref().map_or(false, |v| !v.get_bit(row)) {
+                continue;
+            }
+            let vector = unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(vector) };
+            let index = index.get_or_insert_with(|| HnswIndex::new(distance, vector.len()));
+            if index.dimension() != vector.len() {
+                return Ok(None);
+            }
+            index.insert(row as u32, vector.as_slice())?;
+        }
+        Ok(index)
+    }
+}
@@ -111,6 +111,7 @@ fn test_bloom_filter() -> Result<()> {
         schema,
         LatestBloom::VERSION,
         &blocks_ref,
+        &[],
     )?
     .unwrap();
 
@@ -193,6 +194,148 @@ fn test_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "0",
+        TableDataType::String,
+    )]));
+    let blocks = vec![DataBlock::new_from_columns(vec![StringType::from_data(
+        vec!["error: disk full", "warning: low memory"],
+    )])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema,
+        LatestBloom::VERSION,
+        &blocks_ref,
+        &[0],
+    )?
+    .unwrap();
+
+    // The regular expressions are case-insensitive unless the match type is 'c'.
+    for (func_name, pattern, match_type, expected) in [
+        ("like", "%disk%", None, FilterEvalResult::Uncertain),
+        ("like", "error:%memory", None, FilterEvalResult::Uncertain),
+        ("like", "%network%", None, FilterEvalResult::MustFalse),
+        ("like", "%ne_work%", None, FilterEvalResult::MustFalse),
+        ("like", "%ne%", None, FilterEvalResult::Uncertain),
+        ("regexp", "fatal: \\d+", None, FilterEvalResult::Uncertain),
+        (
+            "regexp_like",
+            "fatal",
+            Some("i"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "fatal",
+            Some("ci"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "fatal",
+            Some("ic"),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            "regexp_like",
+            "^warn.*memory",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            "regexp_like",
+            "fatal: \\d+",
+            Some("c"),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            "regexp_like",
+            "fatalx?",
+            Some("c"),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            "regexp_like",
+            "fatal|error",
+            Some("c"),
+            FilterEvalResult::Uncertain,
+        ),
+    ] {
+        let mut args = vec![
+            Expr::ColumnRef {
+                span: None,
+                id: "0".to_string(),
+                data_type: DataType::String,
+                display_name: "0".to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(pattern.as_bytes().to_vec()),
+                data_type: DataType::String,
+            },
+        ];
+        if let Some(match_type) = match_type {
+            args.push(Expr::Constant {
+                span: None,
+                scalar: Scalar::String(match_type.as_bytes().to_vec()),
+                data_type: DataType::String,
+            });
+        }
+        let expr = check_function(None, func_name, &[], &args, &BUILTIN_FUNCTIONS)?;
+        assert_eq!(
+            expected,
+            index.apply(expr, &HashMap::new())?,
+            "{func_name} {pattern} {match_type:?}"
+        );
+    }
+
+    for (substring, expected) in [
+        ("full", FilterEvalResult::Uncertain),
+        ("empty", FilterEvalResult::MustFalse),
+    ] {
+        let position = check_function(
+            None,
+            "position",
+            &[],
+            &[
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::String(substring.as_bytes().to_vec()),
+                    data_type: DataType::String,
+                },
+                Expr::ColumnRef {
+                    span: None,
+                    id: "0".to_string(),
+                    data_type: DataType::String,
+                    display_name: "0".to_string(),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let expr = check_function(
+            None,
+            "gt",
+            &[],
+            &[position, Expr::Constant {
+                span: None,
+                scalar: Scalar::Number(NumberScalar::UInt64(0)),
+                data_type: DataType::Number(NumberDataType::UInt64),
+            }],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        assert_eq!(
+            vec!["0".to_string()],
+            BloomIndex::find_ngram_columns(&expr)?
+        );
+        assert_eq!(expected, index.apply(expr, &HashMap::new())?, "{substring}");
+    }
+    Ok(())
+}
+
 fn eval_index(index: &BloomIndex, col_name: &str, val: Scalar, ty: DataType) -> FilterEvalResult {
     let expr = check_function(
         None,
@@ -0,0 +1,103 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let column = StringType::from_data(vec![
+        "Error: disk full",
+        "warning: disk almost full",
+        "connection refused",
+    ]);
+    let index = InvertedIndex::try_create(&column)?;
+    assert_eq!(index.num_terms(), 7);
+    assert_eq!(index.rows("disk"), &[0, 1]);
+    assert_eq!(index.rows("error"), &[0]);
+    assert_eq!(index.rows("Error"), &[] as &[u32]);
+
+    let terms = |terms: &[&str]| terms.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+    assert!(index.may_match(&terms(&["disk", "full"])));
+    assert!(index.may_match(&terms(&["error", "full"])));
+    assert!(!index.may_match(&terms(&["error", "almost"])));
+    assert!(!index.may_match(&terms(&["timeout"])));
+
+    let mut data = vec![];
+    index.serialize(&mut data);
+    assert_eq!(InvertedIndex::deserialize(&data)?, index);
+    assert!(InvertedIndex::deserialize(&data[..data.len() - 1]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_inverted_index_apply() -> Result<()> {
+    let column = StringType::from_data(vec!["Error: disk full", "connection refused"]);
+    let indexes = HashMap::from([(
+        "0".to_string(),
+        Arc::new(InvertedIndex::try_create(&column)?),
+    )]);
+
+    for (query, expected) in [
+        ("disk error", FilterEvalResult::Uncertain),
+        ("CONNECTION", FilterEvalResult::Uncertain),
+        ("disk refused", FilterEvalResult::MustFalse),
+        ("timeout", FilterEvalResult::MustFalse),
+        ("", FilterEvalResult::Uncertain),
+    ] {
+        let expr = check_function(
+            None,
+            "match",
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: "0".to_string(),
+                    data_type: DataType::String,
+                    display_name: "0".to_string(),
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::String(query.as_bytes().to_vec()),
+                    data_type: DataType::String,
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        assert_eq!(
+            expected,
+            InvertedIndex::apply(&FunctionContext::default(), expr.clone(), &indexes),
+            "{query}"
+        );
+        // The blocks without index are kept.
+        assert_eq!(
+            FilterEvalResult::Uncertain,
+            InvertedIndex::apply(&FunctionContext::default(), expr, &HashMap::new())
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Number of bits of the hash used to pick a register.
+const HLL_P: u32 = 12;
+/// Number of registers, 2^HLL_P.
+const HLL_M: usize = 1 << HLL_P;
+
+/// A mergeable HyperLogLog sketch which is persisted in the table snapshot statistics.
+///
+/// The sketch does not hash values by itself, callers must feed it with a stable 64-bit
+/// hash of the value, so that sketches built by different queries can be merged.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MetaHLL {
+    registers: Vec<u8>,
+}
+
+impl Default for MetaHLL {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetaHLL {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; HLL_M],
+        }
+    }
+
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_P)) as usize;
+        // Set a sentinel bit so that the rank never exceeds 64 - HLL_P + 1.
+        let rest = (hash << HLL_P) | (1 << (HLL_P - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if self.registers[index] < rank {
+            self.registers[index] = rank;
+        }
+    }
+
+    pub fn merge(&mut self, other: &MetaHLL) {
+        for (r, o) in self.registers.iter_mut().zip(other.registers.iter()) {
+            if *r < *o {
+                *r = *o;
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registers.iter().all(|r| *r == 0)
+    }
+
+    /// Estimated number of distinct values.
+    pub fn count(&self) -> u64 {
+        let m = HLL_M as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let mut sum = 0.0;
+        let mut zeros = 0;
+        for r in self.registers.iter() {
+            sum += 1.0 / (1u64 << *r) as f64;
+            if *r == 0 {
+                zeros += 1;
+            }
+        }
+
+        let estimate = alpha * m * m / sum;
+        if estimate <= 2.5 * m && zeros > 0 {
+            // Small range correction, use linear counting.
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
@@ -18,6 +18,7 @@ mod statistics;
 mod compression;
 mod current;
 mod format;
+mod hll;
 mod utils;
 mod v0;
 mod v1;
@@ -32,6 +33,7 @@ pub use current::*;
 pub(crate) use format::load_json;
 pub(crate) use format::MetaCompression;
 pub(crate) use format::MetaEncoding;
+pub use hll::MetaHLL;
 pub use statistics::ClusterKey;
 pub use statistics::ClusterStatistics;
 pub use statistics::ColumnStatistics;
@@ -55,6 +57,7 @@ pub use versions::Versioned;
 // - export meta encoding to benchmarking tests
 pub mod testing {
     pub use super::format::MetaEncoding;
+    pub use super::v1::TableSnapshotStatistics as TableSnapshotStatisticsV0;
     pub use super::v2::SegmentInfo as SegmentInfoV2;
     pub use super::v2::TableSnapshot as TableSnapshotV2;
     pub use super::v3::SegmentInfo as SegmentInfoV3;
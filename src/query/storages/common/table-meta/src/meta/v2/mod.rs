@@ -14,8 +14,12 @@
 
 mod segment;
 mod snapshot;
+mod table_snapshot_statistics;
 
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
+pub use table_snapshot_statistics::Histogram;
+pub use table_snapshot_statistics::HistogramBucket;
+pub use table_snapshot_statistics::TableSnapshotStatistics;
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::statistics::FormatVersion;
+use crate::meta::v1;
+use crate::meta::MetaHLL;
+use crate::meta::SnapshotId;
+use crate::meta::Versioned;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshotStatistics {
+    /// format version of snapshot
+    pub format_version: FormatVersion,
+
+    /// id of snapshot
+    pub snapshot_id: SnapshotId,
+
+    pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// mergeable distinct value sketches of columns
+    pub hll: HashMap<ColumnId, MetaHLL>,
+
+    /// equi-height histograms of columns
+    pub histograms: HashMap<ColumnId, Histogram>,
+}
+
+impl TableSnapshotStatistics {
+    pub fn new(hll: HashMap<ColumnId, MetaHLL>, histograms: HashMap<ColumnId, Histogram>) -> Self {
+        let column_distinct_values = hll.iter().map(|(k, v)| (*k, v.count())).collect();
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: SnapshotId::new_v4(),
+            column_distinct_values,
+            hll,
+            histograms,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
+        &self.column_distinct_values
+    }
+}
+
+/// An equi-height histogram, buckets are ordered by their upper bounds.
+///
+/// The lower bound of the first bucket is the min value of the column.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Histogram {
+    pub buckets: Vec<HistogramBucket>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HistogramBucket {
+    /// upper bound (inclusive) of the bucket
+    pub upper_bound: Scalar,
+    /// estimated number of values in the bucket
+    pub num_values: f64,
+    /// estimated number of distinct values in the bucket
+    pub num_distinct: f64,
+}
+
+impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
+    fn from(value: v1::TableSnapshotStatistics) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: value.snapshot_id,
+            column_distinct_values: value.column_distinct_values,
+            hll: HashMap::new(),
+            histograms: HashMap::new(),
+        }
+    }
+}
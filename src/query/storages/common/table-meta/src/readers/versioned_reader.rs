@@ -18,6 +18,7 @@ use futures_util::AsyncReadExt;
 
 use crate::meta::load_json;
 use crate::meta::TableSnapshotStatistics;
+use crate::meta::TableSnapshotStatisticsV0;
 use crate::meta::TableSnapshotStatisticsVersion;
 
 #[async_trait::async_trait]
@@ -36,7 +37,11 @@ impl VersionedReader<TableSnapshotStatistics> for TableSnapshotStatisticsVersion
         let mut buffer: Vec<u8> = vec![];
         reader.read_to_end(&mut buffer).await?;
         let r = match self {
-            TableSnapshotStatisticsVersion::V0(v) => load_json(&buffer, v).await?,
+            TableSnapshotStatisticsVersion::V0(v) => {
+                let ts: TableSnapshotStatisticsV0 = load_json(&buffer, v).await?;
+                TableSnapshotStatistics::from(ts)
+            }
+            TableSnapshotStatisticsVersion::V1(v) => load_json(&buffer, v).await?,
         };
         Ok(r)
     }
@@ -15,7 +15,9 @@
 mod table_compression;
 mod table_keys;
 mod table_prefix;
+mod table_widened_column;
 
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use table_widened_column::*;
@@ -22,6 +22,18 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
+/// The user who created the table, the size of the table is counted in the storage quota of the user.
+pub const OPT_KEY_OWNER: &str = "owner";
+/// Comma separated `Array(Float32)` columns to build the vector index for, per block.
+pub const OPT_KEY_VECTOR_INDEX_COLUMNS: &str = "vector_index_columns";
+/// Distance the vector index is built for, one of `cosine`(default), `l2` and `inner_product`.
+pub const OPT_KEY_VECTOR_INDEX_DISTANCE: &str = "vector_index_distance";
+/// Comma separated `String` columns to build the n-gram filter for, in the bloom index.
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
+/// Name of the inverted index, set by `CREATE INVERTED INDEX`.
+pub const OPT_KEY_INVERTED_INDEX_NAME: &str = "inverted_index_name";
+/// Comma separated `String` columns to build the inverted index for.
+pub const OPT_KEY_INVERTED_INDEX_COLUMNS: &str = "inverted_index_columns";
 
 /// Legacy table snapshot location key
 ///
@@ -40,6 +52,7 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_OWNER);
     r
 });
 
@@ -48,6 +61,7 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_OWNER);
     r
 });
 
@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::TableField;
+use common_expression::TableSchema;
+
+/// Prefix of the schema metadata keys recording widened columns.
+///
+/// Widening the data type of a column (e.g. `Int32` to `Int64`) only changes the table meta:
+/// the column gets a new column id, and `widened_column.<new column id>` keeps the old field.
+/// The blocks written before the change store the column under the old column id, they are
+/// read as the old data type and cast to the new one.
+pub const SCHEMA_META_KEY_WIDENED_COLUMN_PREFIX: &str = "widened_column.";
+
+/// Records that the data of the column `column_id` was stored as `source` before the widening.
+pub fn set_widened_column_source(
+    schema: &mut TableSchema,
+    column_id: ColumnId,
+    source: &TableField,
+) -> Result<()> {
+    let key = format!("{}{}", SCHEMA_META_KEY_WIDENED_COLUMN_PREFIX, column_id);
+    schema.metadata.insert(key, serde_json::to_string(source)?);
+    Ok(())
+}
+
+/// The fields the widened columns of `schema` were stored as, by the column id after the widening.
+pub fn widened_column_sources(schema: &TableSchema) -> BTreeMap<ColumnId, TableField> {
+    schema
+        .metadata
+        .iter()
+        .filter_map(|(key, value)| {
+            let column_id = key
+                .strip_prefix(SCHEMA_META_KEY_WIDENED_COLUMN_PREFIX)?
+                .parse::<ColumnId>()
+                .ok()?;
+            let source = serde_json::from_str::<TableField>(value).ok()?;
+            Some((column_id, source))
+        })
+        .collect()
+}
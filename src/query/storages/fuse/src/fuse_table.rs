@@ -28,6 +28,7 @@ use common_catalog::table::AppendMode;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::CompactTarget;
+use common_catalog::table::HistogramBucket;
 use common_catalog::table::NavigationDescriptor;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -51,8 +52,12 @@ use common_storage::StorageMetrics;
 use common_storage::StorageMetricsLayer;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_index::InvertedIndex;
+use storages_common_index::NgramIndex;
+use storages_common_index::VectorIndex;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
+use storages_common_table_meta::meta::Histogram;
 use storages_common_table_meta::meta::Statistics as FuseStatistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
@@ -180,12 +185,25 @@ impl FuseTable {
         let max_page_size = self.get_option(FUSE_OPT_KEY_ROW_PER_PAGE, default_rows_per_page);
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        // the options are checked when the table is created
+        let ngram_index =
+            NgramIndex::parse_options(&self.table_info.schema(), &self.table_info.meta.options)
+                .unwrap_or_default();
+        let inverted_index =
+            InvertedIndex::parse_options(&self.table_info.schema(), &self.table_info.meta.options)
+                .unwrap_or_default();
+        let vector_index =
+            VectorIndex::parse_options(&self.table_info.schema(), &self.table_info.meta.options)
+                .unwrap_or_default();
 
         WriteSettings {
             storage_format: self.storage_format,
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            ngram_index,
+            inverted_index,
+            vector_index,
         }
     }
 
@@ -213,7 +231,7 @@ impl FuseTable {
     }
 
     pub fn table_snapshot_statistics_format_version(&self, location: &String) -> u64 {
-        TableMetaLocationGenerator::snapshot_version(location)
+        TableMetaLocationGenerator::snapshot_statistics_version(location)
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
@@ -314,6 +332,10 @@ impl FuseTable {
         &self.operator
     }
 
+    pub fn get_storage_format(&self) -> FuseStorageFormat {
+        self.storage_format
+    }
+
     pub fn try_from_table(tbl: &dyn Table) -> Result<&FuseTable> {
         tbl.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
             ErrorCode::Internal(format!(
@@ -529,7 +551,7 @@ impl Table for FuseTable {
         copied_files: Option<UpsertTableCopiedFileReq>,
         overwrite: bool,
     ) -> Result<()> {
-        self.do_commit(ctx, pipeline, copied_files, overwrite)
+        self.do_commit(ctx, pipeline, copied_files, overwrite, None)
     }
 
     #[tracing::instrument(level = "debug", name = "fuse_table_truncate", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
@@ -594,12 +616,14 @@ impl Table for FuseTable {
                     row_count: snapshot.summary.row_count,
                     // save row count first
                     column_distinct_values: Some(table_statistics.column_distinct_values.clone()),
+                    histograms: table_statistics.histograms.clone(),
                 }
             } else {
                 FuseTableColumnStatisticsProvider {
                     column_stats: stats.clone(),
                     row_count: snapshot.summary.row_count,
                     column_distinct_values: None,
+                    histograms: HashMap::new(),
                 }
             }
         } else {
@@ -748,6 +772,7 @@ struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, FuseColumnStatistics>,
     pub column_distinct_values: Option<HashMap<ColumnId, u64>>,
     pub row_count: u64,
+    pub histograms: HashMap<ColumnId, Histogram>,
 }
 
 impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
@@ -759,11 +784,22 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
                 .as_ref()
                 .map_or(self.row_count, |map| map.get(&column_id).map_or(0, |v| *v));
             ndv = self.adjust_ndv_by_min_max(ndv, s.min.clone(), s.max.clone());
+            let histogram = self.histograms.get(&column_id).map(|h| {
+                h.buckets
+                    .iter()
+                    .map(|b| HistogramBucket {
+                        upper_bound: b.upper_bound.clone(),
+                        num_values: b.num_values,
+                        num_distinct: b.num_distinct,
+                    })
+                    .collect()
+            });
             ColumnStatistics {
                 min: s.min.clone(),
                 max: s.max.clone(),
                 null_count: s.null_count,
                 number_of_distinct_values: ndv,
+                histogram,
             }
         })
     }
@@ -15,6 +15,7 @@
 use std::marker::PhantomData;
 
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::DataBlock;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -24,9 +25,11 @@ use storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
 
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
+use crate::constants::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -40,6 +43,8 @@ static SNAPSHOT_V4: SnapshotVersion = SnapshotVersion::V4(PhantomData);
 
 static SNAPSHOT_STATISTICS_V0: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V0(PhantomData);
+static SNAPSHOT_STATISTICS_V1: TableSnapshotStatisticsVersion =
+    TableSnapshotStatisticsVersion::V1(PhantomData);
 
 #[derive(Clone)]
 pub struct TableMetaLocationGenerator {
@@ -134,8 +139,15 @@ impl TableMetaLocationGenerator {
         Ok(statistics_version.create(id, &self.prefix))
     }
 
-    pub fn snapshot_statistics_version(_location: impl AsRef<str>) -> u64 {
-        SNAPSHOT_STATISTICS_V0.version()
+    pub fn snapshot_statistics_version(location: impl AsRef<str>) -> u64 {
+        if location
+            .as_ref()
+            .ends_with(SNAPSHOT_STATISTICS_V1.suffix().as_str())
+        {
+            SNAPSHOT_STATISTICS_V1.version()
+        } else {
+            SNAPSHOT_STATISTICS_V0.version()
+        }
     }
 
     pub fn gen_last_snapshot_hint_location(&self) -> String {
@@ -145,6 +157,18 @@ impl TableMetaLocationGenerator {
     pub fn gen_virtual_block_location(location: &str) -> String {
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
+
+    pub fn gen_vector_index_location(location: &str, column_id: ColumnId) -> String {
+        let location = location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VECTOR_INDEX_PREFIX);
+        let location = location.strip_suffix(".parquet").unwrap_or(&location);
+        format!("{}_{}.hnsw", location, column_id)
+    }
+
+    pub fn gen_inverted_index_location(location: &str, column_id: ColumnId) -> String {
+        let location = location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_INVERTED_INDEX_PREFIX);
+        let location = location.strip_suffix(".parquet").unwrap_or(&location);
+        format!("{}_{}.inv", location, column_id)
+    }
 }
 
 trait SnapshotLocationCreator {
@@ -188,6 +212,7 @@ impl SnapshotLocationCreator for TableSnapshotStatisticsVersion {
     fn suffix(&self) -> String {
         match self {
             TableSnapshotStatisticsVersion::V0(_) => "_ts_v0.json".to_string(),
+            TableSnapshotStatisticsVersion::V1(_) => "_ts_v1.json".to_string(),
         }
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::Field;
@@ -27,6 +28,7 @@ use common_expression::ColumnId;
 use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::FieldIndex;
+use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
@@ -34,6 +36,7 @@ use common_sql::field_default_value;
 use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
 use opendal::Operator;
+use storages_common_table_meta::table::widened_column_sources;
 
 // TODO: make BlockReader as a trait.
 #[derive(Clone)]
@@ -46,6 +49,20 @@ pub struct BlockReader {
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
     pub query_internal_columns: bool,
+    // The projected columns whose data type was widened, by column id.
+    pub(crate) widened_columns: HashMap<ColumnId, WidenedColumn>,
+    pub(crate) func_ctx: FunctionContext,
+}
+
+/// A column whose data type was widened without rewriting the blocks.
+///
+/// The blocks written before the widening do not contain the column, it is
+/// read from the column it was stored as, then cast to the new data type.
+#[derive(Clone)]
+pub struct WidenedColumn {
+    pub(crate) data_type: DataType,
+    // The columns it was stored as, with their data types, the latest first.
+    pub(crate) sources: Vec<(ColumnNode, DataType)>,
 }
 
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
@@ -114,17 +131,75 @@ impl BlockReader {
             }
         };
 
-        let arrow_schema = schema.to_arrow();
+        // The columns that widened columns were stored as are appended to the schema,
+        // so that they can be located in the blocks written before the widening.
+        let sources = widened_column_sources(&schema);
+        let mut storage_schema = schema.as_ref().clone();
+        for source in sources.values() {
+            if storage_schema.is_column_deleted(source.column_id()) {
+                storage_schema.fields.push(TableField::new_from_column_id(
+                    &format!("_widened_{}", source.column_id()),
+                    source.data_type().clone(),
+                    source.column_id(),
+                ));
+            }
+        }
+
+        let arrow_schema = storage_schema.to_arrow();
         let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
 
-        let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&schema));
+        let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&storage_schema));
 
         let project_column_nodes: Vec<ColumnNode> = projection
             .project_column_nodes(&column_nodes)?
             .iter()
             .map(|c| (*c).clone())
             .collect();
-        let project_indices = Self::build_projection_indices(&project_column_nodes);
+
+        let mut widened_columns = HashMap::new();
+        for column_node in &project_column_nodes {
+            if column_node.leaf_column_ids.len() != 1 {
+                continue;
+            }
+            let column_id = column_node.leaf_column_ids[0];
+            let field = match schema
+                .fields()
+                .iter()
+                .find(|field| field.column_id() == column_id)
+            {
+                Some(field) => field,
+                None => continue,
+            };
+            let mut widened_sources = vec![];
+            let mut source_id = column_id;
+            // The source is always an earlier column, which has a smaller column id.
+            while let Some(source) = sources
+                .get(&source_id)
+                .filter(|source| source.column_id() < source_id)
+            {
+                source_id = source.column_id();
+                if let Some(source_node) = column_nodes
+                    .column_nodes
+                    .iter()
+                    .skip(schema.num_fields())
+                    .find(|node| node.leaf_column_ids == [source_id])
+                {
+                    widened_sources.push((source_node.clone(), source.data_type().into()));
+                }
+            }
+            if !widened_sources.is_empty() {
+                widened_columns.insert(column_id, WidenedColumn {
+                    data_type: field.data_type().into(),
+                    sources: widened_sources,
+                });
+            }
+        }
+
+        let mut read_column_nodes = project_column_nodes.clone();
+        for widened_column in widened_columns.values() {
+            read_column_nodes.extend(widened_column.sources.iter().map(|(node, _)| node.clone()));
+        }
+        let project_indices = Self::build_projection_indices(&read_column_nodes);
 
         Ok(Arc::new(BlockReader {
             operator,
@@ -135,6 +210,8 @@ impl BlockReader {
             parquet_schema_descriptor,
             default_vals,
             query_internal_columns,
+            widened_columns,
+            func_ctx: ctx.get_function_context()?,
         }))
     }
 
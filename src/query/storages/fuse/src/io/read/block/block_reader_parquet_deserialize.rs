@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
@@ -26,8 +27,15 @@ use common_arrow::parquet::read::PageMetaData;
 use common_arrow::parquet::read::PageReader;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_storage::ColumnNode;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
@@ -107,7 +115,13 @@ impl BlockReader {
             uncompressed_buffer: &uncompressed_buffer,
         };
         for column_node in &self.project_column_nodes {
-            match self.deserialize_field(&field_deserialization_ctx, column_node)? {
+            let deserialized = match self
+                .deserialize_field(&field_deserialization_ctx, column_node)?
+            {
+                None => self.deserialize_widened_field(&field_deserialization_ctx, column_node)?,
+                deserialized => deserialized,
+            };
+            match deserialized {
                 None => {
                     need_to_fill_default_val = true;
                     need_default_vals.push(true);
@@ -312,6 +326,70 @@ impl BlockReader {
         }
     }
 
+    /// Deserializes a widened column from the column it was stored as, for the blocks
+    /// written before the widening, and casts it to the new data type.
+    fn deserialize_widened_field<'a>(
+        &self,
+        deserialization_context: &'a FieldDeserializationContext,
+        column: &ColumnNode,
+    ) -> Result<Option<DeserializedArray<'a>>> {
+        let widened_column = match column
+            .leaf_column_ids
+            .first()
+            .and_then(|column_id| self.widened_columns.get(column_id))
+        {
+            Some(widened_column) => widened_column,
+            None => return Ok(None),
+        };
+
+        for (source, source_type) in &widened_column.sources {
+            if let Some(deserialized) = self.deserialize_field(deserialization_context, source)? {
+                let array = match &deserialized {
+                    DeserializedArray::Deserialized((_, array, ..)) => array.as_ref(),
+                    DeserializedArray::NoNeedToCache(array) => array.as_ref(),
+                    DeserializedArray::Cached(sized_column) => sized_column.0.as_ref(),
+                };
+                let array = self.cast_widened_array(
+                    array,
+                    source_type,
+                    &widened_column.data_type,
+                    deserialization_context.num_rows,
+                )?;
+                return Ok(Some(DeserializedArray::NoNeedToCache(array)));
+            }
+        }
+        Ok(None)
+    }
+
+    // Casts the same way as `TransformCastSchema` does.
+    fn cast_widened_array(
+        &self,
+        array: &dyn Array,
+        source_type: &DataType,
+        data_type: &DataType,
+        num_rows: usize,
+    ) -> Result<Box<dyn Array>> {
+        let column = Column::from_arrow(array, source_type);
+        let block = DataBlock::new(
+            vec![BlockEntry::new(source_type.clone(), Value::Column(column))],
+            num_rows,
+        );
+        let expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: source_type.clone(),
+                display_name: String::new(),
+            }),
+            dest_type: data_type.clone(),
+        };
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&expr)?;
+        Ok(value.convert_to_full_column(data_type, num_rows).as_arrow())
+    }
+
     fn to_parquet_compression(meta_compression: &Compression) -> Result<ParquetCompression> {
         match meta_compression {
             Compression::Lz4 => {
@@ -28,6 +28,8 @@ use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
+use storages_common_index::VectorIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
@@ -107,12 +109,16 @@ impl BloomIndexState {
         source_schema: TableSchemaRef,
         block: &DataBlock,
         location: Location,
+        ngram_fields: &[FieldIndex],
     ) -> Result<Option<Self>> {
         // write index
-        let maybe_bloom_index =
-            BloomIndex::try_create(ctx.get_function_context()?, source_schema, location.1, &[
-                block,
-            ])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            ctx.get_function_context()?,
+            source_schema,
+            location.1,
+            &[block],
+            ngram_fields,
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
@@ -137,11 +143,82 @@ impl BloomIndexState {
     }
 }
 
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl VectorIndexState {
+    pub fn try_create(
+        write_settings: &WriteSettings,
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &Location,
+    ) -> Result<Vec<Self>> {
+        let Some((distance, field_indices)) = &write_settings.vector_index else {
+            return Ok(vec![]);
+        };
+
+        let mut states = Vec::with_capacity(field_indices.len());
+        for field_index in field_indices {
+            let entry = block.get_by_offset(*field_index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+            if let Some(vector_index) = VectorIndex::try_create(*distance, &column)? {
+                let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+                vector_index.serialize(&mut data);
+                let column_id = source_schema.field(*field_index).column_id();
+                let location = TableMetaLocationGenerator::gen_vector_index_location(
+                    &block_location.0,
+                    column_id,
+                );
+                states.push(Self { data, location });
+            }
+        }
+        Ok(states)
+    }
+}
+
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        write_settings: &WriteSettings,
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &Location,
+    ) -> Result<Vec<Self>> {
+        let mut states = Vec::with_capacity(write_settings.inverted_index.len());
+        for field_index in &write_settings.inverted_index {
+            let entry = block.get_by_offset(*field_index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+            let inverted_index = InvertedIndex::try_create(&column)?;
+            let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+            inverted_index.serialize(&mut data);
+            let column_id = source_schema.field(*field_index).column_id();
+            let location = TableMetaLocationGenerator::gen_inverted_index_location(
+                &block_location.0,
+                column_id,
+            );
+            states.push(Self { data, location });
+        }
+        Ok(states)
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub vector_index_states: Vec<VectorIndexState>,
+    pub inverted_index_states: Vec<InvertedIndexState>,
 }
 
 #[derive(Clone)]
@@ -166,6 +243,19 @@ impl BlockBuilder {
             self.source_schema.clone(),
             &data_block,
             bloom_index_location,
+            &self.write_settings.ngram_index,
+        )?;
+        let vector_index_states = VectorIndexState::try_create(
+            &self.write_settings,
+            &self.source_schema,
+            &data_block,
+            &block_location,
+        )?;
+        let inverted_index_states = InvertedIndexState::try_create(
+            &self.write_settings,
+            &self.source_schema,
+            &data_block,
+            &block_location,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -205,6 +295,8 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            vector_index_states,
+            inverted_index_states,
         };
         Ok(serialized)
     }
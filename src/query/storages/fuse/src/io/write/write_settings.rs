@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::FieldIndex;
+use common_vector::VectorDistance;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::FuseStorageFormat;
@@ -26,6 +28,12 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+    // indexed fields of the n-gram filter
+    pub ngram_index: Vec<FieldIndex>,
+    // indexed fields of the inverted index
+    pub inverted_index: Vec<FieldIndex>,
+    // distance and indexed fields of the vector index
+    pub vector_index: Option<(VectorDistance, Vec<FieldIndex>)>,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +43,9 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            ngram_index: vec![],
+            inverted_index: vec![],
+            vector_index: None,
         }
     }
 }
@@ -13,23 +13,43 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use siphasher::sip::SipHasher24;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Histogram;
+use storages_common_table_meta::meta::HistogramBucket;
+use storages_common_table_meta::meta::MetaHLL;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use tracing::info;
 use tracing::warn;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
 use crate::statistics::reduce_block_statistics;
 use crate::FuseTable;
 
+/// Max number of values sampled from a column to build its histogram.
+const HISTOGRAM_SAMPLE_SIZE: u64 = 10000;
+/// Number of buckets of the equi-height histogram.
+const HISTOGRAM_BUCKETS: usize = 100;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -50,12 +70,10 @@ impl FuseTable {
         };
 
         if let Some(snapshot) = snapshot_opt {
-            // 2. Iterator segments and blocks to estimate statistics.
-            let mut sum_map = HashMap::new();
-            let mut row_count_sum = 0;
-            let mut block_count_sum: u64 = 0;
+            // 2. Iterator segments to reduce the column statistics and collect the blocks.
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
+            let mut block_metas: Vec<Arc<BlockMeta>> = Vec::new();
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -73,29 +91,13 @@ impl FuseTable {
                 for segment in segments {
                     let segment = segment?;
                     stats_of_columns.push(segment.summary.col_stats.clone());
-                    segment.blocks.iter().for_each(|block| {
-                        let block = block.as_ref();
-                        let row_count = block.row_count;
-                        if row_count != 0 {
-                            block_count_sum += 1;
-                            row_count_sum += row_count;
-                            for (i, col_stat) in block.col_stats.iter() {
-                                let density = match col_stat.distinct_of_values {
-                                    Some(ndv) => ndv as f64 / row_count as f64,
-                                    None => 0.0,
-                                };
-
-                                match sum_map.get_mut(i) {
-                                    Some(sum) => {
-                                        *sum += density;
-                                    }
-                                    None => {
-                                        let _ = sum_map.insert(*i, density);
-                                    }
-                                }
-                            }
-                        }
-                    });
+                    block_metas.extend(
+                        segment
+                            .blocks
+                            .iter()
+                            .filter(|block| block.row_count != 0)
+                            .cloned(),
+                    );
                 }
 
                 // Generate new column statistics for snapshot
@@ -115,14 +117,79 @@ impl FuseTable {
                 }
             }
 
-            let mut ndv_map = HashMap::new();
-            for (i, sum) in sum_map.iter() {
-                let density_avg = *sum / block_count_sum as f64;
-                ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
+            // 3. Read the blocks to build the distinct value sketches and histograms.
+            let schema = self.schema();
+            let mut field_indices = Vec::new();
+            let mut collectors = Vec::new();
+            for (index, field) in schema.fields().iter().enumerate() {
+                let data_type = DataType::from(field.data_type()).remove_nullable();
+                let support_histogram = match data_type {
+                    DataType::Number(_)
+                    | DataType::String
+                    | DataType::Date
+                    | DataType::Timestamp => true,
+                    DataType::Tuple(_) | DataType::Array(_) | DataType::Map(_) => continue,
+                    _ => false,
+                };
+                field_indices.push(index);
+                collectors.push(ColumnStatisticsCollector::new(
+                    field.column_id(),
+                    support_histogram,
+                ));
+            }
+
+            let sample_step = std::cmp::max(1, snapshot.summary.row_count / HISTOGRAM_SAMPLE_SIZE);
+            let block_reader =
+                self.create_block_reader(Projection::Columns(field_indices), false, ctx.clone())?;
+            let settings = ReadSettings::from_ctx(ctx)?;
+            let mut row_offset = 0;
+            let mut read_block_count = 0;
+            let number_blocks = block_metas.len();
+            for chunk in block_metas.chunks(max_io_requests) {
+                let blocks = futures::future::try_join_all(chunk.iter().map(|block_meta| {
+                    block_reader.read_by_meta(&settings, block_meta.as_ref(), &self.storage_format)
+                }))
+                .await?;
+
+                for block in blocks {
+                    let num_rows = block.num_rows();
+                    for (offset, collector) in collectors.iter_mut().enumerate() {
+                        collector.collect(
+                            block.get_by_offset(offset),
+                            num_rows,
+                            row_offset,
+                            sample_step,
+                        );
+                    }
+                    row_offset += num_rows as u64;
+                }
+
+                // Status.
+                {
+                    read_block_count += chunk.len();
+                    let status = format!(
+                        "analyze: read block files:{}/{}, cost:{} sec",
+                        read_block_count,
+                        number_blocks,
+                        start.elapsed().as_secs()
+                    );
+                    ctx.set_status_info(&status);
+                    info!(status);
+                }
+            }
+
+            let mut hll_map = HashMap::with_capacity(collectors.len());
+            let mut histograms = HashMap::new();
+            for collector in collectors {
+                let ndv = collector.hll.count();
+                if let Some(histogram) = collector.build_histogram(ndv) {
+                    histograms.insert(collector.column_id, histogram);
+                }
+                hll_map.insert(collector.column_id, collector.hll);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 4. Generate new table statistics
+            let table_statistics = TableSnapshotStatistics::new(hll_map, histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -130,7 +197,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.table_statistics_location = Some(table_statistics_location);
@@ -149,3 +216,99 @@ impl FuseTable {
         Ok(())
     }
 }
+
+struct ColumnStatisticsCollector {
+    column_id: ColumnId,
+    hll: MetaHLL,
+    // number of non-null values
+    num_values: u64,
+    // sampled values, none if the column does not support histogram
+    samples: Option<Vec<Scalar>>,
+}
+
+impl ColumnStatisticsCollector {
+    fn new(column_id: ColumnId, support_histogram: bool) -> Self {
+        Self {
+            column_id,
+            hll: MetaHLL::new(),
+            num_values: 0,
+            samples: support_histogram.then(Vec::new),
+        }
+    }
+
+    fn collect(&mut self, entry: &BlockEntry, num_rows: usize, row_offset: u64, step: u64) {
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        for (row, value) in column.iter().enumerate() {
+            if matches!(value, ScalarRef::Null) {
+                continue;
+            }
+            self.num_values += 1;
+
+            // Use a hasher with fixed keys, so that sketches are mergeable across queries.
+            let mut hasher = SipHasher24::new();
+            value.hash(&mut hasher);
+            self.hll.add_hash(hasher.finish());
+
+            if let Some(samples) = &mut self.samples {
+                if (row_offset + row as u64) % step == 0 && !is_nan(&value) {
+                    samples.push(value.to_owned());
+                }
+            }
+        }
+    }
+
+    /// Build an equi-height histogram from the sampled values,
+    /// the number of values and distinct values are scaled to the whole column.
+    fn build_histogram(&self, ndv: u64) -> Option<Histogram> {
+        let mut samples = match &self.samples {
+            Some(samples) if !samples.is_empty() => samples.clone(),
+            _ => return None,
+        };
+        samples.sort();
+
+        let num_samples = samples.len();
+        let sample_ndv = count_distinct(&samples);
+        let values_scale = self.num_values as f64 / num_samples as f64;
+        let distinct_scale = std::cmp::max(ndv, sample_ndv) as f64 / sample_ndv as f64;
+
+        let num_buckets = std::cmp::min(HISTOGRAM_BUCKETS, num_samples);
+        let mut buckets = Vec::with_capacity(num_buckets);
+        let mut start = 0;
+        for idx in 0..num_buckets {
+            if start >= num_samples {
+                break;
+            }
+            let mut end = std::cmp::max(start + 1, (idx + 1) * num_samples / num_buckets);
+            // Values equal to the upper bound must fall into the same bucket.
+            while end < num_samples && samples[end] == samples[end - 1] {
+                end += 1;
+            }
+            buckets.push(HistogramBucket {
+                upper_bound: samples[end - 1].clone(),
+                num_values: (end - start) as f64 * values_scale,
+                num_distinct: count_distinct(&samples[start..end]) as f64 * distinct_scale,
+            });
+            start = end;
+        }
+
+        Some(Histogram { buckets })
+    }
+}
+
+// Count distinct values of sorted values.
+fn count_distinct(sorted: &[Scalar]) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    1 + sorted.windows(2).filter(|w| w[0] != w[1]).count() as u64
+}
+
+fn is_nan(value: &ScalarRef) -> bool {
+    match value {
+        ScalarRef::Number(NumberScalar::Float32(v)) => v.is_nan(),
+        ScalarRef::Number(NumberScalar::Float64(v)) => v.is_nan(),
+        _ => false,
+    }
+}
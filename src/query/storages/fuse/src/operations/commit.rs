@@ -74,6 +74,7 @@ impl FuseTable {
         pipeline: &mut Pipeline,
         copied_files: Option<UpsertTableCopiedFileReq>,
         overwrite: bool,
+        max_retry_elapsed: Option<Duration>,
     ) -> Result<()> {
         pipeline.resize(1)?;
 
@@ -100,7 +101,7 @@ impl FuseTable {
                 copied_files.clone(),
                 snapshot_gen.clone(),
                 input,
-                None,
+                max_retry_elapsed,
             )
         })?;
 
@@ -196,6 +197,14 @@ impl FuseTable {
             number_of_blocks: Some(stats.block_count),
         };
 
+        // 1.3 check the storage quota for all the kinds of commits, before the snapshot is visible
+        let added_bytes = stats.compressed_byte_size as i64
+            - table_info.meta.statistics.compressed_data_bytes as i64;
+        if let Err(e) = ctx.check_storage_quota(table_info, added_bytes).await {
+            let _ = operator.delete(&snapshot_location).await;
+            return Err(e);
+        }
+
         // 2. prepare the request
         let catalog = ctx.get_catalog(table_info.catalog())?;
         let table_id = table_info.ident.table_id;
@@ -209,7 +218,18 @@ impl FuseTable {
             deduplicated_label: ctx.get_settings().get_deduplicate_label()?,
         };
 
-        // 3. let's roll
+        // 3. in an explicit transaction, the new meta is buffered and committed by COMMIT.
+        {
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(table_info.catalog(), table_info, req)?;
+                TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+                return Ok(());
+            }
+        }
+
+        // 4. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
@@ -230,6 +250,16 @@ impl FuseTable {
                         snapshot_location, table_info.desc, table_info.ident
                     );
                     let _ = operator.delete(&snapshot_location).await;
+                    // the commit may be retried with the refreshed table, which checks the
+                    // storage quota again.
+                    if let Err(release_error) =
+                        ctx.release_storage_quota(table_info, added_bytes).await
+                    {
+                        warn!(
+                            "release the storage quota of table {} failed: {}",
+                            table_info.desc, release_error
+                        );
+                    }
                 }
                 Err(e)
             }
@@ -278,30 +278,12 @@ where F: SnapshotGenerator + Send + 'static
                 .await
                 {
                     Ok(_) => {
-                        if self.transient {
-                            // Removes historical data, if table is transient
-                            let latest = self.table.refresh(self.ctx.as_ref()).await?;
-                            let tbl = FuseTable::try_from_table(latest.as_ref())?;
-
-                            tracing::warn!(
-                                "transient table detected, purging historical data. ({})",
-                                tbl.table_info.ident
-                            );
-
-                            let keep_last_snapshot = true;
-                            let snapshot_files = tbl.list_snapshot_files().await?;
-                            if let Err(e) = tbl
-                                .do_purge(&self.ctx, snapshot_files, keep_last_snapshot, None)
-                                .await
-                            {
-                                // Errors of GC, if any, are ignored, since GC task can be picked up
-                                tracing::warn!(
-                                    "GC of transient table not success (this is not a permanent error). the error : {}",
-                                    e
-                                );
-                            } else {
-                                tracing::info!("GC of transient table done");
-                            }
+                        // Inside an explicit transaction the new meta is only buffered, the
+                        // historical data is purged once the transaction is committed.
+                        if self.transient && !self.ctx.txn_mgr().lock().is_active() {
+                            FuseTable::try_from_table(self.table.as_ref())?
+                                .purge_transient_history(&self.ctx)
+                                .await?;
                         }
                         metrics_inc_commit_mutation_success();
                         self.heartbeat.shutdown().await?;
@@ -343,6 +325,13 @@ where F: SnapshotGenerator + Send + 'static
                             }
                         }
                     }
+                    Err(e) if e.code() == ErrorCode::USER_QUOTA_EXCEEDED => {
+                        // Nothing is committed, remove the data written by this operation.
+                        self.heartbeat.shutdown().await?;
+                        let op = self.abort_operation.clone();
+                        op.abort(self.ctx.clone(), self.dal.clone()).await?;
+                        return Err(e);
+                    }
                     Err(e) => {
                         // we are not sure about if the table state has been modified or not, just propagate the error
                         // and return, without aborting anything.
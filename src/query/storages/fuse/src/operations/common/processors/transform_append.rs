@@ -292,6 +292,14 @@ impl Processor for AppendTransform {
                         );
                     }
                 }
+                for index_state in serialized.vector_index_states {
+                    write_data(index_state.data, &self.data_accessor, &index_state.location)
+                        .await?;
+                }
+                for index_state in serialized.inverted_index_states {
+                    write_data(index_state.data, &self.data_accessor, &index_state.location)
+                        .await?;
+                }
 
                 self.accumulator.add_with_block_meta(serialized.block_meta);
 
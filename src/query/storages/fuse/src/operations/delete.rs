@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_base::base::ProgressValues;
+use common_catalog::plan::compute_row_id_prefix;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::Projection;
@@ -40,6 +42,9 @@ use common_expression::TableSchema;
 use common_expression::Value;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::OutputPort;
 use common_sql::evaluator::BlockOperator;
 use storages_common_table_meta::meta::TableSnapshot;
 use tracing::info;
@@ -192,6 +197,26 @@ impl FuseTable {
         query_row_id_col: bool,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        let source_items = self
+            .create_deletion_source_items(ctx, filter, col_indices, base_snapshot, query_row_id_col)
+            .await?;
+        if !source_items.is_empty() {
+            pipeline.add_pipe(Pipe::create(0, source_items.len(), source_items));
+        }
+        Ok(())
+    }
+
+    /// Create the mutation sources which remove the rows matching the filter,
+    /// returns nothing if no block needs to be mutated.
+    #[async_backtrace::framed]
+    pub(crate) async fn create_deletion_source_items(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        filter: &RemoteExpr<String>,
+        col_indices: Vec<usize>,
+        base_snapshot: &TableSnapshot,
+        query_row_id_col: bool,
+    ) -> Result<Vec<PipeItem>> {
         let projection = Projection::Columns(col_indices.clone());
         let total_tasks = self
             .mutation_block_pruning(
@@ -199,10 +224,11 @@ impl FuseTable {
                 Some(filter.clone()),
                 projection.clone(),
                 base_snapshot,
+                None,
             )
             .await?;
         if total_tasks == 0 {
-            return Ok(());
+            return Ok(vec![]);
         }
 
         // Status.
@@ -257,24 +283,24 @@ impl FuseTable {
 
         let max_threads =
             std::cmp::min(ctx.get_settings().get_max_threads()? as usize, total_tasks);
-        // Add source pipe.
-        pipeline.add_source(
-            |output| {
-                MutationSource::try_create(
-                    ctx.clone(),
-                    MutationAction::Deletion,
-                    output,
-                    filter.clone(),
-                    block_reader.clone(),
-                    remain_reader.clone(),
-                    ops.clone(),
-                    self.storage_format,
-                    query_row_id_col,
-                )
-            },
-            max_threads,
-        )?;
-        Ok(())
+        let mut source_items = Vec::with_capacity(max_threads);
+        for _ in 0..max_threads {
+            let output = OutputPort::create();
+            let source = MutationSource::try_create(
+                ctx.clone(),
+                MutationAction::Deletion,
+                output.clone(),
+                filter.clone(),
+                block_reader.clone(),
+                remain_reader.clone(),
+                ops.clone(),
+                self.storage_format,
+                query_row_id_col,
+                Arc::new(None),
+            )?;
+            source_items.push(PipeItem::create(source, vec![], vec![output]));
+        }
+        Ok(source_items)
     }
 
     #[async_backtrace::framed]
@@ -284,6 +310,7 @@ impl FuseTable {
         filter: Option<RemoteExpr<String>>,
         projection: Projection,
         base_snapshot: &TableSnapshot,
+        row_id_prefixes: Option<&HashSet<u64>>,
     ) -> Result<usize> {
         let push_down = Some(PushDownInfo {
             projection: Some(projection),
@@ -300,7 +327,14 @@ impl FuseTable {
         )?;
 
         let segment_locations = create_segment_location_vector(segment_locations, None);
-        let block_metas = pruner.pruning(segment_locations).await?;
+        let mut block_metas = pruner.pruning(segment_locations).await?;
+        if let Some(row_id_prefixes) = row_id_prefixes {
+            // Only the blocks containing the given rows are mutated.
+            block_metas.retain(|(index, _)| {
+                let prefix = compute_row_id_prefix(index.segment_idx as u64, index.block_id as u64);
+                row_id_prefixes.contains(&prefix)
+            });
+        }
 
         let range_block_metas = block_metas
             .clone()
@@ -19,6 +19,7 @@ use std::time::Instant;
 
 use common_cache::CountableMeter;
 use common_catalog::table::Table;
+use common_catalog::table::TableExt;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,6 +27,8 @@ use storages_common_cache::CacheAccessor;
 use storages_common_cache::LoadParams;
 use storages_common_cache_manager::CachedObject;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::InvertedIndex;
+use storages_common_index::VectorIndex;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -41,9 +44,39 @@ use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::FuseTable;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
 impl FuseTable {
+    /// Removes the historical data of the transient table after a commit, only the last
+    /// snapshot is kept.
+    #[async_backtrace::framed]
+    pub async fn purge_transient_history(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
+        let latest = self.refresh(ctx.as_ref()).await?;
+        let tbl = FuseTable::try_from_table(latest.as_ref())?;
+
+        warn!(
+            "transient table detected, purging historical data. ({})",
+            tbl.table_info.ident
+        );
+
+        let keep_last_snapshot = true;
+        let snapshot_files = tbl.list_snapshot_files().await?;
+        if let Err(e) = tbl
+            .do_purge(ctx, snapshot_files, keep_last_snapshot, None)
+            .await
+        {
+            // Errors of GC, if any, are ignored, since GC task can be picked up
+            warn!(
+                "GC of transient table not success (this is not a permanent error). the error : {}",
+                e
+            );
+        } else {
+            info!("GC of transient table done");
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub async fn do_purge(
         &self,
@@ -425,8 +458,22 @@ impl FuseTable {
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
             counter.blocks += blocks_count;
+            let vector_indexes_to_be_purged = self.vector_index_locations(&blocks_to_be_purged);
+            let inverted_indexes_to_be_purged =
+                self.inverted_index_locations(&blocks_to_be_purged).await?;
             self.try_purge_location_files(ctx.clone(), blocks_to_be_purged)
                 .await?;
+            if !vector_indexes_to_be_purged.is_empty() {
+                self.try_purge_location_files(ctx.clone(), vector_indexes_to_be_purged)
+                    .await?;
+            }
+            if !inverted_indexes_to_be_purged.is_empty() {
+                self.try_purge_location_files_and_cache::<InvertedIndex, _, _>(
+                    ctx.clone(),
+                    inverted_indexes_to_be_purged,
+                )
+                .await?;
+            }
         }
 
         // 2. Try to purge bloom index file chunks.
@@ -453,6 +500,69 @@ impl FuseTable {
         Ok(())
     }
 
+    // Vector index files of the blocks, the blocks without vector index are ignored by the purge.
+    fn vector_index_locations(&self, block_locations: &HashSet<String>) -> HashSet<String> {
+        let schema = self.table_info.schema();
+        let Ok(Some((_, field_indices))) =
+            VectorIndex::parse_options(&schema, &self.table_info.meta.options)
+        else {
+            return HashSet::new();
+        };
+
+        let column_ids = field_indices
+            .iter()
+            .map(|field_index| schema.field(*field_index).column_id())
+            .collect::<Vec<_>>();
+        block_locations
+            .iter()
+            .flat_map(|location| {
+                column_ids.iter().map(move |column_id| {
+                    TableMetaLocationGenerator::gen_vector_index_location(location, *column_id)
+                })
+            })
+            .collect()
+    }
+
+    // Inverted index files of the blocks. The files are found by listing the directory of the
+    // inverted indexes instead of the table options, so the files of the dropped indexes are
+    // purged as well.
+    #[async_backtrace::framed]
+    async fn inverted_index_locations(
+        &self,
+        block_locations: &HashSet<String>,
+    ) -> Result<HashSet<String>> {
+        // The index files of a block are named `<block name>_<column id>.inv`.
+        let block_names = block_locations
+            .iter()
+            .map(|location| {
+                let location = TableMetaLocationGenerator::gen_inverted_index_location(location, 0);
+                location
+                    .strip_suffix("_0.inv")
+                    .unwrap_or(&location)
+                    .to_string()
+            })
+            .collect::<HashSet<_>>();
+
+        let prefix = format!(
+            "{}/{}/",
+            self.meta_location_generator.prefix(),
+            FUSE_TBL_INVERTED_INDEX_PREFIX
+        );
+        let files = match SnapshotsIO::list_files(self.operator.clone(), &prefix, None).await {
+            Ok(files) => files,
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => return Ok(HashSet::new()),
+            Err(e) => return Err(e),
+        };
+        Ok(files
+            .into_iter()
+            .filter(|file| {
+                file.strip_suffix(".inv")
+                    .and_then(|name| name.rsplit_once('_'))
+                    .map_or(false, |(block_name, _)| block_names.contains(block_name))
+            })
+            .collect())
+    }
+
     async fn purge_ts_snapshots(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_sources::BlocksSource;
+use parking_lot::Mutex;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::operations::common::AppendTransform;
+use crate::operations::mutation::MatchedRowIds;
+use crate::operations::mutation::MutationAction;
+use crate::operations::mutation::MutationSource;
+use crate::operations::mutation::SerializeDataTransform;
+use crate::pipelines::Pipeline;
+use crate::statistics::ClusterStatsGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// MERGE INTO removes the matched rows (which are deleted or updated) from their blocks,
+    /// and appends the updated rows together with the inserted rows as new blocks. All the
+    /// mutations are committed in a single snapshot.
+    ///
+    /// The matched rows are identified by their `_row_id`, so they must be read from the
+    /// same snapshot as this table.
+    ///
+    /// The flow of Pipeline is as follows:
+    /// +---------------+      +-----------------------+
+    /// |MutationSource1| ---> |SerializeDataTransform1|   ------
+    /// +---------------+      +-----------------------+         |
+    /// |     ...       | ---> |          ...          |   ...   |      +-----------------------+      +----------+
+    /// +---------------+      +-----------------------+         | ---> |TableMutationAggregator| ---> |CommitSink|
+    /// |MutationSourceN| ---> |SerializeDataTransformN|   ------|      +-----------------------+      +----------+
+    /// +---------------+      +-----------------------+         |
+    /// | BlocksSource  | ---> |    AppendTransform    |   ------
+    /// +---------------+      +-----------------------+
+    #[async_backtrace::framed]
+    pub async fn do_merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        matched_row_ids: MatchedRowIds,
+        new_blocks: Vec<DataBlock>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let cluster_stats_gen =
+            self.get_cluster_stats_gen(ctx.clone(), 0, self.get_block_thresholds())?;
+        let base_snapshot = self.read_table_snapshot().await?.unwrap_or_else(|| {
+            Arc::new(TableSnapshot::new_empty_snapshot(schema.as_ref().clone()))
+        });
+
+        // 1. sources which remove the matched rows from their blocks.
+        let mut source_items = if matched_row_ids.is_empty() {
+            vec![]
+        } else {
+            self.create_merge_into_source_items(ctx.clone(), matched_row_ids, &base_snapshot)
+                .await?
+        };
+        let mut transform_items = Vec::with_capacity(source_items.len() + 1);
+        for _ in 0..source_items.len() {
+            let input = InputPort::create();
+            let output = OutputPort::create();
+            let transform = SerializeDataTransform::try_create(
+                ctx.clone(),
+                input.clone(),
+                output.clone(),
+                self,
+                cluster_stats_gen.clone(),
+            )?;
+            transform_items.push(PipeItem::create(transform, vec![input], vec![output]));
+        }
+
+        // 2. source which appends the updated and inserted rows.
+        let new_blocks = self.prepare_merge_into_blocks(new_blocks, &cluster_stats_gen)?;
+        if !new_blocks.is_empty() {
+            let output = OutputPort::create();
+            let source = BlocksSource::create(
+                ctx.clone(),
+                output.clone(),
+                Arc::new(Mutex::new(VecDeque::from(new_blocks))),
+            )?;
+            source_items.push(PipeItem::create(source, vec![], vec![output]));
+
+            let append_transform = AppendTransform::new(
+                ctx.clone(),
+                InputPort::create(),
+                OutputPort::create(),
+                self,
+                cluster_stats_gen,
+                self.get_block_thresholds(),
+            );
+            transform_items.push(append_transform.into_pipe_item());
+        }
+
+        if source_items.is_empty() {
+            // nothing to merge.
+            return Ok(());
+        }
+
+        let size = source_items.len();
+        pipeline.add_pipe(Pipe::create(0, size, source_items));
+        pipeline.add_pipe(Pipe::create(size, size, transform_items));
+        self.chain_mutation_pipes(&ctx, pipeline, base_snapshot)
+            .await
+    }
+
+    // Create the mutation sources which remove the matched rows from the blocks containing them.
+    async fn create_merge_into_source_items(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        matched_row_ids: MatchedRowIds,
+        base_snapshot: &TableSnapshot,
+    ) -> Result<Vec<PipeItem>> {
+        let row_id_prefixes = matched_row_ids.keys().cloned().collect::<HashSet<_>>();
+        let projection = Projection::Columns(self.all_column_indices());
+        let total_tasks = self
+            .mutation_block_pruning(
+                ctx.clone(),
+                None,
+                projection.clone(),
+                base_snapshot,
+                Some(&row_id_prefixes),
+            )
+            .await?;
+        if total_tasks == 0 {
+            return Ok(vec![]);
+        }
+
+        let block_reader = self.create_block_reader(projection, false, ctx.clone())?;
+        let matched_row_ids = Arc::new(Some(matched_row_ids));
+        let max_threads =
+            std::cmp::min(ctx.get_settings().get_max_threads()? as usize, total_tasks);
+        let mut source_items = Vec::with_capacity(max_threads);
+        for _ in 0..max_threads {
+            let output = OutputPort::create();
+            let source = MutationSource::try_create(
+                ctx.clone(),
+                MutationAction::Deletion,
+                output.clone(),
+                Arc::new(None),
+                block_reader.clone(),
+                Arc::new(None),
+                vec![],
+                self.storage_format,
+                false,
+                matched_row_ids.clone(),
+            )?;
+            source_items.push(PipeItem::create(source, vec![], vec![output]));
+        }
+        Ok(source_items)
+    }
+
+    // Compact the new rows into blocks of the expected size, and generate the
+    // extra cluster key columns if the table has cluster keys.
+    fn prepare_merge_into_blocks(
+        &self,
+        new_blocks: Vec<DataBlock>,
+        cluster_stats_gen: &ClusterStatsGenerator,
+    ) -> Result<Vec<DataBlock>> {
+        let new_blocks = new_blocks
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>();
+        if new_blocks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let block = DataBlock::concat(&new_blocks)?;
+        let max_rows_per_block = self.get_block_thresholds().max_rows_per_block;
+        let (mut blocks, tail) = block.split_by_rows(max_rows_per_block);
+        blocks.extend(tail);
+
+        if cluster_stats_gen.operators.is_empty() {
+            return Ok(blocks);
+        }
+        let func_ctx = &cluster_stats_gen.func_ctx;
+        blocks
+            .into_iter()
+            .map(|block| {
+                cluster_stats_gen
+                    .operators
+                    .iter()
+                    .try_fold(block, |input, op| op.execute(func_ctx, input))
+            })
+            .collect()
+    }
+}
@@ -19,6 +19,7 @@ mod common;
 mod compact;
 mod delete;
 mod gc;
+mod merge_into;
 mod mutation;
 mod navigate;
 mod read;
@@ -37,6 +38,7 @@ pub use common::FillInternalColumnProcessor;
 pub use compact::CompactOptions;
 pub use mutation::BlockCompactMutator;
 pub use mutation::CompactPartInfo;
+pub use mutation::MatchedRowIds;
 pub use mutation::ReclusterMutator;
 pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
@@ -185,6 +185,12 @@ impl Processor for CompactSource {
                 if let Some(index_state) = serialized.bloom_index_state {
                     write_data(index_state.data, &self.dal, &index_state.location.0).await?;
                 }
+                for index_state in serialized.vector_index_states {
+                    write_data(index_state.data, &self.dal, &index_state.location).await?;
+                }
+                for index_state in serialized.inverted_index_states {
+                    write_data(index_state.data, &self.dal, &index_state.location).await?;
+                }
 
                 // Perf
                 {
@@ -28,6 +28,7 @@ pub use compact::SegmentCompactionState;
 pub use compact::SegmentCompactor;
 pub use mutation_meta::SerializeDataMeta;
 pub use mutation_part::MutationPartInfo;
+pub use mutation_source::MatchedRowIds;
 pub use mutation_source::MutationAction;
 pub use mutation_source::MutationSource;
 pub use recluster_mutator::ReclusterMutator;
@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::ProgressValues;
+use common_catalog::plan::compute_row_id_prefix;
 use common_catalog::plan::InternalColumn;
 use common_catalog::plan::InternalColumnMeta;
 use common_catalog::plan::InternalColumnType;
@@ -49,6 +52,9 @@ use crate::pipelines::processors::Processor;
 use crate::FuseStorageFormat;
 use crate::MergeIOReadResult;
 
+/// The offsets of the rows to be mutated, keyed by the row id prefix of their blocks.
+pub type MatchedRowIds = HashMap<u64, Vec<u64>>;
+
 pub enum MutationAction {
     Deletion,
     Update,
@@ -85,6 +91,8 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    // The rows to be mutated, the filter is ignored if it is set.
+    matched_row_ids: Arc<Option<MatchedRowIds>>,
 
     index: BlockMetaIndex,
     origin_stats: Option<ClusterStatistics>,
@@ -102,6 +110,7 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        matched_row_ids: Arc<Option<MatchedRowIds>>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -113,11 +122,65 @@ impl MutationSource {
             operators,
             action,
             query_row_id_col,
+            matched_row_ids,
             index: BlockMetaIndex::default(),
             origin_stats: None,
             storage_format,
         })))
     }
+
+    // Evaluate the rows to be mutated in the block, returns none if all the rows are mutated.
+    fn eval_predicates(
+        &self,
+        part: &PartInfoPtr,
+        data_block: &mut DataBlock,
+    ) -> Result<Option<Value<BooleanType>>> {
+        let num_rows = data_block.num_rows();
+        if let Some(matched_row_ids) = self.matched_row_ids.as_ref() {
+            let fuse_part = FusePartInfo::from_part(part)?;
+            let block_meta = fuse_part.block_meta_index().unwrap();
+            let prefix =
+                compute_row_id_prefix(block_meta.segment_idx as u64, block_meta.block_id as u64);
+            let mut bitmap = MutableBitmap::from_len_zeroed(num_rows);
+            for offset in matched_row_ids.get(&prefix).into_iter().flatten() {
+                bitmap.set(*offset as usize, true);
+            }
+            return Ok(Some(Value::Column(bitmap.into())));
+        }
+
+        let Some(filter) = self.filter.as_ref() else {
+            return Ok(None);
+        };
+        if self.query_row_id_col {
+            // Add internal column to data block
+            let fuse_part = FusePartInfo::from_part(part)?;
+            let block_meta = fuse_part.block_meta_index().unwrap();
+            let internal_column_meta = InternalColumnMeta {
+                segment_idx: block_meta.segment_idx,
+                block_id: block_meta.block_id,
+                block_location: block_meta.block_location.clone(),
+                segment_location: block_meta.segment_location.clone(),
+                snapshot_location: "".to_string(),
+                offsets: None,
+            };
+            let internal_col = InternalColumn {
+                column_name: ROW_ID_COL_NAME.to_string(),
+                column_type: InternalColumnType::RowId,
+            };
+            let row_id_col = internal_col.generate_column_values(&internal_column_meta, num_rows);
+            data_block.add_column(row_id_col);
+        }
+        assert_eq!(filter.data_type(), &DataType::Boolean);
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(data_block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let predicates = evaluator
+            .run(filter)
+            .map_err(|e| e.add_message("eval filter failed:"))?
+            .try_downcast::<BooleanType>()
+            .unwrap();
+        Ok(Some(predicates))
+    }
 }
 
 #[async_trait::async_trait]
@@ -183,38 +246,7 @@ impl Processor for MutationSource {
                 )?;
                 let num_rows = data_block.num_rows();
 
-                if let Some(filter) = self.filter.as_ref() {
-                    if self.query_row_id_col {
-                        // Add internal column to data block
-                        let fuse_part = FusePartInfo::from_part(&part)?;
-                        let block_meta = fuse_part.block_meta_index().unwrap();
-                        let internal_column_meta = InternalColumnMeta {
-                            segment_idx: block_meta.segment_idx,
-                            block_id: block_meta.block_id,
-                            block_location: block_meta.block_location.clone(),
-                            segment_location: block_meta.segment_location.clone(),
-                            snapshot_location: "".to_string(),
-                            offsets: None,
-                        };
-                        let internal_col = InternalColumn {
-                            column_name: ROW_ID_COL_NAME.to_string(),
-                            column_type: InternalColumnType::RowId,
-                        };
-                        let row_id_col = internal_col
-                            .generate_column_values(&internal_column_meta, data_block.num_rows());
-                        data_block.add_column(row_id_col);
-                    }
-                    assert_eq!(filter.data_type(), &DataType::Boolean);
-
-                    let func_ctx = self.ctx.get_function_context()?;
-                    let evaluator = Evaluator::new(&data_block, &func_ctx, &BUILTIN_FUNCTIONS);
-
-                    let predicates = evaluator
-                        .run(filter)
-                        .map_err(|e| e.add_message("eval filter failed:"))?
-                        .try_downcast::<BooleanType>()
-                        .unwrap();
-
+                if let Some(predicates) = self.eval_predicates(&part, &mut data_block)? {
                     let affect_rows = match &predicates {
                         Value::Scalar(v) => {
                             if *v {
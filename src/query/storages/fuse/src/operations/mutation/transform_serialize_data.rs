@@ -191,6 +191,12 @@ impl Processor for SerializeDataTransform {
                     )
                     .await?;
                 }
+                for index_state in serialized.vector_index_states {
+                    write_data(index_state.data, &self.dal, &index_state.location).await?;
+                }
+                for index_state in serialized.inverted_index_states {
+                    write_data(index_state.data, &self.dal, &index_state.location).await?;
+                }
                 let block_meta = Arc::new(serialized.block_meta);
                 self.state = State::Output(Replacement::Replaced(block_meta));
             }
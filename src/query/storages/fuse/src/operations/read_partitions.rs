@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -32,6 +33,7 @@ use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_storage::ColumnNodes;
@@ -41,10 +43,12 @@ use sha2::Sha256;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
 use storages_common_index::Index;
+use storages_common_index::InvertedIndex;
 use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::table::widened_column_sources;
 use tracing::debug;
 use tracing::info;
 
@@ -181,6 +185,10 @@ impl FuseTable {
                 cluster_keys,
             )?
         };
+        let inverted_index_fields =
+            InvertedIndex::parse_options(&table_info.schema(), &table_info.meta.options)
+                .unwrap_or_default();
+        let pruner = pruner.with_inverted_index(inverted_index_fields);
 
         let block_metas = pruner.pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
@@ -435,6 +443,17 @@ impl FuseTable {
             return (statistics, partitions);
         }
 
+        // The columns that widened columns were stored as are deleted from the schema,
+        // but still read from the blocks written before the widening.
+        let widened_source_ids: HashSet<ColumnId> = schema
+            .map(|schema| {
+                widened_column_sources(schema)
+                    .values()
+                    .map(|source| source.column_id())
+                    .collect()
+            })
+            .unwrap_or_default();
+
         let mut remaining = limit;
         for (i, (block_meta_index, block_meta)) in block_metas.iter().enumerate() {
             let virtual_columns_meta =
@@ -446,6 +465,7 @@ impl FuseTable {
             let rows = block_meta.row_count as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
+                &widened_source_ids,
                 block_meta_index,
                 &top_k,
                 block_meta,
@@ -530,6 +550,7 @@ impl FuseTable {
 
     fn all_columns_part(
         schema: Option<&TableSchemaRef>,
+        widened_source_ids: &HashSet<ColumnId>,
         block_meta_index: &Option<BlockMetaIndex>,
         top_k: &Option<TopK>,
         meta: &BlockMeta,
@@ -540,7 +561,7 @@ impl FuseTable {
         for column_id in meta.col_metas.keys() {
             // ignore all deleted field
             if let Some(schema) = schema {
-                if schema.is_column_deleted(*column_id) {
+                if schema.is_column_deleted(*column_id) && !widened_source_ids.contains(column_id) {
                     continue;
                 }
             }
@@ -583,11 +604,28 @@ impl FuseTable {
         let mut columns_meta = HashMap::with_capacity(projection.len());
 
         let columns = projection.project_column_nodes(column_nodes).unwrap();
+        let mut has_missing_column = false;
         for column in &columns {
             for column_id in &column.leaf_column_ids {
                 // ignore column this block dose not exist
                 if let Some(column_meta) = meta.col_metas.get(column_id) {
                     columns_meta.insert(*column_id, column_meta.clone());
+                } else {
+                    has_missing_column = true;
+                }
+            }
+        }
+        // A column missing in the block may have been widened after the block was written,
+        // keep the deleted columns of the block, which it may be read from.
+        if has_missing_column {
+            let column_ids = column_nodes
+                .column_nodes
+                .iter()
+                .flat_map(|column| column.leaf_column_ids.iter())
+                .collect::<HashSet<_>>();
+            for (column_id, column_meta) in &meta.col_metas {
+                if !column_ids.contains(column_id) {
+                    columns_meta.insert(*column_id, column_meta.clone());
                 }
             }
         }
@@ -228,7 +228,7 @@ impl FuseTable {
         };
 
         let total_tasks = self
-            .mutation_block_pruning(ctx.clone(), filter, projection, base_snapshot)
+            .mutation_block_pruning(ctx.clone(), filter, projection, base_snapshot, None)
             .await?;
         if total_tasks != 0 {
             let max_threads =
@@ -246,6 +246,7 @@ impl FuseTable {
                         ops.clone(),
                         self.storage_format,
                         false,
+                        Arc::new(None),
                     )
                 },
                 max_threads,
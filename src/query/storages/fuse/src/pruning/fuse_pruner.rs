@@ -21,6 +21,7 @@ use common_catalog::plan::PushDownInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
 use common_expression::TableSchemaRef;
 use common_expression::SEGMENT_NAME_COL_NAME;
@@ -40,6 +41,7 @@ use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::table::widened_column_sources;
 use tracing::warn;
 
 use crate::pruning::segment_pruner::SegmentPruner;
@@ -47,7 +49,9 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -69,6 +73,7 @@ pub struct FusePruner {
     pub table_schema: TableSchemaRef,
     pub pruning_ctx: Arc<PruningContext>,
     pub push_down: Option<PushDownInfo>,
+    pub inverted_index_fields: Vec<FieldIndex>,
 }
 
 impl FusePruner {
@@ -106,6 +111,9 @@ impl FusePruner {
         // prepare the limiter. in case that limit is none, an unlimited limiter will be returned
         let limit_pruner = LimiterPrunerCreator::create(limit);
 
+        // A widened column missing in a block is read from the column it was stored as,
+        // rather than filled with the default value.
+        let widened_columns = widened_column_sources(&table_schema);
         let default_stats: StatisticsOfColumns = filter_expr
             .as_ref()
             .map(|f| f.column_refs())
@@ -113,6 +121,9 @@ impl FusePruner {
             .flatten()
             .filter_map(|(name, _)| {
                 let field = table_schema.field_with_name(&name).ok()?;
+                if widened_columns.contains_key(&field.column_id()) {
+                    return None;
+                }
                 let default_scalar = field_default_value(ctx.clone(), field).ok()?;
 
                 let stats = ColumnStatistics {
@@ -199,11 +210,18 @@ impl FusePruner {
             table_schema,
             push_down: push_down.clone(),
             pruning_ctx,
+            inverted_index_fields: vec![],
         })
     }
 
+    // Use the inverted index of the fields to prune blocks by `match`.
+    pub fn with_inverted_index(mut self, field_indices: Vec<FieldIndex>) -> Self {
+        self.inverted_index_fields = field_indices;
+        self
+    }
+
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> inverted index pruner -> vector pruner -> topn pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &self,
@@ -264,12 +282,58 @@ impl FusePruner {
                     metas.extend(worker?);
                 }
 
+                // Inverted index pruner.
+                let metas = self.inverted_index_pruning(metas).await?;
+
+                // Vector pruner.
+                let metas = self.vector_pruning(metas).await?;
+
                 // TopN pruner.
                 self.topn_pruning(metas)
             }
         }
     }
 
+    // inverted index pruner:
+    // if there are `match` filters on the columns with inverted index, use inverted index
+    #[async_backtrace::framed]
+    async fn inverted_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        if self.inverted_index_fields.is_empty() {
+            return Ok(metas);
+        }
+        let inverted_index_pruner = InvertedIndexPruner::try_create(
+            self.pruning_ctx.clone(),
+            &self.table_schema,
+            &self.push_down,
+            &self.inverted_index_fields,
+        )?;
+        match inverted_index_pruner {
+            Some(inverted_index_pruner) => inverted_index_pruner.pruning(metas).await,
+            None => Ok(metas),
+        }
+    }
+
+    // vector pruner:
+    // if there are ordering by the distance of vectors + limit clause and no filters, use vector index
+    #[async_backtrace::framed]
+    async fn vector_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let vector_pruner = VectorPruner::try_create(
+            self.pruning_ctx.clone(),
+            &self.table_schema,
+            &self.push_down,
+        )?;
+        match vector_pruner {
+            Some(vector_pruner) => vector_pruner.pruning(metas).await,
+            None => Ok(metas),
+        }
+    }
+
     // topn pruner:
     // if there are ordering + limit clause and no filters, use topn pruner
     fn topn_pruning(
@@ -0,0 +1,167 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use futures_util::future;
+use opendal::Operator;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CachedObject;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+use tracing::warn;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::PruningContext;
+
+/// Skip the blocks in which no row contains all the terms of `match(column, '<query>')`,
+/// by searching the inverted index of each block.
+pub struct InvertedIndexPruner {
+    pruning_ctx: Arc<PruningContext>,
+    func_ctx: FunctionContext,
+    filter_expr: Arc<Expr<String>>,
+    // name and column id of the indexed columns searched by the filter
+    columns: Arc<Vec<(String, ColumnId)>>,
+}
+
+impl InvertedIndexPruner {
+    pub fn try_create(
+        pruning_ctx: Arc<PruningContext>,
+        schema: &TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+        field_indices: &[FieldIndex],
+    ) -> Result<Option<Self>> {
+        let Some(filter) = push_down.as_ref().and_then(|p| p.filter.as_ref()) else {
+            return Ok(None);
+        };
+        let filter_expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+
+        let columns = InvertedIndex::find_match_columns(&filter_expr)
+            .into_iter()
+            .filter_map(|name| {
+                let field_index = schema.index_of(&name).ok()?;
+                field_indices
+                    .contains(&field_index)
+                    .then(|| (name, schema.field(field_index).column_id()))
+            })
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return Ok(None);
+        }
+
+        let func_ctx = pruning_ctx.ctx.get_function_context()?;
+        Ok(Some(Self {
+            pruning_ctx,
+            func_ctx,
+            filter_expr: Arc::new(filter_expr),
+            columns: Arc::new(columns),
+        }))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let pruning_runtime = &self.pruning_ctx.pruning_runtime;
+        let pruning_semaphore = &self.pruning_ctx.pruning_semaphore;
+
+        let pruning_tasks = metas.iter().enumerate().map(|(idx, (_, block_meta))| {
+            let dal = self.pruning_ctx.dal.clone();
+            let block_location = block_meta.location.0.clone();
+            let func_ctx = self.func_ctx.clone();
+            let filter_expr = self.filter_expr.clone();
+            let columns = self.columns.clone();
+            move |permit: OwnedSemaphorePermit| async move {
+                let _permit = permit;
+                match Self::load_indexes(&dal, &block_location, &columns).await {
+                    Ok(indexes) => {
+                        let result =
+                            InvertedIndex::apply(&func_ctx, filter_expr.as_ref().clone(), &indexes);
+                        (idx, result != FilterEvalResult::MustFalse)
+                    }
+                    Err(e) => {
+                        warn!(
+                            "failed to apply inverted index pruner, returning true. {}",
+                            e
+                        );
+                        (idx, true)
+                    }
+                }
+            }
+        });
+
+        let join_handlers = pruning_runtime
+            .try_spawn_batch_with_owned_semaphore(pruning_semaphore.clone(), pruning_tasks)
+            .await?;
+
+        let joint = future::try_join_all(join_handlers).await.map_err(|e| {
+            ErrorCode::StorageOther(format!("inverted index pruning failure, {}", e))
+        })?;
+
+        let mut keep = vec![false; metas.len()];
+        for (idx, keep_block) in joint {
+            keep[idx] = keep_block;
+        }
+        Ok(metas
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(meta, keep)| keep.then_some(meta))
+            .collect())
+    }
+
+    // Indexes of the columns in the block, the blocks written before the index was created have none.
+    //
+    // The indexes are cached by location, the index files are never rewritten.
+    async fn load_indexes(
+        dal: &Operator,
+        block_location: &str,
+        columns: &[(String, ColumnId)],
+    ) -> Result<HashMap<String, Arc<InvertedIndex>>> {
+        let cache = InvertedIndex::cache();
+        let mut indexes = HashMap::with_capacity(columns.len());
+        for (name, column_id) in columns {
+            let location =
+                TableMetaLocationGenerator::gen_inverted_index_location(block_location, *column_id);
+            if let Some(index) = cache.as_ref().and_then(|cache| cache.get(&location)) {
+                indexes.insert(name.clone(), index);
+                continue;
+            }
+            let data = match dal.read(&location).await {
+                Ok(data) => data,
+                Err(e) if e.kind() == opendal::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let index = Arc::new(InvertedIndex::deserialize(&data)?);
+            if let Some(cache) = &cache {
+                cache.put(location, index.clone());
+            }
+            indexes.insert(name.clone(), index);
+        }
+        Ok(indexes)
+    }
+}
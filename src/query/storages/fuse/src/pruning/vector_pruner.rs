@@ -0,0 +1,160 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::TableSchemaRef;
+use common_vector::HnswIndex;
+use common_vector::VectorDistance;
+use futures_util::future;
+use opendal::Operator;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+use tracing::warn;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::PruningContext;
+
+/// Keep the blocks holding the nearest rows of the constant vector,
+/// by searching the vector index of each block.
+pub struct VectorPruner {
+    pruning_ctx: Arc<PruningContext>,
+    distance: VectorDistance,
+    column_id: ColumnId,
+    query: Arc<Vec<f32>>,
+    limit: usize,
+}
+
+impl VectorPruner {
+    pub fn try_create(
+        pruning_ctx: Arc<PruningContext>,
+        schema: &TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+    ) -> Result<Option<Self>> {
+        let Some(push_down) = push_down else {
+            return Ok(None);
+        };
+        let Some(vector_index) = &push_down.vector_index else {
+            return Ok(None);
+        };
+        // The filter may remove the nearest rows.
+        if push_down.filter.is_some() || push_down.prewhere.is_some() {
+            return Ok(None);
+        }
+        let Some(distance) = VectorDistance::from_func_name(&vector_index.func_name) else {
+            return Ok(None);
+        };
+
+        let column_id = schema
+            .field_with_name(&vector_index.column_name)?
+            .column_id();
+        let query = vector_index.query.iter().map(|v| v.0).collect();
+        Ok(Some(Self {
+            pruning_ctx,
+            distance,
+            column_id,
+            query: Arc::new(query),
+            limit: vector_index.limit,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let pruning_runtime = &self.pruning_ctx.pruning_runtime;
+        let pruning_semaphore = &self.pruning_ctx.pruning_semaphore;
+
+        let pruning_tasks = metas.iter().enumerate().map(|(idx, (_, block_meta))| {
+            let dal = self.pruning_ctx.dal.clone();
+            let location = TableMetaLocationGenerator::gen_vector_index_location(
+                &block_meta.location.0,
+                self.column_id,
+            );
+            let distance = self.distance;
+            let query = self.query.clone();
+            let limit = self.limit;
+            move |permit: OwnedSemaphorePermit| async move {
+                let _permit = permit;
+                match Self::search(&dal, &location, distance, &query, limit).await {
+                    Ok(distances) => (idx, Some(distances)),
+                    Err(e) => {
+                        warn!("failed to apply vector pruner, returning true. {}", e);
+                        (idx, None)
+                    }
+                }
+            }
+        });
+
+        let join_handlers = pruning_runtime
+            .try_spawn_batch_with_owned_semaphore(pruning_semaphore.clone(), pruning_tasks)
+            .await?;
+
+        let joint = future::try_join_all(join_handlers)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("vector pruning failure, {}", e)))?;
+
+        // Blocks without index are always kept.
+        let mut keep = vec![false; metas.len()];
+        let mut candidates = vec![];
+        for (idx, distances) in joint {
+            match distances {
+                Some(distances) => candidates.extend(distances.into_iter().map(|d| (d, idx))),
+                None => keep[idx] = true,
+            }
+        }
+        // The rows of NULL are needed if there are not enough vectors.
+        if candidates.len() <= self.limit {
+            return Ok(metas);
+        }
+
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (_, idx) in candidates.iter().take(self.limit) {
+            keep[*idx] = true;
+        }
+        Ok(metas
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(meta, keep)| keep.then_some(meta))
+            .collect())
+    }
+
+    // Distances of the nearest vectors in the block.
+    async fn search(
+        dal: &Operator,
+        location: &str,
+        distance: VectorDistance,
+        query: &[f32],
+        limit: usize,
+    ) -> Result<Vec<f32>> {
+        let data = dal.read(location).await?;
+        let index = HnswIndex::deserialize(&data)?;
+        if index.distance() != distance {
+            return Err(ErrorCode::StorageOther(format!(
+                "vector index {} is built for {}, not {}",
+                location,
+                index.distance(),
+                distance
+            )));
+        }
+        let result = index.search(query, limit)?;
+        Ok(result.into_iter().map(|(_, distance)| distance).collect())
+    }
+}
@@ -15,6 +15,8 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_arrow::arrow::io::orc::format::proto::ColumnStatistics as OrcColumnStatistics;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
 use common_arrow::parquet::metadata::RowGroupMetaData;
 use common_arrow::parquet::statistics::BinaryStatistics;
 use common_arrow::parquet::statistics::BooleanStatistics;
@@ -31,6 +33,7 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_storage::orc_column_id;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
@@ -95,36 +98,151 @@ impl HiveBlockFilter {
                 }
             }
 
-            for (p_key, p_value) in part_columns {
-                if let Ok(idx) = self.data_schema.index_of(&p_key) {
-                    let mut null_count = 0;
-                    let v = if p_value == HIVE_DEFAULT_PARTITION {
-                        null_count = row_group.num_rows();
-                        Scalar::Null
-                    } else {
-                        Scalar::String(p_value.as_bytes().to_vec())
-                    };
+            return self.apply(filter, statistics, part_columns, row_group.num_rows());
+        }
+        false
+    }
 
-                    let col_stats = ColumnStatistics {
-                        min: v.clone(),
-                        max: v,
-                        null_count: null_count as u64,
-                        in_memory_size: 0,
-                        distinct_of_values: None,
-                    };
-                    statistics.insert(idx as u32, col_stats);
+    // true: stripe if filtered by predict
+    pub fn filter_orc_stripe(
+        &self,
+        metadata: &FileMetadata,
+        stripe: usize,
+        part_columns: HashMap<String, String>,
+    ) -> bool {
+        if let Some(filter) = &self.range_filter {
+            let num_rows = metadata.footer.stripes[stripe].number_of_rows() as usize;
+            let stripe_stats = match metadata.metadata.stripe_stats.get(stripe) {
+                None => return false,
+                Some(stats) => stats,
+            };
+            let mut statistics = StatisticsOfColumns::new();
+            for col in self.projections.iter() {
+                let stats = orc_column_id(metadata, col.name())
+                    .and_then(|id| stripe_stats.col_stats.get(id as usize));
+                if let Some(stats) = stats {
+                    if let Some((max, min)) = Self::get_orc_max_min_stats(col.data_type(), stats) {
+                        let null_count = num_rows as u64 - stats.number_of_values();
+                        let col_stats = ColumnStatistics {
+                            min,
+                            max,
+                            null_count,
+                            in_memory_size: 0,
+                            distinct_of_values: None,
+                        };
+                        if let Ok(idx) = self.data_schema.index_of(col.name()) {
+                            statistics.insert(idx as u32, col_stats);
+                        }
+                    }
                 }
             }
+            return self.apply(filter, statistics, part_columns, num_rows);
+        }
+        false
+    }
 
-            if let Ok(ret) = filter.apply(&statistics, |_| false) {
-                if !ret {
-                    return true;
-                }
+    // add the statistics of partition columns, then apply the range filter
+    fn apply(
+        &self,
+        filter: &RangeIndex,
+        mut statistics: StatisticsOfColumns,
+        part_columns: HashMap<String, String>,
+        num_rows: usize,
+    ) -> bool {
+        for (p_key, p_value) in part_columns {
+            if let Ok(idx) = self.data_schema.index_of(&p_key) {
+                let mut null_count = 0;
+                let v = if p_value == HIVE_DEFAULT_PARTITION {
+                    null_count = num_rows;
+                    Scalar::Null
+                } else {
+                    Scalar::String(p_value.as_bytes().to_vec())
+                };
+
+                let col_stats = ColumnStatistics {
+                    min: v.clone(),
+                    max: v,
+                    null_count: null_count as u64,
+                    in_memory_size: 0,
+                    distinct_of_values: None,
+                };
+                statistics.insert(idx as u32, col_stats);
+            }
+        }
+
+        if let Ok(ret) = filter.apply(&statistics, |_| false) {
+            if !ret {
+                return true;
             }
         }
         false
     }
 
+    fn get_orc_max_min_stats(
+        column_type: &TableDataType,
+        stats: &OrcColumnStatistics,
+    ) -> Option<(Scalar, Scalar)> {
+        match column_type {
+            TableDataType::Number(num_ty) if !num_ty.is_float() => {
+                let s = stats.int_statistics.as_ref()?;
+                let (max, min) = (s.maximum?, s.minimum?);
+                // The statistics out of the range of the column type disable the pruning,
+                // the values read are cast and don't match the statistics.
+                let scalar = |v: i64| match num_ty {
+                    NumberDataType::UInt8 => {
+                        Some(NumberType::<u8>::upcast_scalar(v.try_into().ok()?))
+                    }
+                    NumberDataType::UInt16 => {
+                        Some(NumberType::<u16>::upcast_scalar(v.try_into().ok()?))
+                    }
+                    NumberDataType::UInt32 => {
+                        Some(NumberType::<u32>::upcast_scalar(v.try_into().ok()?))
+                    }
+                    NumberDataType::UInt64 => {
+                        Some(NumberType::<u64>::upcast_scalar(v.try_into().ok()?))
+                    }
+                    NumberDataType::Int8 => {
+                        Some(NumberType::<i8>::upcast_scalar(v.try_into().ok()?))
+                    }
+                    NumberDataType::Int16 => {
+                        Some(NumberType::<i16>::upcast_scalar(v.try_into().ok()?))
+                    }
+                    NumberDataType::Int32 => {
+                        Some(NumberType::<i32>::upcast_scalar(v.try_into().ok()?))
+                    }
+                    _ => Some(NumberType::<i64>::upcast_scalar(v)),
+                };
+                Some((scalar(max)?, scalar(min)?))
+            }
+            TableDataType::Number(NumberDataType::Float32) => {
+                let s = stats.double_statistics.as_ref()?;
+                let max = NumberType::<F32>::upcast_scalar((s.maximum? as f32).into());
+                let min = NumberType::<F32>::upcast_scalar((s.minimum? as f32).into());
+                Some((max, min))
+            }
+            TableDataType::Number(NumberDataType::Float64) => {
+                let s = stats.double_statistics.as_ref()?;
+                let max = NumberType::<F64>::upcast_scalar(s.maximum?.into());
+                let min = NumberType::<F64>::upcast_scalar(s.minimum?.into());
+                Some((max, min))
+            }
+            TableDataType::String => {
+                let s = stats.string_statistics.as_ref()?;
+                let max = StringType::upcast_scalar(s.maximum.clone()?.into_bytes());
+                let min = StringType::upcast_scalar(s.minimum.clone()?.into_bytes());
+                Some((max, min))
+            }
+            TableDataType::Date => {
+                let s = stats.date_statistics.as_ref()?;
+                Some((Scalar::Date(s.maximum?), Scalar::Date(s.minimum?)))
+            }
+            TableDataType::Nullable(inner_ty) => {
+                Self::get_orc_max_min_stats(inner_ty.as_ref(), stats)
+            }
+            _ => None,
+        }
+    }
+
     fn get_max_min_stats(
         column_type: &TableDataType,
         stats: &dyn Statistics,
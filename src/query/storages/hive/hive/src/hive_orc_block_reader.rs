@@ -0,0 +1,239 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_catalog::plan::Projection;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_storage::infer_orc_schema;
+use common_storage::orc_stripe_range;
+use common_storage::read_orc_metadata_async;
+use common_storage::read_orc_stripe;
+use common_storage::read_orc_stripe_async;
+use common_storage::OrcRangeReader;
+use opendal::Operator;
+
+use crate::filter_hive_partition_from_partition_keys;
+use crate::HiveBlockFilter;
+use crate::HivePartInfo;
+use crate::HivePartitionFiller;
+
+/// An ORC file being read, only the tail and the current stripe of the file are read.
+pub struct HiveOrcFile {
+    pub part: HivePartInfo,
+    pub metadata: FileMetadata,
+    pub file_schema: ArrowSchema,
+    pub valid_stripes: Vec<usize>,
+    pub current_index: usize,
+    // the bytes of the current stripe
+    pub stripe_data: Option<OrcRangeReader>,
+}
+
+impl HiveOrcFile {
+    pub fn advance(&mut self) {
+        self.current_index += 1;
+        self.stripe_data = None;
+    }
+
+    pub fn has_stripes(&self) -> bool {
+        self.current_index < self.valid_stripes.len()
+    }
+
+    pub fn current_stripe(&self) -> usize {
+        self.valid_stripes[self.current_index]
+    }
+}
+
+#[derive(Clone)]
+pub struct HiveOrcBlockReader {
+    operator: Operator,
+    // the data columns, partition columns are not in the file
+    arrow_fields: Vec<Field>,
+    projected_schema: DataSchemaRef,
+    // data columns followed by partition columns
+    source_schema: DataSchemaRef,
+    output_schema: DataSchemaRef,
+    hive_partition_filler: Option<HivePartitionFiller>,
+}
+
+impl HiveOrcBlockReader {
+    pub fn create(
+        operator: Operator,
+        schema: TableSchemaRef,
+        projection: Projection,
+        partition_keys: &Option<Vec<String>>,
+    ) -> Result<Arc<HiveOrcBlockReader>> {
+        let original_projection = match projection {
+            Projection::Columns(projection) => projection,
+            Projection::InnerColumns(b) => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "not support inter columns in hive orc block reader,{:?}",
+                    b
+                )));
+            }
+        };
+        let output_schema =
+            DataSchemaRef::new(DataSchema::from(&schema.project(&original_projection)));
+
+        let (projection, partition_fields) = filter_hive_partition_from_partition_keys(
+            schema.clone(),
+            original_projection,
+            partition_keys,
+        );
+
+        let projected_table_schema = schema.project(&projection);
+        let arrow_fields = projected_table_schema.to_arrow().fields;
+        let projected_schema = DataSchemaRef::new(DataSchema::from(&projected_table_schema));
+
+        let mut source_fields = projected_schema.fields().clone();
+        source_fields.extend(partition_fields.iter().map(|f| f.into()));
+        let source_schema = DataSchemaRef::new(DataSchema::new(source_fields));
+
+        let hive_partition_filler = if !partition_fields.is_empty() {
+            Some(HivePartitionFiller::create(schema, partition_fields))
+        } else {
+            None
+        };
+
+        Ok(Arc::new(HiveOrcBlockReader {
+            operator,
+            arrow_fields,
+            projected_schema,
+            source_schema,
+            output_schema,
+            hive_partition_filler,
+        }))
+    }
+
+    /// Read the metadata of the file and keep the stripes which belong to the range of the part
+    /// and are not filtered by the block filter.
+    #[async_backtrace::framed]
+    pub async fn read_file(
+        &self,
+        part: HivePartInfo,
+        hive_block_filter: &HiveBlockFilter,
+    ) -> Result<HiveOrcFile> {
+        let metadata = read_orc_metadata_async(&self.operator, &part.filename, Some(part.filesize))
+            .await
+            .map_err(|e| e.add_message(format!(" filename of hive part {}", part.filename)))?;
+        let file_schema = infer_orc_schema(&metadata)
+            .map_err(|e| e.add_message(format!(" filename of hive part {}", part.filename)))?;
+
+        let mut valid_stripes = vec![];
+        let mut pruned_stripe_cnt = 0;
+        for idx in 0..metadata.footer.stripes.len() {
+            let range = orc_stripe_range(&metadata, idx);
+            let mid = range.start + (range.end - range.start) / 2;
+            if !part.range.contains(&mid) {
+                continue;
+            }
+            if hive_block_filter.filter_orc_stripe(&metadata, idx, part.get_partition_map()) {
+                pruned_stripe_cnt += 1;
+            } else {
+                valid_stripes.push(idx);
+            }
+        }
+        tracing::debug!(
+            "hive orc predict pushdown have pruned {} stripes",
+            pruned_stripe_cnt
+        );
+
+        Ok(HiveOrcFile {
+            part,
+            metadata,
+            file_schema,
+            valid_stripes,
+            current_index: 0,
+            stripe_data: None,
+        })
+    }
+
+    /// Read the byte range of the current stripe of the file.
+    #[async_backtrace::framed]
+    pub async fn read_stripe_data(&self, file: &mut HiveOrcFile) -> Result<()> {
+        let part = &file.part;
+        let data = read_orc_stripe_async(
+            &self.operator,
+            &part.filename,
+            part.filesize,
+            &file.metadata,
+            file.current_stripe(),
+        )
+        .await
+        .map_err(|e| e.add_message(format!(" filename of hive part {}", part.filename)))?;
+        file.stripe_data = Some(data);
+        Ok(())
+    }
+
+    /// Deserialize the current stripe of the file, the columns are in the order of the projection.
+    ///
+    /// The columns not in the file, e.g. added to the table after the file is written,
+    /// are filled with the default values.
+    pub fn read_stripe(&self, file: &mut HiveOrcFile) -> Result<DataBlock> {
+        let stripe = file.current_stripe();
+        let part = &file.part;
+        let num_rows = file.metadata.footer.stripes[stripe].number_of_rows() as usize;
+        let reader = file
+            .stripe_data
+            .as_mut()
+            .ok_or_else(|| ErrorCode::Internal("the orc stripe must be read"))?;
+        let arrays = read_orc_stripe(
+            reader,
+            &file.metadata,
+            &file.file_schema,
+            stripe,
+            &self.arrow_fields,
+        )
+        .map_err(|e| e.add_message(format!(" filename of hive part {}", part.filename)))?;
+
+        let columns = self
+            .projected_schema
+            .fields()
+            .iter()
+            .zip(arrays)
+            .map(|(field, array)| {
+                let data_type = field.data_type();
+                let column = match array {
+                    Some(array) => Column::from_arrow(array.as_ref(), data_type),
+                    None => ColumnBuilder::repeat(
+                        &Scalar::default_value(data_type).as_ref(),
+                        num_rows,
+                        data_type,
+                    )
+                    .build(),
+                };
+                BlockEntry::new(data_type.clone(), Value::Column(column))
+            })
+            .collect();
+        let block = DataBlock::new(columns, num_rows);
+        let block = match &self.hive_partition_filler {
+            Some(filler) => filler.fill_data(block, part, num_rows)?,
+            None => block,
+        };
+        block.resort(&self.source_schema, &self.output_schema)
+    }
+}
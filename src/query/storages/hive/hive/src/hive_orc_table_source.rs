@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+
+use crate::hive_orc_block_reader::HiveOrcBlockReader;
+use crate::hive_orc_block_reader::HiveOrcFile;
+use crate::HiveBlockFilter;
+use crate::HivePartInfo;
+
+enum State {
+    /// Read the metadata of the orc file and prune the stripes
+    /// IO bound
+    ReadFile(Option<PartInfoPtr>),
+
+    /// Read the bytes of the current stripe of the file
+    /// IO bound
+    ReadStripe(HiveOrcFile),
+
+    /// Deserialize the current stripe of the file
+    /// CPU bound
+    Deserialize(HiveOrcFile),
+
+    /// indicates that the data block is ready, and needs to be consumed
+    Generated(HiveOrcFile, DataBlock),
+    Finish,
+}
+
+pub struct HiveOrcTableSource {
+    state: State,
+    ctx: Arc<dyn TableContext>,
+    scan_progress: Arc<Progress>,
+    block_reader: Arc<HiveOrcBlockReader>,
+    output: Arc<OutputPort>,
+    hive_block_filter: Arc<HiveBlockFilter>,
+}
+
+impl HiveOrcTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        block_reader: Arc<HiveOrcBlockReader>,
+        hive_block_filter: Arc<HiveBlockFilter>,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        Ok(ProcessorPtr::create(Box::new(HiveOrcTableSource {
+            state: State::ReadFile(None),
+            ctx,
+            scan_progress,
+            block_reader,
+            output,
+            hive_block_filter,
+        })))
+    }
+
+    fn try_get_partitions(&mut self) {
+        match self.ctx.get_partition() {
+            None => self.state = State::Finish,
+            Some(part_info) => {
+                self.state = State::ReadFile(Some(part_info));
+            }
+        }
+    }
+
+    fn next_stripe(&mut self, file: HiveOrcFile) {
+        match file.has_stripes() {
+            true => self.state = State::ReadStripe(file),
+            false => self.try_get_partitions(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for HiveOrcTableSource {
+    fn name(&self) -> String {
+        "HiveOrcEngineSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if matches!(self.state, State::ReadFile(None)) {
+            self.try_get_partitions();
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if matches!(self.state, State::Generated(_, _)) {
+            if let State::Generated(mut file, data_block) =
+                std::mem::replace(&mut self.state, State::Finish)
+            {
+                file.advance();
+                self.next_stripe(file);
+                if !data_block.is_empty() {
+                    self.output.push_data(Ok(data_block));
+                    return Ok(Event::NeedConsume);
+                }
+            }
+        }
+
+        match self.state {
+            State::Finish => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+            State::ReadFile(_) | State::ReadStripe(_) => Ok(Event::Async),
+            State::Deserialize(_) => Ok(Event::Sync),
+            State::Generated(_, _) => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::Deserialize(mut file) => {
+                let data_block = self.block_reader.read_stripe(&mut file)?;
+                let progress_values = ProgressValues {
+                    rows: data_block.num_rows(),
+                    bytes: data_block.memory_size(),
+                };
+                self.scan_progress.incr(&progress_values);
+                self.state = State::Generated(file, data_block);
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::ReadFile(Some(part)) => {
+                let part = HivePartInfo::from_part(&part)?.clone();
+                let file = self
+                    .block_reader
+                    .read_file(part, &self.hive_block_filter)
+                    .await?;
+                self.next_stripe(file);
+                Ok(())
+            }
+            State::ReadStripe(mut file) => {
+                self.block_reader.read_stripe_data(&mut file).await?;
+                self.state = State::Deserialize(file);
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+}
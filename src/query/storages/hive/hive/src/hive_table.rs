@@ -60,8 +60,11 @@ use storages_common_table_meta::meta::StatisticsOfColumns;
 
 use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
+use super::hive_table_options::HiveFileFormat;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
+use crate::hive_orc_block_reader::HiveOrcBlockReader;
+use crate::hive_orc_table_source::HiveOrcTableSource;
 use crate::hive_parquet_block_reader::HiveBlockReader;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
@@ -175,6 +178,10 @@ impl HiveTable {
         plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        if self.table_options.file_format == HiveFileFormat::Orc {
+            return self.do_read_orc(ctx, plan, pipeline);
+        }
+
         let push_downs = &plan.push_downs;
         let chunk_size = ctx.get_settings().get_hive_parquet_chunk_size()? as usize;
 
@@ -233,6 +240,44 @@ impl HiveTable {
         Ok(())
     }
 
+    // The stripes of orc files are read without prewhere, the whole file is read at once.
+    fn do_read_orc(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        let projection = PushDownInfo::projection_of_push_downs(&plan.schema(), &plan.push_downs);
+        let block_reader = HiveOrcBlockReader::create(
+            self.dal.clone(),
+            self.table_info.schema(),
+            projection,
+            &self.table_options.partition_keys,
+        )?;
+        let hive_block_filter = self.get_block_filter(ctx.clone(), &plan.push_downs)?;
+
+        let mut source_builder = SourcePipeBuilder::create();
+        for _ in 0..std::cmp::max(1, max_threads) {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                HiveOrcTableSource::create(
+                    ctx.clone(),
+                    output,
+                    block_reader.clone(),
+                    hive_block_filter.clone(),
+                )?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
     // simple select query is the sql likes `select * from xx limit 10` or
     // `select * from xx where p_date = '20220201' limit 10` where p_date is a partition column;
     // we just need to read a few data from table
@@ -628,7 +673,7 @@ impl Table for HiveTable {
     }
 
     fn support_prewhere(&self) -> bool {
-        true
+        self.table_options.file_format == HiveFileFormat::Parquet
     }
 }
 
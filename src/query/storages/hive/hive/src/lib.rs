@@ -22,6 +22,8 @@ mod hive_catalog;
 mod hive_database;
 mod hive_file_splitter;
 mod hive_meta_data_reader;
+mod hive_orc_block_reader;
+mod hive_orc_table_source;
 mod hive_parquet_block_reader;
 mod hive_partition;
 mod hive_partition_filler;
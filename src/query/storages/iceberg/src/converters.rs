@@ -67,6 +67,21 @@ pub(super) fn schema_iceberg_to_databend(schema: &SchemaV2) -> TableSchema {
     TableSchema::new(fields)
 }
 
+/// iceberg field ids of the top level fields, in the order of the generated databend schema
+pub(crate) fn field_ids_iceberg(meta: &TableMetadata) -> Vec<i32> {
+    let meta = meta.clone().to_latest();
+    match meta.schemas.last() {
+        Some(scm) => scm
+            .struct_fields
+            .fields
+            .iter()
+            .map(|f| f.id)
+            .sorted()
+            .collect(),
+        None => vec![],
+    }
+}
+
 fn struct_field_iceberg_to_databend(sf: &StructField) -> TableField {
     let name = &sf.name;
     let ty = primitive_iceberg_to_databend(&sf.field_type);
@@ -84,7 +99,7 @@ fn primitive_iceberg_to_databend(prim: &AllType) -> TableDataType {
         iceberg_rs::model::schema::AllType::Primitive(p) => match p {
             iceberg_rs::model::schema::PrimitiveType::Boolean => TableDataType::Boolean,
             iceberg_rs::model::schema::PrimitiveType::Int => {
-                TableDataType::Number(NumberDataType::UInt32)
+                TableDataType::Number(NumberDataType::Int32)
             }
             iceberg_rs::model::schema::PrimitiveType::Long => {
                 TableDataType::Number(NumberDataType::Int64)
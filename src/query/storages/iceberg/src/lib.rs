@@ -97,8 +97,9 @@ mod database;
 #[allow(unused)]
 mod manifest;
 /// table metadata reader
-#[allow(unused)]
 mod meta_reader;
+/// pruning data files with iceberg statistics
+mod pruning;
 /// table implementation
 mod table;
 
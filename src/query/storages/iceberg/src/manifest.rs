@@ -12,56 +12,366 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Models of iceberg manifest list and manifest files.
+//!
+//! Both of them are avro files, the records are decoded from avro values directly,
+//! since iceberg encodes maps with integer keys as arrays of key-value records,
+//! which could not be mapped to rust maps by serde.
+
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+use crate::meta_reader::PartitionField;
+
+/// status of a manifest entry, deleted entries should not be read.
+pub(crate) const MANIFEST_ENTRY_DELETED: i32 = 2;
+
+/// content type of manifest or data files, only data files are supported now.
+pub(crate) const CONTENT_DATA: i32 = 0;
 
 /// item in manifest list file
 /// read manifest file by this struct
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug)]
 pub(crate) struct ManifestPtr {
     pub manifest_path: String,
-    pub manifest_length: usize,
-    pub partition_spec_id: usize,
-    pub added_snapshot_id: usize,
-    pub existing_data_files_count: usize,
-    pub deleted_data_files_count: usize,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    /// `0` for data manifests and `1` for delete manifests, always `0` in format v1
+    pub content: i32,
+    pub added_snapshot_id: i64,
+    pub added_data_files_count: Option<i32>,
+    pub existing_data_files_count: Option<i32>,
+    pub deleted_data_files_count: Option<i32>,
+    /// summaries of partition fields, in the order of the partition spec
     pub partitions: Vec<ManiPart>,
-    pub added_rows_count: usize,
-    pub existing_rows_count: usize,
-    pub deleted_rows_count: usize,
+    pub added_rows_count: Option<i64>,
+    pub existing_rows_count: Option<i64>,
+    pub deleted_rows_count: Option<i64>,
 }
 
 /// item of manifest spec in `ManifestPtr`
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug)]
 pub(crate) struct ManiPart {
     pub contains_null: bool,
-    pub lower_bound: String,
-    pub upper_bound: String,
+    pub contains_nan: Option<bool>,
+    /// binary single-value serialization of the lower bound
+    pub lower_bound: Option<Vec<u8>>,
+    /// binary single-value serialization of the upper bound
+    pub upper_bound: Option<Vec<u8>>,
 }
 
 /// manifest file
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug)]
 pub(crate) struct Manifest {
-    pub status: isize,
-    pub snapshot_id: i64,
+    /// partition fields of data files in this manifest
+    pub partition_spec: Vec<PartitionField>,
+    pub entries: Vec<ManifestEntry>,
+}
+
+/// item of manifest file
+#[derive(Clone, Debug)]
+pub(crate) struct ManifestEntry {
+    /// `0`: EXISTING, `1`: ADDED, `2`: DELETED
+    pub status: i32,
+    pub snapshot_id: Option<i64>,
     pub data_file: DataFile,
 }
 
 /// data file
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug)]
 pub(crate) struct DataFile {
+    /// `0` for data files, `1` for position deletes and `2` for equality deletes
+    pub content: i32,
     pub file_path: String,
     pub file_format: String,
-    pub partition: HashMap<String, u64>,
-    pub record_count: usize,
-    pub file_size_in_bytes: usize,
-    pub block_size_in_bytes: usize,
-    pub column_sizes: HashMap<u64, u64>,
-    pub value_counts: HashMap<u64, u64>,
-    pub null_value_counts: HashMap<u64, u64>,
-    pub lower_bounds: HashMap<u64, String>,
-    pub upper_bounds: HashMap<u64, String>,
-    pub key_metadata: Option<String>,
-    pub split_offsets: Vec<u64>,
+    /// partition values, in the order of the partition spec
+    pub partition: Vec<(String, Value)>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub column_sizes: HashMap<i32, i64>,
+    pub value_counts: HashMap<i32, i64>,
+    pub null_value_counts: HashMap<i32, i64>,
+    /// binary single-value serialization of lower bounds, keyed by field id
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    /// binary single-value serialization of upper bounds, keyed by field id
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+    pub split_offsets: Vec<i64>,
+}
+
+impl ManifestPtr {
+    fn try_from_avro(value: Value) -> Result<Self> {
+        let mut record = AvroRecord::try_new(value)?;
+        let partitions = match record.take_opt("partitions") {
+            Some(Value::Array(items)) => items
+                .into_iter()
+                .map(ManiPart::try_from_avro)
+                .collect::<Result<Vec<_>>>()?,
+            _ => vec![],
+        };
+        Ok(Self {
+            manifest_path: record.take_string("manifest_path")?,
+            manifest_length: record.take_long("manifest_length")?,
+            partition_spec_id: record.take_int("partition_spec_id")?,
+            content: record.take_opt_int("content").unwrap_or(CONTENT_DATA),
+            added_snapshot_id: record.take_long("added_snapshot_id")?,
+            added_data_files_count: record.take_opt_int("added_data_files_count"),
+            existing_data_files_count: record.take_opt_int("existing_data_files_count"),
+            deleted_data_files_count: record.take_opt_int("deleted_data_files_count"),
+            partitions,
+            added_rows_count: record.take_opt_long("added_rows_count"),
+            existing_rows_count: record.take_opt_long("existing_rows_count"),
+            deleted_rows_count: record.take_opt_long("deleted_rows_count"),
+        })
+    }
+}
+
+impl ManiPart {
+    fn try_from_avro(value: Value) -> Result<Self> {
+        let mut record = AvroRecord::try_new(value)?;
+        Ok(Self {
+            contains_null: record.take_bool("contains_null")?,
+            contains_nan: match record.take_opt("contains_nan") {
+                Some(Value::Boolean(b)) => Some(b),
+                _ => None,
+            },
+            lower_bound: record.take_opt_bytes("lower_bound"),
+            upper_bound: record.take_opt_bytes("upper_bound"),
+        })
+    }
+}
+
+impl ManifestEntry {
+    fn try_from_avro(value: Value) -> Result<Self> {
+        let mut record = AvroRecord::try_new(value)?;
+        Ok(Self {
+            status: record.take_int("status")?,
+            snapshot_id: record.take_opt_long("snapshot_id"),
+            data_file: DataFile::try_from_avro(record.take("data_file")?)?,
+        })
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.status == MANIFEST_ENTRY_DELETED
+    }
+}
+
+impl DataFile {
+    fn try_from_avro(value: Value) -> Result<Self> {
+        let mut record = AvroRecord::try_new(value)?;
+        let partition = match record.take("partition")? {
+            Value::Record(fields) => fields
+                .into_iter()
+                .map(|(name, value)| (name, unwrap_union(value)))
+                .collect(),
+            other => {
+                return Err(invalid_manifest(format!(
+                    "partition of data file should be a record, but got {other:?}"
+                )));
+            }
+        };
+        let split_offsets = match record.take_opt("split_offsets") {
+            Some(Value::Array(items)) => items
+                .into_iter()
+                .filter_map(|v| match v {
+                    Value::Long(l) => Some(l),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+        Ok(Self {
+            content: record.take_opt_int("content").unwrap_or(CONTENT_DATA),
+            file_path: record.take_string("file_path")?,
+            file_format: record.take_string("file_format")?,
+            partition,
+            record_count: record.take_long("record_count")?,
+            file_size_in_bytes: record.take_long("file_size_in_bytes")?,
+            column_sizes: record.take_int_map("column_sizes", as_long)?,
+            value_counts: record.take_int_map("value_counts", as_long)?,
+            null_value_counts: record.take_int_map("null_value_counts", as_long)?,
+            lower_bounds: record.take_int_map("lower_bounds", as_bytes)?,
+            upper_bounds: record.take_int_map("upper_bounds", as_bytes)?,
+            split_offsets,
+        })
+    }
+
+    pub fn is_parquet(&self) -> bool {
+        self.file_format.eq_ignore_ascii_case("parquet")
+    }
+}
+
+/// read all the manifest pointers in a manifest list file
+#[async_backtrace::framed]
+pub(crate) async fn read_manifest_list(op: &Operator, path: &str) -> Result<Vec<ManifestPtr>> {
+    let content = op.read(path).await?;
+    let reader = Reader::new(content.as_slice())
+        .map_err(|e| invalid_manifest(format!("invalid manifest list {path}: {e}")))?;
+    reader
+        .map(|value| {
+            value
+                .map_err(|e| invalid_manifest(format!("invalid manifest list {path}: {e}")))
+                .and_then(ManifestPtr::try_from_avro)
+        })
+        .collect()
+}
+
+/// read all the entries in a manifest file
+#[async_backtrace::framed]
+pub(crate) async fn read_manifest(op: &Operator, path: &str) -> Result<Manifest> {
+    let content = op.read(path).await?;
+    let reader = Reader::new(content.as_slice())
+        .map_err(|e| invalid_manifest(format!("invalid manifest {path}: {e}")))?;
+
+    // partition spec is stored in the key-value metadata of manifest files
+    let partition_spec = match reader.user_metadata().get("partition-spec") {
+        Some(spec) => serde_json::from_slice(spec).map_err(|e| {
+            invalid_manifest(format!("invalid partition spec in manifest {path}: {e}"))
+        })?,
+        None => vec![],
+    };
+
+    let entries = reader
+        .map(|value| {
+            value
+                .map_err(|e| invalid_manifest(format!("invalid manifest {path}: {e}")))
+                .and_then(ManifestEntry::try_from_avro)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Manifest {
+        partition_spec,
+        entries,
+    })
+}
+
+fn invalid_manifest(msg: impl Into<String>) -> ErrorCode {
+    ErrorCode::ReadTableDataError(msg.into())
+}
+
+/// optional fields are written as union with `null`
+fn unwrap_union(value: Value) -> Value {
+    match value {
+        Value::Union(_, inner) => *inner,
+        v => v,
+    }
+}
+
+fn as_long(value: Value) -> Option<i64> {
+    match value {
+        Value::Long(l) => Some(l),
+        Value::Int(i) => Some(i as i64),
+        _ => None,
+    }
+}
+
+fn as_bytes(value: Value) -> Option<Vec<u8>> {
+    match value {
+        Value::Bytes(b) | Value::Fixed(_, b) => Some(b),
+        _ => None,
+    }
+}
+
+/// helper to take named fields out of an avro record
+struct AvroRecord {
+    fields: HashMap<String, Value>,
+}
+
+impl AvroRecord {
+    fn try_new(value: Value) -> Result<Self> {
+        match unwrap_union(value) {
+            Value::Record(fields) => Ok(Self {
+                fields: fields.into_iter().collect(),
+            }),
+            other => Err(invalid_manifest(format!(
+                "expect an avro record, but got {other:?}"
+            ))),
+        }
+    }
+
+    /// take a field, `null` is treated as missing
+    fn take_opt(&mut self, name: &str) -> Option<Value> {
+        match self.fields.remove(name).map(unwrap_union) {
+            Some(Value::Null) | None => None,
+            v => v,
+        }
+    }
+
+    fn take(&mut self, name: &str) -> Result<Value> {
+        self.take_opt(name)
+            .ok_or_else(|| invalid_manifest(format!("missing required field {name}")))
+    }
+
+    fn take_string(&mut self, name: &str) -> Result<String> {
+        match self.take(name)? {
+            Value::String(s) => Ok(s),
+            other => Err(invalid_manifest(format!(
+                "field {name} should be a string, but got {other:?}"
+            ))),
+        }
+    }
+
+    fn take_bool(&mut self, name: &str) -> Result<bool> {
+        match self.take(name)? {
+            Value::Boolean(b) => Ok(b),
+            other => Err(invalid_manifest(format!(
+                "field {name} should be a boolean, but got {other:?}"
+            ))),
+        }
+    }
+
+    fn take_int(&mut self, name: &str) -> Result<i32> {
+        match self.take(name)? {
+            Value::Int(i) => Ok(i),
+            other => Err(invalid_manifest(format!(
+                "field {name} should be an int, but got {other:?}"
+            ))),
+        }
+    }
+
+    fn take_long(&mut self, name: &str) -> Result<i64> {
+        self.take(name).and_then(|v| {
+            as_long(v).ok_or_else(|| invalid_manifest(format!("field {name} should be a long")))
+        })
+    }
+
+    fn take_opt_int(&mut self, name: &str) -> Option<i32> {
+        match self.take_opt(name) {
+            Some(Value::Int(i)) => Some(i),
+            _ => None,
+        }
+    }
+
+    fn take_opt_long(&mut self, name: &str) -> Option<i64> {
+        self.take_opt(name).and_then(as_long)
+    }
+
+    fn take_opt_bytes(&mut self, name: &str) -> Option<Vec<u8>> {
+        self.take_opt(name).and_then(as_bytes)
+    }
+
+    /// maps with non-string keys are written as arrays of `key`, `value` records
+    fn take_int_map<T>(
+        &mut self,
+        name: &str,
+        f: impl Fn(Value) -> Option<T>,
+    ) -> Result<HashMap<i32, T>> {
+        let items = match self.take_opt(name) {
+            Some(Value::Array(items)) => items,
+            _ => return Ok(HashMap::new()),
+        };
+        let mut map = HashMap::with_capacity(items.len());
+        for item in items {
+            let mut kv = AvroRecord::try_new(item)?;
+            let key = kv.take_int("key")?;
+            if let Some(value) = kv.take_opt("value").and_then(&f) {
+                map.insert(key, value);
+            }
+        }
+        Ok(map)
+    }
 }
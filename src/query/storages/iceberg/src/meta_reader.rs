@@ -13,3 +13,143 @@
 // limitations under the License.
 
 //! this module contains metadata reader utilities for table metadata
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+
+/// parts of the table metadata file needed by table scans
+///
+/// compatible with both format v1 and v2
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct ScanMetadata {
+    /// base location of the table, all paths in metadata are prefixed by it
+    pub location: String,
+    /// `-1` or missing if the table has no snapshot
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<SnapshotMeta>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+}
+
+/// snapshot of table
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct SnapshotMeta {
+    pub snapshot_id: i64,
+    /// manifest list file, required by v2
+    pub manifest_list: Option<String>,
+    /// manifest files, only used by v1 tables without manifest list
+    #[serde(default)]
+    pub manifests: Vec<String>,
+}
+
+/// partition spec of table
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct PartitionSpec {
+    pub spec_id: i32,
+    #[serde(default)]
+    pub fields: Vec<PartitionField>,
+}
+
+/// field of partition spec
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct PartitionField {
+    /// the field id of source column in table schema
+    pub source_id: i32,
+    pub name: String,
+    pub transform: String,
+}
+
+impl PartitionField {
+    /// partition values of identity transform are the same as the source column
+    pub fn is_identity(&self) -> bool {
+        self.transform == "identity"
+    }
+}
+
+impl ScanMetadata {
+    pub fn try_from_slice(meta_json: &[u8]) -> Result<Self> {
+        serde_json::de::from_slice(meta_json)
+            .map_err(|e| ErrorCode::ReadTableDataError(format!("invalid table metadata: {:?}", e)))
+    }
+
+    /// get the current snapshot, `None` if the table is empty
+    pub fn current_snapshot(&self) -> Option<&SnapshotMeta> {
+        let current = self.current_snapshot_id.filter(|id| *id != -1)?;
+        self.snapshots.iter().find(|s| s.snapshot_id == current)
+    }
+
+    /// get fields of the partition spec, empty if the spec is not found
+    pub fn partition_spec(&self, spec_id: i32) -> &[PartitionField] {
+        self.partition_specs
+            .iter()
+            .find(|s| s.spec_id == spec_id)
+            .map(|s| s.fields.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// get the relative path from table root of a file in table
+    ///
+    /// files in iceberg tables are always recorded in absolute URIs
+    pub fn rel_path(&self, path: &str) -> Result<String> {
+        let location = self.location.trim_end_matches('/');
+        match path.strip_prefix(location) {
+            Some(rel) => Ok(rel.trim_start_matches('/').to_string()),
+            None => Err(ErrorCode::ReadTableDataError(format!(
+                "file {path} is not in table location {location}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanMetadata;
+
+    const METADATA_FILE: &str = r#"
+    {
+        "format-version": 1,
+        "location": "s3://testbucket/iceberg_data/iceberg_ctl/iceberg_db/iceberg_tbl",
+        "current-snapshot-id": 402819370943546960,
+        "snapshots": [
+            {
+                "snapshot-id": 8380191719297762539,
+                "manifest-list": "s3://testbucket/iceberg_data/iceberg_ctl/iceberg_db/iceberg_tbl/metadata/snap-1.avro"
+            },
+            {
+                "snapshot-id": 402819370943546960,
+                "parent-snapshot-id": 8380191719297762539,
+                "manifest-list": "s3://testbucket/iceberg_data/iceberg_ctl/iceberg_db/iceberg_tbl/metadata/snap-2.avro"
+            }
+        ]
+    }
+    "#;
+
+    #[test]
+    fn test_current_snapshot() {
+        let meta = ScanMetadata::try_from_slice(METADATA_FILE.as_bytes()).unwrap();
+        let snapshot = meta.current_snapshot().unwrap();
+        assert_eq!(snapshot.snapshot_id, 402819370943546960);
+
+        let manifest_list = meta
+            .rel_path(snapshot.manifest_list.as_ref().unwrap())
+            .unwrap();
+        assert_eq!(manifest_list, "metadata/snap-2.avro");
+
+        assert!(meta.rel_path("s3://other/metadata/snap-2.avro").is_err());
+    }
+
+    #[test]
+    fn test_empty_table() {
+        let meta = ScanMetadata::try_from_slice(
+            br#"{"location": "s3://bkt/tbl", "current-snapshot-id": -1}"#,
+        )
+        .unwrap();
+        assert!(meta.current_snapshot().is_none());
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! pruning manifests and data files with statistics recorded in iceberg metadata
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use apache_avro::types::Value;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::manifest::DataFile;
+use crate::manifest::ManiPart;
+use crate::meta_reader::PartitionField;
+
+/// column in databend table schema that an iceberg field maps to
+struct PrunableColumn {
+    column_id: ColumnId,
+    data_type: TableDataType,
+}
+
+/// prune manifests and data files with the pushed down filter
+///
+/// - manifests are pruned by the partition field summaries in manifest list
+/// - data files are pruned by the partition values and the `lower_bounds`/`upper_bounds`
+pub(crate) struct IcebergPruner {
+    /// iceberg field id -> column of table schema
+    columns: HashMap<i32, PrunableColumn>,
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+}
+
+impl IcebergPruner {
+    /// `field_ids` are the iceberg field ids of top level fields in `schema`, in the same order.
+    pub fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        schema: &TableSchemaRef,
+        field_ids: &[i32],
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Self> {
+        let filter = push_downs
+            .as_ref()
+            .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        let range_pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, schema, filter.as_ref())?;
+
+        let columns = schema
+            .fields()
+            .iter()
+            .zip(field_ids)
+            // only primitive columns have bounds
+            .filter(|(field, _)| field.leaf_column_ids().len() == 1)
+            .map(|(field, id)| {
+                (*id, PrunableColumn {
+                    column_id: field.column_id(),
+                    data_type: field.data_type().remove_nullable(),
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            columns,
+            range_pruner,
+        })
+    }
+
+    /// check if a manifest should be read, by the summaries of its partition fields
+    pub fn should_keep_manifest(&self, spec: &[PartitionField], partitions: &[ManiPart]) -> bool {
+        let mut stats = StatisticsOfColumns::new();
+        for (field, summary) in spec.iter().zip(partitions) {
+            if !field.is_identity() {
+                continue;
+            }
+            let column = match self.columns.get(&field.source_id) {
+                Some(column) => column,
+                None => continue,
+            };
+            let bounds = summary
+                .lower_bound
+                .as_ref()
+                .and_then(|v| decode_bound(&column.data_type, v))
+                .zip(
+                    summary
+                        .upper_bound
+                        .as_ref()
+                        .and_then(|v| decode_bound(&column.data_type, v)),
+                );
+            if let Some((min, max)) = bounds {
+                stats.insert(column.column_id, ColumnStatistics {
+                    min,
+                    max,
+                    null_count: summary.contains_null as u64,
+                    in_memory_size: 0,
+                    distinct_of_values: None,
+                });
+            }
+        }
+        stats.is_empty() || self.range_pruner.should_keep(&stats, None)
+    }
+
+    /// check if a data file should be read, by its partition values and column bounds
+    pub fn should_keep_data_file(&self, spec: &[PartitionField], data_file: &DataFile) -> bool {
+        let mut stats = StatisticsOfColumns::new();
+        for (field_id, column) in self.columns.iter() {
+            let bounds = data_file
+                .lower_bounds
+                .get(field_id)
+                .and_then(|v| decode_bound(&column.data_type, v))
+                .zip(
+                    data_file
+                        .upper_bounds
+                        .get(field_id)
+                        .and_then(|v| decode_bound(&column.data_type, v)),
+                );
+            if let Some((min, max)) = bounds {
+                // if null counts are missing, assume there are nulls
+                let null_count = data_file
+                    .null_value_counts
+                    .get(field_id)
+                    .copied()
+                    .unwrap_or(data_file.record_count);
+                stats.insert(column.column_id, ColumnStatistics {
+                    min,
+                    max,
+                    null_count: null_count as u64,
+                    in_memory_size: data_file.column_sizes.get(field_id).copied().unwrap_or(0)
+                        as u64,
+                    distinct_of_values: None,
+                });
+            }
+        }
+
+        // values of identity partitions are exactly the values of the source column
+        for (field, (_, value)) in spec.iter().zip(data_file.partition.iter()) {
+            if !field.is_identity() {
+                continue;
+            }
+            let column = match self.columns.get(&field.source_id) {
+                Some(column) => column,
+                None => continue,
+            };
+            let stat = match value {
+                Value::Null => Some(ColumnStatistics {
+                    min: Scalar::Null,
+                    max: Scalar::Null,
+                    null_count: data_file.record_count as u64,
+                    in_memory_size: 0,
+                    distinct_of_values: Some(0),
+                }),
+                v => decode_partition_value(&column.data_type, v).map(|v| ColumnStatistics {
+                    min: v.clone(),
+                    max: v,
+                    null_count: 0,
+                    in_memory_size: 0,
+                    distinct_of_values: Some(1),
+                }),
+            };
+            if let Some(stat) = stat {
+                stats.insert(column.column_id, stat);
+            }
+        }
+
+        stats.is_empty() || self.range_pruner.should_keep(&stats, None)
+    }
+}
+
+/// decode the binary single-value serialization of iceberg
+///
+/// returns `None` for unsupported types or malformed values, which disables pruning on the column.
+pub(crate) fn decode_bound(data_type: &TableDataType, bytes: &[u8]) -> Option<Scalar> {
+    let scalar = match data_type {
+        TableDataType::Boolean => Scalar::Boolean(*bytes.first()? != 0),
+        TableDataType::Number(NumberDataType::Int32) => Scalar::Number(NumberScalar::Int32(
+            i32::from_le_bytes(bytes.try_into().ok()?),
+        )),
+        TableDataType::Number(NumberDataType::Int64) => Scalar::Number(NumberScalar::Int64(
+            i64::from_le_bytes(bytes.try_into().ok()?),
+        )),
+        TableDataType::Number(NumberDataType::Float32) => Scalar::Number(NumberScalar::Float32(
+            F32::from(f32::from_le_bytes(bytes.try_into().ok()?)),
+        )),
+        TableDataType::Number(NumberDataType::Float64) => Scalar::Number(NumberScalar::Float64(
+            F64::from(f64::from_le_bytes(bytes.try_into().ok()?)),
+        )),
+        TableDataType::Date => Scalar::Date(i32::from_le_bytes(bytes.try_into().ok()?)),
+        TableDataType::Timestamp => Scalar::Timestamp(i64::from_le_bytes(bytes.try_into().ok()?)),
+        TableDataType::String => Scalar::String(bytes.to_vec()),
+        TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            // unscaled value in two's-complement big-endian, using the minimum number of bytes
+            if bytes.is_empty() || bytes.len() > 16 {
+                return None;
+            }
+            let fill = if bytes[0] & 0x80 != 0 { 0xff } else { 0 };
+            let mut buf = [fill; 16];
+            buf[16 - bytes.len()..].copy_from_slice(bytes);
+            Scalar::Decimal(DecimalScalar::Decimal128(i128::from_be_bytes(buf), *size))
+        }
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+/// decode the partition value of identity transforms
+fn decode_partition_value(data_type: &TableDataType, value: &Value) -> Option<Scalar> {
+    let scalar = match (data_type, value) {
+        (TableDataType::Boolean, Value::Boolean(v)) => Scalar::Boolean(*v),
+        (TableDataType::Number(NumberDataType::Int32), Value::Int(v)) => {
+            Scalar::Number(NumberScalar::Int32(*v))
+        }
+        (TableDataType::Number(NumberDataType::Int64), Value::Long(v)) => {
+            Scalar::Number(NumberScalar::Int64(*v))
+        }
+        (TableDataType::Number(NumberDataType::Float32), Value::Float(v)) => {
+            Scalar::Number(NumberScalar::Float32(F32::from(*v)))
+        }
+        (TableDataType::Number(NumberDataType::Float64), Value::Double(v)) => {
+            Scalar::Number(NumberScalar::Float64(F64::from(*v)))
+        }
+        (TableDataType::Date, Value::Date(v) | Value::Int(v)) => Scalar::Date(*v),
+        (TableDataType::Timestamp, Value::TimestampMicros(v) | Value::Long(v)) => {
+            Scalar::Timestamp(*v)
+        }
+        (TableDataType::String, Value::String(v)) => Scalar::String(v.as_bytes().to_vec()),
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use common_expression::types::decimal::DecimalScalar;
+    use common_expression::types::decimal::DecimalSize;
+    use common_expression::types::DecimalDataType;
+    use common_expression::types::NumberDataType;
+    use common_expression::types::NumberScalar;
+    use common_expression::Scalar;
+    use common_expression::TableDataType;
+
+    use super::decode_bound;
+
+    #[test]
+    fn test_decode_bound() {
+        let int = TableDataType::Number(NumberDataType::Int32);
+        assert_eq!(
+            decode_bound(&int, &[1, 0, 0, 0]),
+            Some(Scalar::Number(NumberScalar::Int32(1)))
+        );
+        // malformed length
+        assert_eq!(decode_bound(&int, &[1, 0]), None);
+
+        assert_eq!(
+            decode_bound(&TableDataType::String, b"abc"),
+            Some(Scalar::String(b"abc".to_vec()))
+        );
+
+        let size = DecimalSize {
+            precision: 10,
+            scale: 2,
+        };
+        let decimal = TableDataType::Decimal(DecimalDataType::Decimal128(size));
+        // -2 in the minimum bytes of two's-complement
+        assert_eq!(
+            decode_bound(&decimal, &[0xfe]),
+            Some(Scalar::Decimal(DecimalScalar::Decimal128(-2, size)))
+        );
+        assert_eq!(
+            decode_bound(&decimal, &[0x01, 0x00]),
+            Some(Scalar::Decimal(DecimalScalar::Decimal128(256, size)))
+        );
+
+        assert_eq!(decode_bound(&TableDataType::Variant, b"{}"), None);
+    }
+}
@@ -22,6 +22,8 @@ use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
@@ -29,33 +31,51 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
 use common_storage::DataOperator;
+use common_storage::StageFileInfo;
+use common_storage::StageFileStatus;
+use common_storages_parquet::ParquetTable;
 use futures::StreamExt;
 use iceberg_rs::model::table::TableMetadata;
 use opendal::Operator;
 
+use crate::converters::field_ids_iceberg;
 use crate::converters::meta_iceberg_to_databend;
+use crate::manifest::read_manifest;
+use crate::manifest::read_manifest_list;
+use crate::manifest::CONTENT_DATA;
+use crate::meta_reader::ScanMetadata;
+use crate::pruning::IcebergPruner;
 
 /// file marking the current version of metadata file
 const META_PTR: &str = "metadata/version_hint.text";
 
 /// accessor wrapper as a table
-#[allow(unused)]
 pub struct IcebergTable {
-    /// database that belongs to
-    database: String,
-    /// name of the current table
-    name: String,
     /// root of the table
-    tbl_root: DataOperator,
-    /// table metadata
-    manifests: TableMetadata,
+    op: Operator,
+    /// the latest version of table metadata
+    ///
+    /// it's only absent in tables created from `TableInfo` for reading data,
+    /// and will be loaded from storage if required.
+    version: Option<TableVersion>,
     /// table information
     info: TableInfo,
 }
 
+/// a version of table metadata
+struct TableVersion {
+    /// table metadata
+    manifests: TableMetadata,
+    /// parts of table metadata used by scanning
+    scan_meta: ScanMetadata,
+}
+
 impl IcebergTable {
     /// create a new table on the table directory
     #[async_backtrace::framed]
@@ -66,8 +86,48 @@ impl IcebergTable {
         tbl_root: DataOperator,
     ) -> Result<IcebergTable> {
         let op = tbl_root.operator();
+        let version = Self::load_version(&op).await?;
+
+        let sp = tbl_root.params();
+
+        // construct table info
+        let info = TableInfo {
+            ident: TableIdent::new(0, 0),
+            desc: format!("IcebergTable: '{database}'.'{table_name}'"),
+            name: table_name.to_string(),
+            meta: meta_iceberg_to_databend(catalog, &sp, &version.manifests),
+            ..Default::default()
+        };
+
+        // finish making table
+        Ok(Self {
+            op,
+            version: Some(version),
+            info,
+        })
+    }
+
+    /// create a table from table info, without loading any metadata
+    pub fn try_create(info: TableInfo) -> Result<IcebergTable> {
+        let sp = info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "storage params of iceberg table {} is missing",
+                info.name
+            ))
+        })?;
+        let op = init_operator(sp)?;
+        Ok(Self {
+            op,
+            version: None,
+            info,
+        })
+    }
+
+    /// load the latest version of table metadata
+    #[async_backtrace::framed]
+    async fn load_version(op: &Operator) -> Result<TableVersion> {
         // detect the latest manifest file
-        let latest_manifest = Self::version_detect(&op).await?;
+        let latest_manifest = Self::version_detect(op).await?;
         // get table metadata from metadata file
         let meta_json = op.read(&latest_manifest).await.map_err(|e| {
             ErrorCode::ReadTableDataError(format!(
@@ -75,32 +135,18 @@ impl IcebergTable {
                 &latest_manifest, e
             ))
         })?;
-        let metadata: TableMetadata =
+        let manifests: TableMetadata =
             serde_json::de::from_slice(meta_json.as_slice()).map_err(|e| {
                 ErrorCode::ReadTableDataError(format!(
                     "invalid metadata in {}: {:?}",
                     &latest_manifest, e
                 ))
             })?;
+        let scan_meta = ScanMetadata::try_from_slice(meta_json.as_slice())?;
 
-        let sp = tbl_root.params();
-
-        // construct table info
-        let info = TableInfo {
-            ident: TableIdent::new(0, 0),
-            desc: format!("IcebergTable: '{database}'.'{table_name}'"),
-            name: table_name.to_string(),
-            meta: meta_iceberg_to_databend(catalog, &sp, &metadata),
-            ..Default::default()
-        };
-
-        // finish making table
-        Ok(Self {
-            database: database.to_string(),
-            name: table_name.to_string(),
-            tbl_root,
-            manifests: metadata,
-            info,
+        Ok(TableVersion {
+            manifests,
+            scan_meta,
         })
     }
 
@@ -145,6 +191,139 @@ impl IcebergTable {
             .map(|s| format!("metadata/{s}"))
             .ok_or_else(|| ErrorCode::ReadTableDataError("Cannot get the latest manifest file"))
     }
+
+    /// walk through the manifest list and manifests of the current snapshot,
+    /// collecting data files that could not be pruned by the push downs.
+    #[async_backtrace::framed]
+    async fn list_data_files(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        version: &TableVersion,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Vec<StageFileInfo>> {
+        let scan_meta = &version.scan_meta;
+        let snapshot = match scan_meta.current_snapshot() {
+            Some(snapshot) => snapshot,
+            // table without any snapshot is empty
+            None => return Ok(vec![]),
+        };
+
+        let pruner = IcebergPruner::try_create(
+            ctx,
+            &self.info.schema(),
+            &field_ids_iceberg(&version.manifests),
+            push_downs,
+        )?;
+
+        let manifest_paths = match &snapshot.manifest_list {
+            Some(manifest_list) => {
+                let manifest_list = scan_meta.rel_path(manifest_list)?;
+                let mut paths = vec![];
+                for ptr in read_manifest_list(&self.op, &manifest_list).await? {
+                    if ptr.content != CONTENT_DATA {
+                        return Err(ErrorCode::Unimplemented(
+                            "Reading iceberg tables with delete files is not supported yet",
+                        ));
+                    }
+                    let spec = scan_meta.partition_spec(ptr.partition_spec_id);
+                    if pruner.should_keep_manifest(spec, &ptr.partitions) {
+                        paths.push(ptr.manifest_path);
+                    }
+                }
+                paths
+            }
+            None => snapshot.manifests.clone(),
+        };
+
+        let manifests = futures::future::try_join_all(manifest_paths.iter().map(|path| async {
+            let path = scan_meta.rel_path(path)?;
+            read_manifest(&self.op, &path).await
+        }))
+        .await?;
+
+        let mut total_files = 0;
+        let mut data_files = vec![];
+        for manifest in manifests {
+            for entry in manifest.entries {
+                if entry.is_deleted() {
+                    continue;
+                }
+                let data_file = entry.data_file;
+                if data_file.content != CONTENT_DATA {
+                    return Err(ErrorCode::Unimplemented(
+                        "Reading iceberg tables with delete files is not supported yet",
+                    ));
+                }
+                if !data_file.is_parquet() {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "Reading iceberg data files in format {} is not supported yet",
+                        data_file.file_format
+                    )));
+                }
+                total_files += 1;
+                if !pruner.should_keep_data_file(&manifest.partition_spec, &data_file) {
+                    continue;
+                }
+                data_files.push(StageFileInfo {
+                    path: scan_meta.rel_path(&data_file.file_path)?,
+                    size: data_file.file_size_in_bytes as u64,
+                    md5: None,
+                    last_modified: Default::default(),
+                    etag: None,
+                    status: StageFileStatus::NeedCopy,
+                    creator: None,
+                });
+            }
+        }
+
+        tracing::info!(
+            "iceberg table {} snapshot {}: {} of {} data files to read after pruning",
+            self.info.name,
+            snapshot.snapshot_id,
+            data_files.len(),
+            total_files
+        );
+        Ok(data_files)
+    }
+
+    /// data files of iceberg tables are read by the parquet table
+    fn create_parquet_table(&self, files: Vec<StageFileInfo>) -> Result<ParquetTable> {
+        let sp = self.info.meta.storage_params.clone().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "storage params of iceberg table {} is missing",
+                self.info.name
+            ))
+        })?;
+        Ok(ParquetTable::create_with_files(
+            StageInfo::new_external_stage(sp, "/"),
+            self.op.clone(),
+            self.info.clone(),
+            ParquetReadOptions::default(),
+            files,
+            vec![],
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let loaded;
+        let version = match &self.version {
+            Some(version) => version,
+            None => {
+                loaded = Self::load_version(&self.op).await?;
+                &loaded
+            }
+        };
+
+        let data_files = self.list_data_files(&ctx, version, &push_downs).await?;
+        self.create_parquet_table(data_files)?
+            .read_partitions(ctx, push_downs)
+            .await
+    }
 }
 
 #[async_trait]
@@ -165,12 +344,31 @@ impl Table for IcebergTable {
         &self.get_table_info().name
     }
 
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    fn support_prewhere(&self) -> bool {
+        ParquetReadOptions::default().do_prewhere()
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        todo!()
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        // partitions have been generated, the parquet table doesn't need to know the files.
+        self.create_parquet_table(vec![])?
+            .read_data(ctx, plan, pipeline)
     }
 }
@@ -45,6 +45,7 @@ use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
+use common_storage::parse_partition_values;
 
 use crate::parquet_part::ParquetPart;
 use crate::parquet_part::ParquetRowGroupPart;
@@ -86,6 +87,9 @@ pub struct ParquetDeserializeTransform {
     // Used for reading from small files
     source_reader: Arc<ParquetReader>,
     partition_pruner: PartitionPruner,
+
+    // The partition columns to output, the values are parsed from the file paths.
+    partition_columns: Vec<(String, DataType)>,
 }
 
 impl ParquetDeserializeTransform {
@@ -99,6 +103,7 @@ impl ParquetDeserializeTransform {
         prewhere_info: Option<ParquetPrewhereInfo>,
         source_reader: Arc<ParquetReader>,
         remain_reader: Arc<ParquetReader>,
+        partition_columns: Vec<(String, DataType)>,
         partition_pruner: PartitionPruner,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
@@ -119,6 +124,7 @@ impl ParquetDeserializeTransform {
                 source_reader,
                 remain_reader,
                 partition_pruner,
+                partition_columns,
             },
         )))
     }
@@ -145,11 +151,40 @@ impl ParquetDeserializeTransform {
         assert_eq!(part.files.len(), buffers.len());
         let mut blocks = Vec::new();
         for (path, data) in part.files.iter().zip(buffers.into_iter()) {
-            blocks.extend(self.process_small_file(path.0.as_str(), data)?);
+            for block in self.process_small_file(path.0.as_str(), data)? {
+                blocks.push(self.fill_partition_columns(block, path.0.as_str())?);
+            }
         }
         Ok(blocks)
     }
 
+    fn fill_partition_columns(&self, mut block: DataBlock, path: &str) -> Result<DataBlock> {
+        if self.partition_columns.is_empty() {
+            return Ok(block);
+        }
+
+        let names = self
+            .partition_columns
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        let values = parse_partition_values(path, &names);
+        for ((name, data_type), value) in self.partition_columns.iter().zip(values) {
+            let scalar = match value {
+                Some(value) => Scalar::String(value.into_bytes()),
+                None if data_type.is_nullable() => Scalar::Null,
+                None => {
+                    return Err(ErrorCode::StorageOther(format!(
+                        "File '{}' has no value of partition column {}",
+                        path, name
+                    )));
+                }
+            };
+            block.add_column(BlockEntry::new(data_type.clone(), Value::Scalar(scalar)));
+        }
+        block.resort(&self.src_schema, &self.output_schema)
+    }
+
     fn process_small_file(&mut self, path: &str, data: Vec<u8>) -> Result<Vec<DataBlock>> {
         let mut res = Vec::new();
         use opendal::services::Memory;
@@ -365,6 +400,7 @@ impl Processor for ParquetDeserializeTransform {
             match (&part, data) {
                 (ParquetPart::RowGroup(rg), ParquetPartData::RowGroup(mut reader)) => {
                     if let Some(block) = self.process_row_group(rg, &mut reader)? {
+                        let block = self.fill_partition_columns(block, &rg.location)?;
                         self.add_block(block)?;
                     }
                 }
@@ -20,6 +20,7 @@ use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
@@ -32,12 +33,63 @@ use crate::pruning::PartitionPruner;
 use crate::ParquetTable;
 
 impl ParquetTable {
+    /// Remove the partition columns from the push downs, they are not stored in the files.
+    pub(super) fn file_push_downs(&self, push_down: Option<PushDownInfo>) -> Option<PushDownInfo> {
+        if self.partition_columns.is_empty() {
+            return push_down;
+        }
+        let num_file_columns = self.arrow_schema.fields.len();
+        let file_projection = |projection: Option<Projection>| match projection {
+            None => Projection::Columns((0..num_file_columns).collect()),
+            Some(Projection::Columns(indices)) => Projection::Columns(
+                indices
+                    .into_iter()
+                    .filter(|i| *i < num_file_columns)
+                    .collect(),
+            ),
+            Some(Projection::InnerColumns(path_indices)) => Projection::InnerColumns(
+                path_indices
+                    .into_iter()
+                    .filter(|(i, _)| *i < num_file_columns)
+                    .collect(),
+            ),
+        };
+        let refs_partition_column = |expr: &RemoteExpr<String>| {
+            expr.as_expr(&BUILTIN_FUNCTIONS)
+                .column_refs()
+                .keys()
+                .any(|name| self.partition_columns.contains(name))
+        };
+
+        let mut push_down = push_down.unwrap_or_default();
+        push_down.projection = Some(file_projection(push_down.projection));
+        push_down.output_columns = push_down
+            .output_columns
+            .map(|output_columns| file_projection(Some(output_columns)));
+        if push_down
+            .filter
+            .as_ref()
+            .map_or(false, refs_partition_column)
+        {
+            push_down.filter = None;
+        }
+        if push_down
+            .order_by
+            .iter()
+            .any(|(expr, _, _)| refs_partition_column(expr))
+        {
+            push_down.order_by = vec![];
+        }
+        Some(push_down)
+    }
+
     pub(crate) fn create_pruner(
         &self,
         ctx: Arc<dyn TableContext>,
         push_down: Option<PushDownInfo>,
         is_small_file: bool,
     ) -> Result<PartitionPruner> {
+        let push_down = self.file_push_downs(push_down);
         let parquet_fast_read_bytes = if is_small_file {
             0_usize
         } else {
@@ -20,6 +20,7 @@ use common_catalog::plan::PushDownInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRefExt;
 use common_expression::Expr;
@@ -58,8 +59,8 @@ impl ParquetTable {
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let table_schema: TableSchemaRef = self.table_info.schema();
-        let source_projection =
-            PushDownInfo::projection_of_push_downs(&table_schema, &plan.push_downs);
+        let push_downs = self.file_push_downs(plan.push_downs.clone());
+        let source_projection = PushDownInfo::projection_of_push_downs(&table_schema, &push_downs);
 
         // The front of the src_fields are prewhere columns (if exist).
         // The back of the src_fields are remain columns.
@@ -83,12 +84,12 @@ impl ParquetTable {
             .unwrap_or_default();
 
         // Build prewhere info.
-        let mut push_down_prewhere = PushDownInfo::prewhere_of_push_downs(&plan.push_downs);
+        let mut push_down_prewhere = PushDownInfo::prewhere_of_push_downs(&push_downs);
 
         let top_k = if let Some((prewhere, top_k)) = push_down_prewhere.as_mut().zip(top_k) {
             // If there is a top k, we need to add the top k columns to the prewhere columns.
             if let RemoteExpr::<String>::ColumnRef { id, .. } =
-                &plan.push_downs.as_ref().unwrap().order_by[0].0
+                &push_downs.as_ref().unwrap().order_by[0].0
             {
                 let index = table_schema.index_of(id)?;
                 prewhere.remain_columns.remove_col(index);
@@ -139,6 +140,19 @@ impl ParquetTable {
             .transpose()?;
 
         src_fields.extend_from_slice(remain_reader.output_schema.fields());
+
+        // The partition columns are filled after deserializing.
+        let partition_columns = self
+            .partition_columns
+            .iter()
+            .filter_map(|name| {
+                let field = output_schema.field_with_name(name).ok()?;
+                Some((name.clone(), field.data_type().clone()))
+            })
+            .collect::<Vec<_>>();
+        for (name, data_type) in partition_columns.iter() {
+            src_fields.push(DataField::new(name, data_type.clone()));
+        }
         let src_schema = DataSchemaRefExt::create(src_fields);
         let is_blocking = self.operator.info().can_blocking();
 
@@ -167,7 +181,8 @@ impl ParquetTable {
                 prewhere_info.clone(),
                 source_reader.clone(),
                 remain_reader.clone(),
-                self.create_pruner(ctx.clone(), plan.push_downs.clone(), true)?,
+                partition_columns.clone(),
+                self.create_pruner(ctx.clone(), push_downs.clone(), true)?,
             )
         })
     }
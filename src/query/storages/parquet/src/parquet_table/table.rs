@@ -52,6 +52,9 @@ pub struct ParquetTable {
     pub(super) table_info: TableInfo,
     pub(super) arrow_schema: ArrowSchema,
     pub(super) files_to_read: Option<Vec<StageFileInfo>>,
+    /// The last columns of the table schema, they are not stored in the files,
+    /// the values are parsed from the hive style file paths (e.g. `year=2023/month=01/`).
+    pub(super) partition_columns: Vec<String>,
 }
 
 impl ParquetTable {
@@ -66,8 +69,42 @@ impl ParquetTable {
             stage_info: info.stage_info.clone(),
             files_info: info.files_info.clone(),
             files_to_read: info.files_to_read.clone(),
+            partition_columns: info.partition_columns.clone(),
         }))
     }
+
+    /// Create a parquet table reading the given files only.
+    ///
+    /// Used by table formats tracking their data files by themselves (e.g. Iceberg),
+    /// the schema is provided by the table format instead of inferring from the files.
+    /// The `partition_columns` must be the last columns of the schema.
+    pub fn create_with_files(
+        stage_info: StageInfo,
+        operator: Operator,
+        table_info: TableInfo,
+        read_options: ParquetReadOptions,
+        files_to_read: Vec<StageFileInfo>,
+        partition_columns: Vec<String>,
+    ) -> ParquetTable {
+        let schema = table_info.schema();
+        let num_file_columns = schema.fields().len() - partition_columns.len();
+        let arrow_schema =
+            TableSchema::new(schema.fields()[..num_file_columns].to_vec()).to_arrow();
+        ParquetTable {
+            read_options,
+            stage_info,
+            files_info: StageFilesInfo {
+                path: "/".to_string(),
+                files: None,
+                pattern: None,
+            },
+            operator,
+            table_info,
+            arrow_schema,
+            files_to_read: Some(files_to_read),
+            partition_columns,
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -89,7 +126,8 @@ impl Table for ParquetTable {
     }
 
     fn support_prewhere(&self) -> bool {
-        self.read_options.do_prewhere()
+        // The prewhere filter can not be evaluated on the partition columns.
+        self.read_options.do_prewhere() && self.partition_columns.is_empty()
     }
 
     fn has_exact_total_row_count(&self) -> bool {
@@ -104,6 +142,7 @@ impl Table for ParquetTable {
             stage_info: self.stage_info.clone(),
             files_info: self.files_info.clone(),
             files_to_read: self.files_to_read.clone(),
+            partition_columns: self.partition_columns.clone(),
         })
     }
 
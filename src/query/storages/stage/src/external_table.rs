@@ -0,0 +1,283 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ConstantFolder;
+use common_expression::Domain;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::EmptySource;
+use common_storage::init_stage_operator;
+use common_storage::parse_partition_values;
+use common_storage::StageFileInfo;
+use common_storages_parquet::ParquetTable;
+use common_users::UserApiProvider;
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use opendal::Operator;
+use parking_lot::Mutex;
+
+use crate::external_table_options::ExternalTableOptions;
+use crate::StageTable;
+
+/// The listed files of external tables, by table id.
+///
+/// The files are listed again if the version of the table changes,
+/// e.g. by `ALTER TABLE ... REFRESH`.
+static EXTERNAL_TABLE_FILES: Lazy<DashMap<u64, (u64, Arc<Vec<StageFileInfo>>)>> =
+    Lazy::new(DashMap::new);
+
+/// A persistent table over the files of a stage location.
+///
+/// The files are read by a parquet table or a stage table created in `read_partitions`.
+pub struct ExternalTable {
+    table_info: TableInfo,
+    options: ExternalTableOptions,
+    // The source of the table reading the files, set by `read_partitions`.
+    source_info: Mutex<Option<DataSourceInfo>>,
+}
+
+impl ExternalTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = ExternalTableOptions::try_from(&table_info.meta.engine_options)?;
+        Ok(Box::new(Self {
+            table_info,
+            options,
+            source_info: Mutex::new(None),
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "EXTERNAL".to_string(),
+            comment: "EXTERNAL Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&ExternalTable> {
+        tbl.as_any().downcast_ref::<ExternalTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine EXTERNAL, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    pub fn options(&self) -> &ExternalTableOptions {
+        &self.options
+    }
+
+    /// Get the stage of the table, the file format of the table overrides the one of the stage.
+    #[async_backtrace::framed]
+    pub async fn get_stage(
+        ctx: &dyn TableContext,
+        options: &ExternalTableOptions,
+    ) -> Result<StageInfo> {
+        let mut stage_info = UserApiProvider::instance()
+            .get_stage(&ctx.get_tenant(), &options.stage)
+            .await?;
+        stage_info.file_format_params = options.file_format.clone();
+        Ok(stage_info)
+    }
+
+    /// List the files of the table, the files are cached until the table is changed.
+    #[async_backtrace::framed]
+    pub async fn list_files(&self, operator: &Operator) -> Result<Arc<Vec<StageFileInfo>>> {
+        let table_id = self.table_info.ident.table_id;
+        let seq = self.table_info.ident.seq;
+        if let Some(entry) = EXTERNAL_TABLE_FILES.get(&table_id) {
+            if entry.0 == seq {
+                return Ok(entry.1.clone());
+            }
+        }
+
+        let files_info = self.options.files_info();
+        let files = if operator.info().can_blocking() {
+            files_info.blocking_list(operator, false, None)
+        } else {
+            files_info.list(operator, false, None).await
+        }?;
+        let files = Arc::new(files);
+        EXTERNAL_TABLE_FILES.insert(table_id, (seq, files.clone()));
+        Ok(files)
+    }
+
+    /// Remove the cached files of the table.
+    pub fn clear_files_cache(table_id: u64) {
+        EXTERNAL_TABLE_FILES.remove(&table_id);
+    }
+
+    // Skip the files whose partition values can not satisfy the filter.
+    fn prune_files(
+        &self,
+        ctx: &dyn TableContext,
+        files: &[StageFileInfo],
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Vec<StageFileInfo>> {
+        let partition_by = &self.options.partition_by;
+        let filter = push_downs.as_ref().and_then(|p| p.filter.as_ref());
+        let filter = match filter {
+            Some(filter) if !partition_by.is_empty() => filter.as_expr(&BUILTIN_FUNCTIONS),
+            _ => return Ok(files.to_vec()),
+        };
+
+        let column_refs = filter.column_refs();
+        if !partition_by.iter().any(|c| column_refs.contains_key(c)) {
+            return Ok(files.to_vec());
+        }
+
+        let func_ctx = ctx.get_function_context()?;
+        let mut results = HashMap::new();
+        let mut pruned = Vec::with_capacity(files.len());
+        for file in files {
+            let values = parse_partition_values(&file.path, partition_by);
+            let keep = match results.get(&values) {
+                Some(keep) => *keep,
+                None => {
+                    // The other columns may be any value.
+                    let mut domains = column_refs
+                        .iter()
+                        .map(|(name, ty)| (name.clone(), Domain::full(ty)))
+                        .collect::<HashMap<_, _>>();
+                    for (name, value) in partition_by.iter().zip(values.iter()) {
+                        let scalar = match (column_refs.get(name), value) {
+                            (Some(_), Some(value)) => Scalar::String(value.as_bytes().to_vec()),
+                            (Some(ty), None) if ty.is_nullable() => Scalar::Null,
+                            _ => continue,
+                        };
+                        domains.insert(name.clone(), scalar.as_ref().domain(&column_refs[name]));
+                    }
+                    let (folded, _) = ConstantFolder::fold_with_domain(
+                        &filter,
+                        &domains,
+                        &func_ctx,
+                        &BUILTIN_FUNCTIONS,
+                    );
+                    let keep = !matches!(folded, Expr::Constant {
+                        scalar: Scalar::Boolean(false) | Scalar::Null,
+                        ..
+                    });
+                    results.insert(values, keep);
+                    keep
+                }
+            };
+            if keep {
+                pruned.push(file.clone());
+            }
+        }
+        Ok(pruned)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ExternalTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    // Only the parquet files can be read by the cluster.
+    fn is_local(&self) -> bool {
+        !matches!(self.options.file_format, FileFormatParams::Parquet(_))
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        self.source_info
+            .lock()
+            .clone()
+            .unwrap_or_else(|| DataSourceInfo::TableSource(self.table_info.clone()))
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        matches!(self.options.file_format, FileFormatParams::Parquet(_))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let stage_info = Self::get_stage(ctx.as_ref(), &self.options).await?;
+        let operator = init_stage_operator(&stage_info)?;
+        let files = self.list_files(&operator).await?;
+        let files = self.prune_files(ctx.as_ref(), &files, &push_downs)?;
+
+        let table: Arc<dyn Table> = match &self.options.file_format {
+            FileFormatParams::Parquet(_) => Arc::new(ParquetTable::create_with_files(
+                stage_info,
+                operator,
+                self.table_info.clone(),
+                ParquetReadOptions::default(),
+                files,
+                self.options.partition_by.clone(),
+            )),
+            _ => StageTable::try_create(StageTableInfo {
+                schema: self.table_info.schema(),
+                stage_info,
+                files_info: self.options.files_info(),
+                files_to_copy: Some(files),
+                is_select: false,
+            })?,
+        };
+
+        let parts = table.read_partitions(ctx, push_downs).await?;
+        // The plan reads the data by the source info of the table.
+        *self.source_info.lock() = Some(table.get_data_source_info());
+        Ok(parts)
+    }
+
+    fn read_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        // The source of the plan is the table itself only if `read_partitions` is skipped,
+        // e.g. the filter is always false.
+        pipeline.add_source(EmptySource::create, 1)
+    }
+
+    #[async_backtrace::framed]
+    async fn truncate(&self, _ctx: Arc<dyn TableContext>, _: bool) -> Result<()> {
+        Err(ErrorCode::Unimplemented(format!(
+            "Cannot truncate external table {}, the files are not owned by the table",
+            self.table_info.name
+        )))
+    }
+}
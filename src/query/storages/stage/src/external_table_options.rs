@@ -0,0 +1,105 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::FileFormatParams;
+use common_storage::StageFilesInfo;
+
+pub const STAGE: &str = "stage";
+pub const PATH: &str = "path";
+pub const FILE_FORMAT: &str = "file_format";
+pub const PATTERN: &str = "pattern";
+pub const PARTITION_BY: &str = "partition_by";
+
+// represents the location of the files of an external table, kept in the engine options
+//
+// stage,  name of the stage, resolved when the table is read
+// path,  path of the files relative to the stage, such as: sales/2023/
+// file_format,  the file format params resolved when the table is created, in JSON
+// pattern,  regex of the files to read, all the files under the path if absent
+// partition_by,  partition columns parsed from the file paths, such as: "year,month"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalTableOptions {
+    pub stage: String,
+    pub path: String,
+    pub file_format: FileFormatParams,
+    pub pattern: Option<String>,
+    pub partition_by: Vec<String>,
+}
+
+impl ExternalTableOptions {
+    pub fn files_info(&self) -> StageFilesInfo {
+        StageFilesInfo {
+            path: self.path.clone(),
+            files: None,
+            pattern: self.pattern.clone(),
+        }
+    }
+
+    /// The location in `@stage/path` form.
+    pub fn location(&self) -> String {
+        format!("@{}/{}", self.stage, self.path)
+    }
+}
+
+impl TryFrom<ExternalTableOptions> for BTreeMap<String, String> {
+    type Error = ErrorCode;
+    fn try_from(options: ExternalTableOptions) -> Result<BTreeMap<String, String>> {
+        let mut map = BTreeMap::new();
+        map.insert(STAGE.to_string(), options.stage);
+        map.insert(PATH.to_string(), options.path);
+        let file_format = serde_json::to_string(&options.file_format)
+            .map_err(|e| ErrorCode::Internal(format!("Failed to serialize file format: {e}")))?;
+        map.insert(FILE_FORMAT.to_string(), file_format);
+        if let Some(pattern) = options.pattern {
+            map.insert(PATTERN.to_string(), pattern);
+        }
+        if !options.partition_by.is_empty() {
+            map.insert(PARTITION_BY.to_string(), options.partition_by.join(","));
+        }
+        Ok(map)
+    }
+}
+
+impl TryFrom<&BTreeMap<String, String>> for ExternalTableOptions {
+    type Error = ErrorCode;
+    fn try_from(options: &BTreeMap<String, String>) -> Result<ExternalTableOptions> {
+        let get = |key: &str| {
+            options.get(key).cloned().ok_or_else(|| {
+                ErrorCode::Internal(format!("External engine table missing {key} key"))
+            })
+        };
+
+        let file_format = serde_json::from_str(&get(FILE_FORMAT)?).map_err(|e| {
+            ErrorCode::Internal(format!(
+                "External engine table has invalid file format: {e}"
+            ))
+        })?;
+        let partition_by = match options.get(PARTITION_BY) {
+            Some(columns) => columns.split(',').map(str::to_string).collect(),
+            None => vec![],
+        };
+        Ok(ExternalTableOptions {
+            stage: get(STAGE)?,
+            path: get(PATH)?,
+            file_format,
+            pattern: options.get(PATTERN).cloned(),
+            partition_by,
+        })
+    }
+}
@@ -14,7 +14,11 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod external_table;
+mod external_table_options;
 mod stage_table;
 mod stage_table_sink;
 
+pub use external_table::ExternalTable;
+pub use external_table_options::ExternalTableOptions;
 pub use stage_table::StageTable;
@@ -38,6 +38,7 @@ mod user_mgr;
 mod user_setting;
 mod user_stage;
 mod user_udf;
+mod workload_group;
 
 pub mod file_format;
 pub mod idm_config;
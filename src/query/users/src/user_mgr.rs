@@ -21,6 +21,7 @@ use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 use common_meta_types::MatchSeq;
 
 use crate::role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
@@ -288,6 +289,31 @@ impl UserApiProvider {
         }
     }
 
+    // Update an user's quota.
+    #[async_backtrace::framed]
+    pub async fn update_user_quota(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        quota: UserQuota,
+    ) -> Result<Option<u64>> {
+        if self.get_configured_user(&user.username).is_some() {
+            return Err(ErrorCode::UserAlreadyExists(format!(
+                "Configured user {} cannot be updated",
+                user.username
+            )));
+        }
+        let client = self.get_user_api_client(tenant)?;
+        let update_user = client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| ui.quota = quota)
+            .await;
+
+        match update_user {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while alter user quota).")),
+        }
+    }
+
     // Update an user's default role
     #[async_backtrace::framed]
     pub async fn update_user_default_role(
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::WorkloadGroup;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new workload group.
+    #[async_backtrace::framed]
+    pub async fn add_workload_group(
+        &self,
+        tenant: &str,
+        group: WorkloadGroup,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.add_workload_group(group).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::WORKLOAD_GROUP_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Update a workload group.
+    #[async_backtrace::framed]
+    pub async fn update_workload_group(&self, tenant: &str, group: WorkloadGroup) -> Result<u64> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.update_workload_group(group, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while update workload group).")),
+        }
+    }
+
+    // Get a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(&self, tenant: &str, name: &str) -> Result<WorkloadGroup> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        Ok(client.get_workload_group(name, MatchSeq::GE(0)).await?.data)
+    }
+
+    // Get all workload groups for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &str) -> Result<Vec<WorkloadGroup>> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.get_workload_groups().await {
+            Err(e) => Err(e.add_message_back("(while get workload groups).")),
+            Ok(groups) => Ok(groups),
+        }
+    }
+
+    // Drop a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.drop_workload_group(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop workload group)"))
+                }
+            }
+        }
+    }
+
+    // Assign a user or a role to a workload group, or remove the assignment.
+    #[async_backtrace::framed]
+    pub async fn set_workload_group_assignment(
+        &self,
+        tenant: &str,
+        principal: &PrincipalIdentity,
+        group: Option<&str>,
+    ) -> Result<()> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        client.set_assignment(principal, group).await
+    }
+
+    // Get the workload group a user or a role is assigned to.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group_assignment(
+        &self,
+        tenant: &str,
+        principal: &PrincipalIdentity,
+    ) -> Result<Option<String>> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        client.get_assignment(principal).await
+    }
+}
@@ -0,0 +1,37 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=quota_password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=quota-user\npassword=${TEST_USER_PASSWORD}" >> quota_password.out
+
+## create user with a storage quota of 1 byte
+echo "create user 'quota-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD' WITH MAX_STORAGE_IN_BYTES = 1" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ALL ON default.* TO 'quota-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## the table is owned by the user
+echo "create table t20_0014(c int)" | $TEST_USER_CONNECT
+
+echo "select 'test -- insert by owner'"  | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014 values(1),(2)" | $TEST_USER_CONNECT 2>&1 | grep -o "Code: 2911"
+
+## the quota of owner is checked for other users too
+echo "select 'test -- insert by other user'"  | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014 values(1),(2)" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "Code: 2911"
+
+echo "select 'test -- replace by other user'"  | $MYSQL_CLIENT_CONNECT
+echo "replace into t20_0014 on(c) values(1),(2)" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "Code: 2911"
+
+## nothing is committed
+echo "select count(*) from t20_0014" | $MYSQL_CLIENT_CONNECT
+
+## no limit after the quota is removed
+echo "ALTER USER 'quota-user'@'$QUERY_MYSQL_HANDLER_HOST' WITH MAX_STORAGE_IN_BYTES = 0" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014 values(1),(2)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t20_0014" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table default.t20_0014 all" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'quota-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf quota_password.out
@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+DATABEND_QUERY="$CURDIR/../../../../target/${BUILD_PROFILE:-debug}/databend-query"
+WORKDIR=$(mktemp -d)
+
+cat > "$WORKDIR/people.csv" <<CSV
+"name","city, country"
+alice,"paris, france"
+bob,"tokyo, japan"
+CSV
+
+LOCAL="$DATABEND_QUERY --cmd local --data-path $WORKDIR/data --output-format csv --table people=$WORKDIR/people.csv"
+
+echo "--- sql"
+$LOCAL --sql "select name from people order by name; select \`city, country\` from people where name = 'bob'"
+
+echo "--- file"
+cat > "$WORKDIR/script.sql" <<SQL
+create table t(a int);
+insert into t values (1), (2);
+-- the ';' in strings and comments does not end the statement
+select a, 'x;y' from t order by a;
+SQL
+$LOCAL --file "$WORKDIR/script.sql"
+
+echo "--- stdin"
+echo "select count(*) from t; select count(*) from people" | $LOCAL
+
+echo "--- stop at the first error"
+$LOCAL --sql "select 1; select * from not_exists; select 2" 2>&1 | grep -c "Unknown table"
+
+echo "--- the registered tables are dropped on exit"
+$DATABEND_QUERY --cmd local --data-path "$WORKDIR/data" --output-format csv --sql "select count(*) from system.tables where name = 'people'; select count(*) from system.stages where name like 'local_table_%'"
+
+rm -rf "$WORKDIR"
@@ -0,0 +1,44 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+DATADIR_PATH="/tmp/data_05_07_01"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+cp "$CURDIR"/../../../../data/avro/users.avro ${DATADIR_PATH}/
+
+echo "drop table if exists test_avro" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_avro (
+  id          INT,
+  NAME        VARCHAR,
+  score       DOUBLE NULL,
+  tags        ARRAY(STRING),
+  created_at  TIMESTAMP,
+  missing     VARCHAR NULL
+) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop stage if exists stage_05_07_01;" | $MYSQL_CLIENT_CONNECT
+echo "create stage stage_05_07_01 url = 'fs://${DATADIR_PATH}/' FILE_FORMAT = (type = AVRO);" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy into table"
+echo "copy into test_avro from @stage_05_07_01 pattern = '.*avro';" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_avro order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---select stage"
+echo "select id, name, tags from @stage_05_07_01 where score is not null order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---infer_schema"
+echo "select * from infer_schema(location => '@stage_05_07_01', pattern => '.*avro')" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy into table with splits"
+cp "$CURDIR"/../../../../data/avro/numbers.avro ${DATADIR_PATH}/
+echo "drop table if exists test_avro_split" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_avro_split (id BIGINT, name VARCHAR) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_avro_split from @stage_05_07_01 files = ('numbers.avro') split_size = 1024;" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), count(distinct id), sum(id), min(name), max(name) from test_avro_split" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists test_avro" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_avro_split" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists stage_05_07_01;" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR_PATH}
@@ -0,0 +1,41 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+DATADIR_PATH="/tmp/data_05_09_01"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+cp "$CURDIR"/../../../../data/json/array.json ${DATADIR_PATH}/
+cp "$CURDIR"/../../../../data/json/nested.json ${DATADIR_PATH}/
+
+echo "drop table if exists test_json" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_json (
+  id          INT,
+  name        VARCHAR,
+  score       DOUBLE NULL,
+  tags        ARRAY(STRING)
+) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop stage if exists stage_05_09_01;" | $MYSQL_CLIENT_CONNECT
+echo "create stage stage_05_09_01 url = 'fs://${DATADIR_PATH}/' FILE_FORMAT = (type = JSON);" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy into table"
+echo "copy into test_json from @stage_05_09_01 files = ('array.json');" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_json from @stage_05_09_01 files = ('nested.json') file_format = (type = JSON json_path = '\$.data.items');" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_json order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---select stage"
+echo "select \$1:id, \$1:name from @stage_05_09_01 (files => ('array.json')) order by \$1:id" | $MYSQL_CLIENT_CONNECT
+
+echo "---strip_outer_array = false"
+echo "create file format json_no_strip_05_09_01 type = JSON strip_outer_array = false;" | $MYSQL_CLIENT_CONNECT
+echo "select \$1:meta:count from @stage_05_09_01 (files => ('nested.json'), file_format => 'json_no_strip_05_09_01')" | $MYSQL_CLIENT_CONNECT
+
+echo "---infer_schema"
+echo "select * from infer_schema(location => '@stage_05_09_01/array.json')" | $MYSQL_CLIENT_CONNECT
+
+echo "drop file format if exists json_no_strip_05_09_01;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_json" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists stage_05_09_01;" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR_PATH}
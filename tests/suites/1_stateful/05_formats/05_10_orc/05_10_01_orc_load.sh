@@ -0,0 +1,41 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+DATADIR_PATH="/tmp/data_05_10_01"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+cp "$CURDIR"/../../../../data/orc/numbers.orc ${DATADIR_PATH}/
+cp "$CURDIR"/../../../../data/orc/numbers_corrupted.orc ${DATADIR_PATH}/
+
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_orc (
+  id       BIGINT,
+  NAME     VARCHAR,
+  missing  VARCHAR NULL,
+  num      INT
+) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop stage if exists stage_05_10_01;" | $MYSQL_CLIENT_CONNECT
+echo "create stage stage_05_10_01 url = 'fs://${DATADIR_PATH}/' FILE_FORMAT = (type = ORC);" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy into table"
+echo "copy into test_orc from @stage_05_10_01 files = ('numbers.orc');" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_orc order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---infer_schema"
+echo "select * from infer_schema(location => '@stage_05_10_01', pattern => 'numbers.orc')" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy corrupted file on_error=abort"
+echo "truncate table test_orc" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_orc from @stage_05_10_01 files = ('numbers_corrupted.orc');" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "orc stripe 1"
+echo "select count(*) from test_orc" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy corrupted file on_error=continue"
+echo "copy into test_orc from @stage_05_10_01 files = ('numbers_corrupted.orc') on_error = continue;" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), min(id), max(id) from test_orc" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists stage_05_10_01;" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR_PATH}
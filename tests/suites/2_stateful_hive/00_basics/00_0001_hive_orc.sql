@@ -0,0 +1,3 @@
+select * from hive.default.t_orc order by id;
+select count(*), min(id), max(id) from hive.default.t_orc where id > 7;
+select name, extra from hive.default.t_orc where id = 3;